@@ -0,0 +1,102 @@
+use std::fmt;
+
+use crate::CountryCode;
+
+/// Countries writing `City, Region PostalCode` on one line instead of `PostalCode City`.
+const CITY_FIRST_COUNTRIES: &[&str] = &["AU", "CA", "US"];
+
+/// A validated postal address, obtained through [`crate::AddressInput::to_address`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Address {
+    pub(crate) lines: Vec<String>,
+    pub(crate) city: String,
+    pub(crate) region: Option<String>,
+    pub(crate) postal_code: Option<String>,
+    pub(crate) country: CountryCode,
+}
+
+impl Address {
+    /// Street lines, at least one.
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    pub fn city(&self) -> &str {
+        &self.city
+    }
+
+    pub fn region(&self) -> Option<&str> {
+        self.region.as_deref()
+    }
+
+    /// In the country's canonical form, e.g. `SW1A 1AA`.
+    pub fn postal_code(&self) -> Option<&str> {
+        self.postal_code.as_deref()
+    }
+
+    pub fn country(&self) -> CountryCode {
+        self.country
+    }
+
+    /// The address as it would be written on an envelope, one line per entry.
+    pub fn to_lines(&self) -> Vec<String> {
+        let mut output = self.lines.clone();
+        let postal_code = self.postal_code.as_deref().unwrap_or_default();
+        if CITY_FIRST_COUNTRIES.contains(&self.country.as_str()) {
+            let mut line = self.city.clone();
+            if let Some(region) = &self.region {
+                line.push_str(", ");
+                line.push_str(region);
+            }
+            if !postal_code.is_empty() {
+                line.push(' ');
+                line.push_str(postal_code);
+            }
+            output.push(line);
+        } else {
+            output.push(format!("{postal_code} {}", self.city).trim_start().to_owned());
+            output.extend(self.region.clone());
+        }
+        output.push(self.country.name().unwrap_or(self.country.as_str()).to_owned());
+        output
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_lines().join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn address(country: &str, region: Option<&str>, postal_code: Option<&str>) -> Address {
+        Address {
+            lines: vec!["1 Main Street".to_owned(), "Flat 2".to_owned()],
+            city: "Springfield".to_owned(),
+            region: region.map(str::to_owned),
+            postal_code: postal_code.map(str::to_owned),
+            country: CountryCode::new(country).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_to_lines() {
+        assert_eq!(
+            address("US", Some("IL"), Some("62701")).to_string(),
+            "1 Main Street\nFlat 2\nSpringfield, IL 62701\nUnited States"
+        );
+        assert_eq!(address("DE", None, Some("10115")).to_lines()[2..], [
+            "10115 Springfield",
+            "Germany"
+        ]);
+        assert_eq!(address("HK", None, None).to_lines()[2], "Springfield");
+        assert_eq!(address("ZZ", Some("North"), None).to_lines()[2..], [
+            "Springfield",
+            "North",
+            "ZZ"
+        ]);
+    }
+}
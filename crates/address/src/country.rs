@@ -0,0 +1,110 @@
+use std::fmt;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+/// Countries offered in address forms, sorted by name, with whether the region line is required.
+pub const COUNTRIES: &[(&str, &str, bool)] = &[
+    ("AU", "Australia", true),
+    ("AT", "Austria", false),
+    ("BE", "Belgium", false),
+    ("BR", "Brazil", true),
+    ("CA", "Canada", true),
+    ("CZ", "Czechia", false),
+    ("DK", "Denmark", false),
+    ("FI", "Finland", false),
+    ("FR", "France", false),
+    ("DE", "Germany", false),
+    ("HK", "Hong Kong", false),
+    ("IN", "India", true),
+    ("IE", "Ireland", false),
+    ("IT", "Italy", false),
+    ("JP", "Japan", true),
+    ("MX", "Mexico", true),
+    ("NL", "Netherlands", false),
+    ("NO", "Norway", false),
+    ("PL", "Poland", false),
+    ("PT", "Portugal", false),
+    ("QA", "Qatar", false),
+    ("ES", "Spain", false),
+    ("SE", "Sweden", false),
+    ("CH", "Switzerland", false),
+    ("AE", "United Arab Emirates", false),
+    ("GB", "United Kingdom", false),
+    ("US", "United States", true),
+];
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("invalid country code: expected two ASCII letters")]
+pub struct InvalidCountryCodeError;
+
+/// An ISO 3166-1 alpha-2 code such as `DE`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CountryCode([u8; 2]);
+
+impl CountryCode {
+    /// Accepts any case; the code is stored uppercase.
+    pub fn new(code: &str) -> Result<Self, InvalidCountryCodeError> {
+        let bytes: [u8; 2] = code.trim().as_bytes().try_into().map_err(|_| InvalidCountryCodeError)?;
+        if !bytes.iter().all(u8::is_ascii_alphabetic) {
+            return Err(InvalidCountryCodeError);
+        }
+        Ok(Self(bytes.map(|byte| byte.to_ascii_uppercase())))
+    }
+
+    pub fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.0).expect("country codes are ASCII")
+    }
+
+    /// The English name from [`COUNTRIES`], if listed.
+    pub fn name(&self) -> Option<&'static str> {
+        self.entry().map(|(_, name, _)| *name)
+    }
+
+    pub fn requires_region(&self) -> bool {
+        self.entry().is_some_and(|(_, _, requires_region)| *requires_region)
+    }
+
+    fn entry(&self) -> Option<&'static (&'static str, &'static str, bool)> {
+        COUNTRIES.iter().find(|(code, ..)| *code == self.as_str())
+    }
+}
+
+impl fmt::Display for CountryCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for CountryCode {
+    type Err = InvalidCountryCodeError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::new(input)
+    }
+}
+
+impl AsRef<str> for CountryCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_country_code() {
+        let germany = CountryCode::new("de").unwrap();
+        assert_eq!(germany.to_string(), "DE");
+        assert_eq!(germany.name(), Some("Germany"));
+        assert!(!germany.requires_region());
+        assert!("US".parse::<CountryCode>().unwrap().requires_region());
+        assert_eq!(CountryCode::new("ZZ").unwrap().name(), None);
+
+        assert_eq!(CountryCode::new("DEU"), Err(InvalidCountryCodeError));
+        assert_eq!(CountryCode::new("D1"), Err(InvalidCountryCodeError));
+        assert!(COUNTRIES.windows(2).all(|pair| pair[0].1 < pair[1].1));
+    }
+}
@@ -0,0 +1,207 @@
+use hypertext::{Buffer, Renderable};
+use zabawa_form_view::FormView;
+use zabawa_validation::{Validate, ValidationErrors};
+use zabawa_view_common::{Select, SelectOption, TextInput};
+
+use crate::{Address, COUNTRIES, CountryCode, normalize_postal_code, uses_postal_codes};
+
+pub const MAX_ADDRESS_FIELD_LENGTH: usize = 100;
+
+/// Raw address form values, as submitted.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AddressInput {
+    pub line1: String,
+    pub line2: String,
+    pub city: String,
+    pub region: String,
+    pub postal_code: String,
+    pub country: String,
+}
+
+impl AddressInput {
+    /// Validates the input and builds the [`Address`], with the postal code in canonical form.
+    pub fn to_address(&self) -> Result<Address, ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        let text = |errors: &mut ValidationErrors, field: &str, value: &str, required: bool| {
+            let value = value.trim();
+            if value.is_empty() {
+                if required {
+                    errors.add(field, "is required");
+                }
+                None
+            } else if value.chars().count() > MAX_ADDRESS_FIELD_LENGTH {
+                errors.add(field, format!("must be at most {MAX_ADDRESS_FIELD_LENGTH} characters"));
+                None
+            } else {
+                Some(value.to_owned())
+            }
+        };
+
+        let line1 = text(&mut errors, "line1", &self.line1, true);
+        let line2 = text(&mut errors, "line2", &self.line2, false);
+        let city = text(&mut errors, "city", &self.city, true);
+
+        let country = CountryCode::new(&self.country)
+            .ok()
+            .filter(|country| country.name().is_some());
+        if country.is_none() {
+            errors.add("country", "is not a supported country");
+        }
+        let requires_region = country.is_some_and(|country| country.requires_region());
+        let region = text(&mut errors, "region", &self.region, requires_region);
+
+        let postal_code = match country {
+            Some(country) if uses_postal_codes(country) => match normalize_postal_code(&self.postal_code, country) {
+                Ok(postal_code) => Some(postal_code),
+                Err(_) if self.postal_code.trim().is_empty() => {
+                    errors.add("postal_code", "is required");
+                    None
+                },
+                Err(error) => {
+                    errors.add("postal_code", error);
+                    None
+                },
+            },
+            _ => None,
+        };
+
+        errors.into_result()?;
+        Ok(Address {
+            lines: line1.into_iter().chain(line2).collect(),
+            city: city.unwrap_or_default(),
+            region,
+            postal_code,
+            country: country.expect("validated above"),
+        })
+    }
+}
+
+impl From<&Address> for AddressInput {
+    fn from(address: &Address) -> Self {
+        let mut lines = address.lines().iter();
+        Self {
+            line1: lines.next().cloned().unwrap_or_default(),
+            line2: lines.next().cloned().unwrap_or_default(),
+            city: address.city().to_owned(),
+            region: address.region().unwrap_or_default().to_owned(),
+            postal_code: address.postal_code().unwrap_or_default().to_owned(),
+            country: address.country().to_string(),
+        }
+    }
+}
+
+impl Validate for AddressInput {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        self.to_address().map(|_| ())
+    }
+}
+
+impl FormView for AddressInput {
+    fn render_fields<'a>(&'a self, errors: &'a ValidationErrors) -> impl Renderable + 'a {
+        AddressFields { input: self, errors }
+    }
+}
+
+struct AddressFields<'a> {
+    input: &'a AddressInput,
+    errors: &'a ValidationErrors,
+}
+
+impl Renderable for AddressFields<'_> {
+    fn render_to(&self, buffer: &mut Buffer) {
+        let Self { input, errors } = *self;
+        let selected = input.country.trim().to_ascii_uppercase();
+        let countries = COUNTRIES
+            .iter()
+            .map(|(code, name, _)| SelectOption::new(*code, *name).with_selected(*code == selected));
+
+        TextInput::new("line1", "Address line 1")
+            .with_required(true)
+            .render(&input.line1, errors)
+            .render_to(buffer);
+        TextInput::new("line2", "Address line 2")
+            .render(&input.line2, errors)
+            .render_to(buffer);
+        TextInput::new("city", "City")
+            .with_required(true)
+            .render(&input.city, errors)
+            .render_to(buffer);
+        TextInput::new("region", "State / province / region")
+            .render(&input.region, errors)
+            .render_to(buffer);
+        TextInput::new("postal_code", "Postal code")
+            .render(&input.postal_code, errors)
+            .render_to(buffer);
+        Select::new("country", "Country")
+            .with_placeholder("Select a country")
+            .with_required(true)
+            .render(countries, errors)
+            .render_to(buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zabawa_form_view::Form;
+    use zabawa_view_common::RenderContext;
+    use zabawa_view_common::testing::{assert_has_selector, assert_selector_count};
+
+    use super::*;
+
+    fn input(country: &str, region: &str, postal_code: &str) -> AddressInput {
+        AddressInput {
+            line1: " 10 Downing Street ".to_owned(),
+            city: "London".to_owned(),
+            region: region.to_owned(),
+            postal_code: postal_code.to_owned(),
+            country: country.to_owned(),
+            ..AddressInput::default()
+        }
+    }
+
+    #[test]
+    fn test_to_address() {
+        let address = input("gb", "", "sw1a2aa").to_address().unwrap();
+        assert_eq!(address.lines(), ["10 Downing Street"]);
+        assert_eq!(address.postal_code(), Some("SW1A 2AA"));
+        assert_eq!(address.region(), None);
+        assert_eq!(address.country().as_str(), "GB");
+        assert_eq!(AddressInput::from(&address).to_address(), Ok(address));
+
+        let address = input("HK", "", "").to_address().unwrap();
+        assert_eq!(address.postal_code(), None);
+
+        let errors = input("US", "", "1234").validate().unwrap_err();
+        assert_eq!(errors.field("region").collect::<Vec<_>>(), ["is required"]);
+        assert_eq!(errors.field("postal_code").collect::<Vec<_>>(), [
+            "invalid postal code for US"
+        ]);
+
+        let errors = AddressInput::default().validate().unwrap_err();
+        for field in ["line1", "city", "country"] {
+            assert!(errors.has_field(field), "{field}");
+        }
+        assert!(!errors.has_field("postal_code"));
+
+        let errors = input("DE", "", "").validate().unwrap_err();
+        assert_eq!(errors.field("postal_code").collect::<Vec<_>>(), ["is required"]);
+        assert!(input("XX", "", "1").validate().unwrap_err().has_field("country"));
+    }
+
+    #[test]
+    fn test_render_fields() {
+        let ctx = RenderContext::new();
+        let input = input("DE", "", "");
+        let errors = input.validate().unwrap_err();
+        let html = Form::new("/address")
+            .render(&ctx, &input, &errors)
+            .render()
+            .into_inner();
+
+        assert_selector_count(&html, "form wa-input", 5);
+        assert_has_selector(&html, "wa-input[name=line1][value=\" 10 Downing Street \"][required]");
+        assert_has_selector(&html, "wa-input[name=postal_code][data-invalid]");
+        assert_has_selector(&html, "wa-select[name=country] wa-option[value=DE][selected]");
+        assert_selector_count(&html, "wa-select[name=country] wa-option", COUNTRIES.len());
+    }
+}
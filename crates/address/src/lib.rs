@@ -0,0 +1,9 @@
+pub mod address;
+pub mod country;
+pub mod form;
+pub mod postal_code;
+
+pub use self::address::*;
+pub use self::country::*;
+pub use self::form::*;
+pub use self::postal_code::*;
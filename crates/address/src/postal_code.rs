@@ -0,0 +1,126 @@
+use thiserror::Error;
+
+use crate::CountryCode;
+
+const MAX_UNKNOWN_POSTAL_CODE_LENGTH: usize = 12;
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("invalid postal code for {country}")]
+pub struct InvalidPostalCodeError {
+    pub country: CountryCode,
+}
+
+/// Postal code formats per country. In patterns `9` is a digit, `A` a letter, `X` either, and anything else a
+/// separator that users may omit or replace with a space.
+const POSTAL_CODE_PATTERNS: &[(&str, &[&str])] = &[
+    ("AT", &["9999"]),
+    ("AU", &["9999"]),
+    ("BE", &["9999"]),
+    ("BR", &["99999-999"]),
+    ("CA", &["A9A 9A9"]),
+    ("CH", &["9999"]),
+    ("CZ", &["999 99"]),
+    ("DE", &["99999"]),
+    ("DK", &["9999"]),
+    ("ES", &["99999"]),
+    ("FI", &["99999"]),
+    ("FR", &["99999"]),
+    ("GB", &[
+        "A9 9AA", "A99 9AA", "AA9 9AA", "AA99 9AA", "A9A 9AA", "AA9A 9AA",
+    ]),
+    ("IE", &["A9X XXXX"]),
+    ("IN", &["999999"]),
+    ("IT", &["99999"]),
+    ("JP", &["999-9999"]),
+    ("MX", &["99999"]),
+    ("NL", &["9999 AA"]),
+    ("NO", &["9999"]),
+    ("PL", &["99-999"]),
+    ("PT", &["9999-999"]),
+    ("SE", &["999 99"]),
+    ("US", &["99999", "99999-9999"]),
+];
+
+/// Countries that do not use postal codes; a given code is ignored rather than rejected.
+const WITHOUT_POSTAL_CODES: &[&str] = &["AE", "HK", "QA"];
+
+pub fn uses_postal_codes(country: CountryCode) -> bool {
+    !WITHOUT_POSTAL_CODES.contains(&country.as_str())
+}
+
+/// Validates a postal code against the country's formats and returns it in canonical form, e.g. `sw1a1aa` becomes
+/// `SW1A 1AA` for `GB`. Countries without known rules accept up to 12 letters, digits, spaces and hyphens.
+pub fn normalize_postal_code(input: &str, country: CountryCode) -> Result<String, InvalidPostalCodeError> {
+    let error = InvalidPostalCodeError { country };
+    let compact: String = input
+        .chars()
+        .filter(|ch| !ch.is_whitespace() && *ch != '-')
+        .map(|ch| ch.to_ascii_uppercase())
+        .collect();
+    if compact.is_empty() {
+        return Err(error);
+    }
+
+    let Some((_, patterns)) = POSTAL_CODE_PATTERNS.iter().find(|(code, _)| *code == country.as_str()) else {
+        let input = input.trim().to_ascii_uppercase();
+        let valid = input.len() <= MAX_UNKNOWN_POSTAL_CODE_LENGTH
+            && input
+                .chars()
+                .all(|ch| ch.is_ascii_alphanumeric() || ch == ' ' || ch == '-');
+        return if valid { Ok(input) } else { Err(error) };
+    };
+
+    patterns
+        .iter()
+        .find_map(|pattern| apply_pattern(&compact, pattern))
+        .ok_or(error)
+}
+
+fn apply_pattern(compact: &str, pattern: &str) -> Option<String> {
+    let mut input = compact.chars();
+    let mut output = String::with_capacity(pattern.len());
+    for slot in pattern.chars() {
+        let matches = match slot {
+            '9' => char::is_ascii_digit,
+            'A' => char::is_ascii_alphabetic,
+            'X' => char::is_ascii_alphanumeric,
+            separator => {
+                output.push(separator);
+                continue;
+            },
+        };
+        let ch = input.next().filter(matches)?;
+        output.push(ch);
+    }
+    input.next().is_none().then_some(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn normalize(input: &str, country: &str) -> Result<String, InvalidPostalCodeError> {
+        normalize_postal_code(input, CountryCode::new(country).unwrap())
+    }
+
+    #[test]
+    fn test_normalize_postal_code() {
+        assert_eq!(normalize("sw1a1aa", "GB").as_deref(), Ok("SW1A 1AA"));
+        assert_eq!(normalize("M1 1AE", "GB").as_deref(), Ok("M1 1AE"));
+        assert_eq!(normalize("k1a0b1", "CA").as_deref(), Ok("K1A 0B1"));
+        assert_eq!(normalize("00950", "PL").as_deref(), Ok("00-950"));
+        assert_eq!(normalize("00 950", "PL").as_deref(), Ok("00-950"));
+        assert_eq!(normalize(" 10115 ", "DE").as_deref(), Ok("10115"));
+        assert_eq!(normalize("94103-1234", "US").as_deref(), Ok("94103-1234"));
+        assert_eq!(normalize("1234 ab", "NL").as_deref(), Ok("1234 AB"));
+        assert_eq!(normalize("ab-12 c", "ZZ").as_deref(), Ok("AB-12 C"));
+
+        assert!(normalize("1011", "DE").is_err());
+        assert!(normalize("101155", "DE").is_err());
+        assert!(normalize("9410", "US").is_err());
+        assert!(normalize("SW1A", "GB").is_err());
+        assert!(normalize("", "FR").is_err());
+        assert!(normalize("12/34", "ZZ").is_err());
+        assert!(!uses_postal_codes(CountryCode::new("HK").unwrap()));
+    }
+}
@@ -0,0 +1,3 @@
+pub mod logic;
+pub mod model;
+pub mod view;
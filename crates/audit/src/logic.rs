@@ -0,0 +1,174 @@
+use std::borrow::Cow;
+use std::cmp::Reverse;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use zabawa_pagination::{Page, PageRequest};
+
+use crate::model::AuditEntry;
+
+/// Narrows an audit log listing; unset fields match everything.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuditFilter {
+    pub actor_id: Option<String>,
+    pub action: Option<String>,
+    pub entity: Option<(Cow<'static, str>, String)>,
+}
+
+impl AuditFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_actor_id(mut self, actor_id: impl Into<String>) -> Self {
+        self.actor_id = Some(actor_id.into());
+        self
+    }
+
+    pub fn with_action(mut self, action: impl Into<String>) -> Self {
+        self.action = Some(action.into());
+        self
+    }
+
+    pub fn with_entity(mut self, entity_type: impl Into<Cow<'static, str>>, entity_id: impl Into<String>) -> Self {
+        self.entity = Some((entity_type.into(), entity_id.into()));
+        self
+    }
+
+    pub fn matches(&self, entry: &AuditEntry) -> bool {
+        self.actor_id
+            .as_deref()
+            .is_none_or(|actor_id| entry.actor.id() == Some(actor_id))
+            && self.action.as_deref().is_none_or(|action| entry.action == action)
+            && self.entity.as_ref().is_none_or(|(entity_type, entity_id)| {
+                entry.entity_type == *entity_type && entry.entity_id == *entity_id
+            })
+    }
+}
+
+/// An append-only audit log. Entries are never updated or removed through this trait.
+pub trait AuditStore {
+    fn append(&self, entry: AuditEntry);
+
+    /// Lists matching entries, newest first. Cursor requests continue after the entry id in the cursor.
+    fn list(&self, filter: &AuditFilter, request: PageRequest) -> Page<AuditEntry>;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T: AuditStore + ?Sized> AuditStore for Arc<T> {
+    fn append(&self, entry: AuditEntry) {
+        (**self).append(entry)
+    }
+
+    fn list(&self, filter: &AuditFilter, request: PageRequest) -> Page<AuditEntry> {
+        (**self).list(filter, request)
+    }
+
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct InMemoryAuditStore {
+    entries: Mutex<Vec<AuditEntry>>,
+}
+
+impl InMemoryAuditStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl AuditStore for InMemoryAuditStore {
+    fn append(&self, entry: AuditEntry) {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner).push(entry);
+    }
+
+    fn list(&self, filter: &AuditFilter, request: PageRequest) -> Page<AuditEntry> {
+        let mut matching: Vec<AuditEntry> = self
+            .entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .filter(|entry| filter.matches(entry))
+            .cloned()
+            .collect();
+        matching.sort_by_key(|entry| Reverse(entry.id));
+
+        match &request {
+            PageRequest::Numbered { .. } => Page::paginate(&matching, request),
+            PageRequest::Cursor { after, .. } => {
+                let start = after
+                    .as_deref()
+                    .and_then(|after| after.parse().ok())
+                    .map_or(0, |after| matching.partition_point(|entry| entry.id >= after));
+                let end = start.saturating_add(request.fetch_limit() as usize).min(matching.len());
+                Page::from_overfetched(matching[start..end].to_vec(), request)
+                    .with_next_cursor(|entry| entry.id.to_string())
+            },
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner).len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zabawa_id::Id;
+
+    use super::*;
+    use crate::model::AuditActor;
+
+    fn entry(sequence: u64, actor: &str, action: &str, project: &str) -> AuditEntry {
+        let mut entry = AuditEntry::new(AuditActor::user(actor, actor), action, "project", project);
+        entry.id = Id::from_parts(1_700_000_000_000 + sequence, 0);
+        entry
+    }
+
+    #[test]
+    fn test_list_newest_first() {
+        let store = InMemoryAuditStore::new();
+        store.append(entry(1, "usr_1", "project.created", "prj_1"));
+        store.append(entry(2, "usr_2", "project.updated", "prj_1"));
+        store.append(entry(3, "usr_1", "project.updated", "prj_2"));
+        store.append(entry(4, "usr_1", "project.updated", "prj_1"));
+        assert_eq!(store.len(), 4);
+
+        let filter = AuditFilter::new().with_entity("project", "prj_1");
+        let page = store.list(&filter, PageRequest::first(2));
+        let actions: Vec<(&str, &str)> = page
+            .items
+            .iter()
+            .map(|entry| (entry.actor.label(), entry.action.as_str()))
+            .collect();
+        assert_eq!(actions, [("usr_1", "project.updated"), ("usr_2", "project.updated")]);
+        assert_eq!(page.total, Some(3));
+        assert!(page.has_next);
+
+        let request = PageRequest::Cursor { after: None, size: 2 };
+        let first = store.list(&AuditFilter::new().with_actor_id("usr_1"), request);
+        assert_eq!(first.items.len(), 2);
+        let after = first.next_cursor.clone().unwrap();
+        let second = store.list(&AuditFilter::new().with_actor_id("usr_1"), PageRequest::Cursor {
+            after: Some(after.into()),
+            size: 2,
+        });
+        assert_eq!(second.items.len(), 1);
+        assert_eq!(second.items[0].entity_id, "prj_1");
+        assert_eq!(second.items[0].action, "project.created");
+        assert!(!second.has_next);
+
+        let none = store.list(
+            &AuditFilter::new().with_action("project.deleted"),
+            PageRequest::first(10),
+        );
+        assert!(none.is_empty());
+    }
+}
@@ -0,0 +1,139 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::time::SystemTime;
+
+use zabawa_id::{Id, IdPrefix};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AuditActor {
+    User {
+        id: String,
+        name: String,
+    },
+    /// A background job or integration, e.g. `billing-sync`.
+    System(Cow<'static, str>),
+}
+
+impl AuditActor {
+    pub fn user(id: impl Into<String>, name: impl Into<String>) -> Self {
+        Self::User {
+            id: id.into(),
+            name: name.into(),
+        }
+    }
+
+    pub fn system(name: impl Into<Cow<'static, str>>) -> Self {
+        Self::System(name.into())
+    }
+
+    pub fn id(&self) -> Option<&str> {
+        match self {
+            Self::User { id, .. } => Some(id),
+            Self::System(_) => None,
+        }
+    }
+
+    pub fn label(&self) -> &str {
+        match self {
+            Self::User { name, .. } => name,
+            Self::System(name) => name,
+        }
+    }
+}
+
+/// One changed field; `None` means the field was absent before or after the change.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FieldChange {
+    pub field: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+impl fmt::Display for FieldChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let before = self.before.as_deref().unwrap_or("∅");
+        let after = self.after.as_deref().unwrap_or("∅");
+        write!(f, "{}: {before} → {after}", self.field)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditEntry {
+    pub id: Id<AuditEntry>,
+    pub actor: AuditActor,
+    /// A dotted action name such as `project.updated`, matching the domain event name where there is one.
+    pub action: String,
+    pub entity_type: Cow<'static, str>,
+    pub entity_id: String,
+    pub changes: Vec<FieldChange>,
+    pub metadata: Vec<(String, String)>,
+    pub timestamp: SystemTime,
+}
+
+impl IdPrefix for AuditEntry {
+    const PREFIX: &'static str = "aud";
+}
+
+impl AuditEntry {
+    pub fn new(
+        actor: AuditActor,
+        action: impl Into<String>,
+        entity_type: impl Into<Cow<'static, str>>,
+        entity_id: impl Into<String>,
+    ) -> Self {
+        Self {
+            id: Id::new(),
+            actor,
+            action: action.into(),
+            entity_type: entity_type.into(),
+            entity_id: entity_id.into(),
+            changes: Vec::new(),
+            metadata: Vec::new(),
+            timestamp: SystemTime::now(),
+        }
+    }
+
+    pub fn with_change(
+        mut self,
+        field: impl Into<String>,
+        before: Option<impl Into<String>>,
+        after: Option<impl Into<String>>,
+    ) -> Self {
+        self.changes.push(FieldChange {
+            field: field.into(),
+            before: before.map(Into::into),
+            after: after.map(Into::into),
+        });
+        self
+    }
+
+    /// Records a change only when the values differ.
+    pub fn with_diff<T: PartialEq + fmt::Display + ?Sized>(
+        self,
+        field: impl Into<String>,
+        before: &T,
+        after: &T,
+    ) -> Self {
+        if before == after {
+            return self;
+        }
+        self.with_change(field, Some(before.to_string()), Some(after.to_string()))
+    }
+
+    pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.push((key.into(), value.into()));
+        self
+    }
+
+    pub fn with_timestamp(mut self, timestamp: SystemTime) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    pub fn metadata(&self, key: &str) -> Option<&str> {
+        self.metadata
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.as_str())
+    }
+}
@@ -0,0 +1,144 @@
+use std::borrow::Cow;
+use std::time::SystemTime;
+
+use hypertext::prelude::GlobalAttributes;
+use hypertext::{Buffer, Renderable, rsx};
+use zabawa_time_view::TimeView;
+use zabawa_view_common::{Column, RenderContext, Table, hypertext_elements};
+
+use crate::model::AuditEntry;
+
+/// A table of audit entries: when, who, what and the changed fields.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuditTable {
+    pub time: TimeView,
+    pub empty_message: Cow<'static, str>,
+    pub zebra: bool,
+}
+
+impl AuditTable {
+    pub fn new() -> Self {
+        Self {
+            time: TimeView::relative(),
+            empty_message: Cow::Borrowed("No activity yet"),
+            zebra: true,
+        }
+    }
+
+    pub fn with_time(mut self, time: TimeView) -> Self {
+        self.time = time;
+        self
+    }
+
+    pub fn with_empty_message(mut self, message: impl Into<Cow<'static, str>>) -> Self {
+        self.empty_message = message.into();
+        self
+    }
+
+    pub fn with_zebra(mut self, enabled: bool) -> Self {
+        self.zebra = enabled;
+        self
+    }
+
+    pub fn render<'a>(
+        &'a self,
+        ctx: &'a RenderContext,
+        entries: &'a [AuditEntry],
+        now: SystemTime,
+    ) -> impl Renderable + 'a {
+        AuditRows {
+            table: self,
+            ctx,
+            entries,
+            now,
+        }
+    }
+}
+
+impl Default for AuditTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct AuditRows<'a> {
+    table: &'a AuditTable,
+    ctx: &'a RenderContext,
+    entries: &'a [AuditEntry],
+    now: SystemTime,
+}
+
+impl Renderable for AuditRows<'_> {
+    fn render_to(&self, buffer: &mut Buffer) {
+        let Self { ctx, now, .. } = *self;
+        let time = &self.table.time;
+        let table = Table::new()
+            .with_zebra(self.table.zebra)
+            .with_empty_message(self.table.empty_message.as_ref())
+            .with_column(Column::new("When", move |entry: &AuditEntry| {
+                time.render(ctx, entry.timestamp, now)
+            }))
+            .with_column(Column::new("Actor", |entry: &AuditEntry| {
+                entry.actor.label().to_owned()
+            }))
+            .with_column(Column::new("Action", |entry: &AuditEntry| {
+                let action = entry.action.clone();
+                rsx! { <code>(action)</code> }
+            }))
+            .with_column(Column::new("Entity", |entry: &AuditEntry| {
+                format!("{} {}", entry.entity_type, entry.entity_id)
+            }))
+            .with_column(Column::new("Changes", |entry: &AuditEntry| {
+                let changes: Vec<String> = entry.changes.iter().map(ToString::to_string).collect();
+                rsx! {
+                    @if !changes.is_empty() {
+                        <ul class="audit-changes">
+                            @for change in &changes {
+                                <li>(change)</li>
+                            }
+                        </ul>
+                    }
+                }
+            }));
+        table.render(self.entries).render_to(buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use zabawa_view_common::testing::{assert_has_selector, assert_selector_count};
+
+    use super::*;
+    use crate::model::AuditActor;
+
+    #[test]
+    fn test_render() {
+        let now = SystemTime::now();
+        let entries = [
+            AuditEntry::new(AuditActor::user("usr_1", "Ada"), "project.updated", "project", "prj_1")
+                .with_diff("name", "Website", "Web site")
+                .with_diff("public", &true, &true)
+                .with_timestamp(now - Duration::from_secs(120)),
+            AuditEntry::new(
+                AuditActor::system("billing-sync"),
+                "invoice.created",
+                "invoice",
+                "inv_1",
+            ),
+        ];
+        let ctx = RenderContext::new();
+        let html = AuditTable::new().render(&ctx, &entries, now).render().into_inner();
+
+        assert_selector_count(&html, "table.wa-zebra-rows > tbody > tr", 2);
+        assert_selector_count(&html, "ul.audit-changes > li", 1);
+        assert_has_selector(&html, "td > time[datetime]");
+        assert!(html.contains("2 minutes ago"));
+        assert!(html.contains("name: Website → Web site"));
+        assert!(html.contains("billing-sync"));
+
+        let html = AuditTable::new().render(&ctx, &[], now).render().into_inner();
+        assert!(html.contains("No activity yet"));
+    }
+}
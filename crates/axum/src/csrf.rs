@@ -0,0 +1,199 @@
+use std::fmt;
+use std::sync::Arc;
+
+use axum_core::extract::FromRequestParts;
+use axum_core::response::{IntoResponse, Response};
+use http::request::Parts;
+use http::{Method, StatusCode};
+use thiserror::Error;
+use zabawa_csrf::{CsrfError, CsrfProtection, CsrfToken, DEFAULT_CSRF_FIELD_NAME, DEFAULT_CSRF_HEADER_NAME};
+use zabawa_view_common::RenderContext;
+
+use crate::FormData;
+
+/// The CSRF protection and session id of the current request, inserted as a request extension by the session layer.
+#[derive(Clone)]
+pub struct CsrfSession {
+    pub protection: Arc<CsrfProtection>,
+    pub session_id: String,
+}
+
+impl CsrfSession {
+    pub fn new(protection: Arc<CsrfProtection>, session_id: impl Into<String>) -> Self {
+        Self {
+            protection,
+            session_id: session_id.into(),
+        }
+    }
+}
+
+impl fmt::Debug for CsrfSession {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CsrfSession")
+            .field("protection", &self.protection)
+            .field("session_id", &"***")
+            .finish()
+    }
+}
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CsrfRejection {
+    #[error("no CsrfSession request extension; is the session layer installed?")]
+    MissingSession,
+
+    #[error(transparent)]
+    Invalid(#[from] CsrfError),
+}
+
+impl IntoResponse for CsrfRejection {
+    fn into_response(self) -> Response {
+        let status = match self {
+            CsrfRejection::MissingSession => StatusCode::INTERNAL_SERVER_ERROR,
+            CsrfRejection::Invalid(_) => StatusCode::FORBIDDEN,
+        };
+        (status, self.to_string()).into_response()
+    }
+}
+
+/// Extractor for issuing CSRF tokens to render and checking submitted ones.
+///
+/// Tokens are stateless, so every call to [`Csrf::token`] yields a fresh token that is valid for the session.
+#[derive(Clone)]
+pub struct Csrf {
+    session: CsrfSession,
+    header_token: Option<String>,
+}
+
+impl Csrf {
+    pub fn new(session: CsrfSession, header_token: Option<String>) -> Self {
+        Self { session, header_token }
+    }
+
+    pub fn token(&self) -> CsrfToken {
+        self.session.protection.generate(&self.session.session_id)
+    }
+
+    /// Adds a token to the render context for `CsrfField`.
+    pub fn render_context(&self, ctx: RenderContext) -> RenderContext {
+        ctx.with_csrf_token(String::from(self.token()))
+    }
+
+    /// The token sent in the `X-CSRF-Token` header, if any.
+    pub fn header_token(&self) -> Option<&str> {
+        self.header_token.as_deref()
+    }
+
+    pub fn verify(&self, token: &str) -> Result<(), CsrfRejection> {
+        Ok(self.session.protection.verify(&self.session.session_id, token)?)
+    }
+
+    /// Verifies the header token, falling back to the `csrf_token` form field.
+    pub fn verify_form(&self, form: &FormData) -> Result<(), CsrfRejection> {
+        let token = self
+            .header_token()
+            .or_else(|| form.get(DEFAULT_CSRF_FIELD_NAME))
+            .ok_or(CsrfError::Missing)?;
+        self.verify(token)
+    }
+}
+
+impl fmt::Debug for Csrf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Csrf")
+            .field("session", &self.session)
+            .field("header_token", &self.header_token.as_ref().map(|_| "***"))
+            .finish()
+    }
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for Csrf {
+    type Rejection = CsrfRejection;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let session = parts
+            .extensions
+            .get::<CsrfSession>()
+            .cloned()
+            .ok_or(CsrfRejection::MissingSession)?;
+        let header_token = parts
+            .headers
+            .get(DEFAULT_CSRF_HEADER_NAME)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_owned);
+        Ok(Self::new(session, header_token))
+    }
+}
+
+/// Rejects unsafe requests (anything but `GET`, `HEAD`, `OPTIONS` and `TRACE`) without a valid `X-CSRF-Token` header.
+///
+/// Plain form posts carry the token in the body instead; use [`Csrf::verify_form`] for those.
+#[derive(Clone, Debug)]
+pub struct VerifiedCsrf(pub Csrf);
+
+impl<S: Send + Sync> FromRequestParts<S> for VerifiedCsrf {
+    type Rejection = CsrfRejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let csrf = Csrf::from_request_parts(parts, state).await?;
+        if !matches!(
+            parts.method,
+            Method::GET | Method::HEAD | Method::OPTIONS | Method::TRACE
+        ) {
+            csrf.verify(csrf.header_token().ok_or(CsrfError::Missing)?)?;
+        }
+        Ok(Self(csrf))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Request;
+    use zabawa_csrf::CsrfKey;
+
+    use super::*;
+    use crate::block_on;
+
+    fn parts(method: Method, token: Option<&str>) -> Parts {
+        let mut builder = Request::builder().method(method);
+        if let Some(token) = token {
+            builder = builder.header(DEFAULT_CSRF_HEADER_NAME, token);
+        }
+        let (mut parts, ()) = builder.body(()).unwrap().into_parts();
+        let protection = Arc::new(CsrfProtection::new(CsrfKey::new([1; 32])));
+        parts.extensions.insert(CsrfSession::new(protection, "session-1"));
+        parts
+    }
+
+    #[test]
+    fn test_verify_form_and_header() {
+        let mut get = parts(Method::GET, None);
+        let csrf = block_on(Csrf::from_request_parts(&mut get, &())).unwrap();
+        let token = csrf.token();
+        assert!(csrf.render_context(RenderContext::new()).csrf_token().is_some());
+        let debug = format!("{csrf:?} {:?}", csrf.render_context(RenderContext::new()));
+        assert!(!debug.contains("session-1") && !debug.contains(token.as_str()));
+        let mut post = parts(Method::POST, Some(token.as_str()));
+        let posted = block_on(Csrf::from_request_parts(&mut post, &())).unwrap();
+        assert!(!format!("{posted:?}").contains(token.as_str()));
+
+        let form = FormData::parse(&format!("name=x&csrf_token={token}"));
+        assert_eq!(csrf.verify_form(&form), Ok(()));
+        assert_eq!(
+            csrf.verify_form(&FormData::parse("name=x")),
+            Err(CsrfRejection::Invalid(CsrfError::Missing))
+        );
+        assert!(block_on(VerifiedCsrf::from_request_parts(&mut get, &())).is_ok());
+
+        let mut post = parts(Method::POST, Some(token.as_str()));
+        assert!(block_on(VerifiedCsrf::from_request_parts(&mut post, &())).is_ok());
+        let mut post = parts(Method::POST, Some("1.ab.cd"));
+        let rejection = block_on(VerifiedCsrf::from_request_parts(&mut post, &())).unwrap_err();
+        assert_eq!(rejection.into_response().status(), StatusCode::FORBIDDEN);
+
+        let (mut bare, ()) = Request::new(()).into_parts();
+        assert_eq!(
+            block_on(Csrf::from_request_parts(&mut bare, &())).unwrap_err(),
+            CsrfRejection::MissingSession
+        );
+    }
+}
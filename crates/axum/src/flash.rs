@@ -0,0 +1,130 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::sync::Arc;
+
+use axum_core::extract::FromRequestParts;
+use axum_core::response::{IntoResponse, IntoResponseParts, Response, ResponseParts};
+use http::StatusCode;
+use http::request::Parts;
+use thiserror::Error;
+use zabawa_notification_domain::logic::NotificationStore;
+use zabawa_notification_domain::model::Notification;
+
+/// The notification store of the current request, inserted as a request extension by the session layer.
+#[derive(Clone)]
+pub struct FlashStore(Arc<dyn NotificationStore + Send + Sync>);
+
+impl FlashStore {
+    pub fn new(store: impl NotificationStore + Send + Sync + 'static) -> Self {
+        Self(Arc::new(store))
+    }
+}
+
+impl fmt::Debug for FlashStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FlashStore").field(&self.0.len()).finish()
+    }
+}
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("no FlashStore request extension; is the session layer installed?")]
+pub struct MissingFlashStore;
+
+impl IntoResponse for MissingFlashStore {
+    fn into_response(self) -> Response {
+        (StatusCode::INTERNAL_SERVER_ERROR, self.to_string()).into_response()
+    }
+}
+
+/// Extractor for queueing flash notifications, which can also be returned as part of the response:
+///
+/// `(flash.success("Project created"), redirect)`
+#[derive(Clone, Debug)]
+pub struct Flash(FlashStore);
+
+impl Flash {
+    pub fn new(store: FlashStore) -> Self {
+        Self(store)
+    }
+
+    pub fn push(self, notification: Notification) -> Self {
+        self.0.0.push(notification);
+        self
+    }
+
+    pub fn error(self, message: impl Into<String>) -> Self {
+        self.push(Notification::error(message))
+    }
+
+    pub fn warning(self, message: impl Into<String>) -> Self {
+        self.push(Notification::warning(message))
+    }
+
+    pub fn success(self, message: impl Into<String>) -> Self {
+        self.push(Notification::success(message))
+    }
+
+    pub fn info(self, message: impl Into<String>) -> Self {
+        self.push(Notification::info(message))
+    }
+
+    pub fn note(self, message: impl Into<String>) -> Self {
+        self.push(Notification::note(message))
+    }
+
+    /// Takes the pending notifications for rendering; they won't be shown again.
+    pub fn drain(&self) -> Vec<Notification> {
+        self.0.0.drain()
+    }
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for Flash {
+    type Rejection = MissingFlashStore;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        parts
+            .extensions
+            .get::<FlashStore>()
+            .cloned()
+            .map(Flash)
+            .ok_or(MissingFlashStore)
+    }
+}
+
+impl IntoResponseParts for Flash {
+    type Error = Infallible;
+
+    fn into_response_parts(self, res: ResponseParts) -> Result<ResponseParts, Self::Error> {
+        // Notifications are written to the store as they are queued.
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Request;
+    use zabawa_notification_domain::logic::InMemoryNotificationStore;
+
+    use super::*;
+    use crate::block_on;
+
+    #[test]
+    fn test_extract_and_drain() {
+        let store = Arc::new(InMemoryNotificationStore::new());
+        let (mut parts, ()) = Request::new(()).into_parts();
+        assert!(block_on(Flash::from_request_parts(&mut parts, &())).is_err());
+
+        parts.extensions.insert(FlashStore::new(store.clone()));
+        let flash = block_on(Flash::from_request_parts(&mut parts, &())).unwrap();
+        let response = (
+            flash.clone().success("Saved").info("Check your inbox"),
+            StatusCode::SEE_OTHER,
+        )
+            .into_response();
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+
+        assert_eq!(store.len(), 2);
+        assert_eq!(flash.drain()[0].message, "Saved");
+        assert!(store.is_empty());
+    }
+}
@@ -0,0 +1,219 @@
+use axum_core::extract::{FromRequest, Request};
+use http::header::{ACCEPT, CONTENT_TYPE};
+use zabawa_validation::{Validate, ValidationErrors};
+
+use crate::{ErrorFormat, ValidationRejection};
+
+/// The decoded fields of an `application/x-www-form-urlencoded` body, in submission order.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FormData {
+    fields: Vec<(String, String)>,
+}
+
+impl FormData {
+    pub fn parse(input: &str) -> Self {
+        let fields = input
+            .split('&')
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| {
+                let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+                (decode_component(name), decode_component(value))
+            })
+            .collect();
+        Self { fields }
+    }
+
+    /// The first value submitted for `name`.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn get_all<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a str> {
+        self.fields
+            .iter()
+            .filter(move |(field, _)| field == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// The value of a required field, recording an error if it is missing or blank.
+    pub fn required(&self, name: &str, errors: &mut ValidationErrors) -> String {
+        match self.get(name) {
+            Some(value) if !value.trim().is_empty() => value.to_owned(),
+            _ => {
+                errors.add(name, "is required");
+                String::new()
+            },
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.fields.iter().map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+}
+
+/// Decodes `+` as a space and `%XX` escapes, replacing invalid UTF-8.
+fn decode_component(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'+' => decoded.push(b' '),
+            b'%' if index + 2 < bytes.len() && hex_pair(bytes[index + 1], bytes[index + 2]).is_some() => {
+                decoded.extend(hex_pair(bytes[index + 1], bytes[index + 2]));
+                index += 2;
+            },
+            byte => decoded.push(byte),
+        }
+        index += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn hex_pair(high: u8, low: u8) -> Option<u8> {
+    let digit = |byte: u8| (byte as char).to_digit(16);
+    Some((digit(high)? * 16 + digit(low)?) as u8)
+}
+
+/// Types that can be built from submitted form fields.
+pub trait FromForm: Sized {
+    fn from_form(form: &FormData) -> Result<Self, ValidationErrors>;
+}
+
+/// Extracts a form body into `T` and validates it.
+///
+/// Failures are rendered as an inline HTML error list, or as `application/problem+json` for clients that accept JSON.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ValidatedForm<T>(pub T);
+
+impl<S, T> FromRequest<S> for ValidatedForm<T>
+where
+    S: Send + Sync,
+    T: FromForm + Validate,
+{
+    type Rejection = ValidationRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let format = if accepts_json(&req) {
+            ErrorFormat::ProblemJson
+        } else {
+            ErrorFormat::Html
+        };
+        if !has_content_type(&req, "application/x-www-form-urlencoded") {
+            return Err(ValidationRejection::UnsupportedMediaType { format });
+        }
+
+        let body = String::from_request(req, state)
+            .await
+            .map_err(|rejection| ValidationRejection::InvalidBody {
+                message: rejection.body_text(),
+                format,
+            })?;
+        let form = FormData::parse(&body);
+        let value = T::from_form(&form).map_err(|errors| ValidationRejection::Invalid { errors, format })?;
+        value
+            .validate()
+            .map_err(|errors| ValidationRejection::Invalid { errors, format })?;
+        Ok(Self(value))
+    }
+}
+
+pub(crate) fn has_content_type(req: &Request, expected: &str) -> bool {
+    req.headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .is_some_and(|mime| mime.trim().eq_ignore_ascii_case(expected))
+}
+
+fn accepts_json(req: &Request) -> bool {
+    req.headers()
+        .get(ACCEPT)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|accept| accept.contains("json") && !accept.contains("text/html"))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum_core::response::IntoResponse;
+    use http::StatusCode;
+    use zabawa_validation::validate_length;
+
+    use super::*;
+    use crate::block_on;
+
+    #[derive(Debug)]
+    struct Signup {
+        name: String,
+        topics: Vec<String>,
+    }
+
+    impl FromForm for Signup {
+        fn from_form(form: &FormData) -> Result<Self, ValidationErrors> {
+            let mut errors = ValidationErrors::new();
+            let name = form.required("name", &mut errors);
+            let topics = form.get_all("topic").map(str::to_owned).collect();
+            errors.into_result().map(|()| Signup { name, topics })
+        }
+    }
+
+    impl Validate for Signup {
+        fn validate(&self) -> Result<(), ValidationErrors> {
+            let mut errors = ValidationErrors::new();
+            errors.add_result("name", validate_length(self.name.len(), 3, 20));
+            errors.into_result()
+        }
+    }
+
+    fn request(body: &'static str, accept: &str) -> Request {
+        Request::builder()
+            .header(CONTENT_TYPE, "application/x-www-form-urlencoded; charset=utf-8")
+            .header(ACCEPT, accept)
+            .body(body.into())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_form_data_parse() {
+        let form = FormData::parse("name=Jane+Doe&topic=a%26b&topic=%E2%9C%93&empty=&flag&bad=%zz");
+        assert_eq!(form.get("name"), Some("Jane Doe"));
+        assert_eq!(form.get_all("topic").collect::<Vec<_>>(), ["a&b", "✓"]);
+        assert_eq!(form.get("empty"), Some(""));
+        assert_eq!(form.get("flag"), Some(""));
+        assert_eq!(form.get("bad"), Some("%zz"));
+        assert_eq!(form.get("missing"), None);
+    }
+
+    #[test]
+    fn test_validated_form() {
+        let ValidatedForm(signup) = block_on(ValidatedForm::<Signup>::from_request(
+            request("name=Jane&topic=rust", "text/html"),
+            &(),
+        ))
+        .unwrap();
+        assert_eq!(signup.name, "Jane");
+        assert_eq!(signup.topics, ["rust"]);
+
+        let rejection = block_on(ValidatedForm::<Signup>::from_request(
+            request("name=Jo", "text/html"),
+            &(),
+        ))
+        .unwrap_err();
+        let response = rejection.into_response();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(response.headers()[CONTENT_TYPE], "text/html; charset=utf-8");
+
+        let rejection = block_on(ValidatedForm::<Signup>::from_request(
+            request("topic=rust", "application/json"),
+            &(),
+        ))
+        .unwrap_err();
+        assert_eq!(
+            rejection.into_response().headers()[CONTENT_TYPE],
+            "application/problem+json"
+        );
+    }
+}
@@ -0,0 +1,14 @@
+use axum_core::response::{IntoResponse, Response};
+use hypertext::Renderable;
+
+/// Renders any [`Renderable`] as a `text/html` response.
+///
+/// Pages rendered through [`zabawa_view_common::RenderContext::render`] are already responses and don't need this.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Html<R>(pub R);
+
+impl<R: Renderable> IntoResponse for Html<R> {
+    fn into_response(self) -> Response {
+        self.0.render().into_response()
+    }
+}
@@ -0,0 +1,423 @@
+use axum_core::extract::{FromRequest, Request};
+use thiserror::Error;
+pub use zabawa_validation::write_json_string;
+use zabawa_validation::{Validate, ValidationErrors};
+
+use crate::form::has_content_type;
+use crate::{ErrorFormat, ValidationRejection};
+
+const MAX_DEPTH: usize = 64;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("invalid JSON at byte {offset}")]
+pub struct JsonSyntaxError {
+    pub offset: usize,
+}
+
+impl JsonValue {
+    pub fn parse(input: &str) -> Result<Self, JsonSyntaxError> {
+        let mut parser = Parser { input, offset: 0 };
+        let value = parser.value(0)?;
+        parser.whitespace();
+        if parser.offset == input.len() {
+            Ok(value)
+        } else {
+            Err(parser.error())
+        }
+    }
+
+    /// Looks up a member of an object.
+    pub fn get(&self, key: &str) -> Option<&JsonValue> {
+        match self {
+            JsonValue::Object(members) => members.iter().find(|(name, _)| name == key).map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            JsonValue::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            JsonValue::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            JsonValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[JsonValue]> {
+        match self {
+            JsonValue::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// A string member, recording an error under `key` if it is missing or not a string.
+    pub fn required_str(&self, key: &str, errors: &mut ValidationErrors) -> String {
+        match self.get(key) {
+            Some(JsonValue::String(value)) => value.clone(),
+            Some(_) => {
+                errors.add(key, "must be a string");
+                String::new()
+            },
+            None => {
+                errors.add(key, "is required");
+                String::new()
+            },
+        }
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    offset: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self) -> JsonSyntaxError {
+        JsonSyntaxError { offset: self.offset }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.as_bytes().get(self.offset).copied()
+    }
+
+    fn whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.offset += 1;
+        }
+    }
+
+    fn expect(&mut self, literal: &str) -> Result<(), JsonSyntaxError> {
+        if self.input[self.offset..].starts_with(literal) {
+            self.offset += literal.len();
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn value(&mut self, depth: usize) -> Result<JsonValue, JsonSyntaxError> {
+        if depth > MAX_DEPTH {
+            return Err(self.error());
+        }
+        self.whitespace();
+        match self.peek().ok_or(self.error())? {
+            b'n' => self.expect("null").map(|()| JsonValue::Null),
+            b't' => self.expect("true").map(|()| JsonValue::Bool(true)),
+            b'f' => self.expect("false").map(|()| JsonValue::Bool(false)),
+            b'"' => self.string().map(JsonValue::String),
+            b'[' => {
+                self.offset += 1;
+                let mut values = Vec::new();
+                self.whitespace();
+                if self.peek() == Some(b']') {
+                    self.offset += 1;
+                    return Ok(JsonValue::Array(values));
+                }
+                loop {
+                    values.push(self.value(depth + 1)?);
+                    self.whitespace();
+                    match self.peek() {
+                        Some(b',') => self.offset += 1,
+                        Some(b']') => {
+                            self.offset += 1;
+                            return Ok(JsonValue::Array(values));
+                        },
+                        _ => return Err(self.error()),
+                    }
+                }
+            },
+            b'{' => {
+                self.offset += 1;
+                let mut members = Vec::new();
+                self.whitespace();
+                if self.peek() == Some(b'}') {
+                    self.offset += 1;
+                    return Ok(JsonValue::Object(members));
+                }
+                loop {
+                    self.whitespace();
+                    let key = self.string()?;
+                    self.whitespace();
+                    self.expect(":")?;
+                    members.push((key, self.value(depth + 1)?));
+                    self.whitespace();
+                    match self.peek() {
+                        Some(b',') => self.offset += 1,
+                        Some(b'}') => {
+                            self.offset += 1;
+                            return Ok(JsonValue::Object(members));
+                        },
+                        _ => return Err(self.error()),
+                    }
+                }
+            },
+            b'-' | b'0'..=b'9' => self.number(),
+            _ => Err(self.error()),
+        }
+    }
+
+    /// Parses `-? (0 | [1-9][0-9]*) (. [0-9]+)? ([eE] [+-]? [0-9]+)?` as defined by RFC 8259.
+    fn number(&mut self) -> Result<JsonValue, JsonSyntaxError> {
+        let start = self.offset;
+        if self.peek() == Some(b'-') {
+            self.offset += 1;
+        }
+        match self.peek() {
+            Some(b'0') => self.offset += 1,
+            Some(b'1'..=b'9') => {
+                self.digits();
+            },
+            _ => return Err(self.error()),
+        }
+        if self.peek() == Some(b'.') {
+            self.offset += 1;
+            if self.digits() == 0 {
+                return Err(self.error());
+            }
+        }
+        if matches!(self.peek(), Some(b'e' | b'E')) {
+            self.offset += 1;
+            if matches!(self.peek(), Some(b'+' | b'-')) {
+                self.offset += 1;
+            }
+            if self.digits() == 0 {
+                return Err(self.error());
+            }
+        }
+        self.input[start..self.offset]
+            .parse()
+            .map(JsonValue::Number)
+            .map_err(|_| JsonSyntaxError { offset: start })
+    }
+
+    /// Skips ASCII digits, returning how many there were.
+    fn digits(&mut self) -> usize {
+        let start = self.offset;
+        while matches!(self.peek(), Some(b'0'..=b'9')) {
+            self.offset += 1;
+        }
+        self.offset - start
+    }
+
+    fn string(&mut self) -> Result<String, JsonSyntaxError> {
+        self.expect("\"")?;
+        let mut output = String::new();
+        loop {
+            let rest = &self.input[self.offset..];
+            let end = rest.find(['"', '\\']).ok_or(self.error())?;
+            if rest[..end].chars().any(char::is_control) {
+                return Err(self.error());
+            }
+            output.push_str(&rest[..end]);
+            self.offset += end;
+            if self.peek() == Some(b'"') {
+                self.offset += 1;
+                return Ok(output);
+            }
+
+            self.offset += 1;
+            let escaped = self.peek().ok_or(self.error())?;
+            self.offset += 1;
+            match escaped {
+                b'"' => output.push('"'),
+                b'\\' => output.push('\\'),
+                b'/' => output.push('/'),
+                b'b' => output.push('\u{8}'),
+                b'f' => output.push('\u{c}'),
+                b'n' => output.push('\n'),
+                b'r' => output.push('\r'),
+                b't' => output.push('\t'),
+                b'u' => {
+                    let high = self.hex4()?;
+                    let code = if (0xd800..0xdc00).contains(&high) {
+                        self.expect("\\u")?;
+                        let low = self.hex4()?;
+                        if !(0xdc00..0xe000).contains(&low) {
+                            return Err(self.error());
+                        }
+                        0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00)
+                    } else {
+                        high
+                    };
+                    output.push(char::from_u32(code).ok_or(self.error())?);
+                },
+                _ => return Err(self.error()),
+            }
+        }
+    }
+
+    fn hex4(&mut self) -> Result<u32, JsonSyntaxError> {
+        let digits = self.input.get(self.offset..self.offset + 4).ok_or(self.error())?;
+        if !digits.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return Err(self.error());
+        }
+        let code = u32::from_str_radix(digits, 16).map_err(|_| self.error())?;
+        self.offset += 4;
+        Ok(code)
+    }
+}
+
+/// Types that can be built from a parsed JSON body.
+pub trait FromJson: Sized {
+    fn from_json(value: &JsonValue) -> Result<Self, ValidationErrors>;
+}
+
+/// Extracts a JSON body into `T` and validates it, rejecting with `application/problem+json`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ValidatedJson<T>(pub T);
+
+impl<S, T> FromRequest<S> for ValidatedJson<T>
+where
+    S: Send + Sync,
+    T: FromJson + Validate,
+{
+    type Rejection = ValidationRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let format = ErrorFormat::ProblemJson;
+        if !has_content_type(&req, "application/json") {
+            return Err(ValidationRejection::UnsupportedMediaType { format });
+        }
+
+        let body = String::from_request(req, state)
+            .await
+            .map_err(|rejection| ValidationRejection::InvalidBody {
+                message: rejection.body_text(),
+                format,
+            })?;
+        let json = JsonValue::parse(&body).map_err(|error| ValidationRejection::InvalidBody {
+            message: error.to_string(),
+            format,
+        })?;
+        let value = T::from_json(&json).map_err(|errors| ValidationRejection::Invalid { errors, format })?;
+        value
+            .validate()
+            .map_err(|errors| ValidationRejection::Invalid { errors, format })?;
+        Ok(Self(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum_core::response::IntoResponse;
+    use http::StatusCode;
+    use http::header::CONTENT_TYPE;
+
+    use super::*;
+    use crate::block_on;
+
+    #[test]
+    fn test_parse() {
+        let value = JsonValue::parse(
+            r#" {"name": "Jane \"JD\" Doe", "tags": ["a", "é😀"], "age": -1.5e2, "ok": true, "x": null} "#,
+        )
+        .unwrap();
+        assert_eq!(value.get("name").and_then(JsonValue::as_str), Some("Jane \"JD\" Doe"));
+        assert_eq!(
+            value.get("tags").and_then(JsonValue::as_array).unwrap()[1].as_str(),
+            Some("é😀")
+        );
+        assert_eq!(value.get("age").and_then(JsonValue::as_f64), Some(-150.0));
+        assert_eq!(value.get("ok").and_then(JsonValue::as_bool), Some(true));
+        assert_eq!(value.get("x"), Some(&JsonValue::Null));
+
+        assert_eq!(JsonValue::parse("{\"a\": 1,}"), Err(JsonSyntaxError { offset: 8 }));
+        assert!(JsonValue::parse("[1] 2").is_err());
+        assert!(JsonValue::parse("01").is_err());
+        for invalid in ["1.", "-.5", "1.e5", ".5", "-", "1e", "1e+", "+1", "--1", "1.5.2"] {
+            assert!(JsonValue::parse(invalid).is_err(), "{invalid}");
+        }
+        for (valid, expected) in [("0", 0.0), ("-0.5", -0.5), ("1E3", 1000.0), ("2e-1", 0.2), ("10", 10.0)] {
+            assert_eq!(JsonValue::parse(valid), Ok(JsonValue::Number(expected)), "{valid}");
+        }
+        assert_eq!(JsonValue::parse(r#""\u0041""#), Ok(JsonValue::String("A".to_owned())));
+        assert!(JsonValue::parse(r#""\u+041""#).is_err());
+        assert!(JsonValue::parse(r#""\u-041""#).is_err());
+        assert!(JsonValue::parse("\"line\nbreak\"").is_err());
+        assert!(JsonValue::parse(&"[".repeat(100)).is_err());
+
+        let mut output = String::new();
+        write_json_string(&mut output, "say \"hi\"\n\u{1}");
+        assert_eq!(output, r#""say \"hi\"\n\u0001""#);
+    }
+
+    struct Rename {
+        name: String,
+    }
+
+    impl FromJson for Rename {
+        fn from_json(value: &JsonValue) -> Result<Self, ValidationErrors> {
+            let mut errors = ValidationErrors::new();
+            let name = value.required_str("name", &mut errors);
+            errors.into_result().map(|()| Rename { name })
+        }
+    }
+
+    impl Validate for Rename {
+        fn validate(&self) -> Result<(), ValidationErrors> {
+            let mut errors = ValidationErrors::new();
+            if self.name.is_empty() {
+                errors.add("name", "must not be empty");
+            }
+            errors.into_result()
+        }
+    }
+
+    fn request(content_type: &str, body: &'static str) -> Request {
+        Request::builder()
+            .header(CONTENT_TYPE, content_type)
+            .body(body.into())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_validated_json() {
+        let ValidatedJson(rename) = block_on(ValidatedJson::<Rename>::from_request(
+            request("application/json", r#"{"name":"x"}"#),
+            &(),
+        ))
+        .unwrap();
+        assert_eq!(rename.name, "x");
+
+        let status = |req| {
+            block_on(ValidatedJson::<Rename>::from_request(req, &()))
+                .err()
+                .map(|rejection| rejection.into_response().status())
+        };
+        assert_eq!(
+            status(request("application/json", r#"{"name":""}"#)),
+            Some(StatusCode::UNPROCESSABLE_ENTITY)
+        );
+        assert_eq!(
+            status(request("application/json", r#"{"name":1}"#)),
+            Some(StatusCode::UNPROCESSABLE_ENTITY)
+        );
+        assert_eq!(status(request("application/json", "{")), Some(StatusCode::BAD_REQUEST));
+        assert_eq!(
+            status(request("text/plain", "{}")),
+            Some(StatusCode::UNSUPPORTED_MEDIA_TYPE)
+        );
+    }
+}
@@ -0,0 +1,26 @@
+pub mod csrf;
+pub mod flash;
+pub mod form;
+pub mod html;
+pub mod json;
+pub mod rejection;
+pub mod session;
+
+pub use self::csrf::*;
+pub use self::flash::*;
+pub use self::form::*;
+pub use self::html::*;
+pub use self::json::*;
+pub use self::rejection::*;
+pub use self::session::*;
+
+#[cfg(test)]
+pub(crate) fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    let mut future = std::pin::pin!(future);
+    let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+    loop {
+        if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
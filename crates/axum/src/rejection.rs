@@ -0,0 +1,141 @@
+use axum_core::response::{IntoResponse, Response};
+use http::StatusCode;
+use http::header::CONTENT_TYPE;
+use hypertext::prelude::{AriaAttributes, GlobalAttributes};
+use hypertext::{Renderable, rsx};
+use zabawa_validation::ValidationErrors;
+use zabawa_view_common::hypertext_elements;
+
+use crate::write_json_string;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ErrorFormat {
+    /// `application/problem+json` (RFC 9457) with an `errors` member mapping each field to its messages, as produced
+    /// by [`ValidationErrors::to_json`].
+    #[default]
+    ProblemJson,
+    /// An HTML fragment listing the errors, for swapping into the page with htmx.
+    Html,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ValidationRejection {
+    UnsupportedMediaType {
+        format: ErrorFormat,
+    },
+    InvalidBody {
+        message: String,
+        format: ErrorFormat,
+    },
+    Invalid {
+        errors: ValidationErrors,
+        format: ErrorFormat,
+    },
+}
+
+impl ValidationRejection {
+    pub fn status(&self) -> StatusCode {
+        match self {
+            ValidationRejection::UnsupportedMediaType { .. } => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            ValidationRejection::InvalidBody { .. } => StatusCode::BAD_REQUEST,
+            ValidationRejection::Invalid { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+        }
+    }
+
+    pub fn format(&self) -> ErrorFormat {
+        match self {
+            ValidationRejection::UnsupportedMediaType { format }
+            | ValidationRejection::InvalidBody { format, .. }
+            | ValidationRejection::Invalid { format, .. } => *format,
+        }
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            ValidationRejection::UnsupportedMediaType { .. } => "Unsupported media type",
+            ValidationRejection::InvalidBody { .. } => "Malformed request body",
+            ValidationRejection::Invalid { .. } => "Validation failed",
+        }
+    }
+
+    pub fn errors(&self) -> Option<&ValidationErrors> {
+        match self {
+            ValidationRejection::Invalid { errors, .. } => Some(errors),
+            _ => None,
+        }
+    }
+
+    pub fn to_problem_json(&self) -> String {
+        let mut json = String::from("{\"type\":\"about:blank\",\"title\":");
+        write_json_string(&mut json, self.title());
+        json.push_str(",\"status\":");
+        json.push_str(self.status().as_str());
+        if let ValidationRejection::InvalidBody { message, .. } = self {
+            json.push_str(",\"detail\":");
+            write_json_string(&mut json, message);
+        }
+        if let Some(errors) = self.errors() {
+            json.push_str(",\"errors\":");
+            json.push_str(&errors.to_json());
+        }
+        json.push('}');
+        json
+    }
+
+    pub fn render_html(&self) -> impl Renderable + '_ {
+        rsx! {
+            <div class="validation-errors" role="alert">
+                <p>(self.title())</p>
+                @if let ValidationRejection::InvalidBody { message, .. } = self {
+                    <p>(message)</p>
+                }
+                @if let Some(errors) = self.errors() {
+                    <ul>
+                        @for error in errors {
+                            <li data-field=(error.field)>(error.message)</li>
+                        }
+                    </ul>
+                }
+            </div>
+        }
+    }
+}
+
+impl IntoResponse for ValidationRejection {
+    fn into_response(self) -> Response {
+        match self.format() {
+            ErrorFormat::ProblemJson => (
+                self.status(),
+                [(CONTENT_TYPE, "application/problem+json")],
+                self.to_problem_json(),
+            )
+                .into_response(),
+            ErrorFormat::Html => (self.status(), self.render_html().render()).into_response(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let mut errors = ValidationErrors::new();
+        errors.add("name", "must be \"unique\"");
+        errors.add("name", "must be lowercase");
+        let rejection = ValidationRejection::Invalid {
+            errors,
+            format: ErrorFormat::Html,
+        };
+
+        assert_eq!(
+            rejection.to_problem_json(),
+            r#"{"type":"about:blank","title":"Validation failed","status":422,"errors":{"name":["must be \"unique\"","must be lowercase"]}}"#
+        );
+        assert_eq!(
+            rejection.render_html().render().into_inner(),
+            "<div class=\"validation-errors\" role=\"alert\"><p>Validation failed</p><ul><li data-field=\"name\">must be \"unique\"</li><li data-field=\"name\">must be lowercase</li></ul></div>"
+        );
+    }
+}
@@ -0,0 +1,221 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+
+use zabawa_notification_domain::logic::NotificationStore;
+use zabawa_notification_domain::model::{Notification, NotificationLevel};
+
+pub const DEFAULT_FLASH_KEY: &str = "zabawa.flash";
+/// Keeps the encoded notifications well below the common 4 KiB cookie limit.
+pub const DEFAULT_FLASH_MAX_BYTES: usize = 2048;
+
+/// The string key-value view of a session that [`SessionNotificationStore`] needs.
+pub trait SessionData {
+    fn get(&self, key: &str) -> Option<String>;
+
+    fn insert(&self, key: &str, value: String);
+
+    fn remove(&self, key: &str) -> Option<String>;
+}
+
+/// A process-local session, mainly for tests and single-instance deployments.
+#[derive(Debug, Default)]
+pub struct MemorySession {
+    values: Mutex<HashMap<String, String>>,
+}
+
+impl MemorySession {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl SessionData for MemorySession {
+    fn get(&self, key: &str) -> Option<String> {
+        self.values
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(key)
+            .cloned()
+    }
+
+    fn insert(&self, key: &str, value: String) {
+        self.values
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key.to_owned(), value);
+    }
+
+    fn remove(&self, key: &str) -> Option<String> {
+        self.values.lock().unwrap_or_else(PoisonError::into_inner).remove(key)
+    }
+}
+
+/// Stores flash notifications in the session so they survive redirects, and removes them once drained for rendering.
+///
+/// When the encoded notifications exceed `max_bytes`, the oldest are dropped.
+#[derive(Debug)]
+pub struct SessionNotificationStore<D> {
+    pub session: D,
+    pub key: Cow<'static, str>,
+    pub max_bytes: usize,
+}
+
+impl<D: SessionData> SessionNotificationStore<D> {
+    pub fn new(session: D) -> Self {
+        Self {
+            session,
+            key: Cow::Borrowed(DEFAULT_FLASH_KEY),
+            max_bytes: DEFAULT_FLASH_MAX_BYTES,
+        }
+    }
+
+    pub fn with_key(mut self, key: impl Into<Cow<'static, str>>) -> Self {
+        self.key = key.into();
+        self
+    }
+
+    pub fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    fn load(&self) -> Vec<Notification> {
+        self.session
+            .get(&self.key)
+            .map(|encoded| decode_notifications(&encoded))
+            .unwrap_or_default()
+    }
+}
+
+impl<D: SessionData> NotificationStore for SessionNotificationStore<D> {
+    fn push(&self, notification: Notification) {
+        let mut notifications = self.load();
+        notifications.push(notification);
+        self.session
+            .insert(&self.key, encode_notifications(&notifications, self.max_bytes));
+    }
+
+    fn drain(&self) -> Vec<Notification> {
+        self.session
+            .remove(&self.key)
+            .map(|encoded| decode_notifications(&encoded))
+            .unwrap_or_default()
+    }
+
+    fn len(&self) -> usize {
+        self.load().len()
+    }
+}
+
+/// Encodes notifications one per line as `level:message`, keeping the newest that fit in `max_bytes`.
+pub fn encode_notifications(notifications: &[Notification], max_bytes: usize) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    let mut size = 0;
+    for notification in notifications.iter().rev() {
+        let mut line = String::from(level_code(notification.level));
+        line.push(':');
+        escape_into(&notification.message, &mut line);
+
+        let added = line.len() + usize::from(!lines.is_empty());
+        if size + added > max_bytes {
+            break;
+        }
+        size += added;
+        lines.push(line);
+    }
+    lines.reverse();
+    lines.join("\n")
+}
+
+/// Decodes [`encode_notifications`] output, skipping malformed lines. Creation times restart at decoding.
+pub fn decode_notifications(encoded: &str) -> Vec<Notification> {
+    encoded
+        .lines()
+        .filter_map(|line| {
+            let (level, message) = line.split_once(':')?;
+            Some(Notification::new(parse_level_code(level)?, unescape(message)))
+        })
+        .collect()
+}
+
+fn level_code(level: NotificationLevel) -> &'static str {
+    match level {
+        NotificationLevel::Error => "error",
+        NotificationLevel::Warning => "warning",
+        NotificationLevel::Success => "success",
+        NotificationLevel::Info => "info",
+        NotificationLevel::Note => "note",
+    }
+}
+
+fn parse_level_code(code: &str) -> Option<NotificationLevel> {
+    match code {
+        "error" => Some(NotificationLevel::Error),
+        "warning" => Some(NotificationLevel::Warning),
+        "success" => Some(NotificationLevel::Success),
+        "info" => Some(NotificationLevel::Info),
+        "note" => Some(NotificationLevel::Note),
+        _ => None,
+    }
+}
+
+fn escape_into(message: &str, output: &mut String) {
+    for c in message.chars() {
+        match c {
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            c => output.push(c),
+        }
+    }
+}
+
+fn unescape(message: &str) -> String {
+    let mut output = String::with_capacity(message.len());
+    let mut chars = message.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            output.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => output.push('\n'),
+            Some('r') => output.push('\r'),
+            Some(other) => output.push(other),
+            None => output.push('\\'),
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_and_drain() {
+        let store = SessionNotificationStore::new(MemorySession::new());
+        store.push(Notification::success("Saved\nall 3 files"));
+        store.push(Notification::error("Path C:\\tmp is read-only"));
+        assert_eq!(store.len(), 2);
+
+        let drained = store.drain();
+        assert_eq!(drained[0].level, NotificationLevel::Success);
+        assert_eq!(drained[0].message, "Saved\nall 3 files");
+        assert_eq!(drained[1].message, "Path C:\\tmp is read-only");
+        assert!(store.is_empty());
+        assert!(store.drain().is_empty());
+    }
+
+    #[test]
+    fn test_size_cap_drops_oldest() {
+        let store = SessionNotificationStore::new(MemorySession::new()).with_max_bytes(24);
+        store.push(Notification::info("first message"));
+        store.push(Notification::info("second"));
+        store.push(Notification::info("third"));
+
+        let messages: Vec<String> = store.drain().into_iter().map(|n| n.message).collect();
+        assert_eq!(messages, ["second", "third"]);
+    }
+}
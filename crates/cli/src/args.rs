@@ -0,0 +1,154 @@
+use std::fmt;
+
+pub const USAGE: &str = "\
+usage: zabawa <command> [--kind name|username] [--json] [VALUE...]
+
+commands:
+  normalize      print the normalized form of each value
+  validate       check each value as-is; exits with 1 if any is invalid
+  unique-check   report values that normalize to the same key; exits with 1 on collisions
+
+Values are read one per line from stdin when none are given.";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Command {
+    Normalize,
+    Validate,
+    UniqueCheck,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Kind {
+    #[default]
+    Name,
+    Username,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Args {
+    pub command: Command,
+    pub kind: Kind,
+    pub json: bool,
+    pub values: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ArgsError {
+    Help,
+    MissingCommand,
+    UnknownCommand(String),
+    UnknownKind(String),
+    UnknownOption(String),
+    MissingValue(&'static str),
+}
+
+impl fmt::Display for ArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArgsError::Help => f.write_str(USAGE),
+            ArgsError::MissingCommand => f.write_str("missing command"),
+            ArgsError::UnknownCommand(command) => write!(f, "unknown command {command:?}"),
+            ArgsError::UnknownKind(kind) => write!(f, "unknown kind {kind:?}, expected name or username"),
+            ArgsError::UnknownOption(option) => write!(f, "unknown option {option:?}"),
+            ArgsError::MissingValue(option) => write!(f, "{option} requires a value"),
+        }
+    }
+}
+
+impl Args {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, ArgsError> {
+        let mut args = args.into_iter();
+        let mut command = None;
+        let mut kind = Kind::default();
+        let mut json = false;
+        let mut values = Vec::new();
+
+        while let Some(arg) = args.next() {
+            if arg == "--" {
+                values.extend(args.by_ref());
+                break;
+            }
+            match arg.as_str() {
+                "-h" | "--help" => return Err(ArgsError::Help),
+                "--json" => json = true,
+                "--kind" => {
+                    let value = args.next().ok_or(ArgsError::MissingValue("--kind"))?;
+                    kind = parse_kind(&value)?;
+                },
+                option if option.starts_with("--kind=") => kind = parse_kind(&option["--kind=".len()..])?,
+                option if option.starts_with('-') && option.len() > 1 => {
+                    return Err(ArgsError::UnknownOption(arg));
+                },
+                _ if command.is_none() => command = Some(parse_command(&arg)?),
+                _ => values.push(arg),
+            }
+        }
+
+        Ok(Self {
+            command: command.ok_or(ArgsError::MissingCommand)?,
+            kind,
+            json,
+            values,
+        })
+    }
+}
+
+fn parse_command(input: &str) -> Result<Command, ArgsError> {
+    match input {
+        "normalize" => Ok(Command::Normalize),
+        "validate" => Ok(Command::Validate),
+        "unique-check" => Ok(Command::UniqueCheck),
+        _ => Err(ArgsError::UnknownCommand(input.to_owned())),
+    }
+}
+
+fn parse_kind(input: &str) -> Result<Kind, ArgsError> {
+    match input {
+        "name" => Ok(Kind::Name),
+        "username" => Ok(Kind::Username),
+        _ => Err(ArgsError::UnknownKind(input.to_owned())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, ArgsError> {
+        Args::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse(&["normalize", "--json", "My Project", "--kind=username", "x"]),
+            Ok(Args {
+                command: Command::Normalize,
+                kind: Kind::Username,
+                json: true,
+                values: vec!["My Project".to_owned(), "x".to_owned()],
+            })
+        );
+        assert_eq!(
+            parse(&["--kind", "name", "unique-check", "--", "--json"]).map(|args| (args.command, args.values)),
+            Ok((Command::UniqueCheck, vec!["--json".to_owned()]))
+        );
+        assert_eq!(
+            parse(&["validate", "-"]).map(|args| args.values),
+            Ok(vec!["-".to_owned()])
+        );
+
+        assert_eq!(parse(&[]), Err(ArgsError::MissingCommand));
+        assert_eq!(parse(&["--help"]), Err(ArgsError::Help));
+        assert_eq!(parse(&["check"]), Err(ArgsError::UnknownCommand("check".to_owned())));
+        assert_eq!(parse(&["validate", "--kind"]), Err(ArgsError::MissingValue("--kind")));
+        assert_eq!(
+            parse(&["validate", "--kind=email"]),
+            Err(ArgsError::UnknownKind("email".to_owned()))
+        );
+        assert_eq!(
+            parse(&["validate", "-x"]),
+            Err(ArgsError::UnknownOption("-x".to_owned()))
+        );
+    }
+}
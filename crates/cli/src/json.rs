@@ -0,0 +1,68 @@
+/// Writes `value` as a JSON string literal.
+pub use zabawa_validation::write_json_string as write_string;
+
+/// A single-line JSON object built field by field.
+#[derive(Debug, Default)]
+pub struct Object(String);
+
+impl Object {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn string(self, key: &str, value: &str) -> Self {
+        self.field(key, |output| write_string(output, value))
+    }
+
+    pub fn bool(self, key: &str, value: bool) -> Self {
+        self.field(key, |output| output.push_str(if value { "true" } else { "false" }))
+    }
+
+    pub fn strings<'a>(self, key: &str, values: impl IntoIterator<Item = &'a str>) -> Self {
+        self.field(key, |output| {
+            output.push('[');
+            for (index, value) in values.into_iter().enumerate() {
+                if index > 0 {
+                    output.push(',');
+                }
+                write_string(output, value);
+            }
+            output.push(']');
+        })
+    }
+
+    fn field(mut self, key: &str, write_value: impl FnOnce(&mut String)) -> Self {
+        self.0.push(if self.0.is_empty() { '{' } else { ',' });
+        write_string(&mut self.0, key);
+        self.0.push(':');
+        write_value(&mut self.0);
+        self
+    }
+
+    pub fn finish(mut self) -> String {
+        if self.0.is_empty() {
+            self.0.push('{');
+        }
+        self.0.push('}');
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_object() {
+        let json = Object::new()
+            .string("input", "say \"hi\"\n\u{1}")
+            .bool("valid", false)
+            .strings("inputs", ["a", "b\\c"])
+            .finish();
+        assert_eq!(
+            json,
+            r#"{"input":"say \"hi\"\n\u0001","valid":false,"inputs":["a","b\\c"]}"#
+        );
+        assert_eq!(Object::new().finish(), "{}");
+    }
+}
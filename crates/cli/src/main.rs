@@ -0,0 +1,234 @@
+mod args;
+mod json;
+
+use std::io::{self, BufRead, Write};
+use std::process::ExitCode;
+
+use zabawa_name::{DefaultNameBuilder, NameBulder};
+use zabawa_username::{DefaultUsernameBuilder, UsernameBuilder};
+
+use crate::args::{Args, ArgsError, Command, Kind, USAGE};
+use crate::json::Object;
+
+fn main() -> ExitCode {
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(ArgsError::Help) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        },
+        Err(error) => {
+            eprintln!("zabawa: {error}\n\n{USAGE}");
+            return ExitCode::from(2);
+        },
+    };
+
+    let values = if args.values.is_empty() {
+        match read_lines(io::stdin().lock()) {
+            Ok(values) => values,
+            Err(error) => {
+                eprintln!("zabawa: failed to read stdin: {error}");
+                return ExitCode::from(2);
+            },
+        }
+    } else {
+        args.values.clone()
+    };
+
+    let outcome = run(&args, &values);
+    let mut stdout = io::stdout().lock();
+    for line in &outcome.lines {
+        if writeln!(stdout, "{line}").is_err() {
+            return ExitCode::from(2);
+        }
+    }
+    if outcome.success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn read_lines(input: impl BufRead) -> io::Result<Vec<String>> {
+    let mut values = Vec::new();
+    for line in input.lines() {
+        let line = line?;
+        let line = line.strip_suffix('\r').unwrap_or(&line);
+        if !line.is_empty() {
+            values.push(line.to_owned());
+        }
+    }
+    Ok(values)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Outcome {
+    lines: Vec<String>,
+    success: bool,
+}
+
+fn run(args: &Args, values: &[String]) -> Outcome {
+    match args.command {
+        Command::Normalize => normalize(args, values),
+        Command::Validate => validate(args, values),
+        Command::UniqueCheck => unique_check(args, values),
+    }
+}
+
+fn normalize(args: &Args, values: &[String]) -> Outcome {
+    let mut lines = Vec::with_capacity(values.len());
+    let mut success = true;
+    for value in values {
+        let result = normalize_value(args.kind, value);
+        success &= result.is_ok();
+        let line = match (result, args.json) {
+            (Ok(normalized), false) => normalized,
+            (Ok(normalized), true) => Object::new()
+                .string("input", value)
+                .string("normalized", &normalized)
+                .finish(),
+            (Err(error), false) => format!("error\t{value}\t{error}"),
+            (Err(error), true) => Object::new().string("input", value).string("error", &error).finish(),
+        };
+        lines.push(line);
+    }
+    Outcome { lines, success }
+}
+
+fn validate(args: &Args, values: &[String]) -> Outcome {
+    let mut lines = Vec::with_capacity(values.len());
+    let mut success = true;
+    for value in values {
+        let result = validate_value(args.kind, value);
+        success &= result.is_ok();
+        let line = match (result, args.json) {
+            (Ok(()), false) => format!("ok\t{value}"),
+            (Ok(()), true) => Object::new().string("input", value).bool("valid", true).finish(),
+            (Err(error), false) => format!("invalid\t{value}\t{error}"),
+            (Err(error), true) => Object::new()
+                .string("input", value)
+                .bool("valid", false)
+                .string("error", &error)
+                .finish(),
+        };
+        lines.push(line);
+    }
+    Outcome { lines, success }
+}
+
+/// Groups the values by their normalized key, in first-seen order, and reports the groups with more than one value.
+fn unique_check(args: &Args, values: &[String]) -> Outcome {
+    let mut groups: Vec<(String, Vec<&str>)> = Vec::new();
+    for value in values {
+        let Ok(normalized) = normalize_value(args.kind, value) else {
+            continue;
+        };
+        let key = normalized.to_ascii_lowercase();
+        match groups.iter_mut().find(|(existing, _)| *existing == key) {
+            Some((_, inputs)) => inputs.push(value),
+            None => groups.push((key, vec![value])),
+        }
+    }
+
+    let lines: Vec<String> = groups
+        .iter()
+        .filter(|(_, inputs)| inputs.len() > 1)
+        .map(|(key, inputs)| {
+            if args.json {
+                Object::new()
+                    .string("key", key)
+                    .strings("inputs", inputs.iter().copied())
+                    .finish()
+            } else {
+                format!("{key}\t{}", inputs.join("\t"))
+            }
+        })
+        .collect();
+    Outcome {
+        success: lines.is_empty(),
+        lines,
+    }
+}
+
+fn normalize_value(kind: Kind, input: &str) -> Result<String, String> {
+    match kind {
+        Kind::Name => DefaultNameBuilder::new()
+            .normalize(input)
+            .map_err(|error| error.to_string()),
+        Kind::Username => DefaultUsernameBuilder::new()
+            .normalize(input)
+            .map_err(|error| error.to_string()),
+    }
+}
+
+fn validate_value(kind: Kind, input: &str) -> Result<(), String> {
+    match kind {
+        Kind::Name => DefaultNameBuilder::new()
+            .validate(input)
+            .map_err(|error| error.to_string()),
+        Kind::Username => DefaultUsernameBuilder::new()
+            .validate(input)
+            .map_err(|error| error.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_with(command: Command, kind: Kind, json: bool, values: &[&str]) -> Outcome {
+        let values: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+        let args = Args {
+            command,
+            kind,
+            json,
+            values: Vec::new(),
+        };
+        run(&args, &values)
+    }
+
+    #[test]
+    fn test_normalize() {
+        let outcome = run_with(Command::Normalize, Kind::Name, false, &["My Project", "Café"]);
+        assert_eq!(outcome.lines, ["my-project", "cafe"]);
+        assert!(outcome.success);
+
+        let outcome = run_with(Command::Normalize, Kind::Username, true, &["Jane Doe"]);
+        assert_eq!(outcome.lines, [r#"{"input":"Jane Doe","normalized":"Jane_Doe"}"#]);
+    }
+
+    #[test]
+    fn test_validate() {
+        let outcome = run_with(Command::Validate, Kind::Name, false, &["my-project", "My Project"]);
+        assert_eq!(outcome.lines[0], "ok\tmy-project");
+        assert_eq!(
+            outcome.lines[1],
+            "invalid\tMy Project\tname error: invalid characters 'M' at position 0, ' ' at position 2, 'P' at position 3"
+        );
+        assert!(!outcome.success);
+
+        let outcome = run_with(Command::Validate, Kind::Username, true, &["admin"]);
+        assert_eq!(outcome.lines, [
+            r#"{"input":"admin","valid":false,"error":"username is reserved"}"#
+        ]);
+    }
+
+    #[test]
+    fn test_unique_check() {
+        let values = ["My Project", "my-project", "Other", "MY_PROJECT"];
+        let outcome = run_with(Command::UniqueCheck, Kind::Name, false, &values);
+        assert_eq!(outcome.lines, ["my-project\tMy Project\tmy-project"]);
+        assert!(!outcome.success);
+
+        let outcome = run_with(Command::UniqueCheck, Kind::Username, true, &["Jane", "jane", "john"]);
+        assert_eq!(outcome.lines, [r#"{"key":"jane","inputs":["Jane","jane"]}"#]);
+
+        assert!(run_with(Command::UniqueCheck, Kind::Name, false, &["a", "b"]).success);
+    }
+
+    #[test]
+    fn test_read_lines() {
+        let values = read_lines("one\r\n\ntwo words\n".as_bytes()).unwrap();
+        assert_eq!(values, ["one", "two words"]);
+    }
+}
@@ -0,0 +1,414 @@
+use std::fmt;
+use std::str::FromStr;
+
+use thiserror::Error;
+pub use zabawa_validation::InvalidHexColorError;
+use zabawa_validation::parse_hex_color;
+
+/// The minimum contrast ratio for normal text under WCAG 2 level AA.
+pub const WCAG_AA_CONTRAST: f64 = 4.5;
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorError {
+    #[error("color error: {0}")]
+    InvalidHex(#[from] InvalidHexColorError),
+
+    #[error("invalid color: malformed rgb() value")]
+    InvalidRgb,
+
+    #[error("invalid color: unknown color name")]
+    UnknownName,
+}
+
+/// An sRGB color with alpha, e.g. a user-chosen brand color.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b, a: 255 }
+    }
+
+    pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Parses `#rgb`, `#rrggbbaa` and the other hex forms, `rgb()`/`rgba()` in comma or space syntax, or a CSS
+    /// color name.
+    pub fn parse(input: &str) -> Result<Self, ColorError> {
+        let input = input.trim();
+        if input.starts_with('#') {
+            return Self::parse_hex(input);
+        }
+        if input.contains('(') {
+            return parse_rgb_function(input).ok_or(ColorError::InvalidRgb);
+        }
+        Self::named(input).ok_or(ColorError::UnknownName)
+    }
+
+    pub fn parse_hex(input: &str) -> Result<Self, ColorError> {
+        let hex = parse_hex_color(input)?;
+        let value = u32::from_str_radix(&hex[1..], 16).map_err(|_| InvalidHexColorError)?;
+        Ok(if hex.len() == 7 {
+            Self::from_rgb_u32(value)
+        } else {
+            let [r, g, b, a] = value.to_be_bytes();
+            Self::rgba(r, g, b, a)
+        })
+    }
+
+    pub fn named(name: &str) -> Option<Self> {
+        let name = name.to_ascii_lowercase();
+        if name == "transparent" {
+            return Some(Self::rgba(0, 0, 0, 0));
+        }
+        NAMED_COLORS
+            .binary_search_by(|(candidate, _)| candidate.cmp(&name.as_str()))
+            .ok()
+            .map(|index| Self::from_rgb_u32(NAMED_COLORS[index].1))
+    }
+
+    const fn from_rgb_u32(value: u32) -> Self {
+        let [_, r, g, b] = value.to_be_bytes();
+        Self::rgb(r, g, b)
+    }
+
+    pub const fn is_opaque(&self) -> bool {
+        self.a == 255
+    }
+
+    pub fn with_alpha(mut self, alpha: u8) -> Self {
+        self.a = alpha;
+        self
+    }
+
+    /// `#rrggbb`, or `#rrggbbaa` when not fully opaque.
+    pub fn to_hex(&self) -> String {
+        if self.is_opaque() {
+            format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+        } else {
+            format!("#{:02x}{:02x}{:02x}{:02x}", self.r, self.g, self.b, self.a)
+        }
+    }
+
+    /// `rgb(r g b)`, or `rgb(r g b / alpha)` when not fully opaque.
+    pub fn to_css_rgb(&self) -> String {
+        if self.is_opaque() {
+            return format!("rgb({} {} {})", self.r, self.g, self.b);
+        }
+        let alpha = format!("{:.3}", f64::from(self.a) / 255.0);
+        let alpha = alpha.trim_end_matches('0').trim_end_matches('.');
+        format!("rgb({} {} {} / {alpha})", self.r, self.g, self.b)
+    }
+
+    /// WCAG 2 relative luminance, from 0 for black to 1 for white. Alpha is ignored.
+    pub fn relative_luminance(&self) -> f64 {
+        let linear = |channel: u8| {
+            let value = f64::from(channel) / 255.0;
+            if value <= 0.04045 {
+                value / 12.92
+            } else {
+                ((value + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * linear(self.r) + 0.7152 * linear(self.g) + 0.0722 * linear(self.b)
+    }
+
+    /// WCAG 2 contrast ratio between 1 and 21; the order of the colors doesn't matter.
+    pub fn contrast_ratio(&self, other: &Color) -> f64 {
+        let (a, b) = (self.relative_luminance(), other.relative_luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    pub fn is_readable_on(&self, background: &Color) -> bool {
+        self.contrast_ratio(background) >= WCAG_AA_CONTRAST
+    }
+
+    /// Black or white, whichever contrasts more with this color, e.g. for text on a brand-colored button.
+    pub fn contrasting_text(&self) -> Color {
+        if self.contrast_ratio(&Color::BLACK) >= self.contrast_ratio(&Color::WHITE) {
+            Color::BLACK
+        } else {
+            Color::WHITE
+        }
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_hex())
+    }
+}
+
+impl FromStr for Color {
+    type Err = ColorError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::parse(input)
+    }
+}
+
+fn parse_rgb_function(input: &str) -> Option<Color> {
+    let (name, rest) = input.split_once('(')?;
+    if !name.eq_ignore_ascii_case("rgb") && !name.eq_ignore_ascii_case("rgba") {
+        return None;
+    }
+    let arguments = rest.strip_suffix(')')?.trim();
+
+    let (channels, alpha): (Vec<&str>, Option<&str>) = if arguments.contains(',') {
+        let mut parts: Vec<&str> = arguments.split(',').map(str::trim).collect();
+        let alpha = if parts.len() == 4 { parts.pop() } else { None };
+        (parts, alpha)
+    } else {
+        let (channels, alpha) = match arguments.split_once('/') {
+            Some((channels, alpha)) => (channels, Some(alpha.trim())),
+            None => (arguments, None),
+        };
+        (channels.split_whitespace().collect(), alpha)
+    };
+    let [r, g, b] = channels.as_slice() else {
+        return None;
+    };
+
+    let alpha = match alpha {
+        Some(alpha) => parse_alpha(alpha)?,
+        None => 255,
+    };
+    Some(Color::rgba(
+        parse_channel(r)?,
+        parse_channel(g)?,
+        parse_channel(b)?,
+        alpha,
+    ))
+}
+
+fn parse_channel(input: &str) -> Option<u8> {
+    let value = match input.strip_suffix('%') {
+        Some(percent) => parse_number(percent)? * 255.0 / 100.0,
+        None => parse_number(input)?,
+    };
+    Some(value.round().clamp(0.0, 255.0) as u8)
+}
+
+fn parse_alpha(input: &str) -> Option<u8> {
+    let value = match input.strip_suffix('%') {
+        Some(percent) => parse_number(percent)? / 100.0,
+        None => parse_number(input)?,
+    };
+    Some((value.clamp(0.0, 1.0) * 255.0).round() as u8)
+}
+
+fn parse_number(input: &str) -> Option<f64> {
+    let valid = !input.is_empty() && input.bytes().all(|b| b.is_ascii_digit() || b == b'.' || b == b'-');
+    valid
+        .then(|| input.parse().ok())
+        .flatten()
+        .filter(|value: &f64| value.is_finite())
+}
+
+/// CSS named colors, sorted by name for binary search.
+const NAMED_COLORS: [(&str, u32); 148] = [
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_format() {
+        assert_eq!(Color::parse("#FA0"), Ok(Color::rgb(255, 170, 0)));
+        assert_eq!(Color::parse("#ff000080"), Ok(Color::rgba(255, 0, 0, 128)));
+        assert_eq!(Color::parse("rgb(255, 170, 0)"), Ok(Color::rgb(255, 170, 0)));
+        assert_eq!(Color::parse("RGBA(100%, 0%, 0%, 0.5)"), Ok(Color::rgba(255, 0, 0, 128)));
+        assert_eq!(Color::parse("rgb(10 20 30 / 25%)"), Ok(Color::rgba(10, 20, 30, 64)));
+        assert_eq!(Color::parse("rgb(300 -5 12.4)"), Ok(Color::rgb(255, 0, 12)));
+        assert_eq!(Color::parse(" RebeccaPurple "), Ok(Color::rgb(0x66, 0x33, 0x99)));
+        assert_eq!(Color::parse("transparent"), Ok(Color::rgba(0, 0, 0, 0)));
+        assert_eq!("navy".parse(), Ok(Color::rgb(0, 0, 128)));
+
+        assert_eq!(
+            Color::parse("#12345"),
+            Err(ColorError::InvalidHex(InvalidHexColorError))
+        );
+        assert_eq!(Color::parse("rgb(1, 2)"), Err(ColorError::InvalidRgb));
+        assert_eq!(Color::parse("hsl(0 0% 0%)"), Err(ColorError::InvalidRgb));
+        assert_eq!(Color::parse("rgb(a b c)"), Err(ColorError::InvalidRgb));
+        assert_eq!(Color::parse("blurple"), Err(ColorError::UnknownName));
+
+        let color = Color::rgba(255, 170, 0, 128);
+        assert_eq!(color.to_string(), "#ffaa0080");
+        assert_eq!(color.to_css_rgb(), "rgb(255 170 0 / 0.502)");
+        assert_eq!(Color::rgb(255, 170, 0).to_css_rgb(), "rgb(255 170 0)");
+        assert_eq!(Color::parse(&color.to_hex()), Ok(color));
+        assert!(NAMED_COLORS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn test_contrast() {
+        assert!((Color::BLACK.contrast_ratio(&Color::WHITE) - 21.0).abs() < 1e-9);
+        assert!((Color::WHITE.contrast_ratio(&Color::WHITE) - 1.0).abs() < 1e-9);
+        let gray = Color::rgb(0x76, 0x76, 0x76);
+        assert!((gray.contrast_ratio(&Color::WHITE) - 4.54).abs() < 0.01);
+        assert!(gray.is_readable_on(&Color::WHITE));
+        assert!(!Color::rgb(0x77, 0x77, 0x77).is_readable_on(&Color::WHITE));
+
+        assert_eq!(Color::parse("yellow").unwrap().contrasting_text(), Color::BLACK);
+        assert_eq!(Color::parse("navy").unwrap().contrasting_text(), Color::WHITE);
+    }
+}
@@ -0,0 +1,14 @@
+pub mod hmac;
+pub mod protection;
+pub mod random;
+pub mod token;
+
+pub use self::hmac::*;
+pub use self::protection::*;
+pub use self::random::*;
+pub use self::token::*;
+
+/// Form field carrying the token, shared by the `CsrfField` component and the request extractor.
+pub const DEFAULT_CSRF_FIELD_NAME: &str = "csrf_token";
+/// Header carrying the token for htmx and `fetch` requests.
+pub const DEFAULT_CSRF_HEADER_NAME: &str = "X-CSRF-Token";
@@ -0,0 +1,214 @@
+use std::sync::atomic::{Ordering, compiler_fence};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{fmt, io};
+
+use crate::token::TokenParts;
+use crate::{CsrfError, CsrfToken, constant_time_eq, fill_random, hmac_sha256, to_hex};
+
+const MAC_CONTEXT: &[u8] = b"zabawa-csrf:v1";
+
+/// The secret used to sign CSRF tokens. Keep it out of logs; `Debug` does not print it, and it is zeroed when dropped.
+#[derive(Clone, PartialEq, Eq)]
+pub struct CsrfKey([u8; 32]);
+
+impl CsrfKey {
+    pub const fn new(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    /// A key from the operating system's random source, for a single process; tokens stop verifying after a
+    /// restart.
+    pub fn generate() -> io::Result<Self> {
+        let mut bytes = [0; 32];
+        fill_random(&mut bytes)?;
+        Ok(Self(bytes))
+    }
+
+    fn sign(&self, session_id: &str, expires: u64, nonce: &str) -> [u8; 32] {
+        hmac_sha256(&self.0, &[
+            MAC_CONTEXT,
+            b"\0",
+            session_id.as_bytes(),
+            b"\0",
+            expires.to_string().as_bytes(),
+            b"\0",
+            nonce.as_bytes(),
+        ])
+    }
+}
+
+impl fmt::Debug for CsrfKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CsrfKey(***)")
+    }
+}
+
+impl Drop for CsrfKey {
+    fn drop(&mut self) {
+        for byte in self.0.iter_mut() {
+            // SAFETY: `byte` is a valid, aligned and exclusive reference.
+            unsafe { std::ptr::write_volatile(byte, 0) };
+        }
+        compiler_fence(Ordering::SeqCst);
+    }
+}
+
+/// Issues and verifies [`CsrfToken`]s: an HMAC over the session id and an expiry time.
+///
+/// Signing keys can be rotated by moving the old key into `previous_keys`, which are accepted for verification only.
+#[derive(Clone, Debug)]
+pub struct CsrfProtection {
+    pub key: CsrfKey,
+    pub previous_keys: Vec<CsrfKey>,
+    pub ttl: Duration,
+    /// Tokens with less than this much lifetime left are replaced by [`CsrfProtection::refresh`].
+    pub refresh_before: Duration,
+}
+
+impl CsrfProtection {
+    pub fn new(key: CsrfKey) -> Self {
+        Self {
+            key,
+            previous_keys: Vec::new(),
+            ttl: Duration::from_secs(8 * 60 * 60),
+            refresh_before: Duration::from_secs(4 * 60 * 60),
+        }
+    }
+
+    pub fn with_previous_key(mut self, key: CsrfKey) -> Self {
+        self.previous_keys.push(key);
+        self
+    }
+
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    pub fn with_refresh_before(mut self, refresh_before: Duration) -> Self {
+        self.refresh_before = refresh_before;
+        self
+    }
+
+    pub fn generate(&self, session_id: &str) -> CsrfToken {
+        self.generate_at(session_id, SystemTime::now())
+    }
+
+    /// Panics if the operating system's random source cannot be read.
+    pub fn generate_at(&self, session_id: &str, now: SystemTime) -> CsrfToken {
+        let expires = unix_seconds(now).saturating_add(self.ttl.as_secs());
+        let mut nonce = [0; 16];
+        fill_random(&mut nonce).expect("failed to read the operating system's random source");
+        let nonce = to_hex(&nonce);
+        let mac = self.key.sign(session_id, expires, &nonce);
+        CsrfToken::from_raw(format!("{expires}.{nonce}.{}", to_hex(&mac)))
+    }
+
+    pub fn verify(&self, session_id: &str, token: &str) -> Result<(), CsrfError> {
+        self.verify_at(session_id, token, SystemTime::now())
+    }
+
+    pub fn verify_at(&self, session_id: &str, token: &str, now: SystemTime) -> Result<(), CsrfError> {
+        if token.is_empty() {
+            return Err(CsrfError::Missing);
+        }
+        let parts = TokenParts::parse(token)?;
+        let signed = std::iter::once(&self.key)
+            .chain(&self.previous_keys)
+            .any(|key| constant_time_eq(&key.sign(session_id, parts.expires, parts.nonce), &parts.mac));
+        if !signed {
+            return Err(CsrfError::Invalid);
+        }
+        if parts.expires <= unix_seconds(now) {
+            return Err(CsrfError::Expired);
+        }
+        Ok(())
+    }
+
+    /// Returns `token` while it is valid and not close to expiring, otherwise a new token for the session.
+    pub fn refresh(&self, session_id: &str, token: Option<&str>) -> CsrfToken {
+        self.refresh_at(session_id, token, SystemTime::now())
+    }
+
+    pub fn refresh_at(&self, session_id: &str, token: Option<&str>, now: SystemTime) -> CsrfToken {
+        let threshold = now + self.refresh_before;
+        match token {
+            Some(token) if self.verify_at(session_id, token, threshold).is_ok() => CsrfToken::from_raw(token),
+            _ => self.generate_at(session_id, now),
+        }
+    }
+}
+
+fn unix_seconds(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH).map_or(0, |duration| duration.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn protection() -> CsrfProtection {
+        CsrfProtection::new(CsrfKey::new([7; 32])).with_ttl(Duration::from_secs(3600))
+    }
+
+    #[test]
+    fn test_generate_and_verify() {
+        let protection = protection();
+        let now = UNIX_EPOCH + Duration::from_secs(1_800_000_000);
+        let token = protection.generate_at("session-1", now);
+
+        assert_eq!(protection.verify_at("session-1", token.as_str(), now), Ok(()));
+        assert_ne!(protection.generate_at("session-1", now), token);
+        assert_eq!(token.expires_at(), Some(now + Duration::from_secs(3600)));
+
+        assert_eq!(
+            protection.verify_at("session-2", token.as_str(), now),
+            Err(CsrfError::Invalid)
+        );
+        assert_eq!(
+            protection.verify_at("session-1", token.as_str(), now + Duration::from_secs(3600)),
+            Err(CsrfError::Expired)
+        );
+        let forged = token.as_str().replacen("1800003600", "1900000000", 1);
+        assert_eq!(protection.verify_at("session-1", &forged, now), Err(CsrfError::Invalid));
+        assert_eq!(protection.verify_at("session-1", "", now), Err(CsrfError::Missing));
+        assert_eq!(protection.verify_at("session-1", "abc", now), Err(CsrfError::Malformed));
+        assert_eq!(
+            protection.verify_at("session-1", "1.zz.00", now),
+            Err(CsrfError::Malformed)
+        );
+
+        let other_key = CsrfProtection::new(CsrfKey::new([8; 32]));
+        assert_eq!(
+            other_key.verify_at("session-1", token.as_str(), now),
+            Err(CsrfError::Invalid)
+        );
+        assert_eq!(format!("{:?}", CsrfKey::new([8; 32])), "CsrfKey(***)");
+        assert_eq!(format!("{token:?}"), "CsrfToken(***)");
+    }
+
+    #[test]
+    fn test_generate_key() {
+        let key = CsrfKey::generate().unwrap();
+        assert_ne!(key, CsrfKey::generate().unwrap());
+        assert_ne!(key, CsrfKey::new([0; 32]));
+    }
+
+    #[test]
+    fn test_rotation() {
+        let old = protection();
+        let now = UNIX_EPOCH + Duration::from_secs(1_800_000_000);
+        let token = old.generate_at("session-1", now);
+
+        let rotated = CsrfProtection::new(CsrfKey::generate().unwrap()).with_previous_key(old.key.clone());
+        assert_eq!(rotated.verify_at("session-1", token.as_str(), now), Ok(()));
+
+        let old = old.with_refresh_before(Duration::from_secs(600));
+        let later = now + Duration::from_secs(3000);
+        assert_eq!(old.refresh_at("session-1", Some(token.as_str()), now), token);
+        let refreshed = old.refresh_at("session-1", Some(token.as_str()), later);
+        assert_ne!(refreshed, token);
+        assert_eq!(old.verify_at("session-1", refreshed.as_str(), later), Ok(()));
+        assert_ne!(old.refresh_at("session-1", None, now), token);
+    }
+}
@@ -0,0 +1,22 @@
+use std::fs::File;
+use std::io::{self, Read};
+
+/// Fills `bytes` from the operating system's cryptographically secure random source.
+pub fn fill_random(bytes: &mut [u8]) -> io::Result<()> {
+    File::open("/dev/urandom")?.read_exact(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fill_random() {
+        let mut first = [0; 32];
+        let mut second = [0; 32];
+        fill_random(&mut first).unwrap();
+        fill_random(&mut second).unwrap();
+        assert_ne!(first, second);
+        assert_ne!(first, [0; 32]);
+    }
+}
@@ -0,0 +1,81 @@
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use derive_more::{Display, Into};
+use thiserror::Error;
+
+use crate::from_hex;
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CsrfError {
+    #[error("missing csrf token")]
+    Missing,
+
+    #[error("malformed csrf token")]
+    Malformed,
+
+    #[error("csrf token has expired")]
+    Expired,
+
+    #[error("csrf token is not valid for this session")]
+    Invalid,
+}
+
+/// A signed, expiring CSRF token of the form `expiry.nonce.mac`, bound to one session.
+///
+/// `Display` writes the raw token for embedding in forms and headers, while `Debug` is redacted.
+#[derive(Clone, Display, Into, PartialEq, Eq, Hash)]
+pub struct CsrfToken(String);
+
+impl CsrfToken {
+    pub fn from_raw(token: impl Into<String>) -> Self {
+        Self(token.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn expires_at(&self) -> Option<SystemTime> {
+        let parts = TokenParts::parse(&self.0).ok()?;
+        UNIX_EPOCH.checked_add(Duration::from_secs(parts.expires))
+    }
+}
+
+impl fmt::Debug for CsrfToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CsrfToken(***)")
+    }
+}
+
+impl AsRef<str> for CsrfToken {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+pub(crate) struct TokenParts<'a> {
+    pub expires: u64,
+    pub nonce: &'a str,
+    pub mac: Vec<u8>,
+}
+
+impl<'a> TokenParts<'a> {
+    pub fn parse(token: &'a str) -> Result<Self, CsrfError> {
+        let mut parts = token.split('.');
+        let (Some(expires), Some(nonce), Some(mac), None) = (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(CsrfError::Malformed);
+        };
+        if !expires.bytes().all(|b| b.is_ascii_digit()) || from_hex(nonce).is_none_or(|nonce| nonce.is_empty()) {
+            return Err(CsrfError::Malformed);
+        }
+        Ok(Self {
+            expires: expires.parse().map_err(|_| CsrfError::Malformed)?,
+            nonce,
+            mac: from_hex(mac)
+                .filter(|mac| mac.len() == 32)
+                .ok_or(CsrfError::Malformed)?,
+        })
+    }
+}
@@ -0,0 +1,204 @@
+use std::str::FromStr;
+
+use derive_more::{Display, Into};
+use thiserror::Error;
+pub use zabawa_validation::InvalidEmailError;
+use zabawa_validation::{MAX_EMAIL_LENGTH, split_email, validate_email, validate_trimmed};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum EmailError {
+    #[error("email has leading or trailing whitespaces")]
+    Untrimmed,
+
+    #[error("email error: {0}")]
+    Invalid(#[from] InvalidEmailError),
+}
+
+#[derive(Clone, Debug, Display, Into, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Email(String);
+
+impl Email {
+    pub fn from_raw(email: impl Into<String>) -> Self {
+        Self(email.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn local_part(&self) -> &str {
+        split_email(&self.0).map_or(&self.0, |(local, _)| local)
+    }
+
+    pub fn domain(&self) -> &str {
+        split_email(&self.0).map_or("", |(_, domain)| domain)
+    }
+}
+
+impl AsRef<str> for Email {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Validates with [`DefaultEmailBuilder::new`], without normalizing.
+impl FromStr for Email {
+    type Err = EmailError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        DefaultEmailBuilder::new().build(input)
+    }
+}
+
+impl TryFrom<String> for Email {
+    type Error = EmailError;
+
+    fn try_from(input: String) -> Result<Self, Self::Error> {
+        DefaultEmailBuilder::new().build(input)
+    }
+}
+
+impl TryFrom<&str> for Email {
+    type Error = EmailError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        input.parse()
+    }
+}
+
+pub trait EmailBuilder {
+    type Email;
+    type Error;
+
+    fn validate(&self, input: &str) -> Result<(), Self::Error>;
+
+    fn normalize(&self, input: &str) -> Result<String, Self::Error>;
+
+    fn build(&self, input: impl AsRef<str> + Into<String>) -> Result<Self::Email, Self::Error>;
+
+    fn build_with_normalize(&self, input: impl AsRef<str> + Into<String>) -> Result<Self::Email, Self::Error>;
+}
+
+#[derive(Debug, Default)]
+pub struct DefaultEmailBuilder {
+    pub lowercase_local_part: bool,
+    pub trim_validation_enabled: bool,
+}
+
+impl DefaultEmailBuilder {
+    pub fn new() -> Self {
+        Self {
+            lowercase_local_part: false,
+            trim_validation_enabled: true,
+        }
+    }
+
+    /// Local parts are case-sensitive per RFC 5321, but most providers treat them case-insensitively.
+    pub fn with_lowercase_local_part(mut self, enabled: bool) -> Self {
+        self.lowercase_local_part = enabled;
+        self
+    }
+
+    pub fn with_trim_validation(mut self, enabled: bool) -> Self {
+        self.trim_validation_enabled = enabled;
+        self
+    }
+}
+
+impl EmailBuilder for DefaultEmailBuilder {
+    type Email = Email;
+    type Error = EmailError;
+
+    fn validate(&self, input: &str) -> Result<(), Self::Error> {
+        if self.trim_validation_enabled && !validate_trimmed(input) {
+            return Err(EmailError::Untrimmed);
+        }
+
+        validate_email(input)?;
+
+        Ok(())
+    }
+
+    fn normalize(&self, input: &str) -> Result<String, Self::Error> {
+        let input = if self.trim_validation_enabled {
+            input.trim()
+        } else {
+            input
+        };
+        let (local, domain) = split_email(input).ok_or(InvalidEmailError::MissingAt)?;
+
+        let mut normalized = String::with_capacity(input.len().min(MAX_EMAIL_LENGTH));
+        if self.lowercase_local_part {
+            normalized.push_str(&local.to_lowercase());
+        } else {
+            normalized.push_str(local);
+        }
+        normalized.push('@');
+        normalized.push_str(&domain.to_lowercase());
+
+        Ok(normalized)
+    }
+
+    fn build(&self, input: impl AsRef<str> + Into<String>) -> Result<Self::Email, Self::Error> {
+        self.validate(input.as_ref())?;
+
+        Ok(Email::from_raw(input))
+    }
+
+    fn build_with_normalize(&self, input: impl AsRef<str> + Into<String>) -> Result<Self::Email, Self::Error> {
+        let normalized_input = self.normalize(input.as_ref())?;
+        self.build(normalized_input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build() {
+        let builder = DefaultEmailBuilder::new();
+        let email = builder.build("Jane.Doe@Example.com").unwrap();
+        assert_eq!(email.local_part(), "Jane.Doe");
+        assert_eq!(email.domain(), "Example.com");
+
+        assert!(matches!(builder.build(" jane@example.com"), Err(EmailError::Untrimmed)));
+        assert!(matches!(
+            builder.build("jane@example"),
+            Err(EmailError::Invalid(InvalidEmailError::InvalidDomain))
+        ));
+    }
+
+    #[test]
+    fn test_parse() {
+        let email: Email = "jane@example.com".parse().unwrap();
+        assert_eq!(email.as_str(), "jane@example.com");
+        assert_eq!(Email::try_from("jane@example.com".to_owned()).unwrap(), email);
+        assert_eq!(
+            Email::try_from("jane doe@example.com").unwrap_err().to_string(),
+            "email error: email address local part contains invalid character ' '"
+        );
+    }
+
+    #[test]
+    fn test_build_with_normalize() {
+        let builder = DefaultEmailBuilder::new();
+        assert_eq!(
+            builder
+                .build_with_normalize("  Jane.Doe@EXAMPLE.com ")
+                .unwrap()
+                .as_str(),
+            "Jane.Doe@example.com"
+        );
+
+        let builder = builder.with_lowercase_local_part(true);
+        assert_eq!(
+            builder.build_with_normalize("Jane.Doe@EXAMPLE.com").unwrap().as_str(),
+            "jane.doe@example.com"
+        );
+        assert!(matches!(
+            builder.build_with_normalize("jane"),
+            Err(EmailError::Invalid(InvalidEmailError::MissingAt))
+        ));
+    }
+}
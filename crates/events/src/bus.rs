@@ -0,0 +1,133 @@
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, PoisonError, RwLock};
+
+use crate::DomainEvent;
+
+type Handler = Arc<dyn Fn(&dyn DomainEvent) + Send + Sync>;
+
+/// A synchronous in-process event bus. Handlers run on the publishing thread, in subscription order.
+///
+/// Handlers may publish further events; they see the subscriptions as of the outer `publish` call.
+#[derive(Default)]
+pub struct EventBus {
+    handlers: RwLock<HashMap<TypeId, Vec<Handler>>>,
+    catch_all: RwLock<Vec<Handler>>,
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn subscribe<E: DomainEvent>(&self, handler: impl Fn(&E) + Send + Sync + 'static) {
+        let handler: Handler = Arc::new(move |event: &dyn DomainEvent| {
+            if let Some(event) = event.downcast_ref::<E>() {
+                handler(event);
+            }
+        });
+        self.handlers
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(TypeId::of::<E>())
+            .or_default()
+            .push(handler);
+    }
+
+    /// Subscribes to every event, e.g. for logging or the notification bridge.
+    pub fn subscribe_all(&self, handler: impl Fn(&dyn DomainEvent) + Send + Sync + 'static) {
+        self.catch_all
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(Arc::new(handler));
+    }
+
+    pub fn publish<E: DomainEvent>(&self, event: &E) {
+        self.publish_dyn(event);
+    }
+
+    pub fn publish_dyn(&self, event: &dyn DomainEvent) {
+        let typed = self
+            .handlers
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&(event as &dyn std::any::Any).type_id())
+            .cloned()
+            .unwrap_or_default();
+        let catch_all = self.catch_all.read().unwrap_or_else(PoisonError::into_inner).clone();
+        for handler in typed.iter().chain(&catch_all) {
+            handler(event);
+        }
+    }
+
+    pub fn handler_count(&self) -> usize {
+        let typed: usize = self
+            .handlers
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .values()
+            .map(Vec::len)
+            .sum();
+        typed + self.catch_all.read().unwrap_or_else(PoisonError::into_inner).len()
+    }
+}
+
+impl fmt::Debug for EventBus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventBus")
+            .field("handlers", &self.handler_count())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    struct ProjectCreated(&'static str);
+
+    impl DomainEvent for ProjectCreated {
+        fn name(&self) -> &'static str {
+            "project.created"
+        }
+    }
+
+    struct ProjectDeleted;
+
+    impl DomainEvent for ProjectDeleted {
+        fn name(&self) -> &'static str {
+            "project.deleted"
+        }
+    }
+
+    #[test]
+    fn test_publish() {
+        let bus = Arc::new(EventBus::new());
+        let log = Arc::new(Mutex::new(Vec::new()));
+
+        let created = log.clone();
+        bus.subscribe(move |event: &ProjectCreated| created.lock().unwrap().push(event.0.to_owned()));
+        let all = log.clone();
+        bus.subscribe_all(move |event| all.lock().unwrap().push(event.name().to_owned()));
+        let nested = Arc::downgrade(&bus);
+        bus.subscribe(move |_: &ProjectDeleted| {
+            if let Some(bus) = nested.upgrade() {
+                bus.publish(&ProjectCreated("restored"));
+            }
+        });
+        assert_eq!(bus.handler_count(), 3);
+
+        bus.publish(&ProjectCreated("website"));
+        bus.publish(&ProjectDeleted);
+        assert_eq!(*log.lock().unwrap(), [
+            "website",
+            "project.created",
+            "restored",
+            "project.created",
+            "project.deleted"
+        ]);
+    }
+}
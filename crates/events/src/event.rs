@@ -0,0 +1,17 @@
+use std::any::Any;
+
+/// Something that happened in the domain, e.g. `ProjectCreated`, published after the change is committed.
+pub trait DomainEvent: Any + Send + Sync {
+    /// A stable dotted name such as `project.created`, for logs and audit trails.
+    fn name(&self) -> &'static str;
+}
+
+impl dyn DomainEvent {
+    pub fn is<E: DomainEvent>(&self) -> bool {
+        (self as &dyn Any).is::<E>()
+    }
+
+    pub fn downcast_ref<E: DomainEvent>(&self) -> Option<&E> {
+        (self as &dyn Any).downcast_ref()
+    }
+}
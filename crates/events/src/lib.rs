@@ -0,0 +1,7 @@
+pub mod bus;
+pub mod event;
+pub mod notification;
+
+pub use self::bus::*;
+pub use self::event::*;
+pub use self::notification::*;
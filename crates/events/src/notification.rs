@@ -0,0 +1,136 @@
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use zabawa_notification_domain::logic::NotificationStore;
+use zabawa_notification_domain::model::Notification;
+
+use crate::{DomainEvent, EventBus};
+
+type Rule = Box<dyn Fn(&dyn DomainEvent) -> Option<Notification> + Send + Sync>;
+
+/// The registry of which domain events produce user-facing notifications, e.g. "project created" → success toast.
+#[derive(Default)]
+pub struct NotificationRules {
+    rules: HashMap<TypeId, Vec<Rule>>,
+}
+
+impl NotificationRules {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_rule<E: DomainEvent>(
+        mut self,
+        rule: impl Fn(&E) -> Option<Notification> + Send + Sync + 'static,
+    ) -> Self {
+        self.add_rule(rule);
+        self
+    }
+
+    pub fn add_rule<E: DomainEvent>(&mut self, rule: impl Fn(&E) -> Option<Notification> + Send + Sync + 'static) {
+        let rule: Rule = Box::new(move |event: &dyn DomainEvent| event.downcast_ref::<E>().and_then(&rule));
+        self.rules.entry(TypeId::of::<E>()).or_default().push(rule);
+    }
+
+    pub fn has_rule<E: DomainEvent>(&self) -> bool {
+        self.rules.contains_key(&TypeId::of::<E>())
+    }
+
+    pub fn notifications_for(&self, event: &dyn DomainEvent) -> Vec<Notification> {
+        self.rules
+            .get(&(event as &dyn std::any::Any).type_id())
+            .into_iter()
+            .flatten()
+            .filter_map(|rule| rule(event))
+            .collect()
+    }
+
+    /// Pushes the notifications for `event` to `store`, typically the current request's flash store.
+    pub fn notify(&self, event: &dyn DomainEvent, store: &impl NotificationStore) {
+        for notification in self.notifications_for(event) {
+            store.push(notification);
+        }
+    }
+
+    /// Routes every event published on `bus` through the rules into `store`.
+    pub fn attach(self: Arc<Self>, bus: &EventBus, store: impl NotificationStore + Send + Sync + 'static) {
+        bus.subscribe_all(move |event| self.notify(event, &store));
+    }
+}
+
+impl fmt::Debug for NotificationRules {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NotificationRules")
+            .field("rules", &self.rules.values().map(Vec::len).sum::<usize>())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zabawa_notification_domain::logic::InMemoryNotificationStore;
+    use zabawa_notification_domain::model::NotificationLevel;
+
+    use super::*;
+
+    struct ProjectCreated {
+        name: String,
+        imported: bool,
+    }
+
+    impl DomainEvent for ProjectCreated {
+        fn name(&self) -> &'static str {
+            "project.created"
+        }
+    }
+
+    struct ProjectArchived;
+
+    impl DomainEvent for ProjectArchived {
+        fn name(&self) -> &'static str {
+            "project.archived"
+        }
+    }
+
+    #[test]
+    fn test_bridge() {
+        let rules = Arc::new(
+            NotificationRules::new()
+                .with_rule(|event: &ProjectCreated| {
+                    Some(Notification::success(format!("Project {} created", event.name)))
+                })
+                .with_rule(|event: &ProjectCreated| {
+                    event
+                        .imported
+                        .then(|| Notification::info("Imported issues will appear shortly"))
+                }),
+        );
+        assert!(rules.has_rule::<ProjectCreated>());
+        assert!(!rules.has_rule::<ProjectArchived>());
+
+        let bus = EventBus::new();
+        let store = Arc::new(InMemoryNotificationStore::new());
+        rules.clone().attach(&bus, store.clone());
+
+        bus.publish(&ProjectCreated {
+            name: "website".to_owned(),
+            imported: false,
+        });
+        bus.publish(&ProjectArchived);
+        bus.publish(&ProjectCreated {
+            name: "api".to_owned(),
+            imported: true,
+        });
+
+        let notifications = store.drain();
+        let messages: Vec<&str> = notifications.iter().map(|n| n.message.as_str()).collect();
+        assert_eq!(messages, [
+            "Project website created",
+            "Project api created",
+            "Imported issues will appear shortly"
+        ]);
+        assert_eq!(notifications[0].level, NotificationLevel::Success);
+    }
+}
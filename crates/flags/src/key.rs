@@ -0,0 +1,67 @@
+use derive_more::{Display, Into};
+use zabawa_name::{DefaultNameBuilder, NameBulder, NameError};
+
+pub const MAX_FLAG_KEY_LENGTH: usize = 64;
+
+/// A feature flag identifier following the [`zabawa_name::Name`] rules, e.g. `new-dashboard`.
+#[derive(Clone, Debug, Display, Into, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FlagKey(String);
+
+impl FlagKey {
+    pub fn new(key: impl AsRef<str> + Into<String>) -> Result<Self, NameError> {
+        let name = Self::builder().build(key)?;
+        Ok(Self(name.into()))
+    }
+
+    pub fn from_raw(key: impl Into<String>) -> Self {
+        Self(key.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The environment variable name for this key, e.g. `ZABAWA_FLAG_NEW_DASHBOARD`.
+    pub fn env_var(&self, prefix: &str) -> String {
+        let mut var = String::with_capacity(prefix.len() + self.0.len());
+        var.push_str(prefix);
+        var.extend(
+            self.0
+                .chars()
+                .map(|ch| if ch == '-' { '_' } else { ch.to_ascii_uppercase() }),
+        );
+        var
+    }
+
+    fn builder() -> DefaultNameBuilder {
+        DefaultNameBuilder::new()
+            .with_min_length(1)
+            .with_max_length(MAX_FLAG_KEY_LENGTH)
+    }
+}
+
+impl AsRef<str> for FlagKey {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flag_key() {
+        let key = FlagKey::new("new-dashboard").unwrap();
+        assert_eq!(key.as_str(), "new-dashboard");
+        assert_eq!(key.env_var("ZABAWA_FLAG_"), "ZABAWA_FLAG_NEW_DASHBOARD");
+        assert_eq!(FlagKey::from_raw("beta_2").env_var(""), "BETA_2");
+
+        assert!(matches!(
+            FlagKey::new("New Dashboard"),
+            Err(NameError::InvalidCharacters(_))
+        ));
+        assert!(matches!(FlagKey::new(""), Err(NameError::InvalidLength(_))));
+        assert!(FlagKey::new("a".repeat(MAX_FLAG_KEY_LENGTH + 1)).is_err());
+    }
+}
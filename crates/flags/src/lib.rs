@@ -0,0 +1,7 @@
+pub mod key;
+pub mod provider;
+pub mod view;
+
+pub use self::key::*;
+pub use self::provider::*;
+pub use self::view::*;
@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{PoisonError, RwLock};
+
+use crate::FlagKey;
+
+pub const DEFAULT_FLAG_ENV_PREFIX: &str = "ZABAWA_FLAG_";
+
+pub trait FlagProvider: Send + Sync {
+    /// The configured state, or `None` when this provider knows nothing about the flag.
+    fn flag(&self, key: &FlagKey) -> Option<bool>;
+
+    /// Unknown flags are disabled.
+    fn is_enabled(&self, key: &FlagKey) -> bool {
+        self.flag(key).unwrap_or(false)
+    }
+}
+
+/// Consults each provider in order and uses the first one that knows the flag, e.g. env overrides before defaults.
+impl<P: FlagProvider> FlagProvider for [P] {
+    fn flag(&self, key: &FlagKey) -> Option<bool> {
+        self.iter().find_map(|provider| provider.flag(key))
+    }
+}
+
+impl<P: FlagProvider> FlagProvider for Vec<P> {
+    fn flag(&self, key: &FlagKey) -> Option<bool> {
+        self.as_slice().flag(key)
+    }
+}
+
+impl<P: FlagProvider + ?Sized> FlagProvider for Box<P> {
+    fn flag(&self, key: &FlagKey) -> Option<bool> {
+        (**self).flag(key)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct InMemoryFlagProvider {
+    flags: RwLock<HashMap<FlagKey, bool>>,
+}
+
+impl InMemoryFlagProvider {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_flag(self, key: FlagKey, enabled: bool) -> Self {
+        self.set(key, enabled);
+        self
+    }
+
+    pub fn set(&self, key: FlagKey, enabled: bool) {
+        self.flags
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key, enabled);
+    }
+
+    pub fn remove(&self, key: &FlagKey) {
+        self.flags.write().unwrap_or_else(PoisonError::into_inner).remove(key);
+    }
+}
+
+impl FlagProvider for InMemoryFlagProvider {
+    fn flag(&self, key: &FlagKey) -> Option<bool> {
+        self.flags
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(key)
+            .copied()
+    }
+}
+
+type Lookup = dyn Fn(&str) -> Option<String> + Send + Sync;
+
+/// Reads flags from environment variables such as `ZABAWA_FLAG_NEW_DASHBOARD=1`.
+///
+/// `1`, `true`, `on` and `yes` enable a flag and `0`, `false`, `off` and `no` disable it, ignoring case; any other
+/// value is treated as unset.
+pub struct EnvFlagProvider {
+    prefix: String,
+    lookup: Box<Lookup>,
+}
+
+impl EnvFlagProvider {
+    pub fn new() -> Self {
+        Self::with_prefix(DEFAULT_FLAG_ENV_PREFIX)
+    }
+
+    pub fn with_prefix(prefix: impl Into<String>) -> Self {
+        Self::with_lookup(prefix, |var| std::env::var(var).ok())
+    }
+
+    /// Reads variables through `lookup` instead of the process environment, e.g. from a parsed config file.
+    pub fn with_lookup(
+        prefix: impl Into<String>,
+        lookup: impl Fn(&str) -> Option<String> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            prefix: prefix.into(),
+            lookup: Box::new(lookup),
+        }
+    }
+}
+
+impl Default for EnvFlagProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FlagProvider for EnvFlagProvider {
+    fn flag(&self, key: &FlagKey) -> Option<bool> {
+        let value = (self.lookup)(&key.env_var(&self.prefix))?;
+        match value.trim().to_ascii_lowercase().as_str() {
+            "1" | "true" | "on" | "yes" => Some(true),
+            "0" | "false" | "off" | "no" => Some(false),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Debug for EnvFlagProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EnvFlagProvider")
+            .field("prefix", &self.prefix)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(key: &str) -> FlagKey {
+        FlagKey::new(key).unwrap()
+    }
+
+    #[test]
+    fn test_in_memory_provider() {
+        let flags = InMemoryFlagProvider::new().with_flag(key("beta"), true);
+        assert!(flags.is_enabled(&key("beta")));
+        assert_eq!(flags.flag(&key("other")), None);
+        assert!(!flags.is_enabled(&key("other")));
+
+        flags.set(key("beta"), false);
+        assert_eq!(flags.flag(&key("beta")), Some(false));
+        flags.remove(&key("beta"));
+        assert_eq!(flags.flag(&key("beta")), None);
+    }
+
+    #[test]
+    fn test_env_provider() {
+        let flags = EnvFlagProvider::with_lookup("APP_FLAG_", |var| match var {
+            "APP_FLAG_NEW_DASHBOARD" => Some(" On ".to_owned()),
+            "APP_FLAG_BETA" => Some("0".to_owned()),
+            "APP_FLAG_TYPO" => Some("maybe".to_owned()),
+            _ => None,
+        });
+        assert_eq!(flags.flag(&key("new-dashboard")), Some(true));
+        assert_eq!(flags.flag(&key("beta")), Some(false));
+        assert_eq!(flags.flag(&key("typo")), None);
+        assert_eq!(flags.flag(&key("missing")), None);
+    }
+
+    #[test]
+    fn test_layered_providers() {
+        let overrides: Box<dyn FlagProvider> = Box::new(InMemoryFlagProvider::new().with_flag(key("beta"), false));
+        let defaults: Box<dyn FlagProvider> = Box::new(
+            InMemoryFlagProvider::new()
+                .with_flag(key("beta"), true)
+                .with_flag(key("search"), true),
+        );
+        let flags = vec![overrides, defaults];
+        assert!(!flags.is_enabled(&key("beta")));
+        assert!(flags.is_enabled(&key("search")));
+        assert_eq!(flags.flag(&key("missing")), None);
+    }
+}
@@ -0,0 +1,57 @@
+use hypertext::{Renderable, rsx};
+
+use crate::{FlagKey, FlagProvider};
+
+/// Renders its content only while a flag is enabled.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FlagGate {
+    pub key: FlagKey,
+}
+
+impl FlagGate {
+    pub fn new(key: FlagKey) -> Self {
+        Self { key }
+    }
+
+    pub fn render(&self, flags: &(impl FlagProvider + ?Sized), children: impl Renderable) -> impl Renderable {
+        self.render_or(flags, children, ())
+    }
+
+    /// Renders `fallback` instead while the flag is disabled, e.g. the existing version of an experimental section.
+    pub fn render_or(
+        &self,
+        flags: &(impl FlagProvider + ?Sized),
+        children: impl Renderable,
+        fallback: impl Renderable,
+    ) -> impl Renderable {
+        let enabled = flags.is_enabled(&self.key);
+        rsx! {
+            @if enabled {
+                (children)
+            } @else {
+                (fallback)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InMemoryFlagProvider;
+
+    #[test]
+    fn test_flag_gate() {
+        let key = FlagKey::new("new-dashboard").unwrap();
+        let gate = FlagGate::new(key.clone());
+        let flags = InMemoryFlagProvider::new();
+
+        assert_eq!(gate.render(&flags, "new").render().as_inner(), "");
+        assert_eq!(gate.render_or(&flags, "new", "old").render().as_inner(), "old");
+
+        flags.set(key, true);
+        let flags: &dyn FlagProvider = &flags;
+        assert_eq!(gate.render(flags, "new").render().as_inner(), "new");
+        assert_eq!(gate.render_or(flags, "new", "old").render().as_inner(), "new");
+    }
+}
@@ -0,0 +1,232 @@
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{
+    Data, DeriveInput, Error, Fields, GenericArgument, Ident, LitInt, LitStr, PathArguments, Type, parse_macro_input,
+};
+
+const NUMERIC_TYPES: &[&str] = &[
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize", "f32", "f64",
+];
+const INPUT_TYPES: &[(&str, &str)] = &[
+    ("text", "Text"),
+    ("email", "Email"),
+    ("password", "Password"),
+    ("number", "Number"),
+    ("search", "Search"),
+    ("tel", "Tel"),
+    ("url", "Url"),
+    ("date", "Date"),
+];
+
+/// Derives `FormView`, rendering each named field with the matching view-common form component.
+///
+/// Field attributes, all optional: `#[form(label = "…", hint = "…", placeholder = "…", name = "…", required,
+/// textarea, rows = 6, input_type = "email", skip)]`.
+#[proc_macro_derive(FormView, attributes(form))]
+pub fn derive_form_view(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+#[derive(Default)]
+struct FieldOptions {
+    name: Option<LitStr>,
+    label: Option<LitStr>,
+    hint: Option<LitStr>,
+    placeholder: Option<LitStr>,
+    input_type: Option<LitStr>,
+    rows: Option<LitInt>,
+    required: bool,
+    textarea: bool,
+    skip: bool,
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "FormView can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new_spanned(&input.ident, "FormView requires named fields"));
+    };
+
+    let mut renders = Vec::new();
+    for field in &fields.named {
+        let options = parse_options(field)?;
+        if options.skip {
+            continue;
+        }
+        let ident = field.ident.as_ref().expect("named field");
+        renders.push(render_field(ident, &field.ty, options)?);
+    }
+
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::zabawa_form_view::FormView for #name #type_generics #where_clause {
+            fn render_fields<'a>(
+                &'a self,
+                errors: &'a ::zabawa_form_view::__private::ValidationErrors,
+            ) -> impl ::zabawa_form_view::__private::Renderable + 'a {
+                ::zabawa_form_view::__private::Fields(move |buffer: &mut ::zabawa_form_view::__private::Buffer| {
+                    #[allow(unused_imports)]
+                    use ::zabawa_form_view::__private::Renderable as _;
+                    #(#renders)*
+                })
+            }
+        }
+    })
+}
+
+fn parse_options(field: &syn::Field) -> syn::Result<FieldOptions> {
+    let mut options = FieldOptions::default();
+    for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("form")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                options.name = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("label") {
+                options.label = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("hint") {
+                options.hint = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("placeholder") {
+                options.placeholder = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("input_type") {
+                let value: LitStr = meta.value()?.parse()?;
+                if !INPUT_TYPES.iter().any(|(name, _)| *name == value.value()) {
+                    return Err(Error::new_spanned(&value, "unknown input type"));
+                }
+                options.input_type = Some(value);
+            } else if meta.path.is_ident("rows") {
+                options.rows = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("required") {
+                options.required = true;
+            } else if meta.path.is_ident("textarea") {
+                options.textarea = true;
+            } else if meta.path.is_ident("skip") {
+                options.skip = true;
+            } else {
+                return Err(meta.error("unknown form attribute"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(options)
+}
+
+fn render_field(ident: &Ident, ty: &Type, options: FieldOptions) -> syn::Result<TokenStream2> {
+    let field_name = ident.to_string().trim_start_matches("r#").to_owned();
+    let name = options.name.map_or(field_name.clone(), |name| name.value());
+    let label = options
+        .label
+        .map_or_else(|| humanize(&field_name), |label| label.value());
+    let hint = options.hint.map(|hint| quote! { .with_hint(#hint) });
+    let placeholder = options
+        .placeholder
+        .map(|placeholder| quote! { .with_placeholder(#placeholder) });
+    let required = options.required.then(|| quote! { .with_required(true) });
+    let private = quote! { ::zabawa_form_view::__private };
+
+    let type_name = last_segment(ty);
+    if type_name.as_deref() == Some("bool") {
+        return Ok(quote! {
+            #private::Checkbox::new(#name)
+                #hint
+                #required
+                .render(#label, self.#ident, errors)
+                .render_to(buffer);
+        });
+    }
+
+    let value = match option_inner(ty) {
+        Some(_) => quote! {
+            match &self.#ident {
+                ::std::option::Option::Some(value) => ::std::string::ToString::to_string(value),
+                ::std::option::Option::None => ::std::string::String::new(),
+            }
+        },
+        None => quote! { ::std::string::ToString::to_string(&self.#ident) },
+    };
+
+    if options.textarea {
+        let rows = options.rows.map(|rows| quote! { .with_rows(#rows) });
+        return Ok(quote! {
+            {
+                let value = #value;
+                #private::TextArea::new(#name, #label)
+                    #rows
+                    #hint
+                    #placeholder
+                    #required
+                    .render(&value, errors)
+                    .render_to(buffer);
+            }
+        });
+    }
+    if let Some(rows) = &options.rows {
+        return Err(Error::new_spanned(rows, "`rows` requires `textarea`"));
+    }
+
+    let inner_name = option_inner(ty).and_then(last_segment).or(type_name);
+    let input_type = match &options.input_type {
+        Some(input_type) => INPUT_TYPES
+            .iter()
+            .find(|(name, _)| *name == input_type.value())
+            .map(|(_, variant)| *variant),
+        None if inner_name.is_some_and(|name| NUMERIC_TYPES.contains(&name.as_str())) => Some("Number"),
+        None => None,
+    };
+    let input_type = input_type.map(|variant| {
+        let variant = Ident::new(variant, Span::call_site());
+        quote! { .with_type(#private::InputType::#variant) }
+    });
+
+    Ok(quote! {
+        {
+            let value = #value;
+            #private::TextInput::new(#name, #label)
+                #input_type
+                #hint
+                #placeholder
+                #required
+                .render(&value, errors)
+                .render_to(buffer);
+        }
+    })
+}
+
+fn last_segment(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(path) => path.path.segments.last().map(|segment| segment.ident.to_string()),
+        _ => None,
+    }
+}
+
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    match arguments.args.first()? {
+        GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
+
+/// `contact_email` becomes `Contact email`.
+fn humanize(field: &str) -> String {
+    let words = field.replace('_', " ");
+    let mut chars = words.trim().chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
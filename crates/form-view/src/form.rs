@@ -0,0 +1,95 @@
+use std::borrow::Cow;
+
+use hypertext::prelude::GlobalAttributes;
+use hypertext::{Renderable, rsx};
+use zabawa_validation::ValidationErrors;
+use zabawa_view_common::{Attrs, Button, CsrfField, RenderContext, hypertext_elements};
+
+/// Renders a value's fields as form controls showing the current values and inline errors.
+///
+/// Usually derived with `#[derive(FormView)]`.
+pub trait FormView {
+    fn render_fields<'a>(&'a self, errors: &'a ValidationErrors) -> impl Renderable + 'a;
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FormMethod {
+    #[default]
+    Post,
+    Get,
+}
+
+impl FormMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FormMethod::Post => "post",
+            FormMethod::Get => "get",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Form {
+    pub action: Cow<'static, str>,
+    pub method: FormMethod,
+    pub submit: Button,
+    pub submit_label: Cow<'static, str>,
+    pub csrf: CsrfField,
+    pub attrs: Attrs,
+}
+
+impl Form {
+    pub fn new(action: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            action: action.into(),
+            method: FormMethod::Post,
+            submit: Button::submit(),
+            submit_label: Cow::Borrowed("Save"),
+            csrf: CsrfField::new(),
+            attrs: Attrs::new(),
+        }
+    }
+
+    pub fn with_method(mut self, method: FormMethod) -> Self {
+        self.method = method;
+        self
+    }
+
+    pub fn with_submit(mut self, submit: Button) -> Self {
+        self.submit = submit;
+        self
+    }
+
+    pub fn with_submit_label(mut self, label: impl Into<Cow<'static, str>>) -> Self {
+        self.submit_label = label.into();
+        self
+    }
+
+    pub fn with_csrf(mut self, csrf: CsrfField) -> Self {
+        self.csrf = csrf;
+        self
+    }
+
+    pub fn with_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs = attrs;
+        self
+    }
+
+    /// Renders the form; the CSRF field is only included for `POST` forms.
+    pub fn render<'a, T: FormView>(
+        &'a self,
+        ctx: &'a RenderContext,
+        value: &'a T,
+        errors: &'a ValidationErrors,
+    ) -> impl Renderable + 'a {
+        self.attrs.spread(rsx! {
+            <form class="form wa-stack" method=(self.method.as_str()) action=(self.action)>
+                @if self.method == FormMethod::Post {
+                    (self.csrf.render(ctx))
+                }
+                (value.render_fields(errors))
+                (self.submit.render(&self.submit_label))
+            </form>
+        })
+    }
+}
@@ -0,0 +1,26 @@
+// Lets the derive's `::zabawa_form_view` paths resolve inside this crate's own tests.
+extern crate self as zabawa_form_view;
+
+pub mod form;
+pub mod submission;
+
+pub use zabawa_form_view_derive::FormView;
+
+pub use self::form::*;
+pub use self::submission::*;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use hypertext::{Buffer, Renderable};
+    pub use zabawa_validation::ValidationErrors;
+    pub use zabawa_view_common::{Checkbox, InputType, TextArea, TextInput};
+
+    /// The field list generated by `#[derive(FormView)]`.
+    pub struct Fields<F>(pub F);
+
+    impl<F: Fn(&mut Buffer)> Renderable for Fields<F> {
+        fn render_to(&self, buffer: &mut Buffer) {
+            (self.0)(buffer);
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use hypertext::Rendered;
+use zabawa_validation::{Validate, ValidationErrors};
+use zabawa_view_common::RenderContext;
+
+use crate::{Form, FormView};
+
+/// The outcome of validating a submitted form value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Submission<T> {
+    Accepted(T),
+    Rejected { value: T, errors: ValidationErrors },
+}
+
+impl<T: Validate> Submission<T> {
+    pub fn new(value: T) -> Self {
+        match value.validate() {
+            Ok(()) => Submission::Accepted(value),
+            Err(errors) => Submission::Rejected { value, errors },
+        }
+    }
+}
+
+impl<T> Submission<T> {
+    pub fn is_accepted(&self) -> bool {
+        matches!(self, Submission::Accepted(_))
+    }
+
+    pub fn into_result(self) -> Result<T, (T, ValidationErrors)> {
+        match self {
+            Submission::Accepted(value) => Ok(value),
+            Submission::Rejected { value, errors } => Err((value, errors)),
+        }
+    }
+}
+
+impl<T: FormView> Submission<T> {
+    /// Returns the accepted value, or the form re-rendered with the submitted values and their errors.
+    ///
+    /// `let project = submit(input).redisplay(&form, &ctx)?;`
+    pub fn redisplay(self, form: &Form, ctx: &RenderContext) -> Result<T, Rendered<String>> {
+        self.into_result()
+            .map_err(|(value, errors)| ctx.render(form.render(ctx, &value, &errors)))
+    }
+}
+
+pub fn submit<T: Validate>(value: T) -> Submission<T> {
+    Submission::new(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use hypertext::Renderable;
+    use zabawa_validation::validate_length;
+    use zabawa_view_common::testing::{Document, assert_has_selector, assert_selector_count};
+
+    use super::*;
+
+    #[derive(FormView)]
+    struct ProjectForm {
+        #[form(label = "Project name", hint = "Shown on the dashboard", required)]
+        name: String,
+        #[form(textarea, rows = 6)]
+        description: String,
+        #[form(input_type = "email")]
+        contact_email: Option<String>,
+        max_members: u32,
+        public: bool,
+        #[form(skip)]
+        #[allow(dead_code)]
+        id: u64,
+    }
+
+    impl Validate for ProjectForm {
+        fn validate(&self) -> Result<(), ValidationErrors> {
+            let mut errors = ValidationErrors::new();
+            errors.add_result("name", validate_length(self.name.len(), 3, 64));
+            errors.into_result()
+        }
+    }
+
+    fn project(name: &str) -> ProjectForm {
+        ProjectForm {
+            name: name.to_owned(),
+            description: "Line one".to_owned(),
+            contact_email: None,
+            max_members: 5,
+            public: true,
+            id: 1,
+        }
+    }
+
+    #[test]
+    fn test_derive_form_view() {
+        let ctx = RenderContext::new().with_csrf_token("token");
+        let form = Form::new("/projects").with_submit_label("Create");
+        let html = form
+            .render(&ctx, &project("Apollo"), &ValidationErrors::new())
+            .render()
+            .into_inner();
+
+        assert_has_selector(
+            &html,
+            "form[method=post][action=\"/projects\"] > input[name=csrf_token]",
+        );
+        assert_has_selector(
+            &html,
+            "wa-input[name=name][label=\"Project name\"][value=Apollo][required]",
+        );
+        assert_has_selector(&html, "wa-textarea[name=description][label=Description][rows=\"6\"]");
+        assert_has_selector(&html, "wa-input[name=contact_email][type=email][value=\"\"]");
+        assert_has_selector(&html, "wa-input[name=max_members][type=number][value=\"5\"]");
+        assert_has_selector(&html, "wa-checkbox[name=public][checked]");
+        assert_selector_count(&html, "[name=id]", 0);
+        assert_eq!(
+            Document::parse(&html).select("wa-button[type=submit]")[0].text,
+            "Create"
+        );
+    }
+
+    #[test]
+    fn test_redisplay() {
+        let ctx = RenderContext::new();
+        let form = Form::new("/projects");
+
+        assert_eq!(submit(project("Apollo")).redisplay(&form, &ctx).unwrap().name, "Apollo");
+
+        let Err(page) = submit(project("Ap")).redisplay(&form, &ctx) else {
+            panic!("expected the form to be redisplayed");
+        };
+        let html = page.into_inner();
+        assert_has_selector(&html, "wa-input[name=name][value=Ap][data-invalid]");
+        assert_selector_count(&html, ".field-error", 1);
+    }
+}
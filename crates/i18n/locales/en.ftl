@@ -0,0 +1,41 @@
+# Validation errors
+
+validation-length = Must be between { $min } and { $max } characters.
+validation-email-missing-at = Enter an email address such as name@example.com.
+validation-email-too-long = Email address is too long.
+validation-email-empty-local-part = Enter the part before the @.
+validation-email-local-part-too-long = The part before the @ is too long.
+validation-email-invalid-local-part = The part before the @ cannot start or end with a dot, or contain two in a row.
+validation-email-invalid-local-part-character = The part before the @ cannot contain { $character }.
+validation-email-empty-domain = Enter the part after the @.
+validation-email-domain-label-too-long = The part after the @ has a label longer than 63 characters.
+validation-email-invalid-domain = The part after the @ is not a valid domain.
+validation-password-too-short = Use at least { $min } characters.
+validation-password-too-long = Use at most { $max } characters.
+validation-password-too-few-classes = Mix at least { $required } of lowercase, uppercase, digits and symbols.
+validation-password-too-many-repeats = Do not repeat a character more than { $max } times in a row.
+validation-password-denied = This password is too common. Choose a different one.
+validation-url-missing-scheme = Enter a full address starting with https://.
+validation-url-invalid-scheme = The address scheme is invalid.
+validation-url-disallowed-scheme = This kind of address is not allowed.
+validation-url-missing-host = Enter a domain name.
+validation-url-invalid-host = The domain name is invalid.
+validation-url-invalid-port = The port is invalid.
+validation-url-credentials = Remove the user name and password from the address.
+validation-url-too-long = Use an address of at most { $max } characters.
+validation-url-disallowed-host = Addresses on this domain are not allowed.
+validation-url-invalid-characters = The address contains spaces or invalid characters.
+validation-hex-color = Enter a color such as #ff8800.
+validation-phone = Enter a phone number with 7 to 15 digits.
+validation-currency = Enter an amount with at most { $decimals } decimal places.
+validation-date = Enter a date as YYYY-MM-DD.
+
+# View components
+
+view-breadcrumb = Breadcrumb
+view-close = Close
+view-loading = Loading
+view-progress = Progress
+view-no-entries = No entries
+view-color-scheme = Color scheme
+view-apply = Apply
@@ -0,0 +1,162 @@
+use crate::{DEFAULT_RESOURCE_EN, FluentResource, Locale, PatternElement, ResourceError, negotiate_locale};
+
+/// Named values substituted for `{ $name }` placeables.
+pub type MessageArgs<'a> = [(&'a str, String)];
+
+/// Messages for each supported locale, resolved through the locale's parents and then the fallback locale.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MessageCatalog {
+    pub fallback: Locale,
+    resources: Vec<(Locale, FluentResource)>,
+}
+
+impl MessageCatalog {
+    pub fn new(fallback: Locale) -> Self {
+        Self {
+            fallback,
+            resources: Vec::new(),
+        }
+    }
+
+    /// An English catalog with the built-in validation and view messages.
+    pub fn with_defaults() -> Self {
+        Self::new(Locale::from_static("en"))
+            .with_resource(Locale::from_static("en"), DEFAULT_RESOURCE_EN)
+            .expect("built-in resource is valid")
+    }
+
+    pub fn with_resource(mut self, locale: Locale, source: &str) -> Result<Self, ResourceError> {
+        self.add_resource(locale, source)?;
+        Ok(self)
+    }
+
+    /// Adds messages for `locale`, overriding messages with the same identifier.
+    pub fn add_resource(&mut self, locale: Locale, source: &str) -> Result<(), ResourceError> {
+        let resource = FluentResource::parse(source)?;
+        match self.resources.iter_mut().find(|(existing, _)| *existing == locale) {
+            Some((_, existing)) => existing.extend(resource),
+            None => self.resources.push((locale, resource)),
+        }
+        Ok(())
+    }
+
+    pub fn locales(&self) -> impl Iterator<Item = &Locale> {
+        self.resources.iter().map(|(locale, _)| locale)
+    }
+
+    /// Picks the best supported locale for an `Accept-Language` header.
+    pub fn negotiate(&self, accept_language: &str) -> Locale {
+        let available: Vec<Locale> = self.locales().cloned().collect();
+        negotiate_locale(accept_language, &available, &self.fallback)
+    }
+
+    pub fn has_message(&self, locale: &Locale, id: &str) -> bool {
+        self.lookup(locale, id).is_some()
+    }
+
+    /// Formats message `id`, or returns `None` if neither `locale` nor the fallback define it.
+    pub fn format(&self, locale: &Locale, id: &str, args: &MessageArgs<'_>) -> Option<String> {
+        let (resource, pattern) = self.lookup(locale, id)?;
+        let mut output = String::new();
+        write_pattern(resource, pattern, args, &mut output, 0);
+        Some(output)
+    }
+
+    /// Formats message `id`, falling back to the identifier itself so missing translations stay visible.
+    pub fn message(&self, locale: &Locale, id: &str, args: &MessageArgs<'_>) -> String {
+        self.format(locale, id, args).unwrap_or_else(|| id.to_owned())
+    }
+
+    fn resource(&self, locale: &Locale) -> Option<&FluentResource> {
+        self.resources
+            .iter()
+            .find(|(existing, _)| existing == locale)
+            .map(|(_, resource)| resource)
+    }
+
+    fn lookup(&self, locale: &Locale, id: &str) -> Option<(&FluentResource, &[PatternElement])> {
+        let chain = std::iter::successors(Some(locale.clone()), Locale::parent).chain([self.fallback.clone()]);
+        for locale in chain {
+            if let Some(resource) = self.resource(&locale)
+                && let Some(pattern) = resource.message(id)
+            {
+                return Some((resource, pattern));
+            }
+        }
+        None
+    }
+}
+
+impl Default for MessageCatalog {
+    fn default() -> Self {
+        Self::with_defaults()
+    }
+}
+
+fn write_pattern(
+    resource: &FluentResource,
+    pattern: &[PatternElement],
+    args: &MessageArgs<'_>,
+    output: &mut String,
+    depth: usize,
+) {
+    for element in pattern {
+        match element {
+            PatternElement::Text(text) => output.push_str(text),
+            PatternElement::Variable(name) => match args.iter().find(|(arg, _)| arg == name) {
+                Some((_, value)) => output.push_str(value),
+                None => {
+                    output.push_str("{$");
+                    output.push_str(name);
+                    output.push('}');
+                },
+            },
+            PatternElement::Term(name) => match resource.term(name) {
+                Some(term) if depth < 8 => write_pattern(resource, term, args, output, depth + 1),
+                _ => {
+                    output.push_str("{-");
+                    output.push_str(name);
+                    output.push('}');
+                },
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        let catalog = MessageCatalog::with_defaults()
+            .with_resource(
+                Locale::from_static("de"),
+                "-brand = Zabawa\nwelcome = Willkommen bei { -brand }, { $name }!\nview-close = Schließen",
+            )
+            .unwrap()
+            .with_resource(Locale::from_static("de-CH"), "view-close = Schliessen")
+            .unwrap();
+        let de = Locale::from_static("de");
+        let swiss = Locale::from_static("de-CH");
+
+        assert_eq!(
+            catalog
+                .format(&de, "welcome", &[("name", "Jana".to_owned())])
+                .as_deref(),
+            Some("Willkommen bei Zabawa, Jana!")
+        );
+        assert_eq!(catalog.message(&de, "welcome", &[]), "Willkommen bei Zabawa, {$name}!");
+        assert_eq!(catalog.message(&swiss, "view-close", &[]), "Schliessen");
+        assert_eq!(
+            catalog.message(&swiss, "welcome", &[("name", "Jana".to_owned())]),
+            "Willkommen bei Zabawa, Jana!"
+        );
+        assert_eq!(catalog.message(&de, "view-loading", &[]), "Loading");
+        assert_eq!(catalog.message(&de, "missing-key", &[]), "missing-key");
+
+        assert_eq!(catalog.negotiate("de-CH, de;q=0.8").as_str(), "de-CH");
+        assert_eq!(catalog.negotiate("de-AT").as_str(), "de");
+        assert_eq!(catalog.negotiate("fr").as_str(), "en");
+    }
+}
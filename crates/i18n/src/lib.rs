@@ -0,0 +1,12 @@
+pub mod catalog;
+pub mod locale;
+pub mod localize;
+pub mod resource;
+
+pub use self::catalog::*;
+pub use self::locale::*;
+pub use self::localize::*;
+pub use self::resource::*;
+
+/// English messages for the validation errors and the default labels of the view components.
+pub const DEFAULT_RESOURCE_EN: &str = include_str!("../locales/en.ftl");
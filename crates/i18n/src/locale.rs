@@ -0,0 +1,161 @@
+use std::borrow::Cow;
+use std::fmt;
+
+/// A BCP 47 language tag normalized to `language[-Script][-REGION]` casing, e.g. `en`, `pt-BR`, `zh-Hant-TW`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Locale(Cow<'static, str>);
+
+impl Locale {
+    pub const fn from_static(tag: &'static str) -> Self {
+        Self(Cow::Borrowed(tag))
+    }
+
+    /// Parses a language tag, accepting `_` as separator. Returns `None` for `*` and malformed tags.
+    pub fn parse(tag: &str) -> Option<Self> {
+        let mut normalized = String::with_capacity(tag.len());
+        for (index, subtag) in tag.trim().split(['-', '_']).enumerate() {
+            if subtag.is_empty() || subtag.len() > 8 || !subtag.bytes().all(|b| b.is_ascii_alphanumeric()) {
+                return None;
+            }
+            if index == 0 {
+                if !(2..=3).contains(&subtag.len()) || !subtag.bytes().all(|b| b.is_ascii_alphabetic()) {
+                    return None;
+                }
+                normalized.push_str(&subtag.to_ascii_lowercase());
+                continue;
+            }
+
+            normalized.push('-');
+            match subtag.len() {
+                2 => normalized.push_str(&subtag.to_ascii_uppercase()),
+                4 => {
+                    normalized.push_str(&subtag[..1].to_ascii_uppercase());
+                    normalized.push_str(&subtag[1..].to_ascii_lowercase());
+                },
+                _ => normalized.push_str(&subtag.to_ascii_lowercase()),
+            }
+        }
+        Some(Self(Cow::Owned(normalized)))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn language(&self) -> &str {
+        self.0.split('-').next().unwrap_or(&self.0)
+    }
+
+    /// The locale without its last subtag, e.g. `zh-Hant` for `zh-Hant-TW`.
+    pub fn parent(&self) -> Option<Locale> {
+        self.0
+            .rsplit_once('-')
+            .map(|(parent, _)| Locale(Cow::Owned(parent.to_owned())))
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for Locale {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<Locale> for Cow<'static, str> {
+    fn from(locale: Locale) -> Self {
+        locale.0
+    }
+}
+
+/// Parses an `Accept-Language` header into locales ordered by descending quality, dropping `q=0` entries.
+pub fn parse_accept_language(header: &str) -> Vec<Locale> {
+    let mut ranges: Vec<(u16, usize, Locale)> = header
+        .split(',')
+        .enumerate()
+        .filter_map(|(position, range)| {
+            let mut parts = range.split(';');
+            let locale = Locale::parse(parts.next()?)?;
+            let quality = parts
+                .find_map(|param| param.trim().strip_prefix("q="))
+                .map_or(Some(1000), parse_quality)?;
+            (quality > 0).then_some((quality, position, locale))
+        })
+        .collect();
+    ranges.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+    ranges.into_iter().map(|(_, _, locale)| locale).collect()
+}
+
+fn parse_quality(value: &str) -> Option<u16> {
+    let value: f32 = value.trim().parse().ok()?;
+    (0.0..=1.0).contains(&value).then(|| (value * 1000.0).round() as u16)
+}
+
+/// Picks the best of `available` for an `Accept-Language` header, falling back to `default`.
+///
+/// Each requested locale is matched exactly first, then by its parent tags, then by any available locale of the
+/// same language.
+pub fn negotiate_locale(accept_language: &str, available: &[Locale], default: &Locale) -> Locale {
+    for requested in parse_accept_language(accept_language) {
+        let mut candidate = Some(requested.clone());
+        while let Some(locale) = candidate {
+            if let Some(found) = available.iter().find(|available| **available == locale) {
+                return found.clone();
+            }
+            candidate = locale.parent();
+        }
+        if let Some(found) = available
+            .iter()
+            .find(|available| available.language() == requested.language())
+        {
+            return found.clone();
+        }
+    }
+    default.clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_locale() {
+        assert_eq!(Locale::parse("EN_us").unwrap().as_str(), "en-US");
+        assert_eq!(Locale::parse("zh-hant-tw").unwrap().as_str(), "zh-Hant-TW");
+        assert_eq!(
+            Locale::parse("zh-Hant-TW").unwrap().parent().unwrap().as_str(),
+            "zh-Hant"
+        );
+        assert_eq!(Locale::parse("es-419").unwrap().language(), "es");
+        assert_eq!(Locale::parse("*"), None);
+        assert_eq!(Locale::parse("e"), None);
+        assert_eq!(Locale::parse("en--US"), None);
+    }
+
+    #[test]
+    fn test_negotiate_locale() {
+        let available = [
+            Locale::from_static("en"),
+            Locale::from_static("de"),
+            Locale::from_static("pt-BR"),
+        ];
+        let default = Locale::from_static("en");
+
+        assert_eq!(
+            negotiate_locale("de-AT,de;q=0.9,en;q=0.8", &available, &default).as_str(),
+            "de"
+        );
+        assert_eq!(
+            negotiate_locale("fr;q=0.9, en;q=0.8", &available, &default).as_str(),
+            "en"
+        );
+        assert_eq!(negotiate_locale("pt-PT", &available, &default).as_str(), "pt-BR");
+        assert_eq!(negotiate_locale("en;q=0.5, de", &available, &default).as_str(), "de");
+        assert_eq!(negotiate_locale("de;q=0, fr", &available, &default).as_str(), "en");
+        assert_eq!(negotiate_locale("", &available, &default).as_str(), "en");
+    }
+}
@@ -0,0 +1,180 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use zabawa_notification_domain::model::Notification;
+use zabawa_validation::{
+    InputFormatError, InvalidEmailError, InvalidHexColorError, InvalidLengthError, InvalidUrlError, WeakPasswordError,
+};
+
+use crate::{Locale, MessageCatalog};
+
+/// Errors and other values that have a catalog message.
+pub trait Localize {
+    fn message_id(&self) -> &'static str;
+
+    fn message_args(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+}
+
+impl MessageCatalog {
+    /// Translates `value`, falling back to its `Display` text when the catalog lacks its message.
+    pub fn localize<T: Localize + fmt::Display + ?Sized>(&self, locale: &Locale, value: &T) -> String {
+        let args: Vec<(&str, String)> = value.message_args();
+        self.format(locale, value.message_id(), &args)
+            .unwrap_or_else(|| value.to_string())
+    }
+
+    /// Treats the notification message as a message identifier if the catalog defines it, otherwise as literal text.
+    pub fn localize_notification<'n>(&self, locale: &Locale, notification: &'n Notification) -> Cow<'n, str> {
+        match self.format(locale, &notification.message, &[]) {
+            Some(message) => Cow::Owned(message),
+            None => Cow::Borrowed(&notification.message),
+        }
+    }
+}
+
+impl Localize for InvalidLengthError {
+    fn message_id(&self) -> &'static str {
+        "validation-length"
+    }
+
+    fn message_args(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("min", self.min.to_string()),
+            ("max", self.max.to_string()),
+            ("actual", self.actual.to_string()),
+        ]
+    }
+}
+
+impl Localize for InvalidEmailError {
+    fn message_id(&self) -> &'static str {
+        match self {
+            InvalidEmailError::MissingAt => "validation-email-missing-at",
+            InvalidEmailError::TooLong => "validation-email-too-long",
+            InvalidEmailError::EmptyLocalPart => "validation-email-empty-local-part",
+            InvalidEmailError::LocalPartTooLong => "validation-email-local-part-too-long",
+            InvalidEmailError::InvalidLocalPart => "validation-email-invalid-local-part",
+            InvalidEmailError::InvalidLocalPartCharacter(_) => "validation-email-invalid-local-part-character",
+            InvalidEmailError::EmptyDomain => "validation-email-empty-domain",
+            InvalidEmailError::DomainLabelTooLong => "validation-email-domain-label-too-long",
+            InvalidEmailError::InvalidDomain => "validation-email-invalid-domain",
+        }
+    }
+
+    fn message_args(&self) -> Vec<(&'static str, String)> {
+        match self {
+            InvalidEmailError::InvalidLocalPartCharacter(ch) => vec![("character", format!("{ch:?}"))],
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl Localize for WeakPasswordError {
+    fn message_id(&self) -> &'static str {
+        match self {
+            WeakPasswordError::TooShort { .. } => "validation-password-too-short",
+            WeakPasswordError::TooLong { .. } => "validation-password-too-long",
+            WeakPasswordError::TooFewCharacterClasses { .. } => "validation-password-too-few-classes",
+            WeakPasswordError::TooManyRepeats { .. } => "validation-password-too-many-repeats",
+            WeakPasswordError::Denied => "validation-password-denied",
+        }
+    }
+
+    fn message_args(&self) -> Vec<(&'static str, String)> {
+        match self {
+            WeakPasswordError::TooShort { min } => vec![("min", min.to_string())],
+            WeakPasswordError::TooLong { max } => vec![("max", max.to_string())],
+            WeakPasswordError::TooFewCharacterClasses { required } => vec![("required", required.to_string())],
+            WeakPasswordError::TooManyRepeats { max } => vec![("max", max.to_string())],
+            WeakPasswordError::Denied => Vec::new(),
+        }
+    }
+}
+
+impl Localize for InvalidUrlError {
+    fn message_id(&self) -> &'static str {
+        match self {
+            InvalidUrlError::MissingScheme => "validation-url-missing-scheme",
+            InvalidUrlError::InvalidScheme => "validation-url-invalid-scheme",
+            InvalidUrlError::DisallowedScheme => "validation-url-disallowed-scheme",
+            InvalidUrlError::MissingHost => "validation-url-missing-host",
+            InvalidUrlError::InvalidHost => "validation-url-invalid-host",
+            InvalidUrlError::InvalidPort => "validation-url-invalid-port",
+            InvalidUrlError::Credentials => "validation-url-credentials",
+            InvalidUrlError::TooLong { .. } => "validation-url-too-long",
+            InvalidUrlError::DisallowedHost => "validation-url-disallowed-host",
+            InvalidUrlError::InvalidCharacters => "validation-url-invalid-characters",
+        }
+    }
+
+    fn message_args(&self) -> Vec<(&'static str, String)> {
+        match self {
+            InvalidUrlError::TooLong { max } => vec![("max", max.to_string())],
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl Localize for InvalidHexColorError {
+    fn message_id(&self) -> &'static str {
+        "validation-hex-color"
+    }
+}
+
+impl Localize for InputFormatError {
+    fn message_id(&self) -> &'static str {
+        match self {
+            InputFormatError::Phone => "validation-phone",
+            InputFormatError::Currency { .. } => "validation-currency",
+            InputFormatError::Date => "validation-date",
+        }
+    }
+
+    fn message_args(&self) -> Vec<(&'static str, String)> {
+        match self {
+            InputFormatError::Currency { decimals } => vec![("decimals", decimals.to_string())],
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zabawa_validation::{ValidationErrors, validate_length};
+
+    use super::*;
+
+    #[test]
+    fn test_localize() {
+        let catalog = MessageCatalog::with_defaults()
+            .with_resource(
+                Locale::from_static("de"),
+                "validation-length = Muss { $min } bis { $max } Zeichen lang sein.\nproject-created = Projekt erstellt.",
+            )
+            .unwrap();
+        let de = Locale::from_static("de");
+
+        let mut errors = ValidationErrors::new();
+        if let Err(error) = validate_length(1, 2, 64) {
+            errors.add("name", catalog.localize(&de, &error));
+        }
+        errors.add("email", catalog.localize(&de, &InvalidEmailError::MissingAt));
+        assert_eq!(errors.field("name").collect::<Vec<_>>(), [
+            "Muss 2 bis 64 Zeichen lang sein."
+        ]);
+        assert_eq!(errors.field("email").collect::<Vec<_>>(), [
+            "Enter an email address such as name@example.com."
+        ]);
+        assert_eq!(
+            catalog.localize(&de, &InvalidEmailError::InvalidLocalPartCharacter(',')),
+            "The part before the @ cannot contain ','."
+        );
+
+        let created = Notification::success("project-created");
+        let literal = Notification::info("Plain text");
+        assert_eq!(catalog.localize_notification(&de, &created), "Projekt erstellt.");
+        assert_eq!(catalog.localize_notification(&de, &literal), "Plain text");
+    }
+}
@@ -0,0 +1,250 @@
+use std::collections::HashMap;
+
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ResourceError {
+    #[error("line {line}: expected `identifier = value`")]
+    ExpectedMessage { line: usize },
+
+    #[error("line {line}: invalid identifier {identifier:?}")]
+    InvalidIdentifier { line: usize, identifier: String },
+
+    #[error("line {line}: unterminated or unsupported placeable")]
+    InvalidPlaceable { line: usize },
+
+    #[error("line {line}: duplicate message {identifier:?}")]
+    Duplicate { line: usize, identifier: String },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PatternElement {
+    Text(String),
+    Variable(String),
+    Term(String),
+}
+
+/// A parsed Fluent (`.ftl`) resource.
+///
+/// Supports the subset used for UI strings: messages, terms (`-brand`), attributes (`.label`), multiline values,
+/// comments, `{ $variable }`, `{ -term }` and string literal placeables. Selectors and functions are rejected.
+/// Attributes are stored as `message.attribute`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FluentResource {
+    messages: HashMap<String, Vec<PatternElement>>,
+    terms: HashMap<String, Vec<PatternElement>>,
+}
+
+impl FluentResource {
+    pub fn parse(source: &str) -> Result<Self, ResourceError> {
+        let mut resource = ResourceParser::default();
+        let mut current: Option<(usize, String, String)> = None;
+
+        for (index, line) in source.lines().enumerate() {
+            let number = index + 1;
+            let continuation = line.starts_with([' ', '\t']) && !line.trim().is_empty();
+
+            if continuation && !line.trim_start().starts_with('.') {
+                if let Some((_, _, value)) = &mut current {
+                    if !value.is_empty() {
+                        value.push('\n');
+                    }
+                    value.push_str(line.trim());
+                    continue;
+                }
+                return Err(ResourceError::ExpectedMessage { line: number });
+            }
+
+            if let Some(entry) = current.take() {
+                resource.insert(entry)?;
+            }
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            let (identifier, value) = trimmed
+                .split_once('=')
+                .ok_or(ResourceError::ExpectedMessage { line: number })?;
+            let identifier = identifier.trim();
+            let identifier = match identifier.strip_prefix('.') {
+                Some(attribute) if continuation => {
+                    let message = resource
+                        .last_message
+                        .clone()
+                        .ok_or(ResourceError::ExpectedMessage { line: number })?;
+                    validate_identifier(attribute, number)?;
+                    format!("{message}.{attribute}")
+                },
+                _ => {
+                    validate_identifier(identifier.strip_prefix('-').unwrap_or(identifier), number)?;
+                    resource.last_message = None;
+                    identifier.to_owned()
+                },
+            };
+            current = Some((number, identifier, value.trim().to_owned()));
+        }
+        if let Some(entry) = current.take() {
+            resource.insert(entry)?;
+        }
+
+        Ok(Self {
+            messages: resource.messages,
+            terms: resource.terms,
+        })
+    }
+
+    pub fn message(&self, id: &str) -> Option<&[PatternElement]> {
+        self.messages.get(id).map(Vec::as_slice)
+    }
+
+    pub fn term(&self, id: &str) -> Option<&[PatternElement]> {
+        self.terms.get(id).map(Vec::as_slice)
+    }
+
+    pub fn has_message(&self, id: &str) -> bool {
+        self.messages.contains_key(id)
+    }
+
+    pub fn message_ids(&self) -> impl Iterator<Item = &str> {
+        self.messages.keys().map(String::as_str)
+    }
+
+    /// Adds the entries of `other`, which take precedence over existing ones.
+    pub fn extend(&mut self, other: FluentResource) {
+        self.messages.extend(other.messages);
+        self.terms.extend(other.terms);
+    }
+}
+
+#[derive(Default)]
+struct ResourceParser {
+    messages: HashMap<String, Vec<PatternElement>>,
+    terms: HashMap<String, Vec<PatternElement>>,
+    last_message: Option<String>,
+}
+
+impl ResourceParser {
+    fn insert(&mut self, (line, identifier, value): (usize, String, String)) -> Result<(), ResourceError> {
+        let pattern = parse_pattern(&value, line)?;
+        let (map, key) = match identifier.strip_prefix('-') {
+            Some(term) => (&mut self.terms, term.to_owned()),
+            None => {
+                if !identifier.contains('.') {
+                    self.last_message = Some(identifier.clone());
+                }
+                (&mut self.messages, identifier)
+            },
+        };
+        if map.insert(key.clone(), pattern).is_some() {
+            return Err(ResourceError::Duplicate { line, identifier: key });
+        }
+        Ok(())
+    }
+}
+
+fn validate_identifier(identifier: &str, line: usize) -> Result<(), ResourceError> {
+    let valid = identifier.starts_with(|c: char| c.is_ascii_alphabetic())
+        && identifier
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(ResourceError::InvalidIdentifier {
+            line,
+            identifier: identifier.to_owned(),
+        })
+    }
+}
+
+fn parse_pattern(value: &str, line: usize) -> Result<Vec<PatternElement>, ResourceError> {
+    let error = ResourceError::InvalidPlaceable { line };
+    let mut elements = Vec::new();
+    let mut text = String::new();
+    let mut rest = value;
+
+    while let Some(start) = rest.find('{') {
+        text.push_str(&rest[..start]);
+        let inner = rest[start + 1..].trim_start();
+
+        if let Some(literal) = inner.strip_prefix('"') {
+            let (literal, after) = literal.split_once('"').ok_or(error.clone())?;
+            text.push_str(literal);
+            rest = after.trim_start().strip_prefix('}').ok_or(error.clone())?;
+            continue;
+        }
+
+        let (placeable, after) = inner.split_once('}').ok_or(error.clone())?;
+        let placeable = placeable.trim();
+        if !text.is_empty() {
+            elements.push(PatternElement::Text(std::mem::take(&mut text)));
+        }
+        if let Some(variable) = placeable.strip_prefix('$') {
+            validate_identifier(variable, line).map_err(|_| error.clone())?;
+            elements.push(PatternElement::Variable(variable.to_owned()));
+        } else if let Some(term) = placeable.strip_prefix('-') {
+            validate_identifier(term, line).map_err(|_| error.clone())?;
+            elements.push(PatternElement::Term(term.to_owned()));
+        } else {
+            return Err(error);
+        }
+        rest = after;
+    }
+    text.push_str(rest);
+    if !text.is_empty() {
+        elements.push(PatternElement::Text(text));
+    }
+    Ok(elements)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let resource = FluentResource::parse(
+            "# Comment\n-brand = Zabawa\nwelcome = Welcome to { -brand }, { $name }!\nbraces = Use {\"{\"} and {\"}\"}\nintro =\n    First line\n    second line\nlogin = Log in\n    .title = Log in to { -brand }\n",
+        )
+        .unwrap();
+
+        assert_eq!(resource.message("welcome").unwrap(), [
+            PatternElement::Text("Welcome to ".to_owned()),
+            PatternElement::Term("brand".to_owned()),
+            PatternElement::Text(", ".to_owned()),
+            PatternElement::Variable("name".to_owned()),
+            PatternElement::Text("!".to_owned()),
+        ]);
+        assert_eq!(resource.message("braces").unwrap(), [PatternElement::Text(
+            "Use { and }".to_owned()
+        )]);
+        assert_eq!(resource.message("intro").unwrap(), [PatternElement::Text(
+            "First line\nsecond line".to_owned()
+        )]);
+        assert!(resource.has_message("login.title"));
+        assert!(resource.term("brand").is_some());
+        assert!(!resource.has_message("-brand"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            FluentResource::parse("just text"),
+            Err(ResourceError::ExpectedMessage { line: 1 })
+        );
+        assert_eq!(
+            FluentResource::parse("a = { $n ->\n  *[one] x\n}"),
+            Err(ResourceError::InvalidPlaceable { line: 1 })
+        );
+        assert!(matches!(
+            FluentResource::parse("a = x\na = y"),
+            Err(ResourceError::Duplicate { line: 2, .. })
+        ));
+        assert!(matches!(
+            FluentResource::parse("1a = x"),
+            Err(ResourceError::InvalidIdentifier { line: 1, .. })
+        ));
+        assert!(FluentResource::parse(crate::DEFAULT_RESOURCE_EN).is_ok());
+    }
+}
@@ -0,0 +1,275 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use thiserror::Error;
+use zabawa_csrf::fill_random;
+
+const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const ENCODED_LENGTH: usize = 26;
+const RANDOM_BITS: u32 = 80;
+
+/// Marks an entity type and the human-readable prefix of its IDs, e.g. `usr` for `usr_01H...`.
+pub trait IdPrefix {
+    const PREFIX: &'static str;
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum IdError {
+    #[error("invalid id prefix: expected {expected}_")]
+    InvalidPrefix { expected: &'static str },
+
+    #[error("invalid id length: expected {ENCODED_LENGTH} characters, got {actual}")]
+    InvalidLength { actual: usize },
+
+    #[error("invalid id character: {0:?}")]
+    InvalidCharacter(char),
+
+    #[error("invalid id: value does not fit in 128 bits")]
+    Overflow,
+
+    #[error("invalid uuid")]
+    InvalidUuid,
+}
+
+/// A ULID-based identifier for entities of type `T`, sortable by creation time.
+pub struct Id<T> {
+    value: u128,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Id<T> {
+    /// Generates a new ID from the current time and 80 bits from the operating system's random source, panicking if
+    /// it cannot be read.
+    pub fn new() -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_millis() as u64);
+        Self::from_parts(timestamp, random_bits())
+    }
+
+    /// Builds an ID from a millisecond timestamp (48 bits used) and random bits (80 bits used).
+    pub fn from_parts(timestamp_ms: u64, random: u128) -> Self {
+        let timestamp = u128::from(timestamp_ms & 0xffff_ffff_ffff);
+        Self::from_u128((timestamp << RANDOM_BITS) | (random & ((1 << RANDOM_BITS) - 1)))
+    }
+
+    pub const fn from_u128(value: u128) -> Self {
+        Self {
+            value,
+            marker: PhantomData,
+        }
+    }
+
+    pub const fn as_u128(&self) -> u128 {
+        self.value
+    }
+
+    pub fn timestamp_ms(&self) -> u64 {
+        (self.value >> RANDOM_BITS) as u64
+    }
+
+    /// The 26-character Crockford base32 ULID, without prefix.
+    pub fn to_ulid_string(&self) -> String {
+        (0..ENCODED_LENGTH)
+            .rev()
+            .map(|index| CROCKFORD[((self.value >> (index * 5)) & 0x1f) as usize] as char)
+            .collect()
+    }
+
+    pub fn from_ulid_str(input: &str) -> Result<Self, IdError> {
+        let actual = input.chars().count();
+        if actual != ENCODED_LENGTH {
+            return Err(IdError::InvalidLength { actual });
+        }
+        if !input.starts_with(|c: char| ('0'..='7').contains(&c)) {
+            return Err(IdError::Overflow);
+        }
+
+        let mut value = 0u128;
+        for c in input.chars() {
+            value = (value << 5) | u128::from(decode_crockford(c).ok_or(IdError::InvalidCharacter(c))?);
+        }
+        Ok(Self::from_u128(value))
+    }
+
+    /// The hyphenated UUID form of the same 128 bits, for storage in UUID columns.
+    pub fn to_uuid_string(&self) -> String {
+        let hex = format!("{:032x}", self.value);
+        format!(
+            "{}-{}-{}-{}-{}",
+            &hex[..8],
+            &hex[8..12],
+            &hex[12..16],
+            &hex[16..20],
+            &hex[20..]
+        )
+    }
+
+    pub fn from_uuid_str(input: &str) -> Result<Self, IdError> {
+        let groups: Vec<&str> = input.split('-').collect();
+        let lengths: Vec<usize> = groups.iter().map(|group| group.len()).collect();
+        if lengths != [8, 4, 4, 4, 12] || !groups.iter().all(|group| group.bytes().all(|b| b.is_ascii_hexdigit())) {
+            return Err(IdError::InvalidUuid);
+        }
+        u128::from_str_radix(&groups.concat(), 16)
+            .map(Self::from_u128)
+            .map_err(|_| IdError::InvalidUuid)
+    }
+
+    /// Reinterprets the ID as belonging to another entity type.
+    pub const fn cast<U>(self) -> Id<U> {
+        Id::from_u128(self.value)
+    }
+}
+
+impl<T: IdPrefix> Id<T> {
+    pub fn prefix() -> &'static str {
+        T::PREFIX
+    }
+}
+
+impl<T> Default for Id<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for Id<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Id<T> {}
+
+impl<T> PartialEq for Id<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T> Eq for Id<T> {}
+
+impl<T> PartialOrd for Id<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Id<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<T> Hash for Id<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl<T: IdPrefix> fmt::Display for Id<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}_{}", T::PREFIX, self.to_ulid_string())
+    }
+}
+
+impl<T: IdPrefix> fmt::Debug for Id<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Id({self})")
+    }
+}
+
+impl<T: IdPrefix> FromStr for Id<T> {
+    type Err = IdError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let ulid = input
+            .strip_prefix(T::PREFIX)
+            .and_then(|rest| rest.strip_prefix('_'))
+            .ok_or(IdError::InvalidPrefix { expected: T::PREFIX })?;
+        Self::from_ulid_str(ulid)
+    }
+}
+
+impl<T> From<Id<T>> for u128 {
+    fn from(id: Id<T>) -> Self {
+        id.value
+    }
+}
+
+fn decode_crockford(c: char) -> Option<u8> {
+    let c = match c.to_ascii_uppercase() {
+        'O' => '0',
+        'I' | 'L' => '1',
+        c => c,
+    };
+    CROCKFORD
+        .iter()
+        .position(|&symbol| symbol as char == c)
+        .map(|index| index as u8)
+}
+
+fn random_bits() -> u128 {
+    let mut bytes = [0; 16];
+    fill_random(&mut bytes).expect("failed to read the operating system's random source");
+    u128::from_le_bytes(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    struct User;
+
+    impl IdPrefix for User {
+        const PREFIX: &'static str = "usr";
+    }
+
+    #[test]
+    fn test_format_and_parse() {
+        let id = Id::<User>::from_parts(1_700_000_000_000, 0x1234_5678_9abc_def0_1234);
+        let formatted = id.to_string();
+        assert_eq!(formatted, "usr_01HF7YAT0028T5CY4TQKFF04HM");
+        assert_eq!(formatted.parse::<Id<User>>(), Ok(id));
+        assert_eq!("usr_01hf7yat0028t5cy4tqkff04hm".parse::<Id<User>>(), Ok(id));
+        assert_eq!(id.timestamp_ms(), 1_700_000_000_000);
+
+        assert_eq!(Id::<User>::from_uuid_str(&id.to_uuid_string()), Ok(id));
+        assert_eq!(id.to_uuid_string(), "018bcfe5-6800-1234-5678-9abcdef01234");
+
+        assert_eq!(
+            "org_01HF7YAT0028T5CY4TQKFF04HM".parse::<Id<User>>(),
+            Err(IdError::InvalidPrefix { expected: "usr" })
+        );
+        assert_eq!(
+            "usr_01HF4K6N".parse::<Id<User>>(),
+            Err(IdError::InvalidLength { actual: 8 })
+        );
+        assert_eq!(
+            "usr_01HF7YAT0028T5CY4TQKFF04HU".parse::<Id<User>>(),
+            Err(IdError::InvalidCharacter('U'))
+        );
+        assert_eq!(
+            "usr_81HF7YAT0028T5CY4TQKFF04HM".parse::<Id<User>>(),
+            Err(IdError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_new_is_unique_and_sortable() {
+        let earlier = Id::<User>::from_parts(1_000, u128::MAX);
+        let later = Id::<User>::from_parts(1_001, 0);
+        assert!(earlier < later);
+        assert!(earlier.to_string() < later.to_string());
+
+        let ids: HashSet<Id<User>> = (0..1000).map(|_| Id::new()).collect();
+        assert_eq!(ids.len(), 1000);
+    }
+}
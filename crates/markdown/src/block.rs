@@ -0,0 +1,430 @@
+/// Nesting limit for blockquotes and lists, so hostile input cannot exhaust the stack.
+const MAX_DEPTH: usize = 32;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Block {
+    Paragraph(String),
+    Heading {
+        level: u8,
+        text: String,
+    },
+    Code {
+        info: String,
+        code: String,
+    },
+    Blockquote(Vec<Block>),
+    List {
+        start: Option<u64>,
+        tight: bool,
+        items: Vec<Vec<Block>>,
+    },
+    ThematicBreak,
+}
+
+struct ListMarker<'a> {
+    start: Option<u64>,
+    delimiter: u8,
+    content_offset: usize,
+    rest: &'a str,
+}
+
+impl ListMarker<'_> {
+    fn continues(&self, other: &ListMarker<'_>) -> bool {
+        self.start.is_some() == other.start.is_some() && self.delimiter == other.delimiter
+    }
+}
+
+pub(crate) fn parse_document(source: &str) -> Vec<Block> {
+    let lines: Vec<String> = source.lines().map(expand_leading_tabs).collect();
+    parse_blocks(&lines, 0)
+}
+
+fn parse_blocks(lines: &[String], depth: usize) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i].as_str();
+        if is_blank(line) {
+            i += 1;
+            continue;
+        }
+
+        if let Some((fence_char, fence_length, info)) = parse_fence(line) {
+            let fence_indent = indent(line);
+            let mut code = String::new();
+            i += 1;
+            while i < lines.len() {
+                let line = lines[i].as_str();
+                i += 1;
+                if closes_fence(line, fence_char, fence_length) {
+                    break;
+                }
+                code.push_str(strip_indent(line, fence_indent));
+                code.push('\n');
+            }
+            blocks.push(Block::Code {
+                info: info.to_owned(),
+                code,
+            });
+            continue;
+        }
+
+        if indent(line) >= 4 {
+            let mut code_lines = Vec::new();
+            while i < lines.len() && (is_blank(&lines[i]) || indent(&lines[i]) >= 4) {
+                code_lines.push(strip_indent(&lines[i], 4));
+                i += 1;
+            }
+            while code_lines.last().is_some_and(|line| is_blank(line)) {
+                code_lines.pop();
+            }
+            let code = code_lines.iter().map(|line| format!("{line}\n")).collect();
+            blocks.push(Block::Code {
+                info: String::new(),
+                code,
+            });
+            continue;
+        }
+
+        if let Some((level, text)) = parse_atx_heading(line) {
+            blocks.push(Block::Heading {
+                level,
+                text: text.to_owned(),
+            });
+            i += 1;
+            continue;
+        }
+
+        if is_thematic_break(line) {
+            blocks.push(Block::ThematicBreak);
+            i += 1;
+            continue;
+        }
+
+        if depth < MAX_DEPTH {
+            if blockquote_content(line).is_some() {
+                let mut quoted = Vec::new();
+                while let Some(line) = lines.get(i) {
+                    if let Some(content) = blockquote_content(line) {
+                        quoted.push(content.to_owned());
+                    } else if !is_blank(line) && !starts_block(line) && quoted.last().is_some_and(|l| !is_blank(l)) {
+                        quoted.push(line.trim_start().to_owned());
+                    } else {
+                        break;
+                    }
+                    i += 1;
+                }
+                blocks.push(Block::Blockquote(parse_blocks(&quoted, depth + 1)));
+                continue;
+            }
+
+            if parse_list_marker(line).is_some() {
+                let (list, next) = parse_list(lines, i, depth);
+                blocks.push(list);
+                i = next;
+                continue;
+            }
+        }
+
+        let mut text = line.trim_start().to_owned();
+        i += 1;
+        while let Some(line) = lines.get(i) {
+            if is_blank(line) {
+                break;
+            }
+            if let Some(level) = setext_heading_level(line) {
+                blocks.push(Block::Heading {
+                    level,
+                    text: text.trim_end().to_owned(),
+                });
+                text.clear();
+                i += 1;
+                break;
+            }
+            if starts_block(line) {
+                break;
+            }
+            text.push('\n');
+            text.push_str(line.trim_start());
+            i += 1;
+        }
+        if !text.is_empty() {
+            blocks.push(Block::Paragraph(text.trim_end().to_owned()));
+        }
+    }
+    blocks
+}
+
+fn parse_list(lines: &[String], mut i: usize, depth: usize) -> (Block, usize) {
+    let Some(first) = parse_list_marker(&lines[i]) else {
+        unreachable!("parse_list called without a list marker");
+    };
+    let mut items = Vec::new();
+    let mut tight = true;
+
+    while let Some(marker) = lines.get(i).and_then(|line| parse_list_marker(line)) {
+        if !first.continues(&marker) {
+            break;
+        }
+
+        let mut item_lines = vec![marker.rest.to_owned()];
+        i += 1;
+        while let Some(line) = lines.get(i) {
+            if is_blank(line) {
+                item_lines.push(String::new());
+            } else if indent(line) >= marker.content_offset {
+                if item_lines.last().is_some_and(String::is_empty) {
+                    tight = false;
+                }
+                item_lines.push(line[marker.content_offset..].to_owned());
+            } else if item_lines.last().is_some_and(|l| !l.is_empty())
+                && !starts_block(line)
+                && parse_list_marker(line).is_none()
+            {
+                item_lines.push(line.trim_start().to_owned());
+            } else {
+                break;
+            }
+            i += 1;
+        }
+
+        let mut trailing_blank = false;
+        while item_lines.len() > 1 && item_lines.last().is_some_and(String::is_empty) {
+            item_lines.pop();
+            trailing_blank = true;
+        }
+        let next_continues = lines
+            .get(i)
+            .and_then(|line| parse_list_marker(line))
+            .is_some_and(|next| first.continues(&next));
+        if trailing_blank && next_continues {
+            tight = false;
+        }
+
+        items.push(parse_blocks(&item_lines, depth + 1));
+    }
+
+    (
+        Block::List {
+            start: first.start,
+            tight,
+            items,
+        },
+        i,
+    )
+}
+
+fn expand_leading_tabs(line: &str) -> String {
+    let content = line.trim_start_matches([' ', '\t']);
+    let mut expanded = String::with_capacity(line.len());
+    for ch in line[..line.len() - content.len()].chars() {
+        if ch == '\t' {
+            let width = 4 - expanded.len() % 4;
+            expanded.extend(std::iter::repeat_n(' ', width));
+        } else {
+            expanded.push(ch);
+        }
+    }
+    expanded.push_str(&content.replace('\0', "\u{fffd}"));
+    expanded
+}
+
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+fn indent(line: &str) -> usize {
+    line.len() - line.trim_start_matches(' ').len()
+}
+
+fn strip_indent(line: &str, max: usize) -> &str {
+    &line[indent(line).min(max)..]
+}
+
+fn starts_block(line: &str) -> bool {
+    parse_fence(line).is_some()
+        || parse_atx_heading(line).is_some()
+        || is_thematic_break(line)
+        || blockquote_content(line).is_some()
+        || parse_list_marker(line)
+            .is_some_and(|marker| !marker.rest.trim().is_empty() && marker.start.is_none_or(|start| start == 1))
+}
+
+fn parse_fence(line: &str) -> Option<(u8, usize, &str)> {
+    if indent(line) > 3 {
+        return None;
+    }
+    let rest = line.trim_start();
+    let fence_char = *rest.as_bytes().first().filter(|&&b| b == b'`' || b == b'~')?;
+    let length = rest.bytes().take_while(|&b| b == fence_char).count();
+    let info = rest[length..].trim();
+    if length < 3 || (fence_char == b'`' && info.contains('`')) {
+        return None;
+    }
+    Some((fence_char, length, info))
+}
+
+fn closes_fence(line: &str, fence_char: u8, fence_length: usize) -> bool {
+    let rest = line.trim();
+    indent(line) <= 3 && rest.len() >= fence_length && rest.bytes().all(|b| b == fence_char)
+}
+
+fn parse_atx_heading(line: &str) -> Option<(u8, &str)> {
+    if indent(line) > 3 {
+        return None;
+    }
+    let rest = line.trim_start();
+    let level = rest.bytes().take_while(|&b| b == b'#').count();
+    let text = &rest[level..];
+    if !(1..=6).contains(&level) || !(text.is_empty() || text.starts_with([' ', '\t'])) {
+        return None;
+    }
+    let text = text.trim();
+    let without_closing = text.trim_end_matches('#');
+    let text = if without_closing.is_empty() || without_closing.ends_with([' ', '\t']) {
+        without_closing.trim_end()
+    } else {
+        text
+    };
+    Some((level as u8, text))
+}
+
+fn is_thematic_break(line: &str) -> bool {
+    if indent(line) > 3 {
+        return false;
+    }
+    let mut marks = line.bytes().filter(|&b| b != b' ' && b != b'\t');
+    let Some(mark @ (b'-' | b'*' | b'_')) = marks.next() else {
+        return false;
+    };
+    let mut count = 1;
+    for b in marks {
+        if b != mark {
+            return false;
+        }
+        count += 1;
+    }
+    count >= 3
+}
+
+fn setext_heading_level(line: &str) -> Option<u8> {
+    let rest = line.trim();
+    if indent(line) > 3 || rest.is_empty() {
+        return None;
+    }
+    if rest.bytes().all(|b| b == b'=') {
+        Some(1)
+    } else if rest.bytes().all(|b| b == b'-') {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+fn blockquote_content(line: &str) -> Option<&str> {
+    if indent(line) > 3 {
+        return None;
+    }
+    let rest = line.trim_start().strip_prefix('>')?;
+    Some(rest.strip_prefix(' ').unwrap_or(rest))
+}
+
+fn parse_list_marker(line: &str) -> Option<ListMarker<'_>> {
+    let marker_indent = indent(line);
+    if marker_indent > 3 {
+        return None;
+    }
+    let rest = &line[marker_indent..];
+    let (start, delimiter, marker_length) = match rest.as_bytes().first()? {
+        &b @ (b'-' | b'*' | b'+') => (None, b, 1),
+        _ => {
+            let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+            let delimiter = *rest.as_bytes().get(digits).filter(|&&b| b == b'.' || b == b')')?;
+            if !(1..=9).contains(&digits) {
+                return None;
+            }
+            (Some(rest[..digits].parse().ok()?), delimiter, digits + 1)
+        },
+    };
+
+    let after = &rest[marker_length..];
+    let spaces = after.len() - after.trim_start_matches(' ').len();
+    if after.trim().is_empty() {
+        return Some(ListMarker {
+            start,
+            delimiter,
+            content_offset: marker_indent + marker_length + 1,
+            rest: "",
+        });
+    }
+    if spaces == 0 {
+        return None;
+    }
+    let spaces = if spaces > 4 { 1 } else { spaces };
+    Some(ListMarker {
+        start,
+        delimiter,
+        content_offset: marker_indent + marker_length + spaces,
+        rest: &after[spaces..],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paragraph(text: &str) -> Block {
+        Block::Paragraph(text.to_owned())
+    }
+
+    #[test]
+    fn test_parse_blocks() {
+        let blocks = parse_document(
+            "# Title #\n\nSome *text*\ncontinued\n\n```rust\nfn main() {}\n```\n\n> quoted\nlazy\n\n---\nSetext\n===\n",
+        );
+        assert_eq!(blocks, [
+            Block::Heading {
+                level: 1,
+                text: "Title".to_owned()
+            },
+            paragraph("Some *text*\ncontinued"),
+            Block::Code {
+                info: "rust".to_owned(),
+                code: "fn main() {}\n".to_owned()
+            },
+            Block::Blockquote(vec![paragraph("quoted\nlazy")]),
+            Block::ThematicBreak,
+            Block::Heading {
+                level: 1,
+                text: "Setext".to_owned()
+            },
+        ]);
+    }
+
+    #[test]
+    fn test_parse_lists() {
+        let blocks = parse_document("- one\n- two\n  - nested\n\n3. three\n4. four\n\n   more\n");
+        assert_eq!(blocks, [
+            Block::List {
+                start: None,
+                tight: true,
+                items: vec![vec![paragraph("one")], vec![paragraph("two"), Block::List {
+                    start: None,
+                    tight: true,
+                    items: vec![vec![paragraph("nested")]]
+                }]]
+            },
+            Block::List {
+                start: Some(3),
+                tight: false,
+                items: vec![vec![paragraph("three")], vec![paragraph("four"), paragraph("more")]]
+            },
+        ]);
+    }
+
+    #[test]
+    fn test_parse_deep_nesting() {
+        let source = ">".repeat(10_000) + " deep";
+        assert_eq!(parse_document(&source).len(), 1);
+    }
+}
@@ -0,0 +1,408 @@
+use crate::{MarkdownRenderer, MarkdownTag, escape_html};
+
+/// Nesting limit for emphasis and links, so hostile input cannot exhaust the stack.
+const MAX_DEPTH: usize = 32;
+
+struct Link<'a> {
+    text: &'a str,
+    destination: String,
+    title: Option<String>,
+    end: usize,
+}
+
+pub(crate) fn render_inline(renderer: &MarkdownRenderer, text: &str, out: &mut String) {
+    render_nested(renderer, text, out, 0, false);
+}
+
+fn render_nested(renderer: &MarkdownRenderer, text: &str, out: &mut String, depth: usize, in_link: bool) {
+    let tags = renderer.tags;
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    let mut plain_start = 0;
+    // Delimiter runs that found no closer; a later run of the same kind cannot find one either.
+    let mut unmatched: Vec<(u8, usize)> = Vec::new();
+
+    while i < bytes.len() {
+        let plain_end = i;
+        let next = match bytes[i] {
+            b'\\' => match bytes.get(i + 1) {
+                Some(b'\n') => {
+                    escape_html(&text[plain_start..plain_end], out);
+                    push_line_break(renderer, out);
+                    Some(i + 2)
+                },
+                Some(escaped) if escaped.is_ascii_punctuation() => {
+                    escape_html(&text[plain_start..plain_end], out);
+                    escape_html(&text[i + 1..i + 2], out);
+                    Some(i + 2)
+                },
+                _ => None,
+            },
+            b'`' => {
+                let length = run_length(bytes, i);
+                let end = if unmatched.contains(&(b'`', length)) {
+                    None
+                } else {
+                    find_code_span_end(text, i + length, length)
+                };
+                match end {
+                    Some(end) => {
+                        escape_html(&text[plain_start..plain_end], out);
+                        render_code_span(renderer, &text[i + length..end], out);
+                        Some(end + length)
+                    },
+                    None => {
+                        unmatched.push((b'`', length));
+                        i += length;
+                        continue;
+                    },
+                }
+            },
+            b'!' if bytes.get(i + 1) == Some(&b'[') => parse_link(text, i + 1).map(|link| {
+                escape_html(&text[plain_start..plain_end], out);
+                render_image(renderer, &link, out);
+                link.end
+            }),
+            b'[' if !in_link && depth < MAX_DEPTH => parse_link(text, i).map(|link| {
+                escape_html(&text[plain_start..plain_end], out);
+                render_link(renderer, &link.destination, link.title.as_deref(), out, |out| {
+                    render_nested(renderer, link.text, out, depth + 1, true);
+                });
+                link.end
+            }),
+            b'<' if !in_link => parse_autolink(text, i).map(|(destination, label, end)| {
+                escape_html(&text[plain_start..plain_end], out);
+                render_link(renderer, &destination, None, out, |out| escape_html(label, out));
+                end
+            }),
+            delimiter @ (b'*' | b'_') if depth < MAX_DEPTH => {
+                let length = run_length(bytes, i);
+                let count = length.min(3);
+                let previous = text[..i].chars().next_back();
+                let following = text[i + length..].chars().next();
+                let can_open = following.is_some_and(|ch| !ch.is_whitespace())
+                    && !(delimiter == b'_' && previous.is_some_and(char::is_alphanumeric));
+                let close = if can_open && !unmatched.contains(&(delimiter, count)) {
+                    find_closing_delimiter(text, i + count, delimiter, count)
+                } else {
+                    None
+                };
+                match close {
+                    Some(close) => {
+                        escape_html(&text[plain_start..plain_end], out);
+                        let emphasis = count != 2 && tags.contains(MarkdownTag::Emphasis);
+                        let strong = count != 1 && tags.contains(MarkdownTag::Strong);
+                        if emphasis {
+                            out.push_str("<em>");
+                        }
+                        if strong {
+                            out.push_str("<strong>");
+                        }
+                        render_nested(renderer, &text[i + count..close], out, depth + 1, in_link);
+                        if strong {
+                            out.push_str("</strong>");
+                        }
+                        if emphasis {
+                            out.push_str("</em>");
+                        }
+                        Some(close + count)
+                    },
+                    None => {
+                        if can_open {
+                            unmatched.push((delimiter, count));
+                        }
+                        i += length;
+                        continue;
+                    },
+                }
+            },
+            b'\n' => {
+                let line = &text[plain_start..plain_end];
+                let trimmed = line.trim_end_matches(' ');
+                escape_html(trimmed, out);
+                if line.len() - trimmed.len() >= 2 {
+                    push_line_break(renderer, out);
+                } else {
+                    out.push('\n');
+                }
+                Some(i + 1)
+            },
+            _ => None,
+        };
+
+        match next {
+            Some(next) => {
+                i = next;
+                plain_start = next;
+            },
+            None => i += 1,
+        }
+    }
+    escape_html(&text[plain_start..], out);
+}
+
+fn push_line_break(renderer: &MarkdownRenderer, out: &mut String) {
+    if renderer.tags.contains(MarkdownTag::LineBreak) {
+        out.push_str("<br />\n");
+    } else {
+        out.push('\n');
+    }
+}
+
+fn render_code_span(renderer: &MarkdownRenderer, code: &str, out: &mut String) {
+    let code = code.replace('\n', " ");
+    let code = match code.strip_prefix(' ').and_then(|code| code.strip_suffix(' ')) {
+        Some(stripped) if !code.trim().is_empty() => stripped,
+        _ => &code,
+    };
+    if renderer.tags.contains(MarkdownTag::Code) {
+        out.push_str("<code>");
+        escape_html(code, out);
+        out.push_str("</code>");
+    } else {
+        escape_html(code, out);
+    }
+}
+
+fn render_image(renderer: &MarkdownRenderer, link: &Link<'_>, out: &mut String) {
+    let alt = plain_text(link.text);
+    if !renderer.tags.contains(MarkdownTag::Image) || !renderer.link_policy.allows(&link.destination) {
+        escape_html(&alt, out);
+        return;
+    }
+    out.push_str("<img src=\"");
+    escape_html(&link.destination, out);
+    out.push_str("\" alt=\"");
+    escape_html(&alt, out);
+    if let Some(title) = &link.title {
+        out.push_str("\" title=\"");
+        escape_html(title, out);
+    }
+    out.push_str("\" />");
+}
+
+fn render_link(
+    renderer: &MarkdownRenderer,
+    destination: &str,
+    title: Option<&str>,
+    out: &mut String,
+    content: impl FnOnce(&mut String),
+) {
+    let policy = &renderer.link_policy;
+    if !renderer.tags.contains(MarkdownTag::Link) || !policy.allows(destination) {
+        content(out);
+        return;
+    }
+
+    out.push_str("<a href=\"");
+    escape_html(destination, out);
+    out.push('"');
+    if let Some(title) = title {
+        out.push_str(" title=\"");
+        escape_html(title, out);
+        out.push('"');
+    }
+    if policy.is_external(destination) {
+        if let Some(rel) = &policy.external_rel {
+            out.push_str(" rel=\"");
+            escape_html(rel, out);
+            out.push('"');
+        }
+        if policy.external_target_blank {
+            out.push_str(" target=\"_blank\"");
+        }
+    }
+    out.push('>');
+    content(out);
+    out.push_str("</a>");
+}
+
+fn run_length(bytes: &[u8], start: usize) -> usize {
+    bytes[start..].iter().take_while(|&&b| b == bytes[start]).count()
+}
+
+fn find_code_span_end(text: &str, from: usize, length: usize) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut i = from;
+    while i < bytes.len() {
+        if bytes[i] == b'`' {
+            let run = run_length(bytes, i);
+            if run == length {
+                return Some(i);
+            }
+            i += run;
+        } else {
+            i += 1;
+        }
+    }
+    None
+}
+
+fn find_closing_delimiter(text: &str, from: usize, delimiter: u8, count: usize) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut i = from;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'`' => {
+                let length = run_length(bytes, i);
+                i = find_code_span_end(text, i + length, length).map_or(i + length, |end| end + length);
+            },
+            b if b == delimiter => {
+                let length = run_length(bytes, i);
+                let previous = text[..i].chars().next_back();
+                let following = text[i + length..].chars().next();
+                let can_close = i > from
+                    && previous.is_some_and(|ch| !ch.is_whitespace())
+                    && !(delimiter == b'_' && following.is_some_and(char::is_alphanumeric));
+                if length == count && can_close {
+                    return Some(i);
+                }
+                i += length;
+            },
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+/// Parses `[text](destination "title")` starting at the opening bracket.
+fn parse_link(text: &str, open: usize) -> Option<Link<'_>> {
+    let bytes = text.as_bytes();
+    let mut i = open + 1;
+    let mut nesting = 0;
+    let close = loop {
+        match *bytes.get(i)? {
+            b'\\' => i += 2,
+            b'`' => {
+                let length = run_length(bytes, i);
+                i = find_code_span_end(text, i + length, length).map_or(i + length, |end| end + length);
+            },
+            b'[' if nesting == MAX_DEPTH => return None,
+            b'[' => {
+                nesting += 1;
+                i += 1;
+            },
+            b']' if nesting == 0 => break i,
+            b']' => {
+                nesting -= 1;
+                i += 1;
+            },
+            _ => i += 1,
+        }
+    };
+    if bytes.get(close + 1) != Some(&b'(') {
+        return None;
+    }
+
+    let mut i = skip_whitespace(bytes, close + 2);
+    let destination = if bytes.get(i) == Some(&b'<') {
+        let end = i + 1 + text[i + 1..].find(['>', '<', '\n'])?;
+        if bytes[end] != b'>' {
+            return None;
+        }
+        let destination = &text[i + 1..end];
+        i = end + 1;
+        destination
+    } else {
+        let start = i;
+        let mut parentheses = 0usize;
+        while let Some(&b) = bytes.get(i) {
+            match b {
+                b'\\' => i += 1,
+                b'(' => parentheses += 1,
+                b')' if parentheses == 0 => break,
+                b')' => parentheses -= 1,
+                b if b.is_ascii_whitespace() || b.is_ascii_control() => break,
+                _ => {},
+            }
+            i += 1;
+        }
+        &text[start..i.min(text.len())]
+    };
+
+    let before_title = i;
+    i = skip_whitespace(bytes, i);
+    let mut title = None;
+    if i > before_title
+        && let Some(&quote @ (b'"' | b'\'' | b'(')) = bytes.get(i)
+    {
+        let closing = if quote == b'(' { b')' } else { quote };
+        let start = i + 1;
+        let mut end = start;
+        while *bytes.get(end)? != closing {
+            end += if bytes[end] == b'\\' { 2 } else { 1 };
+        }
+        title = Some(unescape(&text[start..end]));
+        i = skip_whitespace(bytes, end + 1);
+    }
+    if bytes.get(i) != Some(&b')') {
+        return None;
+    }
+
+    Some(Link {
+        text: &text[open + 1..close],
+        destination: unescape(destination),
+        title,
+        end: i + 1,
+    })
+}
+
+/// Parses `<https://example.com>` or `<name@example.com>` into a destination, label and end offset.
+fn parse_autolink(text: &str, open: usize) -> Option<(String, &str, usize)> {
+    let close = open + text[open..].find('>')?;
+    let label = &text[open + 1..close];
+    if label.is_empty() || label.contains(|ch: char| ch.is_whitespace() || ch.is_control() || ch == '<') {
+        return None;
+    }
+
+    if let Some((scheme, _)) = label.split_once(':')
+        && (2..=32).contains(&scheme.len())
+        && scheme.starts_with(|ch: char| ch.is_ascii_alphabetic())
+        && scheme
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'-' | b'.'))
+    {
+        return Some((label.to_owned(), label, close + 1));
+    }
+
+    let (local, domain) = label.split_once('@')?;
+    let valid_email = !local.is_empty()
+        && domain.contains('.')
+        && local
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b".!#$%&'*+/=?^_`{|}~-".contains(&b))
+        && domain
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'.' || b == b'-');
+    valid_email.then(|| (format!("mailto:{label}"), label, close + 1))
+}
+
+fn skip_whitespace(bytes: &[u8], mut i: usize) -> usize {
+    while bytes.get(i).is_some_and(u8::is_ascii_whitespace) {
+        i += 1;
+    }
+    i
+}
+
+fn unescape(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match chars.peek() {
+            Some(&next) if ch == '\\' && next.is_ascii_punctuation() => {
+                unescaped.push(next);
+                chars.next();
+            },
+            _ => unescaped.push(ch),
+        }
+    }
+    unescaped
+}
+
+/// The text of inline Markdown without its markup, for image `alt` attributes.
+fn plain_text(text: &str) -> String {
+    let mut plain = unescape(text);
+    plain.retain(|ch| !matches!(ch, '*' | '_' | '`' | '[' | ']'));
+    plain
+}
@@ -0,0 +1,12 @@
+//! Safe Markdown rendering for user-provided content.
+//!
+//! Raw HTML in the source is always escaped; the output only ever contains the tags enabled in
+//! [`MarkdownTags`] and links accepted by the [`LinkPolicy`].
+
+mod block;
+mod inline;
+pub mod policy;
+pub mod render;
+
+pub use self::policy::*;
+pub use self::render::*;
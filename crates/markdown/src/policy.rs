@@ -0,0 +1,209 @@
+use std::borrow::Cow;
+
+/// The HTML constructs Markdown may produce; paragraphs are always allowed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MarkdownTag {
+    Heading,
+    Emphasis,
+    Strong,
+    Code,
+    CodeBlock,
+    Blockquote,
+    List,
+    Link,
+    Image,
+    ThematicBreak,
+    LineBreak,
+}
+
+impl MarkdownTag {
+    pub const ALL: [MarkdownTag; 11] = [
+        Self::Heading,
+        Self::Emphasis,
+        Self::Strong,
+        Self::Code,
+        Self::CodeBlock,
+        Self::Blockquote,
+        Self::List,
+        Self::Link,
+        Self::Image,
+        Self::ThematicBreak,
+        Self::LineBreak,
+    ];
+
+    const fn bit(self) -> u16 {
+        1 << self as u16
+    }
+}
+
+/// An allowlist of [`MarkdownTag`]s. Disallowed constructs are rendered as their plain content.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MarkdownTags(u16);
+
+impl MarkdownTags {
+    pub const fn none() -> Self {
+        Self(0)
+    }
+
+    pub const fn all() -> Self {
+        let mut bits = 0;
+        let mut index = 0;
+        while index < MarkdownTag::ALL.len() {
+            bits |= MarkdownTag::ALL[index].bit();
+            index += 1;
+        }
+        Self(bits)
+    }
+
+    /// Emphasis, strong, inline code, links and line breaks, e.g. for one-line notification bodies.
+    pub const fn inline() -> Self {
+        Self::none()
+            .with(MarkdownTag::Emphasis)
+            .with(MarkdownTag::Strong)
+            .with(MarkdownTag::Code)
+            .with(MarkdownTag::Link)
+            .with(MarkdownTag::LineBreak)
+    }
+
+    /// Everything but headings and images, which would let commenters restyle or embed content in the page.
+    pub const fn comment() -> Self {
+        Self::all().without(MarkdownTag::Heading).without(MarkdownTag::Image)
+    }
+
+    pub const fn with(self, tag: MarkdownTag) -> Self {
+        Self(self.0 | tag.bit())
+    }
+
+    pub const fn without(self, tag: MarkdownTag) -> Self {
+        Self(self.0 & !tag.bit())
+    }
+
+    pub const fn contains(&self, tag: MarkdownTag) -> bool {
+        self.0 & tag.bit() != 0
+    }
+}
+
+impl Default for MarkdownTags {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+impl FromIterator<MarkdownTag> for MarkdownTags {
+    fn from_iter<I: IntoIterator<Item = MarkdownTag>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::none(), Self::with)
+    }
+}
+
+/// Which link and image URLs are rendered, and the `rel`/`target` of links leaving the site.
+///
+/// Relative URLs are always allowed; absolute ones need an allowed scheme. Links with any other scheme (e.g.
+/// `javascript:`) are rendered as their text only.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LinkPolicy {
+    pub allowed_schemes: Vec<Cow<'static, str>>,
+    pub external_rel: Option<Cow<'static, str>>,
+    pub external_target_blank: bool,
+}
+
+impl LinkPolicy {
+    pub fn new() -> Self {
+        Self {
+            allowed_schemes: vec![Cow::Borrowed("http"), Cow::Borrowed("https"), Cow::Borrowed("mailto")],
+            external_rel: Some(Cow::Borrowed("nofollow noopener noreferrer")),
+            external_target_blank: false,
+        }
+    }
+
+    pub fn with_scheme(mut self, scheme: impl Into<Cow<'static, str>>) -> Self {
+        self.allowed_schemes.push(scheme.into());
+        self
+    }
+
+    pub fn with_allowed_schemes(mut self, schemes: impl IntoIterator<Item = impl Into<Cow<'static, str>>>) -> Self {
+        self.allowed_schemes = schemes.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn with_external_rel(mut self, rel: impl Into<Cow<'static, str>>) -> Self {
+        self.external_rel = Some(rel.into());
+        self
+    }
+
+    pub fn without_external_rel(mut self) -> Self {
+        self.external_rel = None;
+        self
+    }
+
+    pub fn with_external_target_blank(mut self, enabled: bool) -> Self {
+        self.external_target_blank = enabled;
+        self
+    }
+
+    pub fn allows(&self, url: &str) -> bool {
+        if url.chars().any(|ch| ch.is_whitespace() || ch.is_control()) {
+            return false;
+        }
+        match url_scheme(url) {
+            Some(scheme) => self
+                .allowed_schemes
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(scheme)),
+            None => true,
+        }
+    }
+
+    pub fn is_external(&self, url: &str) -> bool {
+        url_scheme(url).is_some() || url.starts_with("//")
+    }
+}
+
+impl Default for LinkPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The scheme of an absolute URL, or `None` for relative references such as `/docs`, `#top` or `page?x=a:b`.
+pub fn url_scheme(url: &str) -> Option<&str> {
+    let end = url.find([':', '/', '?', '#'])?;
+    let scheme = &url[..end];
+    let valid = url[end..].starts_with(':')
+        && scheme.starts_with(|ch: char| ch.is_ascii_alphabetic())
+        && scheme
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'-' | b'.'));
+    valid.then_some(scheme)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_link_policy() {
+        let policy = LinkPolicy::new();
+        assert!(policy.allows("https://example.com"));
+        assert!(policy.allows("MAILTO:team@example.com"));
+        assert!(policy.allows("/docs?page=a:b"));
+        assert!(policy.allows("#section"));
+        assert!(!policy.allows("javascript:alert(1)"));
+        assert!(!policy.allows("JavaScript:alert(1)"));
+        assert!(!policy.allows("data:text/html;base64,AAAA"));
+        assert!(!policy.allows("java\tscript:alert(1)"));
+
+        assert!(policy.is_external("https://example.com"));
+        assert!(policy.is_external("//example.com"));
+        assert!(!policy.is_external("/docs"));
+
+        let tags = MarkdownTags::comment();
+        assert!(tags.contains(MarkdownTag::Link));
+        assert!(!tags.contains(MarkdownTag::Heading));
+        assert_eq!(
+            [MarkdownTag::Link, MarkdownTag::Code]
+                .into_iter()
+                .collect::<MarkdownTags>(),
+            MarkdownTags::none().with(MarkdownTag::Code).with(MarkdownTag::Link)
+        );
+    }
+}
@@ -0,0 +1,318 @@
+use std::fmt;
+use std::sync::Arc;
+
+use hypertext::{Buffer, Renderable};
+
+use crate::block::{Block, parse_document};
+use crate::inline::render_inline;
+use crate::{LinkPolicy, MarkdownTag, MarkdownTags};
+
+/// Turns fenced code into highlighted HTML.
+///
+/// The returned string is inserted into `<code>` verbatim, so implementations must escape the code themselves.
+/// Returning `None` falls back to plain escaped code.
+pub trait Highlighter: Send + Sync {
+    fn highlight(&self, language: &str, code: &str) -> Option<String>;
+}
+
+impl<F: Fn(&str, &str) -> Option<String> + Send + Sync> Highlighter for F {
+    fn highlight(&self, language: &str, code: &str) -> Option<String> {
+        self(language, code)
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct MarkdownRenderer {
+    pub tags: MarkdownTags,
+    pub link_policy: LinkPolicy,
+    pub highlighter: Option<Arc<dyn Highlighter>>,
+}
+
+impl MarkdownRenderer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// For notification bodies: inline formatting and links only.
+    pub fn inline() -> Self {
+        Self::new().with_tags(MarkdownTags::inline())
+    }
+
+    /// For user comments: no headings or images.
+    pub fn comment() -> Self {
+        Self::new().with_tags(MarkdownTags::comment())
+    }
+
+    pub fn with_tags(mut self, tags: MarkdownTags) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    pub fn with_tag(mut self, tag: MarkdownTag) -> Self {
+        self.tags = self.tags.with(tag);
+        self
+    }
+
+    pub fn without_tag(mut self, tag: MarkdownTag) -> Self {
+        self.tags = self.tags.without(tag);
+        self
+    }
+
+    pub fn with_link_policy(mut self, policy: LinkPolicy) -> Self {
+        self.link_policy = policy;
+        self
+    }
+
+    pub fn with_highlighter(mut self, highlighter: impl Highlighter + 'static) -> Self {
+        self.highlighter = Some(Arc::new(highlighter));
+        self
+    }
+
+    pub fn without_highlighter(mut self) -> Self {
+        self.highlighter = None;
+        self
+    }
+
+    pub fn render<'a>(&'a self, source: &'a str) -> Markdown<'a> {
+        Markdown { renderer: self, source }
+    }
+
+    pub fn to_html(&self, source: &str) -> String {
+        let mut html = String::with_capacity(source.len() + source.len() / 4);
+        self.render_blocks(&parse_document(source), true, &mut html);
+        html
+    }
+
+    fn render_blocks(&self, blocks: &[Block], tight: bool, out: &mut String) {
+        for block in blocks {
+            self.render_block(block, tight, out);
+        }
+    }
+
+    fn render_block(&self, block: &Block, paragraphs: bool, out: &mut String) {
+        match block {
+            Block::Paragraph(text) if paragraphs => {
+                out.push_str("<p>");
+                render_inline(self, text, out);
+                out.push_str("</p>\n");
+            },
+            Block::Paragraph(text) => render_inline(self, text, out),
+            Block::Heading { level, text } if self.tags.contains(MarkdownTag::Heading) => {
+                out.push_str(&format!("<h{level}>"));
+                render_inline(self, text, out);
+                out.push_str(&format!("</h{level}>\n"));
+            },
+            Block::Heading { text, .. } => self.render_block(&Block::Paragraph(text.clone()), true, out),
+            Block::Code { info, code } if self.tags.contains(MarkdownTag::CodeBlock) => {
+                let language = info.split_whitespace().next().unwrap_or("");
+                out.push_str("<pre><code");
+                if !language.is_empty() {
+                    out.push_str(" class=\"language-");
+                    escape_html(language, out);
+                    out.push('"');
+                }
+                out.push('>');
+                match self
+                    .highlighter
+                    .as_ref()
+                    .and_then(|highlighter| highlighter.highlight(language, code))
+                {
+                    Some(highlighted) => out.push_str(&highlighted),
+                    None => escape_html(code, out),
+                }
+                out.push_str("</code></pre>\n");
+            },
+            Block::Code { code, .. } => {
+                out.push_str("<p>");
+                escape_html(code.trim_end(), out);
+                out.push_str("</p>\n");
+            },
+            Block::Blockquote(blocks) if self.tags.contains(MarkdownTag::Blockquote) => {
+                out.push_str("<blockquote>\n");
+                self.render_blocks(blocks, true, out);
+                out.push_str("</blockquote>\n");
+            },
+            Block::Blockquote(blocks) => self.render_blocks(blocks, true, out),
+            Block::List { start, tight, items } if self.tags.contains(MarkdownTag::List) => {
+                match start {
+                    Some(1) => out.push_str("<ol>\n"),
+                    Some(start) => out.push_str(&format!("<ol start=\"{start}\">\n")),
+                    None => out.push_str("<ul>\n"),
+                }
+                for item in items {
+                    out.push_str("<li>");
+                    for (index, block) in item.iter().enumerate() {
+                        if *tight && index > 0 && matches!(block, Block::Paragraph(_)) {
+                            out.push('\n');
+                        }
+                        self.render_block(block, !tight, out);
+                    }
+                    out.push_str("</li>\n");
+                }
+                out.push_str(if start.is_some() { "</ol>\n" } else { "</ul>\n" });
+            },
+            Block::List { items, .. } => {
+                for item in items {
+                    self.render_blocks(item, true, out);
+                }
+            },
+            Block::ThematicBreak if self.tags.contains(MarkdownTag::ThematicBreak) => out.push_str("<hr />\n"),
+            Block::ThematicBreak => {},
+        }
+    }
+}
+
+impl fmt::Debug for MarkdownRenderer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MarkdownRenderer")
+            .field("tags", &self.tags)
+            .field("link_policy", &self.link_policy)
+            .field("highlighter", &self.highlighter.is_some())
+            .finish()
+    }
+}
+
+/// Markdown source paired with the renderer that turns it into HTML.
+#[derive(Clone, Copy, Debug)]
+pub struct Markdown<'a> {
+    renderer: &'a MarkdownRenderer,
+    source: &'a str,
+}
+
+impl Renderable for Markdown<'_> {
+    fn render_to(&self, buffer: &mut Buffer) {
+        // XSS SAFETY: all source text is escaped and only allowlisted tags and attributes are emitted.
+        buffer
+            .dangerously_get_string()
+            .push_str(&self.renderer.to_html(self.source));
+    }
+}
+
+/// Escapes text for use in element content and double-quoted attribute values.
+pub fn escape_html(text: &str, out: &mut String) {
+    for ch in text.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            ch => out.push(ch),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn html(source: &str) -> String {
+        MarkdownRenderer::new().to_html(source)
+    }
+
+    #[test]
+    fn test_render_blocks() {
+        assert_eq!(html("# Hello *world*"), "<h1>Hello <em>world</em></h1>\n");
+        assert_eq!(
+            html("one\ntwo  \nthree\\\nfour"),
+            "<p>one\ntwo<br />\nthree<br />\nfour</p>\n"
+        );
+        assert_eq!(
+            html("```rust\nlet x = 1 < 2;\n```"),
+            "<pre><code class=\"language-rust\">let x = 1 &lt; 2;\n</code></pre>\n"
+        );
+        assert_eq!(
+            html("> quote\n\n---"),
+            "<blockquote>\n<p>quote</p>\n</blockquote>\n<hr />\n"
+        );
+        assert_eq!(
+            html("- a\n- b\n  - c"),
+            "<ul>\n<li>a</li>\n<li>b<ul>\n<li>c</li>\n</ul>\n</li>\n</ul>\n"
+        );
+        assert_eq!(
+            html("2. a\n\n3. b"),
+            "<ol start=\"2\">\n<li><p>a</p>\n</li>\n<li><p>b</p>\n</li>\n</ol>\n"
+        );
+    }
+
+    #[test]
+    fn test_render_inline() {
+        assert_eq!(
+            html("**bold** *em* ***both*** _under_ snake_case_name"),
+            "<p><strong>bold</strong> <em>em</em> <em><strong>both</strong></em> <em>under</em> snake_case_name</p>\n"
+        );
+        assert_eq!(html("*a **b** c*"), "<p><em>a <strong>b</strong> c</em></p>\n");
+        assert_eq!(
+            html("`a < b` and `` ` ``"),
+            "<p><code>a &lt; b</code> and <code>`</code></p>\n"
+        );
+        assert_eq!(html("\\*not em\\* 2 * 3 * 4"), "<p>*not em* 2 * 3 * 4</p>\n");
+        assert_eq!(
+            html("[docs](/docs \"The docs\") ![logo](/logo.png)"),
+            "<p><a href=\"/docs\" title=\"The docs\">docs</a> <img src=\"/logo.png\" alt=\"logo\" /></p>\n"
+        );
+        assert_eq!(
+            html("<https://example.com> <team@example.com>"),
+            "<p><a href=\"https://example.com\" rel=\"nofollow noopener noreferrer\">https://example.com</a> \
+             <a href=\"mailto:team@example.com\" rel=\"nofollow noopener noreferrer\">team@example.com</a></p>\n"
+        );
+    }
+
+    #[test]
+    fn test_render_is_safe() {
+        assert_eq!(
+            html("<script>alert(1)</script>\n\n<img src=x onerror=alert(1)>"),
+            "<p>&lt;script&gt;alert(1)&lt;/script&gt;</p>\n<p>&lt;img src=x onerror=alert(1)&gt;</p>\n"
+        );
+        assert_eq!(html("[click](javascript:alert(1))"), "<p>click</p>\n");
+        assert_eq!(
+            html("[x](\"onmouseover=alert(1))"),
+            "<p><a href=\"&quot;onmouseover=alert(1)\">x</a></p>\n"
+        );
+        assert_eq!(html("![x](data:image/svg+xml,<svg>)"), "<p>x</p>\n");
+        assert_eq!(
+            html("```\"><script>\nx\n```"),
+            "<pre><code class=\"language-&quot;&gt;&lt;script&gt;\">x\n</code></pre>\n"
+        );
+
+        let deep = "*".repeat(50_000) + "x" + &"*".repeat(50_000);
+        assert!(html(&deep).starts_with("<p>"));
+        let brackets = "[".repeat(50_000);
+        assert_eq!(html(&brackets), format!("<p>{brackets}</p>\n"));
+        let openers = "*a _b [c ".repeat(20_000);
+        assert_eq!(html(&openers), format!("<p>{}</p>\n", openers.trim_end()));
+    }
+
+    #[test]
+    fn test_allowlist_and_policy() {
+        let renderer = MarkdownRenderer::comment()
+            .without_tag(MarkdownTag::Link)
+            .with_link_policy(LinkPolicy::new().with_external_target_blank(true));
+        assert_eq!(
+            renderer.to_html("# Title\n\n![img](/a.png) [link](https://example.com)"),
+            "<p>Title</p>\n<p>img link</p>\n"
+        );
+
+        let renderer = MarkdownRenderer::inline().with_link_policy(
+            LinkPolicy::new()
+                .without_external_rel()
+                .with_external_target_blank(true),
+        );
+        assert_eq!(
+            renderer.to_html("> [a](https://example.com) [b](/b)\n\n- item"),
+            "<p><a href=\"https://example.com\" target=\"_blank\">a</a> <a href=\"/b\">b</a></p>\n<p>item</p>\n"
+        );
+    }
+
+    #[test]
+    fn test_highlighter() {
+        let renderer = MarkdownRenderer::new().with_highlighter(|language: &str, code: &str| {
+            (language == "rust").then(|| format!("<span class=\"kw\">{}</span>", code.trim_end()))
+        });
+        assert_eq!(
+            renderer.to_html("```rust\nfn\n```\n\n```\n<b>\n```"),
+            "<pre><code class=\"language-rust\"><span class=\"kw\">fn</span></code></pre>\n<pre><code>&lt;b&gt;\n</code></pre>\n"
+        );
+        assert_eq!(renderer.render("*hi*").render().into_inner(), "<p><em>hi</em></p>\n");
+    }
+}
@@ -0,0 +1,132 @@
+use std::fmt;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+/// Currencies whose minor unit is not 1/100, per ISO 4217.
+const MINOR_UNIT_EXCEPTIONS: &[(&str, u8)] = &[
+    ("BHD", 3),
+    ("BIF", 0),
+    ("CLP", 0),
+    ("DJF", 0),
+    ("GNF", 0),
+    ("IQD", 3),
+    ("ISK", 0),
+    ("JOD", 3),
+    ("JPY", 0),
+    ("KMF", 0),
+    ("KRW", 0),
+    ("KWD", 3),
+    ("LYD", 3),
+    ("OMR", 3),
+    ("PYG", 0),
+    ("RWF", 0),
+    ("TND", 3),
+    ("UGX", 0),
+    ("VND", 0),
+    ("VUV", 0),
+    ("XAF", 0),
+    ("XOF", 0),
+    ("XPF", 0),
+];
+
+const SYMBOLS: &[(&str, &str)] = &[
+    ("AUD", "A$"),
+    ("CAD", "CA$"),
+    ("CHF", "CHF"),
+    ("CNY", "CN¥"),
+    ("EUR", "€"),
+    ("GBP", "£"),
+    ("INR", "₹"),
+    ("JPY", "¥"),
+    ("KRW", "₩"),
+    ("PLN", "zł"),
+    ("USD", "$"),
+];
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("invalid currency code: expected three ASCII letters")]
+pub struct InvalidCurrencyCodeError;
+
+/// An ISO 4217 alphabetic code such as `EUR`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CurrencyCode([u8; 3]);
+
+impl CurrencyCode {
+    pub const EUR: CurrencyCode = CurrencyCode(*b"EUR");
+    pub const GBP: CurrencyCode = CurrencyCode(*b"GBP");
+    pub const JPY: CurrencyCode = CurrencyCode(*b"JPY");
+    pub const PLN: CurrencyCode = CurrencyCode(*b"PLN");
+    pub const USD: CurrencyCode = CurrencyCode(*b"USD");
+
+    /// Accepts any case; the code is stored uppercase.
+    pub fn new(code: &str) -> Result<Self, InvalidCurrencyCodeError> {
+        let code = code.trim();
+        let bytes: [u8; 3] = code.as_bytes().try_into().map_err(|_| InvalidCurrencyCodeError)?;
+        if !bytes.iter().all(u8::is_ascii_alphabetic) {
+            return Err(InvalidCurrencyCodeError);
+        }
+        Ok(Self(bytes.map(|byte| byte.to_ascii_uppercase())))
+    }
+
+    pub fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.0).expect("currency codes are ASCII")
+    }
+
+    /// Digits after the decimal point, e.g. 2 for EUR and 0 for JPY.
+    pub fn minor_units(&self) -> u8 {
+        MINOR_UNIT_EXCEPTIONS
+            .iter()
+            .find(|(code, _)| *code == self.as_str())
+            .map_or(2, |(_, units)| *units)
+    }
+
+    /// The customary symbol, or the code itself for currencies without a well-known one.
+    pub fn symbol(&self) -> &str {
+        SYMBOLS
+            .iter()
+            .find(|(code, _)| *code == self.as_str())
+            .map_or(self.as_str(), |(_, symbol)| symbol)
+    }
+}
+
+impl fmt::Display for CurrencyCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for CurrencyCode {
+    type Err = InvalidCurrencyCodeError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::new(input)
+    }
+}
+
+impl AsRef<str> for CurrencyCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_currency_code() {
+        let eur = CurrencyCode::new(" eur ").unwrap();
+        assert_eq!(eur, CurrencyCode::EUR);
+        assert_eq!(eur.to_string(), "EUR");
+        assert_eq!(eur.minor_units(), 2);
+        assert_eq!(eur.symbol(), "€");
+        assert_eq!(CurrencyCode::JPY.minor_units(), 0);
+        assert_eq!("kwd".parse::<CurrencyCode>().unwrap().minor_units(), 3);
+        assert_eq!("SEK".parse::<CurrencyCode>().unwrap().symbol(), "SEK");
+
+        assert_eq!(CurrencyCode::new("EURO"), Err(InvalidCurrencyCodeError));
+        assert_eq!(CurrencyCode::new("E1R"), Err(InvalidCurrencyCodeError));
+        assert_eq!(CurrencyCode::new("€"), Err(InvalidCurrencyCodeError));
+    }
+}
@@ -0,0 +1,128 @@
+use crate::Money;
+
+const NO_BREAK_SPACE: &str = "\u{a0}";
+const NARROW_NO_BREAK_SPACE: &str = "\u{202f}";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct NumberConvention {
+    pub(crate) decimal: char,
+    pub(crate) group: &'static str,
+    /// `1.234,50 €` rather than `€1,234.50`.
+    pub(crate) symbol_after: bool,
+}
+
+impl NumberConvention {
+    pub(crate) fn for_locale(locale: &str) -> Self {
+        let language = locale.split(['-', '_']).next().unwrap_or("").to_ascii_lowercase();
+        let (decimal, group, symbol_after) = match language.as_str() {
+            "de" | "es" | "it" | "nl" | "pt" | "da" | "tr" | "ro" | "el" | "id" => (',', ".", true),
+            "fr" => (',', NARROW_NO_BREAK_SPACE, true),
+            "pl" | "cs" | "sk" | "ru" | "uk" | "fi" | "nb" | "no" | "sv" | "hu" => (',', NO_BREAK_SPACE, true),
+            _ => ('.', ",", false),
+        };
+        Self {
+            decimal,
+            group,
+            symbol_after,
+        }
+    }
+
+    /// Rewrites localized input such as `1.234,5` or `1 234,5` into the `1234.5` form the validation crate parses.
+    pub(crate) fn delocalize(&self, input: &str) -> String {
+        // Comma grouping is kept for the validation crate, which checks its placement.
+        let is_group = |ch: char| ch.is_whitespace() || ch == '\'' || (self.group != "," && self.group.contains(ch));
+        let mut output = String::with_capacity(input.len());
+        // With a decimal comma, a lone `.` not followed by exactly three digits is read as a decimal point, since
+        // many users type `12.5` regardless of their locale.
+        let dot_is_decimal = self.decimal == ','
+            && !input.contains(',')
+            && input.matches('.').count() == 1
+            && input.rsplit('.').next().is_some_and(|rest| rest.len() != 3);
+
+        for ch in input.chars() {
+            if ch == self.decimal || (dot_is_decimal && ch == '.') {
+                output.push('.');
+            } else if !is_group(ch) {
+                output.push(ch);
+            }
+        }
+        output
+    }
+}
+
+/// Formats an amount with the currency symbol following the conventions of a BCP 47 `locale`, e.g. `€1,234.50`
+/// for `en` and `1.234,50 €` for `de`.
+pub fn format_money(money: &Money, locale: &str) -> String {
+    let convention = NumberConvention::for_locale(locale);
+    let number = format_number(money, &convention);
+    let currency = money.currency();
+    let symbol = currency.symbol();
+    let sign = if money.is_negative() { "-" } else { "" };
+    if convention.symbol_after {
+        format!("{sign}{number}{NO_BREAK_SPACE}{symbol}")
+    } else {
+        format!("{sign}{symbol}{number}")
+    }
+}
+
+/// The unsigned amount with localized separators and no symbol.
+fn format_number(money: &Money, convention: &NumberConvention) -> String {
+    let decimals = u32::from(money.currency().minor_units());
+    let divisor = 10u64.pow(decimals);
+    let minor = money.minor().unsigned_abs();
+    let whole = (minor / divisor).to_string();
+
+    let mut output = String::with_capacity(whole.len() + whole.len() / 3 * convention.group.len() + 4);
+    for (index, digit) in whole.chars().enumerate() {
+        if index > 0 && (whole.len() - index).is_multiple_of(3) {
+            output.push_str(convention.group);
+        }
+        output.push(digit);
+    }
+    if decimals > 0 {
+        output.push(convention.decimal);
+        output.push_str(&format!("{:0width$}", minor % divisor, width = decimals as usize));
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CurrencyCode;
+
+    #[test]
+    fn test_format_money() {
+        let money = Money::new(123_456_789, CurrencyCode::EUR);
+        assert_eq!(format_money(&money, "en-US"), "€1,234,567.89");
+        assert_eq!(format_money(&money, "de-DE"), "1.234.567,89\u{a0}€");
+        assert_eq!(format_money(&money, "fr"), "1\u{202f}234\u{202f}567,89\u{a0}€");
+        assert_eq!(format_money(&money, "pl"), "1\u{a0}234\u{a0}567,89\u{a0}€");
+        assert_eq!(format_money(&Money::new(-5, CurrencyCode::USD), "en"), "-$0.05");
+        assert_eq!(format_money(&Money::new(1500, CurrencyCode::JPY), "en"), "¥1,500");
+        assert_eq!(
+            format_money(&Money::new(100, CurrencyCode::new("SEK").unwrap()), "sv"),
+            "1,00\u{a0}SEK"
+        );
+        assert_eq!(
+            format_money(&Money::new(i64::MIN, CurrencyCode::USD), "en"),
+            "-$92,233,720,368,547,758.08"
+        );
+    }
+
+    #[test]
+    fn test_delocalize() {
+        let en = NumberConvention::for_locale("en");
+        assert_eq!(en.delocalize("1,234.5"), "1,234.5");
+        assert_eq!(en.delocalize(" 1 234.5 "), "1234.5");
+
+        let de = NumberConvention::for_locale("de-AT");
+        assert_eq!(de.delocalize("1.234,5"), "1234.5");
+        assert_eq!(de.delocalize("12.5"), "12.5");
+        assert_eq!(de.delocalize("1.234"), "1234");
+
+        let fr = NumberConvention::for_locale("fr-CH");
+        assert_eq!(fr.delocalize("1\u{202f}234,50"), "1234.50");
+        assert_eq!(fr.delocalize("1'234,50"), "1234.50");
+    }
+}
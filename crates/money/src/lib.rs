@@ -0,0 +1,9 @@
+pub mod currency;
+pub mod format;
+pub mod money;
+pub mod view;
+
+pub use self::currency::*;
+pub use self::format::*;
+pub use self::money::*;
+pub use self::view::*;
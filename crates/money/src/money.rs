@@ -0,0 +1,213 @@
+use std::fmt;
+
+use thiserror::Error;
+use zabawa_validation::{InputFormatError, format_minor_units, parse_currency};
+
+use crate::CurrencyCode;
+use crate::format::NumberConvention;
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoneyError {
+    #[error("currency mismatch: expected {expected}, got {actual}")]
+    CurrencyMismatch {
+        expected: CurrencyCode,
+        actual: CurrencyCode,
+    },
+
+    #[error("amount out of range")]
+    Overflow,
+
+    #[error("money error: {0}")]
+    InvalidAmount(#[from] InputFormatError),
+}
+
+/// An amount in the minor unit of its currency, e.g. cents, so no precision is lost to floats.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Money {
+    minor: i64,
+    currency: CurrencyCode,
+}
+
+impl Money {
+    pub const fn new(minor: i64, currency: CurrencyCode) -> Self {
+        Self { minor, currency }
+    }
+
+    pub const fn zero(currency: CurrencyCode) -> Self {
+        Self::new(0, currency)
+    }
+
+    /// Parses user input written for `locale`, such as `1.234,50` for `de`. A leading `-`, surrounding whitespace
+    /// and the currency's code or symbol are accepted; more decimals than the currency has are rejected.
+    pub fn parse(input: &str, currency: CurrencyCode, locale: &str) -> Result<Self, MoneyError> {
+        let decimals = currency.minor_units();
+        let input = input.trim();
+        let input = strip_affix(input, currency.as_str());
+        let input = strip_affix(input, currency.symbol());
+        let (negative, input) = match input.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, input),
+        };
+
+        let amount = NumberConvention::for_locale(locale).delocalize(input.trim());
+        let minor = parse_currency(&amount, decimals)?;
+        Ok(Self::new(if negative { -minor } else { minor }, currency))
+    }
+
+    pub const fn minor(&self) -> i64 {
+        self.minor
+    }
+
+    pub const fn currency(&self) -> CurrencyCode {
+        self.currency
+    }
+
+    pub const fn is_zero(&self) -> bool {
+        self.minor == 0
+    }
+
+    pub const fn is_negative(&self) -> bool {
+        self.minor < 0
+    }
+
+    pub fn checked_add(self, other: Money) -> Result<Money, MoneyError> {
+        self.check_currency(other)?;
+        let minor = self.minor.checked_add(other.minor).ok_or(MoneyError::Overflow)?;
+        Ok(Self::new(minor, self.currency))
+    }
+
+    pub fn checked_sub(self, other: Money) -> Result<Money, MoneyError> {
+        self.check_currency(other)?;
+        let minor = self.minor.checked_sub(other.minor).ok_or(MoneyError::Overflow)?;
+        Ok(Self::new(minor, self.currency))
+    }
+
+    pub fn checked_mul(self, factor: i64) -> Result<Money, MoneyError> {
+        let minor = self.minor.checked_mul(factor).ok_or(MoneyError::Overflow)?;
+        Ok(Self::new(minor, self.currency))
+    }
+
+    pub fn checked_neg(self) -> Result<Money, MoneyError> {
+        let minor = self.minor.checked_neg().ok_or(MoneyError::Overflow)?;
+        Ok(Self::new(minor, self.currency))
+    }
+
+    /// Splits the amount into `parts` shares that differ by at most one minor unit and add up to the original,
+    /// e.g. 10.00 into 3.34, 3.33 and 3.33. Returns an empty list for zero parts.
+    pub fn split(self, parts: usize) -> Vec<Money> {
+        let Ok(count) = i64::try_from(parts) else {
+            return Vec::new();
+        };
+        if count == 0 {
+            return Vec::new();
+        }
+        let (share, remainder) = (self.minor / count, self.minor % count);
+        (0..count)
+            .map(|index| {
+                let extra = if index < remainder.abs() { remainder.signum() } else { 0 };
+                Self::new(share + extra, self.currency)
+            })
+            .collect()
+    }
+
+    /// Adds up amounts of one currency; an empty iterator sums to zero in `currency`.
+    pub fn sum(currency: CurrencyCode, amounts: impl IntoIterator<Item = Money>) -> Result<Money, MoneyError> {
+        amounts
+            .into_iter()
+            .try_fold(Money::zero(currency), |total, amount| total.checked_add(amount))
+    }
+
+    fn check_currency(&self, other: Money) -> Result<(), MoneyError> {
+        if self.currency == other.currency {
+            Ok(())
+        } else {
+            Err(MoneyError::CurrencyMismatch {
+                expected: self.currency,
+                actual: other.currency,
+            })
+        }
+    }
+}
+
+/// Locale-independent, e.g. `1234.50 EUR`; use [`crate::format_money`] for display to users.
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let amount = format_minor_units(self.minor, self.currency.minor_units());
+        write!(f, "{amount} {}", self.currency)
+    }
+}
+
+fn strip_affix<'a>(input: &'a str, affix: &str) -> &'a str {
+    let stripped = input
+        .strip_prefix(affix)
+        .or_else(|| input.strip_suffix(affix))
+        .or_else(|| {
+            let (start, rest) = input.split_at_checked(affix.len())?;
+            start.eq_ignore_ascii_case(affix).then_some(rest)
+        })
+        .or_else(|| {
+            let (rest, end) = input.split_at_checked(input.len().checked_sub(affix.len())?)?;
+            end.eq_ignore_ascii_case(affix).then_some(rest)
+        });
+    stripped.map_or(input, str::trim)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eur(minor: i64) -> Money {
+        Money::new(minor, CurrencyCode::EUR)
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Money::parse("1,234.5", CurrencyCode::EUR, "en"), Ok(eur(123_450)));
+        assert_eq!(Money::parse("1.234,50 €", CurrencyCode::EUR, "de"), Ok(eur(123_450)));
+        assert_eq!(Money::parse("-12.5", CurrencyCode::EUR, "de"), Ok(eur(-1250)));
+        assert_eq!(Money::parse("eur 3", CurrencyCode::EUR, "en"), Ok(eur(300)));
+        assert_eq!(
+            Money::parse("¥1,500", CurrencyCode::JPY, "ja"),
+            Ok(Money::new(1500, CurrencyCode::JPY))
+        );
+
+        let error = MoneyError::InvalidAmount(InputFormatError::Currency { decimals: 2 });
+        assert_eq!(Money::parse("1.234", CurrencyCode::EUR, "en"), Err(error));
+        assert_eq!(Money::parse("abc", CurrencyCode::EUR, "en"), Err(error));
+        assert_eq!(Money::parse("", CurrencyCode::EUR, "en"), Err(error));
+        assert!(Money::parse("1.5", CurrencyCode::JPY, "en").is_err());
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(eur(150).checked_add(eur(250)), Ok(eur(400)));
+        assert_eq!(eur(150).checked_sub(eur(250)), Ok(eur(-100)));
+        assert_eq!(eur(150).checked_mul(3), Ok(eur(450)));
+        assert_eq!(eur(i64::MAX).checked_add(eur(1)), Err(MoneyError::Overflow));
+        assert_eq!(eur(i64::MIN).checked_neg(), Err(MoneyError::Overflow));
+        assert_eq!(
+            eur(1).checked_add(Money::new(1, CurrencyCode::USD)),
+            Err(MoneyError::CurrencyMismatch {
+                expected: CurrencyCode::EUR,
+                actual: CurrencyCode::USD
+            })
+        );
+        assert_eq!(Money::sum(CurrencyCode::EUR, [eur(1), eur(2)]), Ok(eur(3)));
+        assert_eq!(Money::sum(CurrencyCode::EUR, []), Ok(eur(0)));
+    }
+
+    #[test]
+    fn test_split() {
+        assert_eq!(eur(1000).split(3), [eur(334), eur(333), eur(333)]);
+        assert_eq!(eur(-1000).split(3), [eur(-334), eur(-333), eur(-333)]);
+        assert_eq!(eur(2).split(4), [eur(1), eur(1), eur(0), eur(0)]);
+        assert!(eur(5).split(0).is_empty());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(eur(123_450).to_string(), "1234.50 EUR");
+        assert_eq!(eur(-5).to_string(), "-0.05 EUR");
+        assert_eq!(Money::new(1500, CurrencyCode::JPY).to_string(), "1500 JPY");
+    }
+}
@@ -0,0 +1,83 @@
+use hypertext::prelude::GlobalAttributes;
+use hypertext::{Renderable, rsx};
+use zabawa_validation::format_minor_units;
+use zabawa_view_common::{Attrs, Component, RenderContext, hypertext_elements};
+
+use crate::{Money, format_money};
+
+/// A `<data>` element with the localized amount as text and the canonical amount as its `value`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MoneyView {
+    /// Adds the `negative` class to amounts below zero, e.g. for refunds.
+    pub highlight_negative: bool,
+    pub attrs: Attrs,
+}
+
+impl MoneyView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_highlight_negative(mut self, highlight_negative: bool) -> Self {
+        self.highlight_negative = highlight_negative;
+        self
+    }
+
+    pub fn with_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs = attrs;
+        self
+    }
+
+    pub fn render<'a>(&'a self, ctx: &RenderContext, money: Money) -> impl Renderable + 'a {
+        let value = format_minor_units(money.minor(), money.currency().minor_units());
+        let text = format_money(&money, &ctx.locale);
+        let class = if self.highlight_negative && money.is_negative() {
+            "money negative"
+        } else {
+            "money"
+        };
+
+        self.attrs.spread(rsx! {
+            <data class=(class) value=(value) data-currency=(money.currency().as_str())>(text)</data>
+        })
+    }
+}
+
+impl Component for MoneyView {
+    type Props<'p> = Money;
+
+    fn render<'p>(&self, money: Self::Props<'p>, ctx: &RenderContext) -> impl Renderable {
+        MoneyView::render(self, ctx, money)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zabawa_view_common::testing::Document;
+
+    use super::*;
+    use crate::CurrencyCode;
+
+    #[test]
+    fn test_render() {
+        let ctx = RenderContext::new().with_locale("de");
+        let money = Money::new(-123_450, CurrencyCode::EUR);
+        let html = MoneyView::new()
+            .with_highlight_negative(true)
+            .render(&ctx, money)
+            .render()
+            .into_inner();
+
+        let document = Document::parse(&html);
+        let data = document.select("data.money.negative[data-currency=EUR]");
+        assert_eq!(data.len(), 1);
+        assert_eq!(data[0].attr("value"), Some("-1234.50"));
+        assert_eq!(data[0].text, "-1.234,50\u{a0}€");
+
+        let html = Component::render(&MoneyView::new(), money, &RenderContext::new())
+            .render()
+            .into_inner();
+        assert!(html.contains(r#"class="money""#));
+        assert!(html.contains("-€1,234.50"));
+    }
+}
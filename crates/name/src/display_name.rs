@@ -0,0 +1,121 @@
+use std::str::FromStr;
+
+use derive_more::{Display, Into};
+use thiserror::Error;
+use zabawa_validation::{InvalidLengthError, validate_length, validate_trimmed};
+
+use crate::NameBulder;
+
+/// The most characters [`DisplayName::new`] accepts.
+pub const DISPLAY_NAME_MAX_LENGTH: usize = 256;
+
+#[derive(Debug, Clone, Error)]
+pub enum DisplayNameError {
+    #[error("display name has leading or trailing whitespaces")]
+    Untrimmed,
+
+    #[error("display name error: {0}")]
+    InvalidLength(#[from] InvalidLengthError),
+
+    #[error("display name error: control character {ch:?} at position {position}")]
+    ControlCharacter { ch: char, position: usize },
+}
+
+/// A human-readable title such as `My Café Project!`, kept as the user typed it next to the [`Name`](crate::Name)
+/// derived from it.
+///
+/// Only checked for being trimmed, free of control characters, and 1 to `max_length` characters long.
+#[derive(Clone, Debug, Display, Into, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DisplayName(String);
+
+impl DisplayName {
+    pub fn new(input: impl AsRef<str> + Into<String>) -> Result<Self, DisplayNameError> {
+        Self::new_with_max_length(input, DISPLAY_NAME_MAX_LENGTH)
+    }
+
+    pub fn new_with_max_length(
+        input: impl AsRef<str> + Into<String>,
+        max_length: usize,
+    ) -> Result<Self, DisplayNameError> {
+        let value = input.as_ref();
+        if !validate_trimmed(value) {
+            return Err(DisplayNameError::Untrimmed);
+        }
+        validate_length(value.chars().count(), 1, max_length)?;
+        if let Some((position, ch)) = value.chars().enumerate().find(|(_, ch)| ch.is_control()) {
+            return Err(DisplayNameError::ControlCharacter { ch, position });
+        }
+        Ok(Self(input.into()))
+    }
+
+    /// Wraps `name` without validating it, e.g. when loading trusted data.
+    pub fn from_raw(name: impl Into<String>) -> Self {
+        Self(name.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Derives the slug, e.g. `my-cafe-project` from `My Café Project!` with
+    /// [`DefaultNameBuilder`](crate::DefaultNameBuilder).
+    pub fn to_name<B: NameBulder>(&self, builder: &B) -> Result<B::Name, B::Error> {
+        builder.build_with_normalize(self.as_str())
+    }
+}
+
+impl AsRef<str> for DisplayName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl FromStr for DisplayName {
+    type Err = DisplayNameError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::new(input)
+    }
+}
+
+impl TryFrom<String> for DisplayName {
+    type Error = DisplayNameError;
+
+    fn try_from(input: String) -> Result<Self, Self::Error> {
+        Self::new(input)
+    }
+}
+
+impl TryFrom<&str> for DisplayName {
+    type Error = DisplayNameError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        Self::new(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DefaultNameBuilder, Name};
+
+    #[test]
+    fn test_display_name() {
+        let display_name: DisplayName = "My Café Project!".parse().unwrap();
+        assert_eq!(display_name.as_str(), "My Café Project!");
+        let name: Name = display_name.to_name(&DefaultNameBuilder::new()).unwrap();
+        assert_eq!(name.as_str(), "my-cafe-project");
+        assert_eq!(String::from(display_name), "My Café Project!");
+
+        assert!(matches!(DisplayName::new(" padded"), Err(DisplayNameError::Untrimmed)));
+        assert!(matches!(DisplayName::new(""), Err(DisplayNameError::InvalidLength(_))));
+        assert!(matches!(
+            DisplayName::new_with_max_length("Café", 3),
+            Err(DisplayNameError::InvalidLength(InvalidLengthError { actual: 4, .. }))
+        ));
+        assert_eq!(
+            DisplayName::new("Line\nbreak").unwrap_err().to_string(),
+            "display name error: control character '\\n' at position 4"
+        );
+    }
+}
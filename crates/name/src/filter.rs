@@ -0,0 +1,145 @@
+use std::fmt;
+
+use crate::{is_separator, normalize_name};
+
+/// What a [`NameFilter`] decided about a name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FilterOutcome {
+    Accept,
+    /// Replace the name, e.g. with an offending word masked out.
+    Rewrite(String),
+    /// Reject the name; the reason is shown to the user.
+    Reject(String),
+}
+
+/// A check run on every name a [`DefaultNameBuilder`](crate::DefaultNameBuilder) validates, and after normalization,
+/// added with [`with_filter`](crate::DefaultNameBuilder::with_filter).
+///
+/// During validation, a name the filter would rewrite counts as invalid, so `build_with_normalize` applies the rewrite.
+pub trait NameFilter: fmt::Debug + Send + Sync {
+    fn filter(&self, name: &str) -> FilterOutcome;
+}
+
+/// Rejects, or rewrites, names containing words from a deny-list such as a profanity list. By default a denied word
+/// must be the whole name or one of its separator-delimited words.
+///
+/// Words are compared in normalized form with separators removed, so `Bad Word` in the list also catches `bad-word`,
+/// `badword` and `b_a_d_w_o_r_d`.
+#[derive(Debug, Clone)]
+pub struct DenyList {
+    words: Vec<String>,
+    substring_matching_enabled: bool,
+    replacement: Option<String>,
+}
+
+impl DenyList {
+    pub fn new(words: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        Self {
+            words: words
+                .into_iter()
+                .map(|word| compact(word.as_ref()))
+                .filter(|word| !word.is_empty())
+                .collect(),
+            substring_matching_enabled: false,
+            replacement: None,
+        }
+    }
+
+    /// Also matches denied words inside longer names, e.g. `bad` in `mybadname`.
+    pub fn with_substring_matching(mut self, enabled: bool) -> Self {
+        self.substring_matching_enabled = enabled;
+        self
+    }
+
+    /// Rewrites separator-delimited words that match to `replacement` instead of rejecting the name. Matches that do
+    /// not line up with a word are still rejected.
+    pub fn with_replacement(mut self, replacement: impl Into<String>) -> Self {
+        self.replacement = Some(replacement.into());
+        self
+    }
+
+    fn is_denied(&self, name: &str) -> bool {
+        let compacted = compact(name);
+        if self.substring_matching_enabled {
+            return self.words.iter().any(|word| compacted.contains(word.as_str()));
+        }
+        self.is_denied_word(&compacted) || name.split(is_separator).any(|word| self.is_denied_word(&compact(word)))
+    }
+
+    fn is_denied_word(&self, compacted: &str) -> bool {
+        self.words.iter().any(|word| word == compacted)
+    }
+}
+
+impl NameFilter for DenyList {
+    fn filter(&self, name: &str) -> FilterOutcome {
+        if !self.is_denied(name) {
+            return FilterOutcome::Accept;
+        }
+        let rejected = FilterOutcome::Reject(format!("{name:?} contains a word that is not allowed"));
+        let Some(replacement) = &self.replacement else {
+            return rejected;
+        };
+
+        let mut rewritten = String::with_capacity(name.len());
+        let mut word_start = 0;
+        for (index, ch) in name.char_indices().chain([(name.len(), '-')]) {
+            if index < name.len() && !is_separator(ch) {
+                continue;
+            }
+            let word = &name[word_start..index];
+            rewritten.push_str(if self.is_denied_word(&compact(word)) {
+                replacement
+            } else {
+                word
+            });
+            if index < name.len() {
+                rewritten.push(ch);
+            }
+            word_start = index + ch.len_utf8();
+        }
+        if self.is_denied(&rewritten) {
+            return rejected;
+        }
+        FilterOutcome::Rewrite(rewritten)
+    }
+}
+
+fn compact(input: &str) -> String {
+    let mut compacted = normalize_name(input);
+    compacted.retain(|ch| !is_separator(ch));
+    compacted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deny_list() {
+        let deny_list = DenyList::new(["Bad Word", "heck"]);
+        assert_eq!(deny_list.filter("good-name"), FilterOutcome::Accept);
+        assert!(matches!(deny_list.filter("bad-word"), FilterOutcome::Reject(_)));
+        assert!(matches!(deny_list.filter("b_a_d_w_o_r_d"), FilterOutcome::Reject(_)));
+        assert!(matches!(deny_list.filter("HECK"), FilterOutcome::Reject(_)));
+        assert!(matches!(deny_list.filter("what-the-heck"), FilterOutcome::Reject(_)));
+        assert_eq!(deny_list.filter("checkout"), FilterOutcome::Accept);
+
+        let deny_list = deny_list.with_substring_matching(true);
+        assert!(matches!(deny_list.filter("what-the-heck"), FilterOutcome::Reject(_)));
+        assert!(matches!(deny_list.filter("checkout"), FilterOutcome::Reject(_)));
+    }
+
+    #[test]
+    fn test_deny_list_replacement() {
+        let deny_list = DenyList::new(["heck"])
+            .with_substring_matching(true)
+            .with_replacement("x");
+        assert_eq!(
+            deny_list.filter("what-the-heck"),
+            FilterOutcome::Rewrite("what-the-x".to_owned())
+        );
+        assert_eq!(deny_list.filter("heck_heck"), FilterOutcome::Rewrite("x_x".to_owned()));
+        assert!(matches!(deny_list.filter("checkout"), FilterOutcome::Reject(_)));
+    }
+}
@@ -0,0 +1,154 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::Name;
+
+/// A cheap handle to a [`Name`] stored in a [`NameInterner`].
+///
+/// Equality and hashing compare the pooled allocation rather than the text, so they are O(1). Handles are only
+/// comparable with others from the same interner: equal names interned in two different pools are not equal.
+#[derive(Clone)]
+pub struct InternedName(Arc<str>);
+
+impl InternedName {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn to_name(&self) -> Name {
+        Name::from_raw(self.as_str())
+    }
+}
+
+impl PartialEq for InternedName {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for InternedName {}
+
+impl Hash for InternedName {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.0).cast::<u8>().hash(state);
+    }
+}
+
+impl AsRef<str> for InternedName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Debug for InternedName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("InternedName").field(&self.as_str()).finish()
+    }
+}
+
+impl fmt::Display for InternedName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&InternedName> for Name {
+    fn from(name: &InternedName) -> Self {
+        name.to_name()
+    }
+}
+
+/// A thread-safe pool of [`Name`]s for holding large, highly duplicated sets such as tags or labels in memory.
+/// Only validated names can be interned, so every handle is a valid name.
+#[derive(Debug, Default)]
+pub struct NameInterner {
+    names: Mutex<HashSet<Arc<str>>>,
+}
+
+impl NameInterner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the pooled handle for `name`, adding it to the pool on first use.
+    pub fn intern(&self, name: &Name) -> InternedName {
+        let mut names = self.names.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(existing) = names.get(name.as_str()) {
+            return InternedName(Arc::clone(existing));
+        }
+        let pooled: Arc<str> = Arc::from(name.as_str());
+        names.insert(Arc::clone(&pooled));
+        InternedName(pooled)
+    }
+
+    /// Returns the handle for `name` only if it has already been interned.
+    pub fn get(&self, name: &str) -> Option<InternedName> {
+        let names = self.names.lock().unwrap_or_else(PoisonError::into_inner);
+        names.get(name).map(|existing| InternedName(Arc::clone(existing)))
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.lock().unwrap_or_else(PoisonError::into_inner).len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drops pooled names that no handle refers to any more.
+    pub fn shrink(&self) {
+        let mut names = self.names.lock().unwrap_or_else(PoisonError::into_inner);
+        names.retain(|name| Arc::strong_count(name) > 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn test_intern() {
+        let interner = NameInterner::new();
+        let rust = interner.intern(&Name::from_raw("rust"));
+        let web = interner.intern(&Name::from_raw("web"));
+        assert_eq!(interner.intern(&Name::from_raw("rust")), rust);
+        assert_ne!(rust, web);
+        assert_eq!(interner.len(), 2);
+        assert_eq!(interner.get("rust"), Some(rust.clone()));
+        assert_eq!(interner.get("go"), None);
+
+        assert_eq!(rust.as_str(), "rust");
+        assert_eq!(rust.to_string(), "rust");
+        assert_eq!(Name::from(&rust), Name::from_raw("rust"));
+        assert_ne!(NameInterner::new().intern(&Name::from_raw("rust")), rust);
+    }
+
+    #[test]
+    fn test_shrink() {
+        let interner = NameInterner::new();
+        let kept = interner.intern(&Name::from_raw("kept"));
+        drop(interner.intern(&Name::from_raw("dropped")));
+        interner.shrink();
+        assert_eq!(interner.len(), 1);
+        assert_eq!(interner.get("kept"), Some(kept));
+        assert!(interner.get("dropped").is_none());
+    }
+
+    #[test]
+    fn test_shared_between_threads() {
+        let interner = Arc::new(NameInterner::new());
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let interner = Arc::clone(&interner);
+                thread::spawn(move || interner.intern(&Name::from_raw("label")))
+            })
+            .collect();
+        let names: HashSet<InternedName> = handles.into_iter().map(|handle| handle.join().unwrap()).collect();
+        assert_eq!(names.len(), 1);
+        assert_eq!(interner.len(), 1);
+    }
+}
@@ -1,9 +1,35 @@
+pub mod display_name;
+pub mod filter;
+pub mod interner;
+pub mod normalize;
+pub mod path;
+pub mod preset;
+pub mod report;
+pub mod script;
+pub mod suggest;
+pub mod tag_set;
+pub mod typed;
+
+use std::str::FromStr;
+
 use derive_more::{Display, Into};
 use thiserror::Error;
 pub use zabawa_validation::InvalidLengthError;
 use zabawa_validation::{validate_length, validate_trimmed};
 
-#[derive(Debug, Clone, Copy, Error)]
+pub use self::display_name::*;
+pub use self::filter::*;
+pub use self::interner::*;
+pub use self::normalize::*;
+pub use self::path::*;
+pub use self::preset::*;
+pub use self::report::*;
+pub use self::script::*;
+pub use self::suggest::*;
+pub use self::tag_set::*;
+pub use self::typed::*;
+
+#[derive(Debug, Clone, Error)]
 pub enum NameError {
     #[error("name has leading or trailing whitespaces")]
     Untrimmed,
@@ -13,16 +39,103 @@ pub enum NameError {
 
     #[error("name error: {0}")]
     InvalidCharacters(#[from] InvalidCharactersError),
+
+    #[error("name error: {0:?} is reserved")]
+    Reserved(String),
+
+    /// A [`NameFilter`] rejected the name, with its reason.
+    #[error("name error: {0}")]
+    Filtered(String),
+
+    /// The name mixes scripts that look alike, e.g. a Cyrillic `а` in an otherwise Latin name.
+    #[error("name error: mixes {0} and {1} characters, which can be confused")]
+    Confusable(Script, Script),
+
+    /// An error from a preset such as [`DefaultNameBuilder::k8s_label_value`], naming the spec it enforces.
+    #[error("{error} (violates {spec})")]
+    Spec { spec: &'static str, error: Box<NameError> },
+}
+
+impl NameError {
+    /// The underlying error, without any [`NameError::Spec`] wrapper.
+    pub fn cause(&self) -> &NameError {
+        match self {
+            NameError::Spec { error, .. } => error.cause(),
+            error => error,
+        }
+    }
+}
+
+/// Common route segments and account names that user-chosen slugs should not claim, see
+/// [`DefaultNameBuilder::with_default_reserved_names`].
+pub const DEFAULT_RESERVED_NAMES: &[&str] = &[
+    "about",
+    "account",
+    "admin",
+    "administrator",
+    "api",
+    "app",
+    "assets",
+    "auth",
+    "blog",
+    "dashboard",
+    "delete",
+    "docs",
+    "edit",
+    "help",
+    "home",
+    "login",
+    "logout",
+    "me",
+    "new",
+    "null",
+    "register",
+    "root",
+    "search",
+    "settings",
+    "signin",
+    "signup",
+    "static",
+    "status",
+    "support",
+    "system",
+    "undefined",
+    "user",
+    "users",
+    "www",
+];
+
+/// The characters a name may not contain, in the order they appear. Never empty.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("{}", describe_invalid_chars(&self.chars))]
+pub struct InvalidCharactersError {
+    pub chars: Vec<InvalidChar>,
 }
 
-#[derive(Error, Debug, Clone, Copy, PartialEq)]
-#[error("invalid characters")]
-pub struct InvalidCharactersError;
+/// A rejected character and where it is, both as a byte offset and as a (0-based) character position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidChar {
+    pub ch: char,
+    pub byte_offset: usize,
+    pub position: usize,
+}
+
+fn describe_invalid_chars(chars: &[InvalidChar]) -> String {
+    let described: Vec<String> = chars
+        .iter()
+        .map(|invalid| format!("{:?} at position {}", invalid.ch, invalid.position))
+        .collect();
+    match described.len() {
+        1 => format!("invalid character {}", described[0]),
+        _ => format!("invalid characters {}", described.join(", ")),
+    }
+}
 
 #[derive(Clone, Debug, Display, Into, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Name(String);
 
 impl Name {
+    /// Wraps `name` without validating it, e.g. when loading trusted data. Prefer `str::parse` or `Name::try_from`.
     pub fn from_raw(name: impl Into<String>) -> Self {
         Self(name.into())
     }
@@ -38,6 +151,31 @@ impl AsRef<str> for Name {
     }
 }
 
+/// Validates with [`DefaultNameBuilder::new`], without normalizing.
+impl FromStr for Name {
+    type Err = NameError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        DefaultNameBuilder::new().build(input)
+    }
+}
+
+impl TryFrom<String> for Name {
+    type Error = NameError;
+
+    fn try_from(input: String) -> Result<Self, Self::Error> {
+        DefaultNameBuilder::new().build(input)
+    }
+}
+
+impl TryFrom<&str> for Name {
+    type Error = NameError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        input.parse()
+    }
+}
+
 pub trait NameBulder {
     type Name;
     type Error;
@@ -51,12 +189,199 @@ pub trait NameBulder {
     fn build_with_normalize(&self, input: impl AsRef<str> + Into<String>) -> Result<Self::Name, Self::Error>;
 }
 
-#[derive(Debug, Default)]
+/// How [`DefaultNameBuilder::normalize`] treats separators (`-`, `_` and the configured separator).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SeparatorPolicy {
+    /// Keeps separators as produced, e.g. `bei-jing-`.
+    Keep,
+    /// Strips leading and trailing separators, e.g. `bei-jing-` → `bei-jing`.
+    #[default]
+    StripEdges,
+    /// Strips edges and collapses runs of separators to their first one, e.g. `a--_b` → `a-b`.
+    Collapse,
+}
+
+/// How [`DefaultNameBuilder`] joins the words of a name, e.g. for code identifiers or environment variables.
+///
+/// Normalization produces lowercase words with the configured charset first, then joins them in this style.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CaseStyle {
+    /// `my-project`, using the builder's separator.
+    #[default]
+    Kebab,
+    /// `my_project`
+    Snake,
+    /// `MY_PROJECT`
+    ScreamingSnake,
+    /// `myProject`
+    Camel,
+    /// `MyProject`
+    Pascal,
+}
+
+impl CaseStyle {
+    /// Joins lowercase words in this style, e.g. `["my", "project"]` → `myProject` for [`CaseStyle::Camel`].
+    pub fn join<'a>(self, words: impl IntoIterator<Item = &'a str>) -> String {
+        let mut output = String::new();
+        for (index, word) in words.into_iter().enumerate() {
+            match self {
+                CaseStyle::Kebab | CaseStyle::Snake | CaseStyle::ScreamingSnake => {
+                    if index > 0 {
+                        output.push(if self == CaseStyle::Kebab { '-' } else { '_' });
+                    }
+                    if self == CaseStyle::ScreamingSnake {
+                        output.extend(word.chars().flat_map(char::to_uppercase));
+                    } else {
+                        output.push_str(word);
+                    }
+                },
+                CaseStyle::Camel if index == 0 => output.push_str(word),
+                CaseStyle::Camel | CaseStyle::Pascal => {
+                    let mut chars = word.chars();
+                    if let Some(first) = chars.next() {
+                        output.extend(first.to_uppercase());
+                        output.push_str(chars.as_str());
+                    }
+                },
+            }
+        }
+        output
+    }
+}
+
+/// What [`DefaultNameBuilder::normalize`] does with names longer than `max_length`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OverflowStrategy {
+    /// Leaves the name as is, so building it fails with [`NameError::InvalidLength`].
+    #[default]
+    Error,
+    /// Cuts the name down to `max_length`, e.g. `very-long-project-name` → `very-long-projec`.
+    Truncate,
+    /// Truncates and appends a short hash of the full name, e.g. `very-long-project-name` → `very-long-7e2d32`, so
+    /// names sharing a long prefix stay distinct.
+    TruncateWithHash,
+}
+
+/// Hex digits of the hash [`OverflowStrategy::TruncateWithHash`] appends.
+pub const OVERFLOW_HASH_LENGTH: usize = 6;
+
+/// 64-bit FNV-1a, the default overflow hasher. Unlike `DefaultHasher` its output is stable across releases.
+pub fn stable_name_hash(input: &str) -> u64 {
+    input.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Which characters [`DefaultNameBuilder`] accepts, and how `normalize` maps the others.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Charset {
+    /// `a-z`, `0-9`, `-` and `_`. Normalization transliterates to ASCII, e.g. `Café` → `cafe`.
+    #[default]
+    AsciiSlug,
+    /// `a-z`, `0-9` and `-`, with no `-` at either end: a DNS label as defined by RFC 1123.
+    Rfc1123Label,
+    /// RFC 1123 labels joined by `.`, e.g. `api.acme-corp.example`.
+    Rfc1123Hostname,
+    /// ASCII alphanumerics, `-`, `_` and `.`, starting and ending with an alphanumeric: a Kubernetes label value.
+    K8sLabelValue,
+    /// ASCII alphanumerics, `-`, `_` and `.`, not starting with `-` or `.`: a Docker image tag.
+    DockerTag,
+    /// Lowercase characters of the UAX #31 `XID_Continue` set, so letters, digits, marks and `_` in any script,
+    /// starting with an `XID_Start` character. Normalization lowercases instead of transliterating, e.g. `Москва` →
+    /// `москва`.
+    ///
+    /// Names must be in NFC, so that two spellings of the same name compare equal. Without the Unicode composition
+    /// tables this is approximated: a combining diacritic (U+0300–U+036F) directly after a Latin, Greek or Cyrillic
+    /// letter is rejected, since these letters have precomposed forms for the common accents, e.g. `é` for `e` +
+    /// U+0301.
+    UnicodeIdentifier,
+}
+
+impl Charset {
+    fn is_name_char(self, ch: char) -> bool {
+        match self {
+            Charset::AsciiSlug => is_name_safe_char(ch),
+            Charset::Rfc1123Label => ch.is_ascii_lowercase() || ch.is_ascii_digit() || ch == '-',
+            Charset::Rfc1123Hostname => Charset::Rfc1123Label.is_name_char(ch) || ch == '.',
+            Charset::K8sLabelValue | Charset::DockerTag => ch.is_ascii_alphanumeric() || matches!(ch, '-' | '_' | '.'),
+            Charset::UnicodeIdentifier => ch == '-' || (unicode_ident::is_xid_continue(ch) && !ch.is_uppercase()),
+        }
+    }
+}
+
+/// What [`DefaultNameBuilder`] counts when checking `min_length` and `max_length`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LengthUnit {
+    /// UTF-8 bytes, e.g. the storage size of a column.
+    #[default]
+    Bytes,
+    /// Unicode scalar values, so `Москва` is 6 long rather than 12.
+    Chars,
+    /// Extended grapheme clusters, i.e. what a user perceives as characters, so `é` is 1 long even when decomposed.
+    #[cfg(feature = "unicode-segmentation")]
+    Graphemes,
+}
+
+impl LengthUnit {
+    pub fn measure(self, input: &str) -> usize {
+        match self {
+            LengthUnit::Bytes => input.len(),
+            LengthUnit::Chars => input.chars().count(),
+            #[cfg(feature = "unicode-segmentation")]
+            LengthUnit::Graphemes => unicode_segmentation::UnicodeSegmentation::graphemes(input, true).count(),
+        }
+    }
+
+    /// The byte offset at which `input` is at most `length` long, never splitting a character.
+    pub fn byte_offset(self, input: &str, length: usize) -> usize {
+        match self {
+            LengthUnit::Bytes => input.floor_char_boundary(length),
+            LengthUnit::Chars => input
+                .char_indices()
+                .nth(length)
+                .map_or(input.len(), |(offset, _)| offset),
+            #[cfg(feature = "unicode-segmentation")]
+            LengthUnit::Graphemes => unicode_segmentation::UnicodeSegmentation::grapheme_indices(input, true)
+                .nth(length)
+                .map_or(input.len(), |(offset, _)| offset),
+        }
+    }
+}
+
+#[derive(Debug)]
 pub struct DefaultNameBuilder {
     pub min_length: Option<usize>,
     pub max_length: Option<usize>,
+    pub length_unit: LengthUnit,
+    pub charset: Charset,
+    /// Replaces the charset's rule for which characters are allowed, e.g. to also accept `+` or `.`.
+    pub char_predicate: Option<fn(char) -> bool>,
     pub char_validation_enabled: bool,
     pub trim_validation_enabled: bool,
+    /// Rejects names mixing scripts, see [`check_mixed_scripts`]. Only matters for non-ASCII charsets.
+    pub mixed_script_validation_enabled: bool,
+    /// Names the spec a preset enforces, which validation errors are wrapped in as [`NameError::Spec`].
+    pub spec: Option<&'static str>,
+    /// Whether normalizing cuts names down to `max_length` instead of leaving them to fail validation.
+    pub overflow_strategy: OverflowStrategy,
+    /// Hashes the full name for [`OverflowStrategy::TruncateWithHash`].
+    pub overflow_hasher: fn(&str) -> u64,
+    /// Truncates at the last separator that fits, so words are not cut in half.
+    pub word_boundary_truncation_enabled: bool,
+    /// Lowercased words dropped when normalizing, unless the name would be left empty.
+    pub stop_words: Vec<String>,
+    /// Keeps at most this many words when normalizing.
+    pub max_words: Option<usize>,
+    /// Replaces unsafe characters when normalizing, and is accepted as a name character, e.g. `_` for snake_case.
+    pub separator: char,
+    pub separator_policy: SeparatorPolicy,
+    /// Anything but [`CaseStyle::Kebab`] replaces the separator when joining words, and changes which characters
+    /// validation accepts accordingly.
+    pub case_style: CaseStyle,
+    /// Lowercased names that are rejected regardless of case.
+    pub reserved_names: Vec<String>,
+    /// Run in order on validated names and after normalization.
+    pub filters: Vec<Box<dyn NameFilter>>,
 }
 
 impl DefaultNameBuilder {
@@ -64,8 +389,23 @@ impl DefaultNameBuilder {
         Self {
             min_length: Some(2),
             max_length: Some(512),
+            length_unit: LengthUnit::Bytes,
+            charset: Charset::AsciiSlug,
+            char_predicate: None,
             char_validation_enabled: true,
             trim_validation_enabled: true,
+            mixed_script_validation_enabled: false,
+            spec: None,
+            overflow_strategy: OverflowStrategy::Error,
+            overflow_hasher: stable_name_hash,
+            word_boundary_truncation_enabled: false,
+            stop_words: Vec::new(),
+            max_words: None,
+            separator: '-',
+            separator_policy: SeparatorPolicy::StripEdges,
+            case_style: CaseStyle::Kebab,
+            reserved_names: Vec::new(),
+            filters: Vec::new(),
         }
     }
 
@@ -89,6 +429,28 @@ impl DefaultNameBuilder {
         self
     }
 
+    pub fn with_length_unit(mut self, unit: LengthUnit) -> Self {
+        self.length_unit = unit;
+        self
+    }
+
+    pub fn with_charset(mut self, charset: Charset) -> Self {
+        self.charset = charset;
+        self
+    }
+
+    /// Validation accepts exactly the characters `predicate` allows, plus the separator. Normalization still
+    /// lowercases and transliterates, then turns characters the predicate rejects into separators.
+    pub fn with_char_predicate(mut self, predicate: fn(char) -> bool) -> Self {
+        self.char_predicate = Some(predicate);
+        self
+    }
+
+    pub fn without_char_predicate(mut self) -> Self {
+        self.char_predicate = None;
+        self
+    }
+
     pub fn with_char_validation(mut self, enabled: bool) -> Self {
         self.char_validation_enabled = enabled;
         self
@@ -98,49 +460,523 @@ impl DefaultNameBuilder {
         self.trim_validation_enabled = enabled;
         self
     }
-}
 
-impl NameBulder for DefaultNameBuilder {
-    type Name = Name;
-    type Error = NameError;
+    pub fn with_mixed_script_validation(mut self, enabled: bool) -> Self {
+        self.mixed_script_validation_enabled = enabled;
+        self
+    }
 
-    fn validate(&self, input: &str) -> Result<(), Self::Error> {
+    pub fn with_spec(mut self, spec: &'static str) -> Self {
+        self.spec = Some(spec);
+        self
+    }
+
+    pub fn without_spec(mut self) -> Self {
+        self.spec = None;
+        self
+    }
+
+    /// Shorthand for [`OverflowStrategy::Truncate`] when enabled, and [`OverflowStrategy::Error`] otherwise.
+    pub fn with_truncation(self, enabled: bool) -> Self {
+        self.with_overflow_strategy(if enabled {
+            OverflowStrategy::Truncate
+        } else {
+            OverflowStrategy::Error
+        })
+    }
+
+    pub fn with_overflow_strategy(mut self, strategy: OverflowStrategy) -> Self {
+        self.overflow_strategy = strategy;
+        self
+    }
+
+    pub fn with_overflow_hasher(mut self, hasher: fn(&str) -> u64) -> Self {
+        self.overflow_hasher = hasher;
+        self
+    }
+
+    pub fn with_word_boundary_truncation(mut self, enabled: bool) -> Self {
+        self.word_boundary_truncation_enabled = enabled;
+        self
+    }
+
+    pub fn with_stop_words(mut self, words: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        self.stop_words
+            .extend(words.into_iter().map(|word| word.as_ref().to_lowercase()));
+        self
+    }
+
+    pub fn without_stop_words(mut self) -> Self {
+        self.stop_words.clear();
+        self
+    }
+
+    pub fn with_max_words(mut self, max: usize) -> Self {
+        self.max_words = Some(max);
+        self
+    }
+
+    pub fn without_max_words(mut self) -> Self {
+        self.max_words = None;
+        self
+    }
+
+    pub fn with_separator(mut self, separator: char) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    pub fn with_separator_policy(mut self, policy: SeparatorPolicy) -> Self {
+        self.separator_policy = policy;
+        self
+    }
+
+    pub fn with_case_style(mut self, style: CaseStyle) -> Self {
+        self.case_style = style;
+        self
+    }
+
+    pub fn with_reserved_names(mut self, names: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        self.reserved_names
+            .extend(names.into_iter().map(|name| name.as_ref().to_lowercase()));
+        self
+    }
+
+    pub fn with_default_reserved_names(self) -> Self {
+        self.with_reserved_names(DEFAULT_RESERVED_NAMES)
+    }
+
+    pub fn without_reserved_names(mut self) -> Self {
+        self.reserved_names.clear();
+        self
+    }
+
+    pub fn with_filter(mut self, filter: impl NameFilter + 'static) -> Self {
+        self.filters.push(Box::new(filter));
+        self
+    }
+
+    pub fn without_filters(mut self) -> Self {
+        self.filters.clear();
+        self
+    }
+
+    fn is_name_char(&self, ch: char) -> bool {
+        let allowed = match self.char_predicate {
+            Some(predicate) => predicate(ch),
+            None => self.charset.is_name_char(ch),
+        };
+        allowed || ch == self.separator
+    }
+
+    fn is_separator(&self, ch: char) -> bool {
+        is_separator(ch) || ch == self.separator
+    }
+
+    /// Whether `ch` may appear in a name joined in the configured case style.
+    fn is_styled_char(&self, ch: char) -> bool {
+        let lowercase = ch.to_lowercase().next().unwrap_or(ch);
+        match self.case_style {
+            CaseStyle::Kebab => self.is_name_char(ch),
+            CaseStyle::Snake => ch == '_' || (!self.is_separator(ch) && self.is_name_char(ch)),
+            CaseStyle::ScreamingSnake => {
+                ch == '_' || (!self.is_separator(ch) && !ch.is_lowercase() && self.is_name_char(lowercase))
+            },
+            CaseStyle::Camel | CaseStyle::Pascal => !self.is_separator(ch) && self.is_name_char(lowercase),
+        }
+    }
+
+    /// Checks that camelCase names start lowercase and PascalCase names start uppercase.
+    fn validate_case(&self, input: &str) -> Result<(), NameError> {
+        let Some(first) = input.chars().next() else {
+            return Ok(());
+        };
+        let invalid = match self.case_style {
+            CaseStyle::Camel => first.is_uppercase(),
+            CaseStyle::Pascal => first.is_lowercase(),
+            _ => false,
+        };
+        if !invalid {
+            return Ok(());
+        }
+        let chars = vec![InvalidChar {
+            ch: first,
+            byte_offset: 0,
+            position: 0,
+        }];
+        Err(InvalidCharactersError { chars }.into())
+    }
+
+    fn tidy_separators(&self, name: &mut String) {
+        let is_separator = |ch| self.is_separator(ch);
+        match self.separator_policy {
+            SeparatorPolicy::Keep => {},
+            SeparatorPolicy::StripEdges => strip_separators(name, is_separator),
+            SeparatorPolicy::Collapse => {
+                collapse_separators(name, is_separator);
+                strip_separators(name, is_separator);
+            },
+        }
+    }
+
+    fn truncate(&self, name: &mut String, max: usize) {
+        let mut offset = self.length_unit.byte_offset(name, max);
+        if self.word_boundary_truncation_enabled
+            && offset < name.len()
+            && !name[offset..].starts_with(|ch| self.is_separator(ch))
+            && let Some(boundary) = name[..offset]
+                .rfind(|ch| self.is_separator(ch))
+                .filter(|&boundary| boundary > 0)
+        {
+            offset = boundary;
+        }
+        name.truncate(offset);
+        self.tidy_separators(name);
+    }
+
+    /// Applies the overflow strategy to a name longer than `max`.
+    fn shorten(&self, name: &mut String, max: usize) {
+        match self.overflow_strategy {
+            OverflowStrategy::Error => {},
+            OverflowStrategy::Truncate => self.truncate(name, max),
+            OverflowStrategy::TruncateWithHash => {
+                if self.length_unit.measure(name) <= max {
+                    return;
+                }
+                let hash = format!("{:016x}", (self.overflow_hasher)(name));
+                let suffix = &hash[..OVERFLOW_HASH_LENGTH.min(max)];
+                match max
+                    .checked_sub(OVERFLOW_HASH_LENGTH + 1)
+                    .filter(|&prefix_max| prefix_max > 0)
+                {
+                    Some(prefix_max) => {
+                        self.truncate(name, prefix_max);
+                        if !name.is_empty() && !name.ends_with(|ch| self.is_separator(ch)) {
+                            name.push(self.separator);
+                        }
+                        name.push_str(suffix);
+                    },
+                    None => suffix.clone_into(name),
+                }
+            },
+        }
+    }
+
+    /// Drops stop words and words beyond `max_words`, joining the rest with the separator.
+    fn select_words(&self, name: &mut String) {
+        if self.stop_words.is_empty() && self.max_words.is_none() {
+            return;
+        }
+        let words: Vec<&str> = name
+            .split(|ch| self.is_separator(ch))
+            .filter(|word| !word.is_empty())
+            .collect();
+        let mut kept: Vec<&str> = words
+            .iter()
+            .copied()
+            .filter(|word| !self.stop_words.iter().any(|stop_word| stop_word == word))
+            .collect();
+        if kept.is_empty() {
+            kept = words;
+        }
+        kept.truncate(self.max_words.unwrap_or(usize::MAX));
+        *name = kept.join(self.separator.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Tidies and shortens each label of a hostname, dropping labels left empty.
+    fn normalize_labels(&self, hostname: &str) -> String {
+        let labels: Vec<String> = hostname
+            .split('.')
+            .map(|label| {
+                let mut label = label.to_owned();
+                self.tidy_separators(&mut label);
+                strip_separators(&mut label, |ch| ch == '-');
+                if self.overflow_strategy != OverflowStrategy::Error {
+                    self.shorten(&mut label, RFC1123_LABEL_MAX_LENGTH);
+                    strip_separators(&mut label, |ch| ch == '-');
+                }
+                label
+            })
+            .filter(|label| !label.is_empty())
+            .collect();
+        labels.join(".")
+    }
+
+    /// Checks the charsets whose rules depend on where a character is, e.g. no `-` at the end of a DNS label.
+    fn validate_edges(&self, input: &str) -> Result<(), NameError> {
+        if self.charset == Charset::UnicodeIdentifier {
+            return validate_unicode_identifier(input);
+        }
+        let (labels, check_end): (Vec<&str>, bool) = match self.charset {
+            Charset::Rfc1123Label => (vec![input], true),
+            Charset::Rfc1123Hostname => (input.split('.').collect(), true),
+            Charset::K8sLabelValue if !input.is_empty() => (vec![input], true),
+            Charset::DockerTag if !input.is_empty() => (vec![input], false),
+            _ => return Ok(()),
+        };
+        let is_edge_char = |ch: char| ch.is_ascii_alphanumeric() || (self.charset == Charset::DockerTag && ch == '_');
+
+        let mut offset = 0;
+        for label in labels {
+            // Only ASCII is left after character validation, so byte offsets are character positions.
+            let invalid = |position: usize| InvalidCharactersError {
+                chars: vec![InvalidChar {
+                    ch: input[position..].chars().next().unwrap_or('.'),
+                    byte_offset: position,
+                    position,
+                }],
+            };
+            if label.is_empty() && !input.is_empty() {
+                return Err(invalid(offset.min(input.len().saturating_sub(1))).into());
+            }
+            if self.charset == Charset::Rfc1123Hostname && label.len() > RFC1123_LABEL_MAX_LENGTH {
+                return Err(InvalidLengthError {
+                    min: 1,
+                    max: RFC1123_LABEL_MAX_LENGTH,
+                    actual: label.len(),
+                }
+                .into());
+            }
+            if !label.starts_with(is_edge_char) {
+                return Err(invalid(offset).into());
+            }
+            if check_end && !label.ends_with(is_edge_char) {
+                return Err(invalid(offset + label.len() - 1).into());
+            }
+            offset += label.len() + 1;
+        }
+        Ok(())
+    }
+
+    fn check(&self, input: &str) -> Result<(), NameError> {
         if self.trim_validation_enabled && !validate_trimmed(input) {
             return Err(NameError::Untrimmed);
         }
 
         if self.min_length.is_some() || self.max_length.is_some() {
-            validate_length(
-                input.len(),
-                self.min_length.unwrap_or(0),
-                self.max_length.unwrap_or(input.len()),
-            )?;
+            let length = self.length_unit.measure(input);
+            validate_length(length, self.min_length.unwrap_or(0), self.max_length.unwrap_or(length))?;
+        }
+
+        if self.char_validation_enabled {
+            find_invalid_chars(input, |ch| self.is_styled_char(ch))?;
+            self.validate_case(input)?;
+            self.validate_edges(input)?;
         }
 
-        if self.char_validation_enabled && !validate_name_chars(input) {
-            return Err(NameError::InvalidCharacters(InvalidCharactersError));
+        if self.mixed_script_validation_enabled {
+            check_mixed_scripts(input).map_err(|(first, second)| NameError::Confusable(first, second))?;
+        }
+
+        if !self.reserved_names.is_empty() {
+            let folded = input.to_lowercase();
+            if self.reserved_names.contains(&folded) {
+                return Err(NameError::Reserved(input.to_owned()));
+            }
+        }
+
+        for filter in &self.filters {
+            match filter.filter(input) {
+                FilterOutcome::Accept => {},
+                FilterOutcome::Rewrite(_) => {
+                    return Err(NameError::Filtered(format!("{input:?} needs to be adjusted")));
+                },
+                FilterOutcome::Reject(reason) => return Err(NameError::Filtered(reason)),
+            }
         }
 
         Ok(())
     }
 
-    fn normalize(&self, input: &str) -> Result<String, Self::Error> {
+    /// Builds a name like [`NameBulder::build_with_normalize`], also reporting what normalization changed.
+    pub fn build_with_report(
+        &self,
+        input: impl AsRef<str> + Into<String>,
+    ) -> Result<(Name, NormalizationReport), NameError> {
+        if self.validate(input.as_ref()).is_ok() && !self.has_untidy_separators(input.as_ref()) {
+            return Ok((Name::from_raw(input), NormalizationReport::default()));
+        }
+
+        let mut changes = Vec::new();
+        let normalized = self.normalize_reporting(input.as_ref(), &mut changes)?;
+        Ok((self.build(normalized)?, NormalizationReport { changes }))
+    }
+
+    fn normalize_reporting(&self, input: &str, changes: &mut Vec<NormalizationChange>) -> Result<String, NameError> {
         let input = if self.trim_validation_enabled {
-            input.trim()
+            let trimmed = input.trim();
+            if trimmed.len() != input.len() {
+                changes.push(NormalizationChange::Trimmed);
+            }
+            trimmed
         } else {
             input
         };
 
         let mut normalized = String::with_capacity(input.len());
-        if self.char_validation_enabled {
-            make_name(input, &mut normalized);
-        } else {
+        if !self.char_validation_enabled {
             input.clone_into(&mut normalized);
+        } else {
+            self.report_char_changes(input, changes);
+            if self.charset == Charset::UnicodeIdentifier {
+                make_unicode_name(input, self.separator, |ch| self.is_name_char(ch), &mut normalized);
+            } else {
+                make_name_with(input, self.separator, |ch| self.is_name_char(ch), &mut normalized);
+            }
+        }
+
+        let length = normalized.len();
+        self.tidy_separators(&mut normalized);
+        if normalized.len() != length {
+            changes.push(NormalizationChange::SeparatorsTidied);
+        }
+
+        let length = normalized.len();
+        self.select_words(&mut normalized);
+        if normalized.len() != length {
+            changes.push(NormalizationChange::WordsDropped);
+        }
+
+        if let Some(max) = self.max_length {
+            let length = normalized.len();
+            self.shorten(&mut normalized, max);
+            if normalized.len() != length {
+                changes.push(NormalizationChange::Truncated { max_length: max });
+            }
+        }
+
+        if self.char_validation_enabled {
+            let length = normalized.len();
+            match self.charset {
+                Charset::Rfc1123Hostname => normalized = self.normalize_labels(&normalized),
+                Charset::K8sLabelValue | Charset::DockerTag => {
+                    strip_separators(&mut normalized, |ch| !ch.is_ascii_alphanumeric());
+                },
+                _ => {},
+            }
+            if normalized.len() != length && !changes.contains(&NormalizationChange::SeparatorsTidied) {
+                changes.push(NormalizationChange::SeparatorsTidied);
+            }
+        }
+
+        if self.case_style != CaseStyle::Kebab {
+            let words = normalized
+                .split(|ch| self.is_separator(ch))
+                .filter(|word| !word.is_empty());
+            normalized = self.case_style.join(words);
+        }
+
+        for filter in &self.filters {
+            match filter.filter(&normalized) {
+                FilterOutcome::Accept => {},
+                FilterOutcome::Rewrite(rewritten) => {
+                    normalized = rewritten;
+                    changes.push(NormalizationChange::Rewritten);
+                },
+                FilterOutcome::Reject(reason) => return Err(NameError::Filtered(reason)),
+            }
         }
 
         Ok(normalized)
     }
 
+    fn report_char_changes(&self, input: &str, changes: &mut Vec<NormalizationChange>) {
+        let mut seen = Vec::new();
+        for ch in input.chars() {
+            if self.is_name_char(ch) || seen.contains(&ch) {
+                continue;
+            }
+            seen.push(ch);
+
+            let lowercase = ch.to_lowercase().next().unwrap_or(ch);
+            let change = if lowercase != ch && self.is_name_char(lowercase) {
+                NormalizationChange::Lowercased
+            } else if let Some(transliterated) = deunicode::deunicode_char(ch)
+                .filter(|_| !ch.is_ascii() && self.charset != Charset::UnicodeIdentifier)
+                .map(|transliterated| {
+                    let mut mapped = String::new();
+                    make_name_with(transliterated, self.separator, |ch| self.is_name_char(ch), &mut mapped);
+                    strip_separators(&mut mapped, |ch| self.is_separator(ch));
+                    mapped
+                })
+                .filter(|mapped| !mapped.is_empty())
+            {
+                NormalizationChange::Transliterated {
+                    from: ch,
+                    to: transliterated,
+                }
+            } else {
+                NormalizationChange::Replaced {
+                    from: ch,
+                    to: self.separator,
+                }
+            };
+            if !changes.contains(&change) {
+                changes.push(change);
+            }
+        }
+    }
+
+    fn has_untidy_separators(&self, input: &str) -> bool {
+        let is_separator = |ch| self.is_separator(ch);
+        let untidy_edges = input.starts_with(is_separator) || input.ends_with(is_separator);
+        let mut chars = input.chars().peekable();
+        let mut untidy_runs = false;
+        while let Some(ch) = chars.next() {
+            untidy_runs |= is_separator(ch) && chars.peek().is_some_and(|&next| is_separator(next));
+        }
+        match self.separator_policy {
+            SeparatorPolicy::Keep => false,
+            SeparatorPolicy::StripEdges => untidy_edges,
+            SeparatorPolicy::Collapse => untidy_edges || untidy_runs,
+        }
+    }
+}
+
+impl Default for DefaultNameBuilder {
+    fn default() -> Self {
+        Self {
+            min_length: None,
+            max_length: None,
+            length_unit: LengthUnit::Bytes,
+            charset: Charset::AsciiSlug,
+            char_predicate: None,
+            char_validation_enabled: false,
+            trim_validation_enabled: false,
+            mixed_script_validation_enabled: false,
+            spec: None,
+            overflow_strategy: OverflowStrategy::Error,
+            overflow_hasher: stable_name_hash,
+            word_boundary_truncation_enabled: false,
+            stop_words: Vec::new(),
+            max_words: None,
+            separator: '-',
+            separator_policy: SeparatorPolicy::Keep,
+            case_style: CaseStyle::Kebab,
+            reserved_names: Vec::new(),
+            filters: Vec::new(),
+        }
+    }
+}
+
+impl NameBulder for DefaultNameBuilder {
+    type Name = Name;
+    type Error = NameError;
+
+    fn validate(&self, input: &str) -> Result<(), Self::Error> {
+        self.check(input).map_err(|error| match self.spec {
+            Some(spec) => NameError::Spec {
+                spec,
+                error: Box::new(error),
+            },
+            None => error,
+        })
+    }
+
+    fn normalize(&self, input: &str) -> Result<String, Self::Error> {
+        self.normalize_reporting(input, &mut Vec::new())
+    }
+
     fn build(&self, input: impl AsRef<str> + Into<String>) -> Result<Self::Name, Self::Error> {
         self.validate(input.as_ref())?;
 
@@ -148,7 +984,7 @@ impl NameBulder for DefaultNameBuilder {
     }
 
     fn build_with_normalize(&self, input: impl AsRef<str> + Into<String>) -> Result<Self::Name, Self::Error> {
-        if self.validate(input.as_ref()).is_ok() {
+        if self.validate(input.as_ref()).is_ok() && !self.has_untidy_separators(input.as_ref()) {
             return Ok(Name::from_raw(input));
         }
 
@@ -157,53 +993,139 @@ impl NameBulder for DefaultNameBuilder {
     }
 }
 
-pub fn validate_name_chars(input: &str) -> bool {
-    input.chars().all(is_name_safe_char)
+pub fn validate_name_chars(input: &str) -> Result<(), InvalidCharactersError> {
+    find_invalid_chars(input, is_name_safe_char)
 }
 
-fn is_name_safe_char(ch: char) -> bool {
+fn find_invalid_chars(input: &str, is_valid: impl Fn(char) -> bool) -> Result<(), InvalidCharactersError> {
+    let chars: Vec<InvalidChar> = input
+        .char_indices()
+        .enumerate()
+        .filter(|(_, (_, ch))| !is_valid(*ch))
+        .map(|(position, (byte_offset, ch))| InvalidChar {
+            ch,
+            byte_offset,
+            position,
+        })
+        .collect();
+    if chars.is_empty() {
+        Ok(())
+    } else {
+        Err(InvalidCharactersError { chars })
+    }
+}
+
+pub(crate) fn is_name_safe_char(ch: char) -> bool {
     ch.is_ascii_lowercase() || ch.is_ascii_digit() || ch == '-' || ch == '_'
 }
 
+/// Whether `input` is already in the form produced by [`normalize_name`], which then returns it unchanged.
+///
+/// Normalization is idempotent: `normalize_name(&normalize_name(x)) == normalize_name(x)` for every input.
+pub fn is_normalized(input: &str) -> bool {
+    input.bytes().all(|byte| is_name_safe_char(char::from(byte)))
+        && !input.starts_with(is_separator)
+        && !input.ends_with(is_separator)
+}
+
 pub fn normalize_name(input: &str) -> String {
-    let trimmed = input.trim();
+    normalize_name_cow(input).into_owned()
+}
 
-    let mut normalized = String::with_capacity(trimmed.len());
-    make_name(trimmed, &mut normalized);
+pub fn make_name(input: &str, output: &mut String) {
+    make_name_with_separator(input, '-', output);
+}
 
-    normalized
+/// Like [`make_name`], but replaces unsafe characters with `separator`, which is itself kept as-is.
+pub fn make_name_with_separator(input: &str, separator: char, output: &mut String) {
+    make_name_with(input, separator, |ch| is_name_safe_char(ch) || ch == separator, output);
 }
 
-pub fn make_name(input: &str, output: &mut String) {
+fn make_name_with(input: &str, separator: char, is_name_char: impl Fn(char) -> bool, output: &mut String) {
     for ch in input.chars() {
         if ch.is_ascii() {
-            process_char(ch, output);
+            process_char(ch, separator, &is_name_char, output);
         } else if let Some(transliterated) = deunicode::deunicode_char(ch) {
             for trans in transliterated.chars() {
-                process_char(trans, output);
+                process_char(trans, separator, &is_name_char, output);
             }
         } else {
-            push_separator(output);
+            push_separator(separator, output);
         }
     }
 }
 
-fn process_char(ch: char, output: &mut String) {
+/// Checks that a [`Charset::UnicodeIdentifier`] name starts with an `XID_Start` character and is not decomposed.
+fn validate_unicode_identifier(input: &str) -> Result<(), NameError> {
+    let mut previous = None;
+    for (position, (byte_offset, ch)) in input.char_indices().enumerate() {
+        let invalid = match previous {
+            None => !unicode_ident::is_xid_start(ch),
+            Some(base) => {
+                matches!(ch, '\u{300}'..='\u{36f}')
+                    && matches!(Script::of(base), Some(Script::Latin | Script::Greek | Script::Cyrillic))
+            },
+        };
+        if invalid {
+            return Err(InvalidCharactersError {
+                chars: vec![InvalidChar {
+                    ch,
+                    byte_offset,
+                    position,
+                }],
+            }
+            .into());
+        }
+        previous = Some(ch);
+    }
+    Ok(())
+}
+
+fn make_unicode_name(input: &str, separator: char, is_name_char: impl Fn(char) -> bool, output: &mut String) {
+    for ch in input.chars().flat_map(char::to_lowercase) {
+        if is_name_char(ch) {
+            output.push(ch);
+        } else {
+            push_separator(separator, output);
+        }
+    }
+}
+
+fn process_char(ch: char, separator: char, is_name_char: impl Fn(char) -> bool, output: &mut String) {
     let ch = ch.to_ascii_lowercase();
-    if is_name_safe_char(ch) {
+    if is_name_char(ch) {
         output.push(ch);
     } else {
-        push_separator(output);
+        push_separator(separator, output);
     }
 }
 
-fn push_separator(output: &mut String) {
-    match output.chars().last() {
-        Some('-') => {},
-        _ => output.push('-'),
+fn push_separator(separator: char, output: &mut String) {
+    if !output.ends_with(separator) {
+        output.push(separator);
     }
 }
 
+pub(crate) fn is_separator(ch: char) -> bool {
+    ch == '-' || ch == '_'
+}
+
+fn strip_separators(name: &mut String, is_separator: impl Fn(char) -> bool) {
+    let end = name.trim_end_matches(&is_separator).len();
+    name.truncate(end);
+    let start = name.len() - name.trim_start_matches(&is_separator).len();
+    name.drain(..start);
+}
+
+fn collapse_separators(name: &mut String, is_separator: impl Fn(char) -> bool) {
+    let mut previous_was_separator = false;
+    name.retain(|ch| {
+        let keep = !(previous_was_separator && is_separator(ch));
+        previous_was_separator = is_separator(ch);
+        keep
+    });
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -219,11 +1141,11 @@ mod tests {
         assert_eq!(normalize_name("Café"), "cafe");
         assert_eq!(normalize_name("Straße"), "strasse");
         assert_eq!(normalize_name("Москва"), "moskva");
-        assert_eq!(normalize_name("北京"), "bei-jing-"); // TODO: remove last "-"
+        assert_eq!(normalize_name("北京"), "bei-jing");
         assert_eq!(normalize_name("Æneid"), "aeneid");
         assert_eq!(normalize_name("étude"), "etude");
-        assert_eq!(normalize_name("🦄☣"), "unicorn-biohazard-"); // TODO: remove last "-"
-        assert_eq!(normalize_name("…"), "-");
+        assert_eq!(normalize_name("🦄☣"), "unicorn-biohazard");
+        assert_eq!(normalize_name("…"), "");
     }
 
     #[test]
@@ -241,12 +1163,12 @@ mod tests {
         assert_eq!(normalize_name("hello@world"), "hello-world");
         assert_eq!(normalize_name("hello!!!world"), "hello-world");
         assert_eq!(normalize_name("hello#$%world"), "hello-world");
-        assert_eq!(normalize_name("hello!!!"), "hello-");
-        assert_eq!(normalize_name("!!!hello"), "-hello");
-        assert_eq!(normalize_name("@@@hello"), "-hello");
-        assert_eq!(normalize_name("hello@@@"), "hello-");
-        assert_eq!(normalize_name("@@@hello@@@"), "-hello-");
-        assert_eq!(normalize_name("   !!!   hello   !!!   "), "-hello-");
+        assert_eq!(normalize_name("hello!!!"), "hello");
+        assert_eq!(normalize_name("!!!hello"), "hello");
+        assert_eq!(normalize_name("@@@hello"), "hello");
+        assert_eq!(normalize_name("hello@@@"), "hello");
+        assert_eq!(normalize_name("@@@hello@@@"), "hello");
+        assert_eq!(normalize_name("   !!!   hello   !!!   "), "hello");
     }
 
     #[test]
@@ -266,8 +1188,8 @@ mod tests {
     fn test_normalize_name_dashes_underscores() {
         assert_eq!(normalize_name("hello-world"), "hello-world");
         assert_eq!(normalize_name("hello_world"), "hello_world");
-        assert_eq!(normalize_name("---"), "---");
-        assert_eq!(normalize_name("___"), "___");
+        assert_eq!(normalize_name("---"), "");
+        assert_eq!(normalize_name("___"), "");
         assert_eq!(normalize_name("hello-world"), "hello-world");
         assert_eq!(normalize_name("test---test"), "test---test");
         assert_eq!(normalize_name("a----b"), "a----b");
@@ -313,9 +1235,9 @@ mod tests {
 
     #[test]
     fn test_normalize_name_only_separators() {
-        assert_eq!(normalize_name("!!!"), "-");
-        assert_eq!(normalize_name("@@@"), "-");
-        assert_eq!(normalize_name("!@#$%"), "-");
+        assert_eq!(normalize_name("!!!"), "");
+        assert_eq!(normalize_name("@@@"), "");
+        assert_eq!(normalize_name("!@#$%"), "");
     }
 
     #[test]
@@ -331,28 +1253,448 @@ mod tests {
         assert_eq!(normalize_name("mix-of_both"), "mix-of_both");
     }
 
+    #[test]
+    fn test_builder_separators() {
+        let builder = DefaultNameBuilder::new();
+        assert_eq!(builder.normalize("北京").unwrap(), "bei-jing");
+        assert_eq!(builder.normalize("a -_ b").unwrap(), "a--_-b");
+        assert_eq!(builder.build_with_normalize("-hello-").unwrap().as_str(), "hello");
+        assert_eq!(builder.build("-hello-").unwrap().as_str(), "-hello-");
+
+        let builder = DefaultNameBuilder::new().with_separator_policy(SeparatorPolicy::Collapse);
+        assert_eq!(builder.normalize("a -_ b").unwrap(), "a-b");
+        assert_eq!(builder.build_with_normalize("a__b").unwrap().as_str(), "a_b");
+
+        let builder = DefaultNameBuilder::new().with_separator_policy(SeparatorPolicy::Keep);
+        assert_eq!(builder.normalize("北京").unwrap(), "bei-jing-");
+        assert_eq!(builder.build_with_normalize("-hello-").unwrap().as_str(), "-hello-");
+    }
+
+    #[test]
+    fn test_builder_custom_separator() {
+        let builder = DefaultNameBuilder::new().with_separator('_');
+        assert_eq!(builder.normalize("My Cool Project!").unwrap(), "my_cool_project");
+        assert_eq!(
+            builder.build_with_normalize("  Straße 12 ").unwrap().as_str(),
+            "strasse_12"
+        );
+
+        let builder = DefaultNameBuilder::new()
+            .with_separator('.')
+            .with_separator_policy(SeparatorPolicy::Collapse);
+        assert_eq!(builder.normalize("..Api  Gateway.v2..").unwrap(), "api.gateway.v2");
+        assert_eq!(builder.build("api.gateway").unwrap().as_str(), "api.gateway");
+        assert!(matches!(
+            DefaultNameBuilder::new().build("api.gateway"),
+            Err(NameError::InvalidCharacters(_))
+        ));
+    }
+
+    #[test]
+    fn test_length_unit() {
+        assert_eq!(LengthUnit::Bytes.measure("Москва"), 12);
+        assert_eq!(LengthUnit::Chars.measure("Москва"), 6);
+
+        let builder = DefaultNameBuilder::new()
+            .with_char_validation(false)
+            .with_min_length(4)
+            .with_max_length(8);
+        assert!(builder.build("Мир").is_ok());
+        assert!(builder.build("Москва").is_err());
+
+        let builder = builder.with_length_unit(LengthUnit::Chars);
+        assert!(matches!(
+            builder.build("Мир"),
+            Err(NameError::InvalidLength(InvalidLengthError { min: 4, actual: 3, .. }))
+        ));
+        assert!(builder.build("Москва").is_ok());
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    #[test]
+    fn test_length_unit_graphemes() {
+        let decomposed = "cafe\u{301}";
+        assert_eq!(LengthUnit::Chars.measure(decomposed), 5);
+        assert_eq!(LengthUnit::Graphemes.measure(decomposed), 4);
+        assert_eq!(LengthUnit::Graphemes.measure("👩‍👩‍👧"), 1);
+    }
+
+    #[test]
+    fn test_unicode_identifier_charset() {
+        let builder = DefaultNameBuilder::new().with_charset(Charset::UnicodeIdentifier);
+        assert!(builder.build("café").is_ok());
+        assert!(builder.build("москва").is_ok());
+        assert!(builder.build("北京-2024_v1").is_ok());
+        assert!(builder.build("Москва").is_err());
+        assert!(builder.build("🦄-horn").is_err());
+        assert!(builder.build("a b").is_err());
+
+        assert!(builder.build("_café").is_err());
+        assert!(builder.build("2024-plan").is_err());
+        assert!(matches!(
+            builder.build("-москва"),
+            Err(NameError::InvalidCharacters(InvalidCharactersError { chars })) if chars[0].position == 0
+        ));
+
+        assert!(builder.build("caf\u{e9}").is_ok());
+        assert!(matches!(
+            builder.build("cafe\u{301}"),
+            Err(NameError::InvalidCharacters(InvalidCharactersError { chars }))
+                if chars == [InvalidChar { ch: '\u{301}', byte_offset: 4, position: 4 }]
+        ));
+        assert!(builder.build("\u{439}\u{43e}\u{433}\u{430}").is_ok());
+        assert!(builder.build("\u{438}\u{306}\u{43e}\u{433}\u{430}").is_err());
+        assert!(builder.build("\u{3b1}\u{301}").is_err());
+
+        assert_eq!(builder.normalize("  Привет, Мир! ").unwrap(), "привет-мир");
+        assert_eq!(builder.normalize("Café 🦄").unwrap(), "café");
+        assert_eq!(builder.build_with_normalize("ÉTUDE").unwrap().as_str(), "étude");
+    }
+
+    #[test]
+    fn test_mixed_script_validation() {
+        let builder = DefaultNameBuilder::new()
+            .with_charset(Charset::UnicodeIdentifier)
+            .with_mixed_script_validation(true);
+        assert!(builder.build("paypal").is_ok());
+        assert!(builder.build("москва").is_ok());
+        assert!(matches!(
+            builder.build("pаypal"),
+            Err(NameError::Confusable(Script::Latin, Script::Cyrillic))
+        ));
+        assert_eq!(
+            builder.build("pаypal").unwrap_err().to_string(),
+            "name error: mixes Latin and Cyrillic characters, which can be confused"
+        );
+        assert!(
+            DefaultNameBuilder::new()
+                .with_charset(Charset::UnicodeIdentifier)
+                .build("pаypal")
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_char_predicate() {
+        fn allow_plus(ch: char) -> bool {
+            ch.is_ascii_lowercase() || ch.is_ascii_digit() || ch == '+'
+        }
+
+        let builder = DefaultNameBuilder::new().with_char_predicate(allow_plus);
+        assert!(builder.build("c++").is_ok());
+        assert!(builder.build("c-plus").is_ok());
+        assert!(matches!(builder.build("c_plus"), Err(NameError::InvalidCharacters(_))));
+        assert_eq!(builder.normalize("C++ / C#").unwrap(), "c++-c");
+        assert_eq!(builder.normalize("snake_case").unwrap(), "snake-case");
+
+        let builder = DefaultNameBuilder::new().with_char_predicate(|ch| ch.is_ascii_alphanumeric());
+        assert!(builder.build("MyProject").is_ok());
+        assert!(builder.without_char_predicate().build("MyProject").is_err());
+    }
+
+    #[test]
+    fn test_filters() {
+        let builder = DefaultNameBuilder::new().with_filter(DenyList::new(["heck"]).with_replacement("x"));
+        assert!(builder.build("hello").is_ok());
+        assert!(matches!(builder.build("what-the-heck"), Err(NameError::Filtered(_))));
+        assert_eq!(
+            builder.build_with_normalize("What the HECK").unwrap().as_str(),
+            "what-the-x"
+        );
+
+        let builder = DefaultNameBuilder::seo_slug().with_filter(DenyList::new(["heck"]).with_substring_matching(true));
+        assert_eq!(
+            builder.build_with_normalize("Checkout").unwrap_err().to_string(),
+            "name error: \"checkout\" contains a word that is not allowed"
+        );
+        assert!(builder.without_filters().build_with_normalize("Checkout").is_ok());
+    }
+
+    #[test]
+    fn test_case_style() {
+        let input = "Café Menu: Daily Specials";
+        let cases = [
+            (CaseStyle::Kebab, "cafe-menu-daily-specials"),
+            (CaseStyle::Snake, "cafe_menu_daily_specials"),
+            (CaseStyle::ScreamingSnake, "CAFE_MENU_DAILY_SPECIALS"),
+            (CaseStyle::Camel, "cafeMenuDailySpecials"),
+            (CaseStyle::Pascal, "CafeMenuDailySpecials"),
+        ];
+        for (style, expected) in cases {
+            let builder = DefaultNameBuilder::new().with_case_style(style);
+            let name = builder.build_with_normalize(input).unwrap();
+            assert_eq!(name.as_str(), expected, "{style:?}");
+            assert!(builder.validate(expected).is_ok(), "{style:?}");
+        }
+
+        let builder = DefaultNameBuilder::new().with_case_style(CaseStyle::Snake);
+        assert!(builder.validate("my-project").is_err());
+        assert_eq!(
+            builder.build_with_normalize("my-project").unwrap().as_str(),
+            "my_project"
+        );
+
+        let builder = DefaultNameBuilder::new().with_case_style(CaseStyle::ScreamingSnake);
+        assert!(builder.validate("DATABASE_URL").is_ok());
+        assert!(builder.validate("database_url").is_err());
+
+        let builder = DefaultNameBuilder::new().with_case_style(CaseStyle::Camel);
+        assert!(builder.validate("myProject2").is_ok());
+        assert_eq!(
+            builder.validate("MyProject").unwrap_err().to_string(),
+            "name error: invalid character 'M' at position 0"
+        );
+        assert!(builder.validate("my_project").is_err());
+
+        let builder = DefaultNameBuilder::new().with_case_style(CaseStyle::Pascal);
+        assert!(builder.validate("MyProject").is_ok());
+        assert!(builder.validate("myProject").is_err());
+    }
+
+    #[test]
+    fn test_overflow_strategy() {
+        let input = "very long project name";
+        let builder = DefaultNameBuilder::new().with_max_length(16);
+        assert!(matches!(
+            builder.build_with_normalize(input),
+            Err(NameError::InvalidLength(_))
+        ));
+
+        let builder = DefaultNameBuilder::new()
+            .with_max_length(16)
+            .with_overflow_strategy(OverflowStrategy::Truncate);
+        assert_eq!(
+            builder.build_with_normalize(input).unwrap().as_str(),
+            "very-long-projec"
+        );
+
+        let builder = DefaultNameBuilder::new()
+            .with_max_length(16)
+            .with_overflow_strategy(OverflowStrategy::TruncateWithHash);
+        let name = builder.build_with_normalize(input).unwrap();
+        let hash = format!("{:016x}", stable_name_hash("very-long-project-name"));
+        assert_eq!(name.as_str(), format!("very-long-{}", &hash[..OVERFLOW_HASH_LENGTH]));
+        assert_eq!(builder.build_with_normalize(input).unwrap(), name);
+        assert_ne!(builder.build_with_normalize("very long project title").unwrap(), name);
+        assert_eq!(
+            builder.build_with_normalize("short name").unwrap().as_str(),
+            "short-name"
+        );
+
+        let builder = DefaultNameBuilder::new()
+            .with_max_length(16)
+            .with_overflow_strategy(OverflowStrategy::TruncateWithHash)
+            .with_overflow_hasher(|_| 0xabcdef << 40);
+        assert_eq!(
+            builder.build_with_normalize(input).unwrap().as_str(),
+            "very-long-abcdef"
+        );
+
+        let builder = DefaultNameBuilder::new()
+            .with_max_length(4)
+            .with_overflow_strategy(OverflowStrategy::TruncateWithHash)
+            .with_overflow_hasher(|_| 0xabcdef << 40);
+        assert_eq!(builder.build_with_normalize(input).unwrap().as_str(), "abcd");
+    }
+
+    #[test]
+    fn test_build_with_report() {
+        let builder = DefaultNameBuilder::new();
+        let (name, report) = builder.build_with_report("  My Café Project! ").unwrap();
+        assert_eq!(name.as_str(), "my-cafe-project");
+        assert_eq!(report.changes, [
+            NormalizationChange::Trimmed,
+            NormalizationChange::Lowercased,
+            NormalizationChange::Replaced { from: ' ', to: '-' },
+            NormalizationChange::Transliterated {
+                from: 'é',
+                to: "e".to_owned()
+            },
+            NormalizationChange::Replaced { from: '!', to: '-' },
+            NormalizationChange::SeparatorsTidied,
+        ]);
+        assert_eq!(
+            report.to_string(),
+            "removed leading and trailing whitespace; converted to lowercase; replaced ' ' with '-'; replaced 'é' \
+             with \"e\"; replaced '!' with '-'; removed extra separators"
+        );
+
+        let (name, report) = builder.build_with_report("my-project").unwrap();
+        assert_eq!(name.as_str(), "my-project");
+        assert!(report.is_unchanged());
+
+        let builder = DefaultNameBuilder::seo_slug().with_max_length(12);
+        let (name, report) = builder.build_with_report("The ownership guide").unwrap();
+        assert_eq!(name.as_str(), "ownership");
+        assert!(report.changes.contains(&NormalizationChange::WordsDropped));
+        assert!(
+            report
+                .changes
+                .contains(&NormalizationChange::Truncated { max_length: 12 })
+        );
+    }
+
+    #[test]
+    fn test_reserved_names() {
+        let builder = DefaultNameBuilder::new().with_default_reserved_names();
+        assert!(matches!(builder.build("admin"), Err(NameError::Reserved(name)) if name == "admin"));
+        assert!(matches!(builder.build_with_normalize(" WWW "), Err(NameError::Reserved(name)) if name == "www"));
+        assert!(builder.build("admins").is_ok());
+
+        let builder = DefaultNameBuilder::new()
+            .with_char_validation(false)
+            .with_reserved_names(["Billing"]);
+        assert!(matches!(builder.build("BILLING"), Err(NameError::Reserved(_))));
+        assert!(builder.build("admin").is_ok());
+        assert!(builder.without_reserved_names().build("billing").is_ok());
+        assert_eq!(
+            NameError::Reserved("api".to_owned()).to_string(),
+            "name error: \"api\" is reserved"
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("my-project".parse::<Name>().unwrap(), Name::from_raw("my-project"));
+        assert_eq!(Name::try_from("my-project").unwrap(), Name::from_raw("my-project"));
+        assert_eq!(
+            Name::try_from(String::from("my-project")).unwrap(),
+            Name::from_raw("my-project")
+        );
+
+        assert!(matches!(
+            "My Project".parse::<Name>(),
+            Err(NameError::InvalidCharacters(_))
+        ));
+        assert!(matches!(Name::try_from("a"), Err(NameError::InvalidLength(_))));
+        assert!(matches!(Name::try_from(" ab".to_owned()), Err(NameError::Untrimmed)));
+    }
+
+    #[test]
+    fn test_is_normalized() {
+        assert!(is_normalized(""));
+        assert!(is_normalized("my-project_2"));
+
+        assert!(!is_normalized("My-Project"));
+        assert!(!is_normalized(" test"));
+        assert!(!is_normalized("café"));
+        assert!(!is_normalized("a b"));
+        assert!(!is_normalized("-hello-"));
+        assert!(!is_normalized("hello_"));
+    }
+
+    #[test]
+    fn test_normalize_name_is_idempotent() {
+        const SAMPLES: &[&str] = &[
+            "",
+            " ",
+            "a",
+            "Z",
+            "0",
+            "-",
+            "_",
+            "@",
+            "\t",
+            "\n",
+            "é",
+            "ß",
+            "Æ",
+            "Ж",
+            "北",
+            "京",
+            "🦄",
+            "☣",
+            "…",
+            "™",
+            "©",
+            "€",
+            "\u{0301}",
+            "\u{200b}",
+            "\u{feff}",
+            "\u{10ffff}",
+            "ﬁ",
+            "Ⅻ",
+            "ǅ",
+            "İ",
+            "ﾟ",
+            "𝔄",
+        ];
+
+        // A fixed xorshift sequence keeps the generated inputs reproducible.
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        for _ in 0..2000 {
+            let length = next() % 12;
+            let input: String = (0..length)
+                .map(|_| match next() % 3 {
+                    0 => SAMPLES[(next() % SAMPLES.len() as u64) as usize].to_owned(),
+                    _ => char::from_u32((next() % 0x11_0000) as u32)
+                        .map(String::from)
+                        .unwrap_or_default(),
+                })
+                .collect();
+
+            let normalized = normalize_name(&input);
+            let mut reference = String::new();
+            make_name(input.trim(), &mut reference);
+            strip_separators(&mut reference, is_separator);
+            assert_eq!(normalized, reference, "input: {input:?}");
+            assert!(is_normalized(&normalized), "{input:?} normalized to {normalized:?}");
+            assert_eq!(normalize_name(&normalized), normalized, "input: {input:?}");
+            assert_eq!(is_normalized(&input), normalized == input, "input: {input:?}");
+        }
+    }
+
     #[test]
     fn test_validate_name_chars() {
-        assert!(validate_name_chars("hello-world"));
-        assert!(validate_name_chars("test123"));
-        assert!(validate_name_chars("my_project"));
-        assert!(validate_name_chars("abc"));
-        assert!(validate_name_chars("a-b-c"));
-        assert!(validate_name_chars("a"));
-        assert!(validate_name_chars("1"));
-        assert!(validate_name_chars("-"));
-        assert!(validate_name_chars("_"));
-        assert!(validate_name_chars("a1-_"));
-        assert!(validate_name_chars("-hello-"));
-        assert!(validate_name_chars(""));
-
-        assert!(!validate_name_chars("Hello"));
-        assert!(!validate_name_chars("hello world"));
-        assert!(!validate_name_chars("café"));
-        assert!(!validate_name_chars("hello@world"));
-        assert!(!validate_name_chars("A"));
-        assert!(!validate_name_chars(" "));
-        assert!(!validate_name_chars("hello "));
-        assert!(!validate_name_chars(" hello"));
+        assert!(validate_name_chars("hello-world").is_ok());
+        assert!(validate_name_chars("test123").is_ok());
+        assert!(validate_name_chars("my_project").is_ok());
+        assert!(validate_name_chars("abc").is_ok());
+        assert!(validate_name_chars("a-b-c").is_ok());
+        assert!(validate_name_chars("a").is_ok());
+        assert!(validate_name_chars("1").is_ok());
+        assert!(validate_name_chars("-").is_ok());
+        assert!(validate_name_chars("_").is_ok());
+        assert!(validate_name_chars("a1-_").is_ok());
+        assert!(validate_name_chars("-hello-").is_ok());
+        assert!(validate_name_chars("").is_ok());
+
+        assert!(validate_name_chars("Hello").is_err());
+        assert!(validate_name_chars("hello world").is_err());
+        assert!(validate_name_chars("café").is_err());
+        assert!(validate_name_chars("hello@world").is_err());
+        assert!(validate_name_chars("A").is_err());
+        assert!(validate_name_chars(" ").is_err());
+        assert!(validate_name_chars("hello ").is_err());
+        assert!(validate_name_chars(" hello").is_err());
+    }
+
+    #[test]
+    fn test_invalid_characters_error() {
+        let error = validate_name_chars("café-Été").unwrap_err();
+        assert_eq!(error.chars[0], InvalidChar {
+            ch: 'é',
+            byte_offset: 3,
+            position: 3,
+        });
+        assert_eq!(error.chars[1], InvalidChar {
+            ch: 'É',
+            byte_offset: 6,
+            position: 5,
+        });
+        assert_eq!(
+            error.to_string(),
+            "invalid characters 'é' at position 3, 'É' at position 5, 'é' at position 7"
+        );
+
+        let error = DefaultNameBuilder::new().build("my project").unwrap_err();
+        assert_eq!(error.to_string(), "name error: invalid character ' ' at position 2");
     }
 }
@@ -0,0 +1,138 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::{is_name_safe_char, is_normalized, is_separator};
+
+/// Like [`normalize_name`](crate::normalize_name), but borrows `input` when it is already normalized.
+pub fn normalize_name_cow(input: &str) -> Cow<'_, str> {
+    if is_normalized(input) {
+        return Cow::Borrowed(input);
+    }
+    let mut normalized = String::with_capacity(input.len());
+    normalize_name_into(input, &mut normalized).expect("writing to a String cannot fail");
+    Cow::Owned(normalized)
+}
+
+/// Writes the normalized form of `input` to `output` without allocating, e.g. straight into a batch export.
+pub fn normalize_name_into(input: &str, output: &mut impl fmt::Write) -> fmt::Result {
+    if is_normalized(input) {
+        return output.write_str(input);
+    }
+
+    let mut writer = NameWriter {
+        output,
+        started: false,
+        last: None,
+        pending: PendingSeparators::default(),
+    };
+    for ch in input.trim().chars() {
+        if ch.is_ascii() {
+            writer.push(ch)?;
+        } else if let Some(transliterated) = deunicode::deunicode_char(ch) {
+            for trans in transliterated.chars() {
+                writer.push(trans)?;
+            }
+        } else {
+            writer.push_separator();
+        }
+    }
+    // Separators still pending are trailing ones, which normalization strips.
+    Ok(())
+}
+
+/// Streams the output of `make_name`, holding back separators until a name character follows so that leading and
+/// trailing ones can be dropped.
+struct NameWriter<'w, W> {
+    output: &'w mut W,
+    started: bool,
+    last: Option<char>,
+    pending: PendingSeparators,
+}
+
+impl<W: fmt::Write> NameWriter<'_, W> {
+    fn push(&mut self, ch: char) -> fmt::Result {
+        let ch = ch.to_ascii_lowercase();
+        if !is_name_safe_char(ch) {
+            self.push_separator();
+            return Ok(());
+        }
+        self.last = Some(ch);
+        if is_separator(ch) {
+            self.hold(ch);
+            return Ok(());
+        }
+        self.pending.flush(self.output)?;
+        self.started = true;
+        self.output.write_char(ch)
+    }
+
+    fn push_separator(&mut self) {
+        if self.last != Some('-') {
+            self.last = Some('-');
+            self.hold('-');
+        }
+    }
+
+    fn hold(&mut self, separator: char) {
+        if self.started {
+            self.pending.push(separator);
+        }
+    }
+}
+
+/// A run of `-`/`_` kept as bits, so only runs longer than 128 separators allocate.
+#[derive(Default)]
+struct PendingSeparators {
+    underscores: u128,
+    len: u32,
+    overflow: String,
+}
+
+impl PendingSeparators {
+    fn push(&mut self, separator: char) {
+        if self.len < u128::BITS {
+            if separator == '_' {
+                self.underscores |= 1 << self.len;
+            }
+            self.len += 1;
+        } else {
+            self.overflow.push(separator);
+        }
+    }
+
+    fn flush(&mut self, output: &mut impl fmt::Write) -> fmt::Result {
+        for index in 0..self.len {
+            output.write_char(if self.underscores & (1 << index) == 0 { '-' } else { '_' })?;
+        }
+        output.write_str(&self.overflow)?;
+        self.underscores = 0;
+        self.len = 0;
+        self.overflow.clear();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_name_cow() {
+        assert!(matches!(normalize_name_cow("my-project"), Cow::Borrowed("my-project")));
+        assert!(matches!(normalize_name_cow("My Project"), Cow::Owned(name) if name == "my-project"));
+    }
+
+    #[test]
+    fn test_normalize_name_into() {
+        let mut output = String::from("names: ");
+        normalize_name_into(" __Hello  Wörld!__ ", &mut output).unwrap();
+        output.push_str(", ");
+        normalize_name_into("a-_-@b", &mut output).unwrap();
+        assert_eq!(output, "names: hello-world, a-_-b");
+
+        let long_run = format!("a{}b!", "_".repeat(200));
+        let mut output = String::new();
+        normalize_name_into(&long_run, &mut output).unwrap();
+        assert_eq!(output, format!("a{}b", "_".repeat(200)));
+    }
+}
@@ -0,0 +1,202 @@
+use std::fmt;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use crate::{DefaultNameBuilder, Name, NameBulder, NameError};
+
+/// The delimiter [`NamePath`] uses unless told otherwise, as in `org/project/service`.
+pub const DEFAULT_NAME_PATH_DELIMITER: char = '/';
+
+#[derive(Debug, Clone, Error)]
+pub enum NamePathError {
+    #[error("name path is empty")]
+    Empty,
+
+    #[error("name path has an empty segment at position {index}")]
+    EmptySegment { index: usize },
+
+    #[error("name path segment {index} ({segment:?}) is invalid: {error}")]
+    InvalidSegment {
+        index: usize,
+        segment: String,
+        error: NameError,
+    },
+}
+
+/// A hierarchical name such as `org/project/service`, made of at least one validated [`Name`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NamePath(Vec<Name>);
+
+impl NamePath {
+    pub fn new(segments: Vec<Name>) -> Result<Self, NamePathError> {
+        if segments.is_empty() {
+            return Err(NamePathError::Empty);
+        }
+        Ok(Self(segments))
+    }
+
+    /// Splits `input` on `delimiter` and validates each segment with `builder`.
+    pub fn parse_with<B>(input: &str, delimiter: char, builder: &B) -> Result<Self, NamePathError>
+    where
+        B: NameBulder<Name = Name, Error = NameError>,
+    {
+        if input.is_empty() {
+            return Err(NamePathError::Empty);
+        }
+        let segments = input
+            .split(delimiter)
+            .enumerate()
+            .map(|(index, segment)| {
+                if segment.is_empty() {
+                    return Err(NamePathError::EmptySegment { index });
+                }
+                builder.build(segment).map_err(|error| NamePathError::InvalidSegment {
+                    index,
+                    segment: segment.to_owned(),
+                    error,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self(segments))
+    }
+
+    pub fn segments(&self) -> &[Name] {
+        &self.0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Always `false`, as a path has at least one segment.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The last segment, e.g. `service` in `org/project/service`.
+    pub fn name(&self) -> &Name {
+        &self.0[self.0.len() - 1]
+    }
+
+    /// The path without its last segment, or `None` for a single-segment path.
+    pub fn parent(&self) -> Option<NamePath> {
+        match self.0.split_last() {
+            Some((_, parent)) if !parent.is_empty() => Some(Self(parent.to_vec())),
+            _ => None,
+        }
+    }
+
+    pub fn join(&self, name: Name) -> NamePath {
+        let mut segments = self.0.clone();
+        segments.push(name);
+        Self(segments)
+    }
+
+    pub fn starts_with(&self, prefix: &NamePath) -> bool {
+        self.0.starts_with(&prefix.0)
+    }
+
+    /// Formats the path with `delimiter` instead of `/`, e.g. `org.project.service`.
+    pub fn display_with(&self, delimiter: char) -> NamePathDisplay<'_> {
+        NamePathDisplay { path: self, delimiter }
+    }
+}
+
+pub struct NamePathDisplay<'a> {
+    path: &'a NamePath,
+    delimiter: char,
+}
+
+impl fmt::Display for NamePathDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, segment) in self.path.0.iter().enumerate() {
+            if index > 0 {
+                fmt::Write::write_char(f, self.delimiter)?;
+            }
+            f.write_str(segment.as_str())?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for NamePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display_with(DEFAULT_NAME_PATH_DELIMITER).fmt(f)
+    }
+}
+
+/// Splits on `/` and validates each segment with [`DefaultNameBuilder::new`].
+impl FromStr for NamePath {
+    type Err = NamePathError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(input, DEFAULT_NAME_PATH_DELIMITER, &DefaultNameBuilder::new())
+    }
+}
+
+impl TryFrom<Vec<Name>> for NamePath {
+    type Error = NamePathError;
+
+    fn try_from(segments: Vec<Name>) -> Result<Self, Self::Error> {
+        Self::new(segments)
+    }
+}
+
+impl From<Name> for NamePath {
+    fn from(name: Name) -> Self {
+        Self(vec![name])
+    }
+}
+
+impl From<NamePath> for Vec<Name> {
+    fn from(path: NamePath) -> Self {
+        path.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let path: NamePath = "acme/web-app/api".parse().unwrap();
+        assert_eq!(path.len(), 3);
+        assert_eq!(path.name().as_str(), "api");
+        assert_eq!(path.to_string(), "acme/web-app/api");
+        assert_eq!(path.display_with('.').to_string(), "acme.web-app.api");
+
+        assert!(matches!("".parse::<NamePath>(), Err(NamePathError::Empty)));
+        assert!(matches!(
+            "acme//api".parse::<NamePath>(),
+            Err(NamePathError::EmptySegment { index: 1 })
+        ));
+        assert!(matches!(
+            "acme/Web App".parse::<NamePath>(),
+            Err(NamePathError::InvalidSegment { index: 1, segment, .. }) if segment == "Web App"
+        ));
+
+        let dotted = NamePath::parse_with("acme.api", '.', &DefaultNameBuilder::new()).unwrap();
+        assert_eq!(dotted.to_string(), "acme/api");
+    }
+
+    #[test]
+    fn test_navigation() {
+        let path: NamePath = "acme/web-app/api".parse().unwrap();
+        let parent = path.parent().unwrap();
+        assert_eq!(parent.to_string(), "acme/web-app");
+        assert_eq!(parent.parent().unwrap().to_string(), "acme");
+        assert_eq!(parent.parent().unwrap().parent(), None);
+        assert!(path.starts_with(&parent));
+        assert!(!parent.starts_with(&path));
+        assert_eq!(parent.join(Name::from_raw("api")), path);
+
+        let segments: Vec<Name> = path.clone().into();
+        assert_eq!(NamePath::try_from(segments).unwrap(), path);
+        assert!(matches!(NamePath::try_from(Vec::new()), Err(NamePathError::Empty)));
+        assert_eq!(NamePath::from(Name::from_raw("acme")).segments(), [Name::from_raw(
+            "acme"
+        )]);
+    }
+}
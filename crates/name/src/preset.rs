@@ -0,0 +1,225 @@
+use crate::{Charset, DefaultNameBuilder, SeparatorPolicy};
+
+/// The longest DNS label allowed by RFC 1035 and RFC 1123, in bytes.
+pub const RFC1123_LABEL_MAX_LENGTH: usize = 63;
+
+/// The longest hostname allowed by RFC 1123, in bytes, without a trailing dot.
+pub const RFC1123_HOSTNAME_MAX_LENGTH: usize = 253;
+
+/// The longest Kubernetes label value, in bytes.
+pub const K8S_LABEL_VALUE_MAX_LENGTH: usize = 63;
+
+/// The longest Docker image tag, in bytes.
+pub const DOCKER_TAG_MAX_LENGTH: usize = 128;
+
+/// English words that add nothing to a URL slug, see [`DefaultNameBuilder::seo_slug`].
+pub const DEFAULT_STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "from", "in", "into", "is", "it", "of", "on", "or",
+    "so", "than", "that", "the", "this", "to", "was", "with",
+];
+
+impl DefaultNameBuilder {
+    /// A single DNS label such as a subdomain: 1 to 63 lowercase alphanumerics or `-`, not starting or ending with
+    /// `-`. Normalization lowercases, replaces `_` and other characters with `-`, and truncates, so
+    /// `My_Project!` becomes `my-project`.
+    pub fn rfc1123_label() -> Self {
+        Self::new()
+            .with_min_length(1)
+            .with_max_length(RFC1123_LABEL_MAX_LENGTH)
+            .with_charset(Charset::Rfc1123Label)
+            .with_separator_policy(SeparatorPolicy::Collapse)
+            .with_truncation(true)
+            .with_spec("RFC 1123 label")
+    }
+
+    /// A hostname made of [`rfc1123_label`](Self::rfc1123_label)s joined by `.`, at most 253 bytes long.
+    pub fn rfc1123_hostname() -> Self {
+        Self::new()
+            .with_min_length(1)
+            .with_max_length(RFC1123_HOSTNAME_MAX_LENGTH)
+            .with_charset(Charset::Rfc1123Hostname)
+            .with_separator_policy(SeparatorPolicy::Collapse)
+            .with_truncation(true)
+            .with_spec("RFC 1123 hostname")
+    }
+
+    /// The name of most Kubernetes objects (a DNS subdomain), e.g. `web-frontend.v2`.
+    pub fn k8s_resource() -> Self {
+        Self::rfc1123_hostname().with_spec("Kubernetes resource name")
+    }
+
+    /// A Kubernetes label value: empty, or up to 63 alphanumerics, `-`, `_` and `.`, starting and ending with an
+    /// alphanumeric. Normalization lowercases, so `Team Payments!` becomes `team-payments`.
+    pub fn k8s_label_value() -> Self {
+        Self::new()
+            .without_min_length()
+            .with_max_length(K8S_LABEL_VALUE_MAX_LENGTH)
+            .with_charset(Charset::K8sLabelValue)
+            .with_separator_policy(SeparatorPolicy::Collapse)
+            .with_truncation(true)
+            .with_spec("Kubernetes label value")
+    }
+
+    /// A Docker image tag: 1 to 128 alphanumerics, `_`, `.` and `-`, not starting with `.` or `-`, e.g. `v1.2.0-rc1`.
+    pub fn docker_image_tag() -> Self {
+        Self::new()
+            .with_min_length(1)
+            .with_max_length(DOCKER_TAG_MAX_LENGTH)
+            .with_charset(Charset::DockerTag)
+            .with_separator_policy(SeparatorPolicy::Collapse)
+            .with_truncation(true)
+            .with_spec("Docker image tag")
+    }
+
+    /// A URL slug generated from a title: stop words are dropped and long titles are cut at a word boundary, so
+    /// `The Quick Guide to Rust and WebAssembly` becomes `quick-guide-rust-webassembly`.
+    pub fn seo_slug() -> Self {
+        Self::new()
+            .with_min_length(1)
+            .with_max_length(60)
+            .with_separator_policy(SeparatorPolicy::Collapse)
+            .with_stop_words(DEFAULT_STOP_WORDS)
+            .with_truncation(true)
+            .with_word_boundary_truncation(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{NameBulder, NameError};
+
+    #[test]
+    fn test_rfc1123_label() {
+        let builder = DefaultNameBuilder::rfc1123_label();
+        assert!(builder.build("acme-corp-2").is_ok());
+        assert!(builder.build("a").is_ok());
+        for invalid in ["-acme", "acme-", "acme_corp", "Acme", "acme.corp", ""] {
+            assert!(builder.build(invalid).is_err(), "{invalid:?} should be invalid");
+        }
+        assert_eq!(
+            builder.build("acme-").unwrap_err().to_string(),
+            "name error: invalid character '-' at position 4 (violates RFC 1123 label)"
+        );
+        assert!(matches!(
+            builder.build("a".repeat(64)).unwrap_err().cause(),
+            NameError::InvalidLength(_)
+        ));
+
+        assert_eq!(builder.normalize(" My_Project! ").unwrap(), "my-project");
+        assert_eq!(
+            builder.build_with_normalize("__Café--Bar__").unwrap().as_str(),
+            "cafe-bar"
+        );
+        let long = format!("{}-tail", "a".repeat(62));
+        assert_eq!(builder.build_with_normalize(long).unwrap().as_str(), "a".repeat(62));
+    }
+
+    #[test]
+    fn test_rfc1123_hostname() {
+        let builder = DefaultNameBuilder::rfc1123_hostname();
+        assert!(builder.build("api.acme-corp.example").is_ok());
+        for invalid in [
+            "api..example",
+            ".example",
+            "example.",
+            "api.-acme.example",
+            "api_v1.example",
+        ] {
+            assert!(builder.build(invalid).is_err(), "{invalid:?} should be invalid");
+        }
+        assert!(matches!(
+            builder.build(format!("{}.example", "a".repeat(64))).unwrap_err().cause(),
+            NameError::InvalidLength(error) if error.max == RFC1123_LABEL_MAX_LENGTH
+        ));
+
+        assert_eq!(
+            builder.normalize("API_v1 . Acme Corp..Example.").unwrap(),
+            "api-v1.acme-corp.example"
+        );
+        let long_label = format!("{}.example", "b".repeat(70));
+        assert_eq!(
+            builder.build_with_normalize(long_label).unwrap().as_str(),
+            format!("{}.example", "b".repeat(63))
+        );
+    }
+
+    #[test]
+    fn test_k8s_resource() {
+        let builder = DefaultNameBuilder::k8s_resource();
+        assert!(builder.build("web-frontend.v2").is_ok());
+        assert_eq!(
+            builder.build("Web").unwrap_err().to_string(),
+            "name error: invalid character 'W' at position 0 (violates Kubernetes resource name)"
+        );
+        assert_eq!(builder.normalize("Web Frontend (v2)").unwrap(), "web-frontend-v2");
+    }
+
+    #[test]
+    fn test_k8s_label_value() {
+        let builder = DefaultNameBuilder::k8s_label_value();
+        for valid in ["", "v1.2", "Team_Payments", "a"] {
+            assert!(builder.build(valid).is_ok(), "{valid:?} should be valid");
+        }
+        for invalid in ["-team", "team.", "_a", "team payments", "é"] {
+            assert!(builder.build(invalid).is_err(), "{invalid:?} should be invalid");
+        }
+        assert!(matches!(builder.build("a".repeat(64)).unwrap_err(), NameError::Spec {
+            spec: "Kubernetes label value",
+            ..
+        }));
+
+        assert_eq!(builder.normalize(" Team Payments! ").unwrap(), "team-payments");
+        assert_eq!(builder.build_with_normalize(".hidden.").unwrap().as_str(), "hidden");
+        assert_eq!(
+            builder.build_with_normalize("x".repeat(80)).unwrap().as_str(),
+            "x".repeat(63)
+        );
+    }
+
+    #[test]
+    fn test_docker_image_tag() {
+        let builder = DefaultNameBuilder::docker_image_tag();
+        for valid in ["latest", "v1.2.0-rc1", "_build", "Release_2024."] {
+            assert!(builder.build(valid).is_ok(), "{valid:?} should be valid");
+        }
+        for invalid in ["", ".hidden", "-rc", "v1+build", "tag/with/slash"] {
+            assert!(builder.build(invalid).is_err(), "{invalid:?} should be invalid");
+        }
+        assert_eq!(
+            builder.build("-rc").unwrap_err().to_string(),
+            "name error: invalid character '-' at position 0 (violates Docker image tag)"
+        );
+
+        assert_eq!(builder.normalize("Release 1.2 (beta)").unwrap(), "release-1.2-beta");
+        assert_eq!(builder.build_with_normalize("--v2").unwrap().as_str(), "v2");
+    }
+
+    #[test]
+    fn test_seo_slug() {
+        let builder = DefaultNameBuilder::seo_slug();
+        assert_eq!(
+            builder.normalize("The Quick Guide to Rust and WebAssembly").unwrap(),
+            "quick-guide-rust-webassembly"
+        );
+        assert_eq!(builder.normalize("The The").unwrap(), "the-the");
+
+        let builder = builder.with_max_length(20);
+        assert_eq!(
+            builder.normalize("Understanding ownership in practice").unwrap(),
+            "understanding"
+        );
+        assert_eq!(
+            builder.normalize("Ownership explained well").unwrap(),
+            "ownership-explained"
+        );
+        assert_eq!(
+            builder.normalize("Supercalifragilisticexpialidocious").unwrap(),
+            "supercalifragilistic"
+        );
+        let builder = DefaultNameBuilder::seo_slug().with_max_words(2);
+        assert_eq!(builder.normalize("Rust async traits explained").unwrap(), "rust-async");
+        let builder = DefaultNameBuilder::seo_slug().without_stop_words();
+        assert_eq!(builder.normalize("The art of Rust").unwrap(), "the-art-of-rust");
+    }
+}
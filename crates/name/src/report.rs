@@ -0,0 +1,55 @@
+use std::fmt;
+
+/// One adjustment [`DefaultNameBuilder::build_with_report`](crate::DefaultNameBuilder::build_with_report) made to
+/// the input. Character changes are listed once per distinct character, in order of appearance.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NormalizationChange {
+    Trimmed,
+    Lowercased,
+    Transliterated { from: char, to: String },
+    Replaced { from: char, to: char },
+    SeparatorsTidied,
+    WordsDropped,
+    Truncated { max_length: usize },
+    Rewritten,
+}
+
+impl fmt::Display for NormalizationChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NormalizationChange::Trimmed => f.write_str("removed leading and trailing whitespace"),
+            NormalizationChange::Lowercased => f.write_str("converted to lowercase"),
+            NormalizationChange::Transliterated { from, to } => write!(f, "replaced {from:?} with {to:?}"),
+            NormalizationChange::Replaced { from, to } => write!(f, "replaced {from:?} with {to:?}"),
+            NormalizationChange::SeparatorsTidied => f.write_str("removed extra separators"),
+            NormalizationChange::WordsDropped => f.write_str("removed some words"),
+            NormalizationChange::Truncated { max_length } => write!(f, "shortened to {max_length} characters"),
+            NormalizationChange::Rewritten => f.write_str("adjusted by a content filter"),
+        }
+    }
+}
+
+/// What normalization changed about a name, e.g. to tell users "your name was adjusted to `my-cafe-project`".
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NormalizationReport {
+    pub changes: Vec<NormalizationChange>,
+}
+
+impl NormalizationReport {
+    pub fn is_unchanged(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// The changes separated by `; `, e.g. `converted to lowercase; replaced 'é' with "e"`.
+impl fmt::Display for NormalizationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, change) in self.changes.iter().enumerate() {
+            if index > 0 {
+                f.write_str("; ")?;
+            }
+            change.fmt(f)?;
+        }
+        Ok(())
+    }
+}
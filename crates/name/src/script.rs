@@ -0,0 +1,139 @@
+use std::fmt;
+
+/// The writing systems [`check_mixed_scripts`] tells apart. Digits, punctuation and combining marks belong to none.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Script {
+    Latin,
+    Greek,
+    Cyrillic,
+    Armenian,
+    Hebrew,
+    Arabic,
+    Devanagari,
+    Thai,
+    Georgian,
+    Hangul,
+    Hiragana,
+    Katakana,
+    Bopomofo,
+    Han,
+}
+
+impl Script {
+    pub fn of(ch: char) -> Option<Self> {
+        let script = match u32::from(ch) {
+            0x41..=0x5a | 0x61..=0x7a => Script::Latin,
+            0xd7 | 0xf7 => return None,
+            0xc0..=0x24f | 0x250..=0x2af | 0x1e00..=0x1eff | 0x2c60..=0x2c7f | 0xa720..=0xa7ff | 0xab30..=0xab6f => {
+                Script::Latin
+            },
+            0xff21..=0xff3a | 0xff41..=0xff5a => Script::Latin,
+            0x370..=0x3ff | 0x1f00..=0x1fff => Script::Greek,
+            0x400..=0x52f | 0x1c80..=0x1c8f | 0x2de0..=0x2dff | 0xa640..=0xa69f => Script::Cyrillic,
+            0x530..=0x58f => Script::Armenian,
+            0x590..=0x5ff => Script::Hebrew,
+            0x600..=0x6ff | 0x750..=0x77f | 0x8a0..=0x8ff => Script::Arabic,
+            0x900..=0x97f => Script::Devanagari,
+            0xe00..=0xe7f => Script::Thai,
+            0x10a0..=0x10ff => Script::Georgian,
+            0x1100..=0x11ff | 0x3130..=0x318f | 0xac00..=0xd7af => Script::Hangul,
+            0x3040..=0x309f => Script::Hiragana,
+            0x30a0..=0x30ff | 0x31f0..=0x31ff | 0xff66..=0xff9f => Script::Katakana,
+            0x3100..=0x312f => Script::Bopomofo,
+            0x2e80..=0x2fdf
+            | 0x3005
+            | 0x3007
+            | 0x3021..=0x3029
+            | 0x3400..=0x4dbf
+            | 0x4e00..=0x9fff
+            | 0xf900..=0xfaff => Script::Han,
+            0x20000..=0x3134f => Script::Han,
+            _ => return None,
+        };
+        Some(script)
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Script::Latin => "Latin",
+            Script::Greek => "Greek",
+            Script::Cyrillic => "Cyrillic",
+            Script::Armenian => "Armenian",
+            Script::Hebrew => "Hebrew",
+            Script::Arabic => "Arabic",
+            Script::Devanagari => "Devanagari",
+            Script::Thai => "Thai",
+            Script::Georgian => "Georgian",
+            Script::Hangul => "Hangul",
+            Script::Hiragana => "Hiragana",
+            Script::Katakana => "Katakana",
+            Script::Bopomofo => "Bopomofo",
+            Script::Han => "Han",
+        }
+    }
+}
+
+impl fmt::Display for Script {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Script combinations that are ordinary writing rather than spoofing, following the "highly restrictive" level of
+/// Unicode TS #39: Latin may be combined with the scripts of Japanese, Chinese or Korean text.
+const ALLOWED_COMBINATIONS: &[&[Script]] = &[
+    &[Script::Latin, Script::Han, Script::Hiragana, Script::Katakana],
+    &[Script::Latin, Script::Han, Script::Bopomofo],
+    &[Script::Latin, Script::Han, Script::Hangul],
+];
+
+/// Finds the first two scripts in `input` that should not appear together, e.g. the Cyrillic `а` in `pаypal`.
+pub fn check_mixed_scripts(input: &str) -> Result<(), (Script, Script)> {
+    let mut scripts: Vec<Script> = Vec::new();
+    for script in input.chars().filter_map(Script::of) {
+        if !scripts.contains(&script) {
+            scripts.push(script);
+        }
+    }
+    let allowed = scripts.len() <= 1
+        || ALLOWED_COMBINATIONS
+            .iter()
+            .any(|combination| scripts.iter().all(|script| combination.contains(script)));
+    match scripts.as_slice() {
+        [first, second, ..] if !allowed => Err((*first, *second)),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_script_of() {
+        assert_eq!(Script::of('a'), Some(Script::Latin));
+        assert_eq!(Script::of('é'), Some(Script::Latin));
+        assert_eq!(Script::of('а'), Some(Script::Cyrillic));
+        assert_eq!(Script::of('ο'), Some(Script::Greek));
+        assert_eq!(Script::of('北'), Some(Script::Han));
+        assert_eq!(Script::of('7'), None);
+        assert_eq!(Script::of('-'), None);
+        assert_eq!(Script::of('\u{301}'), None);
+    }
+
+    #[test]
+    fn test_check_mixed_scripts() {
+        assert!(check_mixed_scripts("paypal-2024").is_ok());
+        assert!(check_mixed_scripts("москва").is_ok());
+        assert!(check_mixed_scripts("東京タワー-tokyo").is_ok());
+        assert!(check_mixed_scripts("서울-seoul").is_ok());
+        assert!(check_mixed_scripts("").is_ok());
+
+        assert_eq!(check_mixed_scripts("pаypal"), Err((Script::Latin, Script::Cyrillic)));
+        assert_eq!(check_mixed_scripts("αpple"), Err((Script::Greek, Script::Latin)));
+        assert_eq!(
+            check_mixed_scripts("東京-서울-タワー"),
+            Err((Script::Han, Script::Hangul))
+        );
+    }
+}
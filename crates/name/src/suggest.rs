@@ -0,0 +1,202 @@
+use std::future::Future;
+use std::hash::{BuildHasher, RandomState};
+
+use thiserror::Error;
+
+use crate::{DefaultNameBuilder, Name, NameBulder};
+
+/// Hex digits of the suffix [`SuffixStyle::Random`] appends.
+pub const RANDOM_SUFFIX_LENGTH: usize = 6;
+
+/// How [`UniqueNameSuggester`] tells candidates apart after the base name itself.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SuffixStyle {
+    /// `name-2`, `name-3`, …
+    #[default]
+    Counter,
+    /// `name-3f9a1c`, with a fresh random suffix per candidate.
+    Random,
+}
+
+#[derive(Debug, Clone, Error)]
+#[error("name error: no free name found for {base:?} after {attempts} attempts")]
+pub struct UniqueNameError {
+    pub base: Name,
+    pub attempts: usize,
+}
+
+/// Finds a free variant of a name, e.g. `my-project-2` when `my-project` is taken.
+///
+/// Suffixed candidates are shortened to fit the builder's `max_length`, and candidates the builder rejects (such as
+/// reserved names) are skipped.
+#[derive(Debug, Clone, Copy)]
+pub struct UniqueNameSuggester<'a> {
+    pub builder: &'a DefaultNameBuilder,
+    pub suffix_style: SuffixStyle,
+    pub max_attempts: usize,
+}
+
+impl<'a> UniqueNameSuggester<'a> {
+    pub fn new(builder: &'a DefaultNameBuilder) -> Self {
+        Self {
+            builder,
+            suffix_style: SuffixStyle::Counter,
+            max_attempts: 100,
+        }
+    }
+
+    pub fn with_suffix_style(mut self, style: SuffixStyle) -> Self {
+        self.suffix_style = style;
+        self
+    }
+
+    pub fn with_max_attempts(mut self, attempts: usize) -> Self {
+        self.max_attempts = attempts;
+        self
+    }
+
+    /// The base name followed by up to `max_attempts - 1` suffixed variants.
+    pub fn candidates<'b>(&'b self, base: &'b Name) -> impl Iterator<Item = Name> + 'b {
+        let random = RandomState::new();
+        let suffixed = (1..self.max_attempts)
+            .map(move |attempt| match self.suffix_style {
+                SuffixStyle::Counter => (attempt + 1).to_string(),
+                SuffixStyle::Random => {
+                    let hash = format!("{:016x}", random.hash_one(attempt));
+                    hash[..RANDOM_SUFFIX_LENGTH].to_owned()
+                },
+            })
+            .filter_map(|suffix| self.builder.build(self.with_suffix(base.as_str(), &suffix)).ok());
+        std::iter::once(base.clone()).chain(suffixed).take(self.max_attempts)
+    }
+
+    pub fn suggest(&self, base: &Name, mut is_taken: impl FnMut(&Name) -> bool) -> Result<Name, UniqueNameError> {
+        self.candidates(base)
+            .find(|candidate| !is_taken(candidate))
+            .ok_or_else(|| self.error(base))
+    }
+
+    /// Like [`UniqueNameSuggester::suggest`], for lookups that have to wait, e.g. on a database.
+    pub async fn suggest_async<F, Fut>(&self, base: &Name, mut is_taken: F) -> Result<Name, UniqueNameError>
+    where
+        F: FnMut(&Name) -> Fut,
+        Fut: Future<Output = bool>,
+    {
+        for candidate in self.candidates(base) {
+            if !is_taken(&candidate).await {
+                return Ok(candidate);
+            }
+        }
+        Err(self.error(base))
+    }
+
+    /// Appends `-suffix`, cutting the base down so the result fits `max_length`.
+    fn with_suffix(&self, base: &str, suffix: &str) -> String {
+        let builder = self.builder;
+        let mut candidate = base.to_owned();
+        if let Some(max) = builder.max_length {
+            let offset = builder
+                .length_unit
+                .byte_offset(base, max.saturating_sub(suffix.len() + 1));
+            candidate.truncate(offset);
+        }
+        while candidate.ends_with(|ch| builder.is_separator(ch)) {
+            candidate.pop();
+        }
+        if !candidate.is_empty() {
+            candidate.push(builder.separator);
+        }
+        candidate.push_str(suffix);
+        candidate
+    }
+
+    fn error(&self, base: &Name) -> UniqueNameError {
+        UniqueNameError {
+            base: base.clone(),
+            attempts: self.max_attempts,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = std::pin::pin!(future);
+        let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+        loop {
+            if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    #[test]
+    fn test_suggest_counter() {
+        let builder = DefaultNameBuilder::new();
+        let suggester = UniqueNameSuggester::new(&builder);
+        let base = Name::from_raw("my-project");
+        let taken = ["my-project", "my-project-2"];
+
+        let name = suggester.suggest(&base, |name| taken.contains(&name.as_str())).unwrap();
+        assert_eq!(name.as_str(), "my-project-3");
+        assert_eq!(suggester.suggest(&base, |_| false).unwrap(), base);
+
+        let error = suggester.with_max_attempts(3).suggest(&base, |_| true).unwrap_err();
+        assert_eq!(error.attempts, 3);
+        assert_eq!(
+            error.to_string(),
+            "name error: no free name found for Name(\"my-project\") after 3 attempts"
+        );
+    }
+
+    #[test]
+    fn test_suggest_respects_max_length() {
+        let builder = DefaultNameBuilder::new().with_max_length(10);
+        let suggester = UniqueNameSuggester::new(&builder);
+        let candidates: Vec<Name> = suggester.candidates(&Name::from_raw("my-project")).take(3).collect();
+        assert_eq!(
+            candidates,
+            ["my-project", "my-proje-2", "my-proje-3"].map(Name::from_raw)
+        );
+
+        let base = Name::from_raw("my-proj-xy");
+        let candidates: Vec<Name> = suggester.candidates(&base).skip(1).take(1).collect();
+        assert_eq!(candidates, [Name::from_raw("my-proj-2")]);
+    }
+
+    #[test]
+    fn test_suggest_skips_rejected_candidates() {
+        let builder = DefaultNameBuilder::new().with_reserved_names(["api-2"]);
+        let suggester = UniqueNameSuggester::new(&builder);
+        let name = suggester
+            .suggest(&Name::from_raw("api"), |name| name.as_str() == "api")
+            .unwrap();
+        assert_eq!(name.as_str(), "api-3");
+    }
+
+    #[test]
+    fn test_suggest_random() {
+        let builder = DefaultNameBuilder::new();
+        let suggester = UniqueNameSuggester::new(&builder).with_suffix_style(SuffixStyle::Random);
+        let base = Name::from_raw("my-project");
+        let name = suggester.suggest(&base, |name| name == &base).unwrap();
+        let suffix = name.as_str().strip_prefix("my-project-").unwrap();
+        assert_eq!(suffix.len(), RANDOM_SUFFIX_LENGTH);
+        assert!(suffix.chars().all(|ch| ch.is_ascii_hexdigit()));
+    }
+
+    #[test]
+    fn test_suggest_async() {
+        let builder = DefaultNameBuilder::new();
+        let suggester = UniqueNameSuggester::new(&builder);
+        let base = Name::from_raw("my-project");
+        let name = block_on(suggester.suggest_async(&base, |name| {
+            let taken = name == &base;
+            async move { taken }
+        }))
+        .unwrap();
+        assert_eq!(name.as_str(), "my-project-2");
+    }
+}
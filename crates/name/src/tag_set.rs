@@ -0,0 +1,181 @@
+use std::fmt;
+
+use thiserror::Error;
+
+use crate::{DefaultNameBuilder, Name, NameBulder, NameError};
+
+#[derive(Debug, Clone, Error)]
+pub enum TagSetError {
+    #[error("too many tags: at most {max} allowed, got {actual}")]
+    TooMany { max: usize, actual: usize },
+
+    #[error("invalid tag {tag:?}: {error}")]
+    InvalidTag { tag: String, error: NameError },
+}
+
+/// A deduplicated list of tag [`Name`]s, in the order they were first given.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TagSet(Vec<Name>);
+
+impl TagSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn as_slice(&self) -> &[Name] {
+        &self.0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Name> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn contains(&self, tag: &str) -> bool {
+        self.0.iter().any(|name| name.as_str() == tag)
+    }
+
+    fn insert(&mut self, name: Name) {
+        if !self.0.contains(&name) {
+            self.0.push(name);
+        }
+    }
+}
+
+/// Comma-separated, as accepted by [`TagSetBuilder::parse`].
+impl fmt::Display for TagSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, name) in self.0.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(name.as_str())?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> IntoIterator for &'a TagSet {
+    type Item = &'a Name;
+    type IntoIter = std::slice::Iter<'a, Name>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl From<TagSet> for Vec<Name> {
+    fn from(tags: TagSet) -> Self {
+        tags.0
+    }
+}
+
+#[derive(Debug)]
+pub struct TagSetBuilder {
+    pub name_builder: DefaultNameBuilder,
+    pub max_tags: usize,
+}
+
+impl TagSetBuilder {
+    pub fn new() -> Self {
+        Self {
+            name_builder: DefaultNameBuilder::new().with_min_length(1).with_max_length(32),
+            max_tags: 10,
+        }
+    }
+
+    pub fn with_name_builder(mut self, name_builder: DefaultNameBuilder) -> Self {
+        self.name_builder = name_builder;
+        self
+    }
+
+    pub fn with_max_tag_length(mut self, max: usize) -> Self {
+        self.name_builder = self.name_builder.with_max_length(max);
+        self
+    }
+
+    pub fn with_max_tags(mut self, max: usize) -> Self {
+        self.max_tags = max;
+        self
+    }
+
+    /// Builds a set from already-valid tags, e.g. loaded from storage. Duplicates are dropped.
+    pub fn build(&self, tags: impl IntoIterator<Item = impl AsRef<str> + Into<String>>) -> Result<TagSet, TagSetError> {
+        self.collect(tags, |tag| self.name_builder.build(tag))
+    }
+
+    /// Parses user input such as `Rust, web dev, rust`, normalizing each tag and dropping empty entries.
+    pub fn parse(&self, input: &str) -> Result<TagSet, TagSetError> {
+        let tags = input.split(',').map(str::trim).filter(|tag| !tag.is_empty());
+        self.collect(tags, |tag| self.name_builder.build_with_normalize(tag))
+    }
+
+    fn collect<T: AsRef<str> + Into<String>>(
+        &self,
+        tags: impl IntoIterator<Item = T>,
+        build: impl Fn(T) -> Result<Name, NameError>,
+    ) -> Result<TagSet, TagSetError> {
+        let mut set = TagSet::new();
+        for tag in tags {
+            let raw = tag.as_ref().to_owned();
+            let name = build(tag).map_err(|error| TagSetError::InvalidTag { tag: raw, error })?;
+            set.insert(name);
+        }
+        if set.len() > self.max_tags {
+            return Err(TagSetError::TooMany {
+                max: self.max_tags,
+                actual: set.len(),
+            });
+        }
+        Ok(set)
+    }
+}
+
+impl Default for TagSetBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let builder = TagSetBuilder::new().with_max_tags(3);
+        let tags = builder.parse(" Rust, web dev ,, rust,Café ").unwrap();
+        assert_eq!(tags.to_string(), "rust, web-dev, cafe");
+        assert!(tags.contains("web-dev"));
+        assert_eq!(builder.parse(&tags.to_string()).unwrap(), tags);
+        assert!(builder.parse("").unwrap().is_empty());
+
+        assert!(matches!(
+            builder.parse("a, b, c, d"),
+            Err(TagSetError::TooMany { max: 3, actual: 4 })
+        ));
+        assert!(matches!(
+            TagSetBuilder::new().with_max_tag_length(5).parse("short, much-too-long"),
+            Err(TagSetError::InvalidTag { tag, .. }) if tag == "much-too-long"
+        ));
+    }
+
+    #[test]
+    fn test_build() {
+        let builder = TagSetBuilder::new();
+        let tags = builder.build(["rust", "web", "rust"]).unwrap();
+        assert_eq!(tags.len(), 2);
+        assert_eq!(tags.iter().map(Name::as_str).collect::<Vec<_>>(), ["rust", "web"]);
+        assert!(matches!(
+            builder.build(["Rust"]),
+            Err(TagSetError::InvalidTag { tag, error: NameError::InvalidCharacters(_) }) if tag == "Rust"
+        ));
+    }
+}
@@ -0,0 +1,194 @@
+use std::marker::PhantomData;
+
+use crate::{DefaultNameBuilder, Name, NameBulder, NameError};
+
+/// A distinct name type generated by [`define_name_type!`], validated by its own builder rules.
+pub trait TypedName: Sized {
+    /// The rules names of this type are validated and normalized with.
+    fn default_builder() -> DefaultNameBuilder;
+
+    /// Wraps a name that `default_builder` has already accepted.
+    fn from_name(name: Name) -> Self;
+}
+
+/// A [`NameBulder`] producing a [`TypedName`] instead of a plain [`Name`].
+#[derive(Debug)]
+pub struct TypedNameBuilder<N> {
+    pub inner: DefaultNameBuilder,
+    marker: PhantomData<fn() -> N>,
+}
+
+impl<N: TypedName> TypedNameBuilder<N> {
+    pub fn new() -> Self {
+        Self::with_builder(N::default_builder())
+    }
+
+    /// Uses different rules than the type's default, e.g. a stricter maximum length in one form.
+    pub fn with_builder(inner: DefaultNameBuilder) -> Self {
+        Self {
+            inner,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<N: TypedName> Default for TypedNameBuilder<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: TypedName> NameBulder for TypedNameBuilder<N> {
+    type Name = N;
+    type Error = NameError;
+
+    fn validate(&self, input: &str) -> Result<(), Self::Error> {
+        self.inner.validate(input)
+    }
+
+    fn normalize(&self, input: &str) -> Result<String, Self::Error> {
+        self.inner.normalize(input)
+    }
+
+    fn build(&self, input: impl AsRef<str> + Into<String>) -> Result<Self::Name, Self::Error> {
+        self.inner.build(input).map(N::from_name)
+    }
+
+    fn build_with_normalize(&self, input: impl AsRef<str> + Into<String>) -> Result<Self::Name, Self::Error> {
+        self.inner.build_with_normalize(input).map(N::from_name)
+    }
+}
+
+/// Defines a newtype over [`Name`] so that a `ServiceName` cannot be passed where a `ProjectName` is expected:
+/// `define_name_type! { pub struct ServiceName = DefaultNameBuilder::rfc1123_label(); }`. The builder expression
+/// defaults to [`DefaultNameBuilder::new`].
+#[macro_export]
+macro_rules! define_name_type {
+    ($(#[$meta:meta])* $vis:vis struct $name:ident;) => {
+        $crate::define_name_type! {
+            $(#[$meta])*
+            $vis struct $name = $crate::DefaultNameBuilder::new();
+        }
+    };
+    ($(#[$meta:meta])* $vis:vis struct $name:ident = $builder:expr;) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        $vis struct $name($crate::Name);
+
+        impl $name {
+            /// Wraps `name` without validating it, e.g. when loading trusted data.
+            pub fn from_raw(name: impl Into<String>) -> Self {
+                Self($crate::Name::from_raw(name))
+            }
+
+            pub fn builder() -> $crate::TypedNameBuilder<Self> {
+                $crate::TypedNameBuilder::new()
+            }
+
+            pub fn as_str(&self) -> &str {
+                self.0.as_str()
+            }
+
+            pub fn as_name(&self) -> &$crate::Name {
+                &self.0
+            }
+
+            pub fn into_name(self) -> $crate::Name {
+                self.0
+            }
+        }
+
+        impl $crate::TypedName for $name {
+            fn default_builder() -> $crate::DefaultNameBuilder {
+                $builder
+            }
+
+            fn from_name(name: $crate::Name) -> Self {
+                Self(name)
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl ::std::convert::AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl ::std::str::FromStr for $name {
+            type Err = $crate::NameError;
+
+            fn from_str(input: &str) -> ::std::result::Result<Self, Self::Err> {
+                $crate::NameBulder::build(&Self::builder(), input)
+            }
+        }
+
+        impl ::std::convert::TryFrom<String> for $name {
+            type Error = $crate::NameError;
+
+            fn try_from(input: String) -> ::std::result::Result<Self, Self::Error> {
+                $crate::NameBulder::build(&Self::builder(), input)
+            }
+        }
+
+        impl ::std::convert::TryFrom<&str> for $name {
+            type Error = $crate::NameError;
+
+            fn try_from(input: &str) -> ::std::result::Result<Self, Self::Error> {
+                input.parse()
+            }
+        }
+
+        impl ::std::convert::From<$name> for $crate::Name {
+            fn from(name: $name) -> Self {
+                name.0
+            }
+        }
+
+        impl ::std::convert::From<$name> for String {
+            fn from(name: $name) -> Self {
+                name.0.into()
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    define_name_type! {
+        /// A project name.
+        pub struct ProjectName;
+    }
+
+    define_name_type! {
+        pub struct ServiceName = DefaultNameBuilder::rfc1123_label().with_max_length(20);
+    }
+
+    #[test]
+    fn test_define_name_type() {
+        let project: ProjectName = "acme_web".parse().unwrap();
+        assert_eq!(project.to_string(), "acme_web");
+        assert_eq!(project.as_name(), &Name::from_raw("acme_web"));
+        assert_eq!(Name::from(project.clone()), Name::from_raw("acme_web"));
+        assert!(ProjectName::try_from("Acme").is_err());
+
+        assert!(ServiceName::try_from("acme_web".to_owned()).is_err());
+        let service = ServiceName::builder().build_with_normalize("Acme Web!").unwrap();
+        assert_eq!(service, ServiceName::from_raw("acme-web"));
+        assert_eq!(String::from(service), "acme-web");
+    }
+
+    #[test]
+    fn test_typed_name_builder() {
+        let builder = TypedNameBuilder::<ProjectName>::with_builder(DefaultNameBuilder::new().with_max_length(4));
+        assert!(builder.build("acme").is_ok());
+        assert!(matches!(builder.build("acme-web"), Err(NameError::InvalidLength(_))));
+    }
+}
@@ -1 +1,81 @@
+use std::sync::{Arc, Mutex, PoisonError};
 
+use crate::model::Notification;
+
+/// Holds notifications between requests, e.g. flash messages shown after a redirect.
+pub trait NotificationStore {
+    fn push(&self, notification: Notification);
+
+    /// Removes and returns all pending notifications, oldest first.
+    fn drain(&self) -> Vec<Notification>;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T: NotificationStore + ?Sized> NotificationStore for Arc<T> {
+    fn push(&self, notification: Notification) {
+        (**self).push(notification)
+    }
+
+    fn drain(&self) -> Vec<Notification> {
+        (**self).drain()
+    }
+
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct InMemoryNotificationStore {
+    notifications: Mutex<Vec<Notification>>,
+}
+
+impl InMemoryNotificationStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_notifications(notifications: impl IntoIterator<Item = Notification>) -> Self {
+        Self {
+            notifications: Mutex::new(notifications.into_iter().collect()),
+        }
+    }
+}
+
+impl NotificationStore for InMemoryNotificationStore {
+    fn push(&self, notification: Notification) {
+        self.notifications
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(notification);
+    }
+
+    fn drain(&self) -> Vec<Notification> {
+        std::mem::take(&mut *self.notifications.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    fn len(&self) -> usize {
+        self.notifications.lock().unwrap_or_else(PoisonError::into_inner).len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_in_memory_store_drains() {
+        let store = InMemoryNotificationStore::with_notifications([Notification::info("Welcome back")]);
+        store.push(Notification::success("Project created"));
+        assert_eq!(store.len(), 2);
+
+        let messages: Vec<String> = store.drain().into_iter().map(|n| n.message).collect();
+        assert_eq!(messages, ["Welcome back", "Project created"]);
+        assert!(store.is_empty());
+    }
+}
@@ -1,4 +1,5 @@
 use zabawa_notification_domain::model::{Notification, NotificationLevel};
+use zabawa_view_common::Variant;
 
 #[derive(Clone, Copy, Default, Debug)]
 pub struct Notifications<'a>(pub &'a [Notification]);
@@ -11,7 +12,7 @@ impl<'a> Notifications<'a> {
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct NotificationViewData<'a> {
-    pub variant: &'static str,
+    pub variant: Variant,
     pub icon: &'static str,
     pub message: &'a str,
 }
@@ -21,27 +22,27 @@ impl<'a> From<&'a Notification> for NotificationViewData<'a> {
         let message = &notification.message;
         match notification.level {
             NotificationLevel::Error => NotificationViewData {
-                variant: "danger",
+                variant: Variant::Danger,
                 icon: "circle-exclamation",
                 message,
             },
             NotificationLevel::Warning => NotificationViewData {
-                variant: "warning",
+                variant: Variant::Warning,
                 icon: "triangle-exclamation",
                 message,
             },
             NotificationLevel::Success => NotificationViewData {
-                variant: "success",
+                variant: Variant::Success,
                 icon: "circle-check",
                 message,
             },
             NotificationLevel::Info => NotificationViewData {
-                variant: "brand",
+                variant: Variant::Brand,
                 icon: "circle-info",
                 message,
             },
             NotificationLevel::Note => NotificationViewData {
-                variant: "neutral",
+                variant: Variant::Neutral,
                 icon: "pen-to-square",
                 message,
             },
@@ -5,14 +5,6 @@ pub use self::context::*;
 pub use self::notification::*;
 
 pub mod hypertext_elements {
-    use hypertext::define_elements;
-    // Re-export all standard HTML elements
-    pub use hypertext::validation::hypertext_elements::*;
-
-    define_elements! {
-        wa_animation { name duration iterations }
-        wa_button { appearance variant size }
-        wa_icon { slot name library variant label role aria_label }
-        wa_callout { variant }
-    }
+    // Re-export all standard HTML elements along with the shared Web Awesome ones
+    pub use zabawa_view_common::hypertext_elements::*;
 }
@@ -1,10 +1,9 @@
 use std::borrow::Cow;
 
-use hypertext::prelude::GlobalAttributes;
-use hypertext::{Renderable, rsx};
-use zabawa_view_common::Animation;
+use hypertext::{Renderable, rsx, rsx_borrow};
+use zabawa_view_common::{Animation, Callout, Component, ProgressBar, RenderContext};
 
-use crate::{NotificationViewData, Notifications, hypertext_elements};
+use crate::{NotificationViewData, Notifications};
 
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct NotificationView {
@@ -44,37 +43,76 @@ impl NotificationView {
         self
     }
 
-    pub fn render<'a>(
+    pub fn render<'a>(&self, ctx: &RenderContext, view_data: NotificationViewData<'a>) -> impl Renderable {
+        self.render_callout(ctx, view_data, ())
+    }
+
+    pub fn render_with_progress<'a>(
+        &self,
+        ctx: &RenderContext,
+        view_data: NotificationViewData<'a>,
+        progress: &ProgressBar,
+    ) -> impl Renderable {
+        self.render_callout(ctx, view_data, progress.render())
+    }
+
+    fn render_callout<'a>(
         &self,
+        ctx: &RenderContext,
         NotificationViewData { variant, icon, message }: NotificationViewData<'a>,
+        footer: impl Renderable,
     ) -> impl Renderable {
-        rsx! {
-            <wa-callout class={ "notification-" (variant) } variant=(variant)>
-                <wa-icon slot="icon" name=(icon)></wa-icon>
-                <div class="wa-flank:end wa-align-items-start">
-                    <div>(message)</div>
-                    <div>
-                        <wa-button class="close" appearance="plain" variant=(variant) size="small">
-                            <wa-icon name="xmark" library="system" variant="solid" label="Close" role="img" aria-label="Close"></wa-icon>
-                        </wa-button>
-                    </div>
-                </div>
-                @if let Some(script) = &self.callout_script {
-                    <script>(script)</script>
-                }
-            </wa-callout>
+        let mut callout = Callout::new(variant)
+            .with_icon(icon)
+            .with_class(format!("notification-{variant}"))
+            .with_dismissible(true);
+        if let Some(script) = &self.callout_script {
+            callout = callout.with_dismiss_script(script.clone());
         }
+
+        rsx! { (callout.render(ctx, rsx_borrow! { (message)(footer) })) }
     }
 
-    pub fn render_list<'a>(&self, notifications: Notifications<'a>) -> impl Renderable {
+    pub fn render_list<'a>(&self, ctx: &RenderContext, notifications: Notifications<'a>) -> impl Renderable {
         rsx! {
             @for view_data in notifications.iter() {
                 @if let Some(animation) = &self.animation {
-                    (animation.render(self.render(view_data)))
+                    (animation.render(self.render(ctx, view_data)))
                 } @else {
-                    (self.render(view_data))
+                    (self.render(ctx, view_data))
                 }
             }
         }
     }
 }
+
+impl Component for NotificationView {
+    type Props<'p> = NotificationViewData<'p>;
+
+    fn render<'p>(&self, view_data: Self::Props<'p>, ctx: &RenderContext) -> impl Renderable {
+        NotificationView::render(self, ctx, view_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zabawa_notification_domain::model::Notification;
+    use zabawa_view_common::testing::{assert_has_selector, assert_selector_count};
+
+    use super::*;
+
+    #[test]
+    fn test_render_list() {
+        let notifications = [Notification::error("Failed"), Notification::success("Saved")];
+        let ctx = RenderContext::new().with_nonce("abc");
+        let html = NotificationView::new()
+            .render_list(&ctx, Notifications(&notifications))
+            .render()
+            .into_inner();
+
+        assert_selector_count(&html, "wa-animation > wa-callout", 2);
+        assert_has_selector(&html, "wa-callout[variant=danger] wa-icon[name=circle-exclamation]");
+        assert_has_selector(&html, "wa-callout.notification-success wa-icon[name=circle-check]");
+        assert_has_selector(&html, "wa-callout script[nonce=abc]");
+    }
+}
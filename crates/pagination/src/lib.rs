@@ -0,0 +1,335 @@
+use std::borrow::Cow;
+
+use thiserror::Error;
+
+pub const DEFAULT_PAGE_SIZE: u32 = 20;
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaginationError {
+    #[error("page must be a number starting at 1")]
+    InvalidPage,
+
+    #[error("page size must be between 1 and {max}")]
+    InvalidSize { max: u32 },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PageRequest {
+    /// 1-based page number.
+    Numbered { page: u32, size: u32 },
+    /// Items after an opaque cursor, or from the start.
+    Cursor {
+        after: Option<Cow<'static, str>>,
+        size: u32,
+    },
+}
+
+impl PageRequest {
+    pub fn first(size: u32) -> Self {
+        PageRequest::Numbered { page: 1, size }
+    }
+
+    pub fn size(&self) -> u32 {
+        match self {
+            PageRequest::Numbered { size, .. } | PageRequest::Cursor { size, .. } => *size,
+        }
+    }
+
+    pub fn page(&self) -> Option<u32> {
+        match self {
+            PageRequest::Numbered { page, .. } => Some(*page),
+            PageRequest::Cursor { .. } => None,
+        }
+    }
+
+    pub fn cursor(&self) -> Option<&str> {
+        match self {
+            PageRequest::Cursor { after, .. } => after.as_deref(),
+            PageRequest::Numbered { .. } => None,
+        }
+    }
+
+    /// Rows to skip for `OFFSET`; cursor requests always start at 0.
+    pub fn offset(&self) -> u64 {
+        match self {
+            PageRequest::Numbered { page, size } => u64::from(page.saturating_sub(1)) * u64::from(*size),
+            PageRequest::Cursor { .. } => 0,
+        }
+    }
+
+    /// Rows to fetch for `LIMIT`, one more than the page size so [`Page::from_overfetched`] can detect a next page.
+    pub fn fetch_limit(&self) -> u64 {
+        u64::from(self.size()) + 1
+    }
+}
+
+impl Default for PageRequest {
+    fn default() -> Self {
+        Self::first(DEFAULT_PAGE_SIZE)
+    }
+}
+
+/// Bounds applied to client-supplied page requests.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PageLimits {
+    pub default_size: u32,
+    pub max_size: u32,
+}
+
+impl Default for PageLimits {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PageLimits {
+    pub fn new() -> Self {
+        Self {
+            default_size: DEFAULT_PAGE_SIZE,
+            max_size: MAX_PAGE_SIZE,
+        }
+    }
+
+    pub fn with_default_size(mut self, size: u32) -> Self {
+        self.default_size = size;
+        self
+    }
+
+    pub fn with_max_size(mut self, size: u32) -> Self {
+        self.max_size = size;
+        self
+    }
+
+    pub fn numbered(&self, page: Option<u32>, size: Option<u32>) -> Result<PageRequest, PaginationError> {
+        let page = page.unwrap_or(1);
+        if page == 0 {
+            return Err(PaginationError::InvalidPage);
+        }
+        Ok(PageRequest::Numbered {
+            page,
+            size: self.validate_size(size)?,
+        })
+    }
+
+    pub fn cursor(
+        &self,
+        after: Option<impl Into<Cow<'static, str>>>,
+        size: Option<u32>,
+    ) -> Result<PageRequest, PaginationError> {
+        Ok(PageRequest::Cursor {
+            after: after
+                .map(Into::into)
+                .filter(|after: &Cow<'static, str>| !after.is_empty()),
+            size: self.validate_size(size)?,
+        })
+    }
+
+    /// Reads `page`, `after` and `size` from decoded query parameters; `after` selects cursor pagination.
+    pub fn from_params<'a>(
+        &self,
+        params: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Result<PageRequest, PaginationError> {
+        let (mut page, mut after, mut size) = (None, None, None);
+        for (name, value) in params {
+            match name {
+                "page" => page = Some(value.parse().map_err(|_| PaginationError::InvalidPage)?),
+                "after" => after = Some(value.to_owned()),
+                "size" => {
+                    let invalid = PaginationError::InvalidSize { max: self.max_size };
+                    size = Some(value.parse().map_err(|_| invalid)?);
+                },
+                _ => {},
+            }
+        }
+        match after {
+            Some(after) => self.cursor(Some(after), size),
+            None => self.numbered(page, size),
+        }
+    }
+
+    fn validate_size(&self, size: Option<u32>) -> Result<u32, PaginationError> {
+        let size = size.unwrap_or(self.default_size);
+        if size == 0 || size > self.max_size {
+            return Err(PaginationError::InvalidSize { max: self.max_size });
+        }
+        Ok(size)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub request: PageRequest,
+    /// Total number of items, when the query counted them.
+    pub total: Option<u64>,
+    pub has_next: bool,
+    pub next_cursor: Option<String>,
+}
+
+impl<T> Page<T> {
+    pub fn new(items: Vec<T>, request: PageRequest, total: u64) -> Self {
+        let has_next = request.offset() + (items.len() as u64) < total;
+        Self {
+            items,
+            request,
+            total: Some(total),
+            has_next,
+            next_cursor: None,
+        }
+    }
+
+    /// Builds a page from up to [`PageRequest::fetch_limit`] rows, dropping the extra row used to detect a next page.
+    pub fn from_overfetched(mut items: Vec<T>, request: PageRequest) -> Self {
+        let size = request.size() as usize;
+        let has_next = items.len() > size;
+        items.truncate(size);
+        Self {
+            items,
+            request,
+            total: None,
+            has_next,
+            next_cursor: None,
+        }
+    }
+
+    /// Slices an in-memory list, e.g. for small collections or tests.
+    pub fn paginate(all: &[T], request: PageRequest) -> Self
+    where
+        T: Clone,
+    {
+        let start = usize::try_from(request.offset()).unwrap_or(usize::MAX).min(all.len());
+        let end = start.saturating_add(request.size() as usize).min(all.len());
+        Self::new(all[start..end].to_vec(), request, all.len() as u64)
+    }
+
+    /// Sets the cursor of the next page from its last item, for cursor pagination.
+    pub fn with_next_cursor(mut self, cursor: impl FnOnce(&T) -> String) -> Self {
+        self.next_cursor = self.items.last().filter(|_| self.has_next).map(cursor);
+        self
+    }
+
+    pub fn has_previous(&self) -> bool {
+        match &self.request {
+            PageRequest::Numbered { page, .. } => *page > 1,
+            PageRequest::Cursor { after, .. } => after.is_some(),
+        }
+    }
+
+    pub fn total_pages(&self) -> Option<u32> {
+        let size = u64::from(self.request.size());
+        self.total
+            .map(|total| u32::try_from(total.div_ceil(size).max(1)).unwrap_or(u32::MAX))
+    }
+
+    /// Page numbers around the current page for a pager, with `None` marking gaps: `1 … 4 5 [6] 7 8 … 20`.
+    pub fn page_window(&self, radius: u32) -> Vec<Option<u32>> {
+        let (Some(current), Some(last)) = (self.request.page(), self.total_pages()) else {
+            return Vec::new();
+        };
+        let start = current.saturating_sub(radius).max(1);
+        let end = current.saturating_add(radius).min(last);
+
+        let mut window = Vec::new();
+        if start > 1 {
+            window.push(Some(1));
+            if start > 2 {
+                window.push(None);
+            }
+        }
+        window.extend((start..=end).map(Some));
+        if end < last {
+            if end + 1 < last {
+                window.push(None);
+            }
+            window.push(Some(last));
+        }
+        window
+    }
+
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            request: self.request,
+            total: self.total,
+            has_next: self.has_next,
+            next_cursor: self.next_cursor,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_limits() {
+        let limits = PageLimits::new().with_max_size(50);
+        assert_eq!(limits.from_params([]), Ok(PageRequest::Numbered { page: 1, size: 20 }));
+        assert_eq!(
+            limits.from_params([("page", "3"), ("size", "10")]),
+            Ok(PageRequest::Numbered { page: 3, size: 10 })
+        );
+        assert_eq!(
+            limits.from_params([("after", "abc"), ("page", "3")]).unwrap().cursor(),
+            Some("abc")
+        );
+        assert_eq!(limits.from_params([("page", "0")]), Err(PaginationError::InvalidPage));
+        assert_eq!(limits.from_params([("page", "-1")]), Err(PaginationError::InvalidPage));
+        assert_eq!(
+            limits.from_params([("size", "51")]),
+            Err(PaginationError::InvalidSize { max: 50 })
+        );
+    }
+
+    #[test]
+    fn test_page() {
+        let all: Vec<u32> = (1..=45).collect();
+        let page = Page::paginate(&all, PageRequest::Numbered { page: 3, size: 20 });
+        assert_eq!(page.items, (41..=45).collect::<Vec<_>>());
+        assert!(!page.has_next);
+        assert!(page.has_previous());
+        assert_eq!(page.total_pages(), Some(3));
+
+        let page = Page::paginate(&all, PageRequest::Numbered { page: 1, size: 20 });
+        assert!(page.has_next);
+        assert_eq!(page.map(|n| n * 2).items[0], 2);
+
+        let request = PageRequest::Cursor { after: None, size: 2 };
+        let page = Page::from_overfetched(vec!["a", "b", "c"], request).with_next_cursor(|item| item.to_string());
+        assert_eq!(page.items, ["a", "b"]);
+        assert_eq!(page.next_cursor.as_deref(), Some("b"));
+        assert!(!page.has_previous());
+    }
+
+    #[test]
+    fn test_page_window() {
+        let page = |current| {
+            Page::new(
+                Vec::<()>::new(),
+                PageRequest::Numbered {
+                    page: current,
+                    size: 10,
+                },
+                200,
+            )
+        };
+        assert_eq!(page(6).page_window(2), [
+            Some(1),
+            None,
+            Some(4),
+            Some(5),
+            Some(6),
+            Some(7),
+            Some(8),
+            None,
+            Some(20)
+        ]);
+        assert_eq!(page(2).page_window(1), [Some(1), Some(2), Some(3), None, Some(20)]);
+        assert_eq!(page(20).page_window(1), [Some(1), None, Some(19), Some(20)]);
+    }
+}
@@ -0,0 +1,104 @@
+use std::fmt;
+use std::sync::atomic::{Ordering, compiler_fence};
+
+use thiserror::Error;
+pub use zabawa_validation::WeakPasswordError;
+use zabawa_validation::validate_password_strength;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum PasswordError {
+    #[error("password error: {0}")]
+    Weak(#[from] WeakPasswordError),
+}
+
+/// A plaintext credential. It is never printed, and its memory is zeroed when dropped.
+#[derive(Clone)]
+pub struct Password(String);
+
+impl Password {
+    /// Wraps a password without checking its strength, e.g. for a login attempt.
+    pub fn new(password: impl Into<String>) -> Self {
+        Self(password.into())
+    }
+
+    /// Wraps a new password, rejecting it if it is too weak, e.g. on sign-up or password change.
+    pub fn new_validated(password: impl Into<String>) -> Result<Self, PasswordError> {
+        let password = Self::new(password);
+        password.validate_strength()?;
+        Ok(password)
+    }
+
+    pub fn validate_strength(&self) -> Result<(), PasswordError> {
+        validate_password_strength(&self.0)?;
+        Ok(())
+    }
+
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Debug for Password {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Password(\"***\")")
+    }
+}
+
+impl From<String> for Password {
+    fn from(password: String) -> Self {
+        Self::new(password)
+    }
+}
+
+impl Drop for Password {
+    fn drop(&mut self) {
+        zeroize(&mut self.0);
+    }
+}
+
+fn zeroize(secret: &mut String) {
+    // SAFETY: NUL bytes are valid UTF-8, so the string stays well-formed while it is overwritten.
+    let bytes = unsafe { secret.as_mut_vec() };
+    for byte in bytes.iter_mut() {
+        // SAFETY: `byte` is a valid, aligned and exclusive reference.
+        unsafe { std::ptr::write_volatile(byte, 0) };
+    }
+    compiler_fence(Ordering::SeqCst);
+    bytes.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debug_is_redacted() {
+        let password = Password::new("hunter2");
+        assert_eq!(format!("{password:?}"), "Password(\"***\")");
+        assert_eq!(password.expose_secret(), "hunter2");
+    }
+
+    #[test]
+    fn test_new_validated() {
+        assert!(Password::new_validated("Tr0ub4dor").is_ok());
+        assert_eq!(
+            Password::new_validated("hunter2").unwrap_err(),
+            PasswordError::Weak(WeakPasswordError::TooShort { min: 8 })
+        );
+    }
+
+    #[test]
+    fn test_zeroize() {
+        let mut secret = String::from("hunter2");
+        zeroize(&mut secret);
+        assert!(secret.is_empty());
+    }
+}
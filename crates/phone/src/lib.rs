@@ -0,0 +1,184 @@
+use derive_more::{Display, Into};
+use thiserror::Error;
+pub use zabawa_validation::InputFormatError;
+use zabawa_validation::parse_phone;
+
+/// Two-digit ITU-T E.164 country calling codes. Codes starting with 1 or 7 are one digit, all others three.
+const TWO_DIGIT_COUNTRY_CODES: &[u16] = &[
+    20, 27, 30, 31, 32, 33, 34, 36, 39, 40, 41, 43, 44, 45, 46, 47, 48, 49, 51, 52, 53, 54, 55, 56, 57, 58, 60, 61, 62,
+    63, 64, 65, 66, 81, 82, 84, 86, 90, 91, 92, 93, 94, 95, 98,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum PhoneNumberError {
+    #[error("phone number error: {0}")]
+    Invalid(#[from] InputFormatError),
+
+    #[error("phone number must start with + and a country code")]
+    MissingCountryCode,
+
+    #[error("phone number has an invalid country code")]
+    InvalidCountryCode,
+}
+
+/// A phone number in E.164 form, e.g. `+15551234567`.
+#[derive(Clone, Debug, Display, Into, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PhoneNumber(String);
+
+impl PhoneNumber {
+    pub fn from_raw(phone: impl Into<String>) -> Self {
+        Self(phone.into())
+    }
+
+    /// Parses an international number, accepting `+` or `00` before the country code and common punctuation.
+    pub fn parse(input: &str) -> Result<Self, PhoneNumberError> {
+        let input = input.trim();
+        let input = match input.strip_prefix("00") {
+            Some(rest) => format!("+{rest}"),
+            None => input.to_owned(),
+        };
+        let digits = parse_phone(&input)?;
+        if !digits.starts_with('+') {
+            return Err(PhoneNumberError::MissingCountryCode);
+        }
+        if split_country_code(&digits[1..]).is_none() {
+            return Err(PhoneNumberError::InvalidCountryCode);
+        }
+        Ok(Self(digits))
+    }
+
+    /// Parses a number that may be written in national form, using `country_code` when none is given.
+    pub fn parse_with_country_code(input: &str, country_code: u16) -> Result<Self, PhoneNumberError> {
+        let trimmed = input.trim();
+        if trimmed.starts_with('+') || trimmed.starts_with("00") {
+            return Self::parse(trimmed);
+        }
+        let national = parse_phone(trimmed)?;
+        let national = national.trim_start_matches('0');
+        Self::parse(&format!("+{country_code}{national}"))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn country_code(&self) -> u16 {
+        self.parts().0
+    }
+
+    pub fn national_number(&self) -> &str {
+        self.parts().1
+    }
+
+    /// Formats the number for display within its own country, e.g. `(555) 123-4567` for North America.
+    pub fn format_national(&self) -> String {
+        let (country_code, national) = self.parts();
+        if country_code == 1 && national.len() == 10 {
+            return format!("({}) {}-{}", &national[..3], &national[3..6], &national[6..]);
+        }
+        group_digits(national)
+    }
+
+    /// Formats the number for display abroad, e.g. `+44 207 946 0958`.
+    pub fn format_international(&self) -> String {
+        let (country_code, national) = self.parts();
+        if country_code == 1 && national.len() == 10 {
+            return format!("+1 {} {} {}", &national[..3], &national[3..6], &national[6..]);
+        }
+        format!("+{country_code} {}", group_digits(national))
+    }
+
+    fn parts(&self) -> (u16, &str) {
+        let digits = self.0.strip_prefix('+').unwrap_or(&self.0);
+        split_country_code(digits).unwrap_or((0, digits))
+    }
+}
+
+impl AsRef<str> for PhoneNumber {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+fn split_country_code(digits: &str) -> Option<(u16, &str)> {
+    let len = match digits.as_bytes().first()? {
+        b'0' => return None,
+        b'1' | b'7' => 1,
+        _ => {
+            let two_digit: u16 = digits.get(..2)?.parse().ok()?;
+            if TWO_DIGIT_COUNTRY_CODES.contains(&two_digit) {
+                2
+            } else {
+                3
+            }
+        },
+    };
+    let (code, national) = (digits.get(..len)?, &digits[len..]);
+    if national.is_empty() {
+        return None;
+    }
+    Some((code.parse().ok()?, national))
+}
+
+/// Groups digits by three, keeping a final group of four instead of leaving a single digit.
+fn group_digits(digits: &str) -> String {
+    let mut groups = Vec::new();
+    let mut rest = digits;
+    while rest.len() > 4 {
+        let (group, tail) = rest.split_at(3);
+        groups.push(group);
+        rest = tail;
+    }
+    groups.push(rest);
+    groups.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let phone = PhoneNumber::parse("+1 (555) 123-4567").unwrap();
+        assert_eq!(phone.as_str(), "+15551234567");
+        assert_eq!(phone.country_code(), 1);
+        assert_eq!(phone.national_number(), "5551234567");
+        assert_eq!(phone.format_national(), "(555) 123-4567");
+        assert_eq!(phone.format_international(), "+1 555 123 4567");
+
+        let phone = PhoneNumber::parse("0044 20 7946 0958").unwrap();
+        assert_eq!(phone.to_string(), "+442079460958");
+        assert_eq!(phone.country_code(), 44);
+        assert_eq!(phone.format_national(), "207 946 0958");
+        assert_eq!(PhoneNumber::parse("+353 1 234 5678").unwrap().country_code(), 353);
+
+        assert_eq!(
+            PhoneNumber::parse("555 123 4567").unwrap_err(),
+            PhoneNumberError::MissingCountryCode
+        );
+        assert_eq!(
+            PhoneNumber::parse("+0 555 123 4567").unwrap_err(),
+            PhoneNumberError::InvalidCountryCode
+        );
+        assert_eq!(
+            PhoneNumber::parse("+1 555").unwrap_err(),
+            PhoneNumberError::Invalid(InputFormatError::Phone)
+        );
+    }
+
+    #[test]
+    fn test_parse_with_country_code() {
+        assert_eq!(
+            PhoneNumber::parse_with_country_code("020 7946 0958", 44)
+                .unwrap()
+                .as_str(),
+            "+442079460958"
+        );
+        assert_eq!(
+            PhoneNumber::parse_with_country_code("+49 30 123456", 44)
+                .unwrap()
+                .country_code(),
+            49
+        );
+    }
+}
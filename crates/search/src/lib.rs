@@ -0,0 +1,271 @@
+use derive_more::{Display, Into};
+use thiserror::Error;
+pub use zabawa_validation::InvalidLengthError;
+use zabawa_validation::validate_length;
+
+#[derive(Debug, Clone, Copy, PartialEq, Error)]
+pub enum SearchQueryError {
+    #[error("search query is not normalized")]
+    Unnormalized,
+
+    #[error("search query error: {0}")]
+    InvalidLength(#[from] InvalidLengthError),
+}
+
+/// A sanitized search query: trimmed, single-spaced and free of control characters.
+#[derive(Clone, Debug, Display, Into, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SearchQuery(String);
+
+impl SearchQuery {
+    pub fn from_raw(query: impl Into<String>) -> Self {
+        Self(query.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Splits the query into words and `"quoted phrases"`; an unterminated quote runs to the end of the query.
+    pub fn terms(&self) -> Vec<SearchTerm<'_>> {
+        tokenize_search_query(&self.0)
+    }
+}
+
+impl AsRef<str> for SearchQuery {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SearchTerm<'a> {
+    Word(&'a str),
+    Phrase(&'a str),
+}
+
+impl<'a> SearchTerm<'a> {
+    pub fn as_str(&self) -> &'a str {
+        match self {
+            Self::Word(term) | Self::Phrase(term) => term,
+        }
+    }
+
+    pub fn is_phrase(&self) -> bool {
+        matches!(self, Self::Phrase(_))
+    }
+}
+
+pub trait SearchQueryBuilder {
+    type SearchQuery;
+    type Error;
+
+    fn validate(&self, input: &str) -> Result<(), Self::Error>;
+
+    fn normalize(&self, input: &str) -> Result<String, Self::Error>;
+
+    fn build(&self, input: impl AsRef<str> + Into<String>) -> Result<Self::SearchQuery, Self::Error>;
+
+    fn build_with_normalize(&self, input: impl AsRef<str> + Into<String>) -> Result<Self::SearchQuery, Self::Error>;
+}
+
+#[derive(Debug, Default)]
+pub struct DefaultSearchQueryBuilder {
+    pub min_length: usize,
+    pub max_length: usize,
+}
+
+impl DefaultSearchQueryBuilder {
+    pub fn new() -> Self {
+        Self {
+            min_length: 1,
+            max_length: 256,
+        }
+    }
+
+    pub fn with_min_length(mut self, min: usize) -> Self {
+        self.min_length = min;
+        self
+    }
+
+    pub fn with_max_length(mut self, max: usize) -> Self {
+        self.max_length = max;
+        self
+    }
+}
+
+impl SearchQueryBuilder for DefaultSearchQueryBuilder {
+    type SearchQuery = SearchQuery;
+    type Error = SearchQueryError;
+
+    fn validate(&self, input: &str) -> Result<(), Self::Error> {
+        if !validate_search_query_normalized(input) {
+            return Err(SearchQueryError::Unnormalized);
+        }
+
+        validate_length(input.chars().count(), self.min_length, self.max_length)?;
+
+        Ok(())
+    }
+
+    /// Sanitizes the input and cuts it down to `max_length` characters, at a word boundary where possible, instead
+    /// of rejecting long queries.
+    fn normalize(&self, input: &str) -> Result<String, Self::Error> {
+        let mut normalized = normalize_search_query(input);
+        if let Some((index, ch)) = normalized.char_indices().nth(self.max_length) {
+            let end = match normalized[..index].rfind(' ') {
+                Some(space) if ch != ' ' => space,
+                _ => index,
+            };
+            normalized.truncate(end);
+            normalized.truncate(normalized.trim_end().len());
+        }
+
+        Ok(normalized)
+    }
+
+    fn build(&self, input: impl AsRef<str> + Into<String>) -> Result<Self::SearchQuery, Self::Error> {
+        self.validate(input.as_ref())?;
+
+        Ok(SearchQuery::from_raw(input))
+    }
+
+    fn build_with_normalize(&self, input: impl AsRef<str> + Into<String>) -> Result<Self::SearchQuery, Self::Error> {
+        if self.validate(input.as_ref()).is_ok() {
+            return Ok(SearchQuery::from_raw(input));
+        }
+
+        let normalized_input = self.normalize(input.as_ref())?;
+        self.build(normalized_input)
+    }
+}
+
+/// Whether the input is trimmed, has only single spaces between words and no control characters.
+pub fn validate_search_query_normalized(input: &str) -> bool {
+    let mut previous_space = true;
+    for ch in input.chars() {
+        if ch == ' ' {
+            if previous_space {
+                return false;
+            }
+            previous_space = true;
+        } else if ch.is_whitespace() || ch.is_control() {
+            return false;
+        } else {
+            previous_space = false;
+        }
+    }
+    input.is_empty() || !previous_space
+}
+
+/// Drops control characters and collapses any run of whitespace into a single space.
+pub fn normalize_search_query(input: &str) -> String {
+    let mut normalized = String::with_capacity(input.len());
+    for word in input.split(char::is_whitespace) {
+        let mut word = word.chars().filter(|ch| !ch.is_control()).peekable();
+        if word.peek().is_none() {
+            continue;
+        }
+        if !normalized.is_empty() {
+            normalized.push(' ');
+        }
+        normalized.extend(word);
+    }
+    normalized
+}
+
+pub fn tokenize_search_query(input: &str) -> Vec<SearchTerm<'_>> {
+    let mut terms = Vec::new();
+    let mut rest = input;
+    loop {
+        rest = rest.trim_start();
+        if rest.is_empty() {
+            break;
+        }
+
+        let (term, remaining) = if let Some(quoted) = rest.strip_prefix('"') {
+            let end = quoted.find('"').unwrap_or(quoted.len());
+            let phrase = quoted[..end].trim();
+            (
+                (!phrase.is_empty()).then_some(SearchTerm::Phrase(phrase)),
+                quoted.get(end + 1..).unwrap_or(""),
+            )
+        } else {
+            let end = rest
+                .find(|ch: char| ch.is_whitespace() || ch == '"')
+                .unwrap_or(rest.len());
+            (Some(SearchTerm::Word(&rest[..end])), &rest[end..])
+        };
+        terms.extend(term);
+        rest = remaining;
+    }
+    terms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_search_query() {
+        assert_eq!(
+            normalize_search_query("  rust   web\tframework \n"),
+            "rust web framework"
+        );
+        assert_eq!(normalize_search_query("ru\u{0}st\u{7}"), "rust");
+        assert_eq!(normalize_search_query("\u{1b} \u{1b}"), "");
+        assert_eq!(normalize_search_query("café\u{a0}menu"), "café menu");
+
+        assert!(validate_search_query_normalized("rust web"));
+        assert!(validate_search_query_normalized(""));
+        assert!(!validate_search_query_normalized(" rust"));
+        assert!(!validate_search_query_normalized("rust  web"));
+        assert!(!validate_search_query_normalized("rust\tweb"));
+        assert!(!validate_search_query_normalized("rust\u{0}"));
+    }
+
+    #[test]
+    fn test_build_search_query() {
+        let builder = DefaultSearchQueryBuilder::new().with_max_length(10);
+
+        assert_eq!(builder.build("rust web").unwrap().as_str(), "rust web");
+        assert_eq!(builder.build(" rust"), Err(SearchQueryError::Unnormalized));
+        assert_eq!(
+            builder.build_with_normalize("  rust \t web ").unwrap().as_str(),
+            "rust web"
+        );
+        assert_eq!(
+            builder.build_with_normalize("rust web framework").unwrap().as_str(),
+            "rust web"
+        );
+        assert_eq!(
+            builder.build_with_normalize("żółć żółć żółć").unwrap().as_str(),
+            "żółć żółć"
+        );
+        assert!(matches!(
+            builder.build_with_normalize(" \u{7} "),
+            Err(SearchQueryError::InvalidLength(_))
+        ));
+    }
+
+    #[test]
+    fn test_tokenize_search_query() {
+        let query = SearchQuery::from_raw(r#"rust "web framework" async"#);
+        assert_eq!(query.terms(), [
+            SearchTerm::Word("rust"),
+            SearchTerm::Phrase("web framework"),
+            SearchTerm::Word("async")
+        ]);
+
+        assert_eq!(tokenize_search_query(r#"a"b c"d"#), [
+            SearchTerm::Word("a"),
+            SearchTerm::Phrase("b c"),
+            SearchTerm::Word("d")
+        ]);
+        assert_eq!(tokenize_search_query(r#"rust "web frame"#), [
+            SearchTerm::Word("rust"),
+            SearchTerm::Phrase("web frame")
+        ]);
+        assert_eq!(tokenize_search_query(r#""" " " x"#), [SearchTerm::Word("x")]);
+        assert!(tokenize_search_query("").is_empty());
+    }
+}
@@ -0,0 +1,66 @@
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// 2024-01-01T00:00:00Z, the default start of a [`FakeClock`].
+pub const FIXED_UNIX_SECONDS: u64 = 1_704_067_200;
+
+/// A manually advanced clock for APIs that take the current time as a parameter, e.g.
+/// `CsrfProtection::verify_at` or `TimeView::render`.
+#[derive(Debug)]
+pub struct FakeClock {
+    now: Mutex<SystemTime>,
+}
+
+impl FakeClock {
+    pub fn new(now: SystemTime) -> Self {
+        Self { now: Mutex::new(now) }
+    }
+
+    pub fn at_unix_seconds(seconds: u64) -> Self {
+        Self::new(UNIX_EPOCH + Duration::from_secs(seconds))
+    }
+
+    pub fn now(&self) -> SystemTime {
+        *self.now.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub fn set(&self, now: SystemTime) {
+        *self.now.lock().unwrap_or_else(PoisonError::into_inner) = now;
+    }
+
+    /// Moves the clock forward and returns the new time.
+    pub fn advance(&self, duration: Duration) -> SystemTime {
+        let mut now = self.now.lock().unwrap_or_else(PoisonError::into_inner);
+        *now += duration;
+        *now
+    }
+
+    pub fn ago(&self, duration: Duration) -> SystemTime {
+        self.now() - duration
+    }
+}
+
+impl Default for FakeClock {
+    fn default() -> Self {
+        Self::at_unix_seconds(FIXED_UNIX_SECONDS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fake_clock() {
+        let clock = FakeClock::default();
+        let start = clock.now();
+        assert_eq!(start.duration_since(UNIX_EPOCH).unwrap().as_secs(), FIXED_UNIX_SECONDS);
+        assert_eq!(clock.now(), start);
+
+        assert_eq!(clock.advance(Duration::from_secs(90)), start + Duration::from_secs(90));
+        assert_eq!(clock.ago(Duration::from_secs(90)), start);
+
+        clock.set(UNIX_EPOCH);
+        assert_eq!(clock.now(), UNIX_EPOCH);
+    }
+}
@@ -0,0 +1,148 @@
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+use zabawa_name::{DefaultNameBuilder, Name, NameBulder};
+use zabawa_notification_domain::logic::InMemoryNotificationStore;
+use zabawa_notification_domain::model::{Notification, NotificationLevel};
+use zabawa_validation::ValidationErrors;
+
+pub const ALL_NOTIFICATION_LEVELS: [NotificationLevel; 5] = [
+    NotificationLevel::Error,
+    NotificationLevel::Warning,
+    NotificationLevel::Success,
+    NotificationLevel::Info,
+    NotificationLevel::Note,
+];
+
+#[derive(Debug, Clone)]
+pub struct NotificationFixture {
+    pub level: NotificationLevel,
+    pub message: String,
+    pub age: Duration,
+}
+
+impl NotificationFixture {
+    pub fn new() -> Self {
+        Self {
+            level: NotificationLevel::Info,
+            message: "Test notification".to_owned(),
+            age: Duration::ZERO,
+        }
+    }
+
+    pub fn with_level(mut self, level: NotificationLevel) -> Self {
+        self.level = level;
+        self
+    }
+
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+
+    /// Backdates `creation_time`, e.g. to test expiry of flash messages.
+    pub fn with_age(mut self, age: Duration) -> Self {
+        self.age = age;
+        self
+    }
+
+    pub fn build(&self) -> Notification {
+        let mut notification = Notification::new(self.level, self.message.clone());
+        notification.creation_time = Instant::now()
+            .checked_sub(self.age)
+            .unwrap_or(notification.creation_time);
+        notification
+    }
+}
+
+impl Default for NotificationFixture {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// One notification per level, in [`ALL_NOTIFICATION_LEVELS`] order, with messages like `"Error notification"`.
+pub fn notification_per_level() -> Vec<Notification> {
+    ALL_NOTIFICATION_LEVELS
+        .iter()
+        .map(|&level| {
+            NotificationFixture::new()
+                .with_level(level)
+                .with_message(format!("{level:?} notification"))
+                .build()
+        })
+        .collect()
+}
+
+pub fn notification_store(notifications: impl IntoIterator<Item = Notification>) -> InMemoryNotificationStore {
+    InMemoryNotificationStore::with_notifications(notifications)
+}
+
+/// A valid [`Name`]; panics with the validation error when `input` is not one.
+#[track_caller]
+pub fn name(input: &str) -> Name {
+    match DefaultNameBuilder::new().build(input) {
+        Ok(name) => name,
+        Err(error) => panic!("invalid fixture name {input:?}: {error}"),
+    }
+}
+
+/// A [`Name`] built from arbitrary input through the default normalizer.
+#[track_caller]
+pub fn normalized_name(input: &str) -> Name {
+    match DefaultNameBuilder::new().build_with_normalize(input) {
+        Ok(name) => name,
+        Err(error) => panic!("fixture name {input:?} does not normalize to a valid name: {error}"),
+    }
+}
+
+pub fn validation_errors<F: Into<String>, M: Display>(errors: impl IntoIterator<Item = (F, M)>) -> ValidationErrors {
+    let mut validation_errors = ValidationErrors::new();
+    for (field, message) in errors {
+        validation_errors.add(field, message);
+    }
+    validation_errors
+}
+
+#[cfg(test)]
+mod tests {
+    use zabawa_notification_domain::logic::NotificationStore;
+
+    use super::*;
+
+    #[test]
+    fn test_notification_fixtures() {
+        let notification = NotificationFixture::new()
+            .with_level(NotificationLevel::Warning)
+            .with_message("Disk almost full")
+            .with_age(Duration::from_millis(50))
+            .build();
+        assert_eq!(notification.level, NotificationLevel::Warning);
+        assert_eq!(notification.message, "Disk almost full");
+        assert!(notification.creation_time.elapsed() >= Duration::from_millis(50));
+
+        let store = notification_store(notification_per_level());
+        assert_eq!(store.len(), ALL_NOTIFICATION_LEVELS.len());
+        assert_eq!(store.drain()[0].message, "Error notification");
+    }
+
+    #[test]
+    fn test_name_fixtures() {
+        assert_eq!(name("my-project").as_str(), "my-project");
+        assert_eq!(normalized_name("My Project").as_str(), "my-project");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid fixture name \"My Project\"")]
+    fn test_name_fixture_panics() {
+        name("My Project");
+    }
+
+    #[test]
+    fn test_validation_errors() {
+        let errors = validation_errors([("email", "is required"), ("name", "is too short")]);
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors.field("email").collect::<Vec<_>>(), ["is required"]);
+        assert!(validation_errors::<&str, &str>([]).is_empty());
+    }
+}
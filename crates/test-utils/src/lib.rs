@@ -0,0 +1,9 @@
+pub mod clock;
+pub mod fixtures;
+pub mod render;
+
+pub use zabawa_view_common::testing::*;
+
+pub use self::clock::*;
+pub use self::fixtures::*;
+pub use self::render::*;
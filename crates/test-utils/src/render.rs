@@ -0,0 +1,67 @@
+use hypertext::Renderable;
+use zabawa_view_common::testing::Document;
+use zabawa_view_common::{Children, Component, RenderContext};
+
+/// Renders markup against a fixed [`RenderContext`] and parses the result for selector assertions.
+#[derive(Debug, Default)]
+pub struct RenderHarness {
+    pub ctx: RenderContext,
+}
+
+impl RenderHarness {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_context(mut self, ctx: RenderContext) -> Self {
+        self.ctx = ctx;
+        self
+    }
+
+    pub fn html(&self, renderable: impl Renderable) -> String {
+        renderable.render().into_inner()
+    }
+
+    pub fn component<C: Component>(&self, component: &C, props: C::Props<'_>) -> String {
+        self.html(component.render(props, &self.ctx))
+    }
+
+    /// Renders a component whose props are its children.
+    pub fn component_with_children<C>(&self, component: &C, children: impl Renderable) -> String
+    where
+        C: for<'p> Component<Props<'p> = Children<'p>>,
+    {
+        self.component(component, Children::new(&children))
+    }
+
+    pub fn document(&self, renderable: impl Renderable) -> Document {
+        Document::parse(&self.html(renderable))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zabawa_view_common::{Animation, Callout, Variant};
+
+    use super::*;
+    use crate::assert_has_selector;
+
+    #[test]
+    fn test_render_harness() {
+        let harness = RenderHarness::new().with_context(RenderContext::new().with_nonce("abc"));
+
+        let html = harness.component_with_children(&Animation::new("fadeIn", 200, 1), "hello");
+        assert_has_selector(&html, "wa-animation[name=fadeIn]");
+
+        let html = harness.component_with_children(
+            &Callout::new(Variant::Danger)
+                .with_dismissible(true)
+                .with_dismiss_script("close()"),
+            "Oops",
+        );
+        assert_has_selector(&html, "script[nonce=abc]");
+
+        let document = harness.document(Animation::new("fadeOut", 100, 2).render("bye"));
+        assert_eq!(document.select("wa-animation[iterations=\"2\"]").len(), 1);
+    }
+}
@@ -0,0 +1,101 @@
+use std::fmt;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("invalid length: expected a non-negative number with a px, rem, em or % unit")]
+pub struct InvalidLengthError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LengthUnit {
+    Px,
+    Rem,
+    Em,
+    Percent,
+}
+
+impl LengthUnit {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LengthUnit::Px => "px",
+            LengthUnit::Rem => "rem",
+            LengthUnit::Em => "em",
+            LengthUnit::Percent => "%",
+        }
+    }
+}
+
+/// A CSS length such as `0.75rem`. Only a number and a known unit are kept, so a length can never inject CSS.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Length {
+    pub value: f32,
+    pub unit: LengthUnit,
+}
+
+impl Length {
+    pub const fn px(value: f32) -> Self {
+        Self {
+            value,
+            unit: LengthUnit::Px,
+        }
+    }
+
+    pub const fn rem(value: f32) -> Self {
+        Self {
+            value,
+            unit: LengthUnit::Rem,
+        }
+    }
+}
+
+impl fmt::Display for Length {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.value, self.unit.as_str())
+    }
+}
+
+impl FromStr for Length {
+    type Err = InvalidLengthError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = input.trim();
+        let split = input
+            .find(|ch: char| !ch.is_ascii_digit() && ch != '.')
+            .unwrap_or(input.len());
+        let (number, unit) = input.split_at(split);
+        let unit = match unit.to_ascii_lowercase().as_str() {
+            "px" => LengthUnit::Px,
+            "rem" => LengthUnit::Rem,
+            "em" => LengthUnit::Em,
+            "%" => LengthUnit::Percent,
+            "" if number.parse::<f32>() == Ok(0.0) => LengthUnit::Px,
+            _ => return Err(InvalidLengthError),
+        };
+        let value: f32 = number.parse().map_err(|_| InvalidLengthError)?;
+        if !value.is_finite() {
+            return Err(InvalidLengthError);
+        }
+        Ok(Self { value, unit })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_length() {
+        assert_eq!("0.75rem".parse(), Ok(Length::rem(0.75)));
+        assert_eq!(" 12PX ".parse(), Ok(Length::px(12.0)));
+        assert_eq!("0".parse(), Ok(Length::px(0.0)));
+        assert_eq!(Length::rem(0.75).to_string(), "0.75rem");
+        assert_eq!("50%".parse::<Length>().unwrap().to_string(), "50%");
+
+        assert_eq!("12".parse::<Length>(), Err(InvalidLengthError));
+        assert_eq!("-1px".parse::<Length>(), Err(InvalidLengthError));
+        assert_eq!("1vh".parse::<Length>(), Err(InvalidLengthError));
+        assert_eq!("1px;}body{".parse::<Length>(), Err(InvalidLengthError));
+        assert_eq!("px".parse::<Length>(), Err(InvalidLengthError));
+    }
+}
@@ -0,0 +1,7 @@
+pub mod length;
+pub mod theme;
+pub mod token;
+
+pub use self::length::*;
+pub use self::theme::*;
+pub use self::token::*;
@@ -0,0 +1,177 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+
+use thiserror::Error;
+use zabawa_color::{Color, ColorError};
+
+use crate::{ColorRole, InvalidLengthError, Length, Scale, TokenSize};
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ThemeError {
+    #[error("line {line}: expected `key = value`")]
+    InvalidLine { line: usize },
+
+    #[error("line {line}: unknown token {key:?}")]
+    UnknownToken { line: usize, key: String },
+
+    #[error("line {line}: {error}")]
+    InvalidColor { line: usize, error: ColorError },
+
+    #[error("line {line}: {error}")]
+    InvalidLength { line: usize, error: InvalidLengthError },
+}
+
+/// Design token overrides, e.g. a tenant's brand. Tokens that are not set keep the Web Awesome defaults.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Theme {
+    pub colors: BTreeMap<ColorRole, Color>,
+    pub scales: BTreeMap<(Scale, TokenSize), Length>,
+}
+
+impl Theme {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_color(mut self, role: ColorRole, color: Color) -> Self {
+        self.colors.insert(role, color);
+        self
+    }
+
+    pub fn with_space(self, size: TokenSize, length: Length) -> Self {
+        self.with_token(Scale::Space, size, length)
+    }
+
+    pub fn with_radius(self, size: TokenSize, length: Length) -> Self {
+        self.with_token(Scale::Radius, size, length)
+    }
+
+    pub fn with_font_size(self, size: TokenSize, length: Length) -> Self {
+        self.with_token(Scale::FontSize, size, length)
+    }
+
+    pub fn with_token(mut self, scale: Scale, size: TokenSize, length: Length) -> Self {
+        self.scales.insert((scale, size), length);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty() && self.scales.is_empty()
+    }
+
+    /// Parses the format written by `Display`: one `key = value` per line, such as `color.brand = #4f46e5` or
+    /// `space.m = 1rem`. Blank lines and lines starting with `//` are skipped.
+    pub fn parse(input: &str) -> Result<Self, ThemeError> {
+        let mut theme = Theme::new();
+        for (index, line) in input.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with("//") {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or(ThemeError::InvalidLine { line: line_number })?;
+            let (key, value) = (key.trim(), value.trim());
+            let unknown = || ThemeError::UnknownToken {
+                line: line_number,
+                key: key.to_owned(),
+            };
+
+            let (group, name) = key.split_once('.').ok_or_else(unknown)?;
+            if group == "color" {
+                let role = name.parse().map_err(|_| unknown())?;
+                let color = Color::parse(value).map_err(|error| ThemeError::InvalidColor {
+                    line: line_number,
+                    error,
+                })?;
+                theme.colors.insert(role, color);
+            } else {
+                let scale = group.parse().map_err(|_| unknown())?;
+                let size = name.parse().map_err(|_| unknown())?;
+                let length = value.parse().map_err(|error| ThemeError::InvalidLength {
+                    line: line_number,
+                    error,
+                })?;
+                theme.scales.insert((scale, size), length);
+            }
+        }
+        Ok(theme)
+    }
+
+    /// A `:root` rule setting the Web Awesome custom properties. Each color also sets its `on-loud` text color to
+    /// black or white, whichever contrasts more.
+    pub fn to_css(&self) -> String {
+        let mut css = String::from(":root {\n");
+        for (role, color) in &self.colors {
+            let _ = writeln!(css, "  --wa-color-{role}-fill-loud: {color};");
+            let _ = writeln!(css, "  --wa-color-{role}-on-loud: {};", color.contrasting_text());
+        }
+        for ((scale, size), length) in &self.scales {
+            let _ = writeln!(css, "  {}: {length};", scale.css_property(*size));
+        }
+        css.push('}');
+        css
+    }
+}
+
+impl fmt::Display for Theme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (role, color) in &self.colors {
+            writeln!(f, "color.{role} = {color}")?;
+        }
+        for ((scale, size), length) in &self.scales {
+            writeln!(f, "{}.{size} = {length}", scale.as_str())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_css() {
+        let theme = Theme::new()
+            .with_color(ColorRole::Brand, Color::rgb(0x4f, 0x46, 0xe5))
+            .with_space(TokenSize::M, Length::rem(1.25))
+            .with_radius(TokenSize::S, Length::px(2.0));
+        assert_eq!(
+            theme.to_css(),
+            ":root {\n  --wa-color-brand-fill-loud: #4f46e5;\n  --wa-color-brand-on-loud: #ffffff;\n  \
+             --wa-space-m: 1.25rem;\n  --wa-border-radius-s: 2px;\n}"
+        );
+        assert_eq!(Theme::new().to_css(), ":root {\n}");
+    }
+
+    #[test]
+    fn test_parse() {
+        let input = "// Acme\ncolor.brand = #FFCC00\n\ncolor.danger = rgb(200 0 0)\nfont-size.2xl = 2rem\n";
+        let theme = Theme::parse(input).unwrap();
+        assert_eq!(theme.colors[&ColorRole::Brand], Color::rgb(255, 204, 0));
+        assert_eq!(theme.scales[&(Scale::FontSize, TokenSize::TwoXl)], Length::rem(2.0));
+        assert_eq!(Theme::parse(&theme.to_string()), Ok(theme));
+
+        assert_eq!(Theme::parse("color.brand"), Err(ThemeError::InvalidLine { line: 1 }));
+        assert_eq!(
+            Theme::parse("\ncolor.primary = red"),
+            Err(ThemeError::UnknownToken {
+                line: 2,
+                key: "color.primary".to_owned()
+            })
+        );
+        assert!(matches!(
+            Theme::parse("space.huge = 1rem"),
+            Err(ThemeError::UnknownToken { .. })
+        ));
+        assert!(matches!(
+            Theme::parse("color.brand = #zzz"),
+            Err(ThemeError::InvalidColor { line: 1, .. })
+        ));
+        assert!(matches!(
+            Theme::parse("space.m = 1rem; } body { display: none"),
+            Err(ThemeError::InvalidLength { line: 1, .. })
+        ));
+    }
+}
@@ -0,0 +1,154 @@
+use std::fmt;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("unknown design token")]
+pub struct UnknownTokenError;
+
+/// The semantic color groups of the Web Awesome palette.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ColorRole {
+    Brand,
+    Neutral,
+    Success,
+    Warning,
+    Danger,
+}
+
+impl ColorRole {
+    pub const ALL: [ColorRole; 5] = [
+        ColorRole::Brand,
+        ColorRole::Neutral,
+        ColorRole::Success,
+        ColorRole::Warning,
+        ColorRole::Danger,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ColorRole::Brand => "brand",
+            ColorRole::Neutral => "neutral",
+            ColorRole::Success => "success",
+            ColorRole::Warning => "warning",
+            ColorRole::Danger => "danger",
+        }
+    }
+}
+
+impl fmt::Display for ColorRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ColorRole {
+    type Err = UnknownTokenError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|role| role.as_str() == input)
+            .ok_or(UnknownTokenError)
+    }
+}
+
+/// A step on the spacing, radius and font-size scales, named like the Web Awesome tokens.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TokenSize {
+    ThreeXs,
+    TwoXs,
+    Xs,
+    S,
+    M,
+    L,
+    Xl,
+    TwoXl,
+    ThreeXl,
+}
+
+impl TokenSize {
+    pub const ALL: [TokenSize; 9] = [
+        TokenSize::ThreeXs,
+        TokenSize::TwoXs,
+        TokenSize::Xs,
+        TokenSize::S,
+        TokenSize::M,
+        TokenSize::L,
+        TokenSize::Xl,
+        TokenSize::TwoXl,
+        TokenSize::ThreeXl,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TokenSize::ThreeXs => "3xs",
+            TokenSize::TwoXs => "2xs",
+            TokenSize::Xs => "xs",
+            TokenSize::S => "s",
+            TokenSize::M => "m",
+            TokenSize::L => "l",
+            TokenSize::Xl => "xl",
+            TokenSize::TwoXl => "2xl",
+            TokenSize::ThreeXl => "3xl",
+        }
+    }
+}
+
+impl fmt::Display for TokenSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for TokenSize {
+    type Err = UnknownTokenError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|size| size.as_str() == input)
+            .ok_or(UnknownTokenError)
+    }
+}
+
+/// The scales a [`TokenSize`] can be set on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Scale {
+    Space,
+    Radius,
+    FontSize,
+}
+
+impl Scale {
+    pub const ALL: [Scale; 3] = [Scale::Space, Scale::Radius, Scale::FontSize];
+
+    /// The key prefix used by [`crate::Theme::parse`].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Scale::Space => "space",
+            Scale::Radius => "radius",
+            Scale::FontSize => "font-size",
+        }
+    }
+
+    pub fn css_property(&self, size: TokenSize) -> String {
+        match self {
+            Scale::Space => format!("--wa-space-{size}"),
+            Scale::Radius => format!("--wa-border-radius-{size}"),
+            Scale::FontSize => format!("--wa-font-size-{size}"),
+        }
+    }
+}
+
+impl FromStr for Scale {
+    type Err = UnknownTokenError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|scale| scale.as_str() == input)
+            .ok_or(UnknownTokenError)
+    }
+}
@@ -0,0 +1,121 @@
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A calendar date and wall-clock time at a fixed UTC offset, derived from a `SystemTime`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CivilDateTime {
+    pub year: i64,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    pub utc_offset_minutes: i32,
+}
+
+impl CivilDateTime {
+    pub fn from_system_time(time: SystemTime, utc_offset_minutes: i32) -> Self {
+        Self::from_unix_seconds(unix_seconds(time), utc_offset_minutes)
+    }
+
+    pub fn from_unix_seconds(seconds: i64, utc_offset_minutes: i32) -> Self {
+        let local = seconds + i64::from(utc_offset_minutes) * 60;
+        let (days, seconds_of_day) = (local.div_euclid(86_400), local.rem_euclid(86_400));
+        let (year, month, day) = civil_from_days(days);
+        Self {
+            year,
+            month,
+            day,
+            hour: (seconds_of_day / 3600) as u8,
+            minute: (seconds_of_day / 60 % 60) as u8,
+            second: (seconds_of_day % 60) as u8,
+            utc_offset_minutes,
+        }
+    }
+
+    /// RFC 3339, e.g. `2026-10-16T15:04:05Z` or `2026-10-16T17:04:05+02:00`, for `<time datetime>`.
+    pub fn to_rfc3339(&self) -> String {
+        format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}{}",
+            self.year,
+            self.month,
+            self.day,
+            self.hour,
+            self.minute,
+            self.second,
+            UtcOffset(self.utc_offset_minutes)
+        )
+    }
+}
+
+struct UtcOffset(i32);
+
+impl fmt::Display for UtcOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0 == 0 {
+            return f.write_str("Z");
+        }
+        let sign = if self.0 < 0 { '-' } else { '+' };
+        let minutes = self.0.unsigned_abs();
+        write!(f, "{sign}{:02}:{:02}", minutes / 60, minutes % 60)
+    }
+}
+
+/// Seconds since the Unix epoch, negative for earlier times.
+pub fn unix_seconds(time: SystemTime) -> i64 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_secs() as i64,
+        Err(error) => -(error.duration().as_secs_f64().ceil() as i64),
+    }
+}
+
+// Howard Hinnant's `civil_from_days`: proleptic Gregorian calendar from days since 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u8;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    } as u8;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_from_system_time() {
+        let time = UNIX_EPOCH + Duration::from_secs(1_792_163_045);
+        let utc = CivilDateTime::from_system_time(time, 0);
+        assert_eq!((utc.year, utc.month, utc.day), (2026, 10, 16));
+        assert_eq!((utc.hour, utc.minute, utc.second), (15, 4, 5));
+        assert_eq!(utc.to_rfc3339(), "2026-10-16T15:04:05Z");
+
+        assert_eq!(
+            CivilDateTime::from_system_time(time, 10 * 60).to_rfc3339(),
+            "2026-10-17T01:04:05+10:00"
+        );
+        assert_eq!(
+            CivilDateTime::from_system_time(time, -(3 * 60 + 30)).to_rfc3339(),
+            "2026-10-16T11:34:05-03:30"
+        );
+        assert_eq!(
+            CivilDateTime::from_unix_seconds(951_782_400, 0).to_rfc3339(),
+            "2000-02-29T00:00:00Z"
+        );
+        assert_eq!(
+            CivilDateTime::from_unix_seconds(-1, 0).to_rfc3339(),
+            "1969-12-31T23:59:59Z"
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use std::time::{Duration, SystemTime};
+
+use crate::{CivilDateTime, unix_seconds};
+
+const MINUTE: u64 = 60;
+const HOUR: u64 = 60 * MINUTE;
+const DAY: u64 = 24 * HOUR;
+const MONTH: u64 = 30 * DAY;
+const YEAR: u64 = 365 * DAY;
+
+const MONTH_ABBREVIATIONS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DateTimeStyle {
+    Date,
+    Time,
+    #[default]
+    DateTime,
+}
+
+/// Formats `time` relative to `now`, e.g. `3 hours ago`, `in 2 days` or `just now`.
+pub fn format_relative(time: SystemTime, now: SystemTime) -> String {
+    let difference = unix_seconds(now) - unix_seconds(time);
+    let phrase = format_span(difference.unsigned_abs());
+    match phrase {
+        None => "just now".to_owned(),
+        Some(phrase) if difference >= 0 => format!("{phrase} ago"),
+        Some(phrase) => format!("in {phrase}"),
+    }
+}
+
+/// Formats how long ago something happened, for monotonic timestamps such as `Instant::elapsed()`.
+pub fn format_elapsed(elapsed: Duration) -> String {
+    match format_span(elapsed.as_secs()) {
+        Some(phrase) => format!("{phrase} ago"),
+        None => "just now".to_owned(),
+    }
+}
+
+fn format_span(seconds: u64) -> Option<String> {
+    let (count, unit) = match seconds {
+        0..45 => return None,
+        45..HOUR => (seconds.div_ceil(MINUTE).max(1), "minute"),
+        HOUR..DAY => (seconds / HOUR, "hour"),
+        DAY..MONTH => (seconds / DAY, "day"),
+        MONTH..YEAR => (seconds / MONTH, "month"),
+        _ => (seconds / YEAR, "year"),
+    };
+    let plural = if count == 1 { "" } else { "s" };
+    Some(format!("{count} {unit}{plural}"))
+}
+
+/// Formats a date and/or time following the conventions of a BCP 47 `locale` such as `en-US`, `de` or `fr-CA`.
+///
+/// Unknown locales fall back to ISO 8601 dates with a 24-hour clock.
+pub fn format_absolute(datetime: &CivilDateTime, locale: &str, style: DateTimeStyle) -> String {
+    let convention = DateConvention::for_locale(locale);
+    match style {
+        DateTimeStyle::Date => convention.format_date(datetime),
+        DateTimeStyle::Time => convention.format_time(datetime),
+        DateTimeStyle::DateTime => format!(
+            "{}{}{}",
+            convention.format_date(datetime),
+            convention.separator(),
+            convention.format_time(datetime)
+        ),
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DateConvention {
+    /// `Oct 16, 2026, 3:04 PM`
+    UnitedStates,
+    /// `16 Oct 2026, 15:04`
+    English,
+    /// `16.10.2026, 15:04`
+    Dotted,
+    /// `16/10/2026 15:04`
+    Slashed,
+    /// `2026/10/16 15:04`
+    YearFirst,
+    /// `2026-10-16 15:04`
+    Iso,
+}
+
+impl DateConvention {
+    fn for_locale(locale: &str) -> Self {
+        let mut subtags = locale.split(['-', '_']);
+        let language = subtags.next().unwrap_or("").to_ascii_lowercase();
+        let region = subtags.find(|subtag| subtag.len() == 2).map(str::to_ascii_uppercase);
+        match (language.as_str(), region.as_deref()) {
+            ("en", None | Some("US")) => Self::UnitedStates,
+            ("en", _) => Self::English,
+            ("de" | "pl" | "cs" | "sk" | "ru" | "uk" | "fi" | "nb" | "no" | "da" | "tr" | "ro", _) => Self::Dotted,
+            ("fr" | "es" | "it" | "pt" | "nl" | "el", _) => Self::Slashed,
+            ("ja" | "zh" | "ko", _) => Self::YearFirst,
+            _ => Self::Iso,
+        }
+    }
+
+    fn format_date(self, datetime: &CivilDateTime) -> String {
+        let CivilDateTime { year, month, day, .. } = *datetime;
+        let month_name = MONTH_ABBREVIATIONS[usize::from(month.clamp(1, 12)) - 1];
+        match self {
+            Self::UnitedStates => format!("{month_name} {day}, {year}"),
+            Self::English => format!("{day} {month_name} {year}"),
+            Self::Dotted => format!("{day:02}.{month:02}.{year}"),
+            Self::Slashed => format!("{day:02}/{month:02}/{year}"),
+            Self::YearFirst => format!("{year}/{month:02}/{day:02}"),
+            Self::Iso => format!("{year:04}-{month:02}-{day:02}"),
+        }
+    }
+
+    fn format_time(self, datetime: &CivilDateTime) -> String {
+        let CivilDateTime { hour, minute, .. } = *datetime;
+        match self {
+            Self::UnitedStates => {
+                let period = if hour < 12 { "AM" } else { "PM" };
+                let hour = match hour % 12 {
+                    0 => 12,
+                    hour => hour,
+                };
+                format!("{hour}:{minute:02} {period}")
+            },
+            _ => format!("{hour:02}:{minute:02}"),
+        }
+    }
+
+    fn separator(self) -> &'static str {
+        match self {
+            Self::UnitedStates | Self::English | Self::Dotted => ", ",
+            Self::Slashed | Self::YearFirst | Self::Iso => " ",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::UNIX_EPOCH;
+
+    use super::*;
+
+    #[test]
+    fn test_format_relative() {
+        let now = UNIX_EPOCH + Duration::from_secs(1_792_163_045);
+        let ago = |seconds| format_relative(now - Duration::from_secs(seconds), now);
+
+        assert_eq!(ago(0), "just now");
+        assert_eq!(ago(44), "just now");
+        assert_eq!(ago(45), "1 minute ago");
+        assert_eq!(ago(5 * MINUTE), "5 minutes ago");
+        assert_eq!(ago(3 * HOUR + 59 * MINUTE), "3 hours ago");
+        assert_eq!(ago(DAY), "1 day ago");
+        assert_eq!(ago(40 * DAY), "1 month ago");
+        assert_eq!(ago(800 * DAY), "2 years ago");
+        assert_eq!(format_relative(now + Duration::from_secs(2 * DAY), now), "in 2 days");
+        assert_eq!(format_elapsed(Duration::from_secs(HOUR)), "1 hour ago");
+    }
+
+    #[test]
+    fn test_format_absolute() {
+        let morning = CivilDateTime::from_unix_seconds(1_792_134_245, 0);
+        let afternoon = CivilDateTime::from_unix_seconds(1_792_163_045, 0);
+        let format = |datetime, locale| format_absolute(datetime, locale, DateTimeStyle::DateTime);
+
+        assert_eq!(format(&afternoon, "en"), "Oct 16, 2026, 3:04 PM");
+        assert_eq!(format(&morning, "en-US"), "Oct 16, 2026, 7:04 AM");
+        assert_eq!(format(&afternoon, "en-GB"), "16 Oct 2026, 15:04");
+        assert_eq!(format(&afternoon, "pl-PL"), "16.10.2026, 15:04");
+        assert_eq!(format(&afternoon, "fr_CA"), "16/10/2026 15:04");
+        assert_eq!(format(&afternoon, "ja"), "2026/10/16 15:04");
+        assert_eq!(format(&afternoon, "sv"), "2026-10-16 15:04");
+        assert_eq!(format_absolute(&morning, "de", DateTimeStyle::Date), "16.10.2026");
+        assert_eq!(format_absolute(&morning, "en", DateTimeStyle::Time), "7:04 AM");
+    }
+}
@@ -0,0 +1,7 @@
+pub mod datetime;
+pub mod format;
+pub mod time;
+
+pub use self::datetime::*;
+pub use self::format::*;
+pub use self::time::*;
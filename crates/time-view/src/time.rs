@@ -0,0 +1,107 @@
+use std::time::SystemTime;
+
+use hypertext::prelude::GlobalAttributes;
+use hypertext::{Renderable, rsx};
+use zabawa_view_common::{Attrs, Component, RenderContext, hypertext_elements};
+
+use crate::{CivilDateTime, DateTimeStyle, format_absolute, format_relative};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TimeFormat {
+    #[default]
+    Relative,
+    Absolute(DateTimeStyle),
+}
+
+/// A `<time>` element with a machine-readable `datetime` and the full date and time as its tooltip.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TimeView {
+    pub format: TimeFormat,
+    /// Offset of the viewer's time zone, used for absolute formats.
+    pub utc_offset_minutes: i32,
+    pub attrs: Attrs,
+}
+
+impl TimeView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn relative() -> Self {
+        Self::new().with_format(TimeFormat::Relative)
+    }
+
+    pub fn absolute(style: DateTimeStyle) -> Self {
+        Self::new().with_format(TimeFormat::Absolute(style))
+    }
+
+    pub fn with_format(mut self, format: TimeFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    pub fn with_utc_offset_minutes(mut self, minutes: i32) -> Self {
+        self.utc_offset_minutes = minutes;
+        self
+    }
+
+    pub fn with_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs = attrs;
+        self
+    }
+
+    pub fn render<'a>(&'a self, ctx: &RenderContext, time: SystemTime, now: SystemTime) -> impl Renderable + 'a {
+        let local = CivilDateTime::from_system_time(time, self.utc_offset_minutes);
+        let datetime = CivilDateTime::from_system_time(time, 0).to_rfc3339();
+        let title = format_absolute(&local, &ctx.locale, DateTimeStyle::DateTime);
+        let text = match self.format {
+            TimeFormat::Relative => format_relative(time, now),
+            TimeFormat::Absolute(style) => format_absolute(&local, &ctx.locale, style),
+        };
+
+        self.attrs.spread(rsx! {
+            <time datetime=(datetime) title=(title)>(text)</time>
+        })
+    }
+}
+
+impl Component for TimeView {
+    type Props<'p> = SystemTime;
+
+    fn render<'p>(&self, time: Self::Props<'p>, ctx: &RenderContext) -> impl Renderable {
+        TimeView::render(self, ctx, time, SystemTime::now())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use zabawa_view_common::testing::assert_has_selector;
+
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let now = UNIX_EPOCH + Duration::from_secs(1_792_163_045);
+        let time = now - Duration::from_secs(3 * 3600);
+        let ctx = RenderContext::new().with_locale("en-GB");
+
+        let html = TimeView::relative().render(&ctx, time, now).render().into_inner();
+        assert_eq!(
+            html,
+            r#"<time datetime="2026-10-16T12:04:05Z" title="16 Oct 2026, 12:04">3 hours ago</time>"#
+        );
+
+        let html = TimeView::absolute(DateTimeStyle::Date)
+            .with_utc_offset_minutes(14 * 60)
+            .render(&ctx, time, now)
+            .render()
+            .into_inner();
+        assert_has_selector(
+            &html,
+            "time[datetime=\"2026-10-16T12:04:05Z\"][title=\"17 Oct 2026, 02:04\"]",
+        );
+        assert!(html.contains(">17 Oct 2026</time>"));
+    }
+}
@@ -0,0 +1,51 @@
+use derive_more::{Display, Into};
+use thiserror::Error;
+
+const SHA256_HEX_LENGTH: usize = 64;
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("invalid checksum: expected {SHA256_HEX_LENGTH} hex digits of a SHA-256 digest")]
+pub struct InvalidChecksumError;
+
+/// A SHA-256 digest as lowercase hex.
+#[derive(Clone, Debug, Display, Into, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Checksum(String);
+
+impl Checksum {
+    /// Accepts hex in either case, optionally prefixed with `sha256:`.
+    pub fn parse(input: &str) -> Result<Self, InvalidChecksumError> {
+        let hex = input.trim();
+        let hex = hex.strip_prefix("sha256:").unwrap_or(hex);
+        if hex.len() != SHA256_HEX_LENGTH || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(InvalidChecksumError);
+        }
+        Ok(Self(hex.to_ascii_lowercase()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Checksum {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_checksum() {
+        let hex = "E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855";
+        let checksum = Checksum::parse(hex).unwrap();
+        assert_eq!(checksum.as_str(), hex.to_ascii_lowercase());
+        assert_eq!(Checksum::parse(&format!("sha256:{checksum}")), Ok(checksum));
+
+        assert_eq!(Checksum::parse(&hex[1..]), Err(InvalidChecksumError));
+        assert_eq!(Checksum::parse(&hex.replace('E', "g")), Err(InvalidChecksumError));
+        assert_eq!(Checksum::parse(""), Err(InvalidChecksumError));
+    }
+}
@@ -0,0 +1,72 @@
+use derive_more::{Display, Into};
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("invalid content type: expected type/subtype")]
+pub struct InvalidContentTypeError;
+
+/// A declared MIME type without parameters, e.g. `image/png`.
+#[derive(Clone, Debug, Display, Into, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ContentType(String);
+
+impl ContentType {
+    /// Lowercases the type and drops parameters such as `; charset=utf-8`.
+    pub fn parse(input: &str) -> Result<Self, InvalidContentTypeError> {
+        let essence = input.split(';').next().unwrap_or_default().trim();
+        let (kind, subtype) = essence.split_once('/').ok_or(InvalidContentTypeError)?;
+        if !is_token(kind) || !is_token(subtype) {
+            return Err(InvalidContentTypeError);
+        }
+        Ok(Self(essence.to_ascii_lowercase()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn kind(&self) -> &str {
+        self.0.split_once('/').map_or(&self.0, |(kind, _)| kind)
+    }
+
+    /// Matches an exact type or a `type/*` wildcard.
+    pub fn matches(&self, pattern: &str) -> bool {
+        match pattern.strip_suffix("/*") {
+            Some(kind) => self.kind().eq_ignore_ascii_case(kind),
+            None => self.0.eq_ignore_ascii_case(pattern),
+        }
+    }
+}
+
+impl AsRef<str> for ContentType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+fn is_token(input: &str) -> bool {
+    !input.is_empty()
+        && input
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b"!#$&-^_.+".contains(&b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_content_type() {
+        let content_type = ContentType::parse("Text/HTML; charset=utf-8").unwrap();
+        assert_eq!(content_type.as_str(), "text/html");
+        assert_eq!(content_type.kind(), "text");
+        assert!(content_type.matches("text/*"));
+        assert!(content_type.matches("text/html"));
+        assert!(!content_type.matches("image/*"));
+        assert!(ContentType::parse("application/vnd.ms-excel").is_ok());
+
+        assert_eq!(ContentType::parse("image"), Err(InvalidContentTypeError));
+        assert_eq!(ContentType::parse("image/"), Err(InvalidContentTypeError));
+        assert_eq!(ContentType::parse("image/png/x"), Err(InvalidContentTypeError));
+        assert_eq!(ContentType::parse("ima ge/png"), Err(InvalidContentTypeError));
+    }
+}
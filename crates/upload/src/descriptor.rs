@@ -0,0 +1,189 @@
+use thiserror::Error;
+
+use crate::{
+    Checksum, ContentType, DEFAULT_MAX_FILENAME_LENGTH, EmptyFilenameError, FileName, InvalidChecksumError,
+    InvalidContentTypeError, sanitize_filename,
+};
+
+pub const DEFAULT_MAX_UPLOAD_SIZE: u64 = 10 * 1024 * 1024;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum UploadError {
+    #[error("upload error: {0}")]
+    EmptyFilename(#[from] EmptyFilenameError),
+
+    #[error("file is empty")]
+    EmptyFile,
+
+    #[error("file is too large: at most {max} bytes allowed, got {actual}")]
+    TooLarge { max: u64, actual: u64 },
+
+    #[error("upload error: {0}")]
+    InvalidContentType(#[from] InvalidContentTypeError),
+
+    #[error("content type {0} is not allowed")]
+    ContentTypeNotAllowed(String),
+
+    #[error("upload error: {0}")]
+    InvalidChecksum(#[from] InvalidChecksumError),
+}
+
+/// Metadata of an uploaded file, only obtainable through [`UploadPolicy::describe`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct UploadDescriptor {
+    filename: FileName,
+    size: u64,
+    content_type: ContentType,
+    checksum: Checksum,
+}
+
+impl UploadDescriptor {
+    pub fn filename(&self) -> &FileName {
+        &self.filename
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn content_type(&self) -> &ContentType {
+        &self.content_type
+    }
+
+    pub fn checksum(&self) -> &Checksum {
+        &self.checksum
+    }
+}
+
+#[derive(Debug)]
+pub struct UploadPolicy {
+    pub max_size: u64,
+    pub max_filename_length: usize,
+    /// Exact types or `type/*` wildcards; empty allows any type.
+    pub allowed_content_types: Vec<String>,
+}
+
+impl UploadPolicy {
+    pub fn new() -> Self {
+        Self {
+            max_size: DEFAULT_MAX_UPLOAD_SIZE,
+            max_filename_length: DEFAULT_MAX_FILENAME_LENGTH,
+            allowed_content_types: Vec::new(),
+        }
+    }
+
+    pub fn with_max_size(mut self, max: u64) -> Self {
+        self.max_size = max;
+        self
+    }
+
+    pub fn with_max_filename_length(mut self, max: usize) -> Self {
+        self.max_filename_length = max;
+        self
+    }
+
+    pub fn with_allowed_content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.allowed_content_types.push(content_type.into());
+        self
+    }
+
+    /// Validates what the client sent with a file and sanitizes its name.
+    pub fn describe(
+        &self,
+        filename: &str,
+        size: u64,
+        content_type: &str,
+        checksum: &str,
+    ) -> Result<UploadDescriptor, UploadError> {
+        if size == 0 {
+            return Err(UploadError::EmptyFile);
+        }
+        if size > self.max_size {
+            return Err(UploadError::TooLarge {
+                max: self.max_size,
+                actual: size,
+            });
+        }
+
+        let content_type = ContentType::parse(content_type)?;
+        if !self.allows(&content_type) {
+            return Err(UploadError::ContentTypeNotAllowed(content_type.into()));
+        }
+
+        Ok(UploadDescriptor {
+            filename: sanitize_filename(filename, self.max_filename_length)?,
+            size,
+            content_type,
+            checksum: Checksum::parse(checksum)?,
+        })
+    }
+
+    pub fn allows(&self, content_type: &ContentType) -> bool {
+        self.allowed_content_types.is_empty()
+            || self
+                .allowed_content_types
+                .iter()
+                .any(|pattern| content_type.matches(pattern))
+    }
+}
+
+impl Default for UploadPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHECKSUM: &str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+    #[test]
+    fn test_describe() {
+        let policy = UploadPolicy::new()
+            .with_max_size(1024)
+            .with_allowed_content_type("image/*")
+            .with_allowed_content_type("application/pdf");
+
+        let upload = policy.describe("My Cat.PNG", 512, "image/png", CHECKSUM).unwrap();
+        assert_eq!(upload.filename().as_str(), "my-cat.png");
+        assert_eq!(upload.size(), 512);
+        assert_eq!(upload.content_type().as_str(), "image/png");
+        assert_eq!(upload.checksum().as_str(), CHECKSUM);
+        assert!(policy.describe("a.pdf", 1, "application/pdf", CHECKSUM).is_ok());
+
+        assert_eq!(
+            policy.describe("a.png", 0, "image/png", CHECKSUM),
+            Err(UploadError::EmptyFile)
+        );
+        assert_eq!(
+            policy.describe("a.png", 2048, "image/png", CHECKSUM),
+            Err(UploadError::TooLarge {
+                max: 1024,
+                actual: 2048
+            })
+        );
+        assert_eq!(
+            policy.describe("a.html", 1, "Text/HTML", CHECKSUM),
+            Err(UploadError::ContentTypeNotAllowed("text/html".to_owned()))
+        );
+        assert!(matches!(
+            policy.describe("a.png", 1, "png", CHECKSUM),
+            Err(UploadError::InvalidContentType(_))
+        ));
+        assert!(matches!(
+            policy.describe("", 1, "image/png", CHECKSUM),
+            Err(UploadError::EmptyFilename(_))
+        ));
+        assert!(matches!(
+            policy.describe("a.png", 1, "image/png", "abc"),
+            Err(UploadError::InvalidChecksum(_))
+        ));
+        assert!(
+            UploadPolicy::new()
+                .describe("a.exe", 1, "application/x-msdownload", CHECKSUM)
+                .is_ok()
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use derive_more::{Display, Into};
+use thiserror::Error;
+use zabawa_name::make_name;
+
+pub const DEFAULT_MAX_FILENAME_LENGTH: usize = 255;
+
+const FALLBACK_STEM: &str = "file";
+const MAX_EXTENSION_LENGTH: usize = 16;
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("filename is empty")]
+pub struct EmptyFilenameError;
+
+/// A storage-safe file name: a name-safe stem plus an optional lowercase alphanumeric extension.
+#[derive(Clone, Debug, Display, Into, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FileName(String);
+
+impl FileName {
+    pub fn from_raw(name: impl Into<String>) -> Self {
+        Self(name.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn extension(&self) -> Option<&str> {
+        self.0.rsplit_once('.').map(|(_, extension)| extension)
+    }
+}
+
+impl AsRef<str> for FileName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Sanitizes a client-supplied file name: directory components are dropped, the stem goes through the name
+/// normalizer and the result is cut to `max_length` bytes, keeping the extension.
+pub fn sanitize_filename(input: &str, max_length: usize) -> Result<FileName, EmptyFilenameError> {
+    let base = input.rsplit(['/', '\\']).next().unwrap_or(input).trim();
+    if base.is_empty() {
+        return Err(EmptyFilenameError);
+    }
+
+    let (stem, extension) = match base.rsplit_once('.') {
+        Some((stem, extension)) if !stem.is_empty() => (stem, sanitize_extension(extension)),
+        _ => (base, String::new()),
+    };
+
+    let mut normalized = String::with_capacity(stem.len());
+    make_name(stem, &mut normalized);
+    let mut stem = normalized.trim_matches(['-', '_']).to_owned();
+    if stem.is_empty() {
+        stem.push_str(FALLBACK_STEM);
+    }
+
+    let suffix_length = if extension.is_empty() { 0 } else { extension.len() + 1 };
+    stem.truncate(max_length.saturating_sub(suffix_length).max(1));
+
+    if !extension.is_empty() {
+        stem.push('.');
+        stem.push_str(&extension);
+    }
+    Ok(FileName(stem))
+}
+
+fn sanitize_extension(input: &str) -> String {
+    input
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|ch| ch.to_ascii_lowercase())
+        .take(MAX_EXTENSION_LENGTH)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sanitize(input: &str) -> String {
+        sanitize_filename(input, DEFAULT_MAX_FILENAME_LENGTH).unwrap().into()
+    }
+
+    #[test]
+    fn test_sanitize_filename() {
+        assert_eq!(sanitize("Holiday Photo.JPG"), "holiday-photo.jpg");
+        assert_eq!(sanitize("../../etc/passwd"), "passwd");
+        assert_eq!(sanitize(r"C:\Users\me\Résumé (final).pdf"), "resume-final.pdf");
+        assert_eq!(sanitize("archive.tar.gz"), "archive-tar.gz");
+        assert_eq!(sanitize(".bashrc"), "bashrc");
+        assert_eq!(sanitize("???.png"), "file.png");
+        assert_eq!(sanitize("report.p$f"), "report.pf");
+        assert_eq!(sanitize_filename(" ", 255), Err(EmptyFilenameError));
+        assert_eq!(sanitize_filename("dir/", 255), Err(EmptyFilenameError));
+
+        let long = sanitize_filename(&format!("{}.txt", "a".repeat(300)), 20).unwrap();
+        assert_eq!(long.as_str(), format!("{}.txt", "a".repeat(16)));
+        assert_eq!(long.extension(), Some("txt"));
+        assert_eq!(FileName::from_raw("readme").extension(), None);
+    }
+}
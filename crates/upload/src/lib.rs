@@ -0,0 +1,9 @@
+pub mod checksum;
+pub mod content_type;
+pub mod descriptor;
+pub mod filename;
+
+pub use self::checksum::*;
+pub use self::content_type::*;
+pub use self::descriptor::*;
+pub use self::filename::*;
@@ -0,0 +1,276 @@
+pub mod route;
+
+use std::borrow::Cow;
+
+use derive_more::{Display, Into};
+use thiserror::Error;
+pub use zabawa_validation::InvalidUrlError;
+use zabawa_validation::{UrlParts, parse_url, validate_trimmed, validate_web_url};
+
+pub use self::route::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum WebUrlError {
+    #[error("url has leading or trailing whitespaces")]
+    Untrimmed,
+
+    #[error("url error: {0}")]
+    Invalid(#[from] InvalidUrlError),
+}
+
+/// An absolute web URL without credentials, e.g. for notification actions, avatars and form fields.
+#[derive(Clone, Debug, Display, Into, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WebUrl(String);
+
+impl WebUrl {
+    pub fn from_raw(url: impl Into<String>) -> Self {
+        Self(url.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn scheme(&self) -> &str {
+        self.parts().map_or("", |parts| parts.scheme)
+    }
+
+    pub fn host(&self) -> &str {
+        self.parts().map_or("", |parts| parts.host)
+    }
+
+    pub fn port(&self) -> Option<u16> {
+        self.parts().and_then(|parts| parts.port)
+    }
+
+    pub fn path(&self) -> &str {
+        self.parts().map_or("", |parts| parts.path)
+    }
+
+    pub fn query(&self) -> Option<&str> {
+        self.parts().and_then(|parts| parts.query)
+    }
+
+    pub fn fragment(&self) -> Option<&str> {
+        self.parts().and_then(|parts| parts.fragment)
+    }
+
+    /// `scheme://host[:port]`
+    pub fn origin(&self) -> &str {
+        let authority_start = self.0.find("://").map_or(0, |index| index + 3);
+        let end = self.0[authority_start..]
+            .find(['/', '?', '#'])
+            .map_or(self.0.len(), |index| authority_start + index);
+        &self.0[..end]
+    }
+
+    /// The path, query and fragment, for links within the same origin.
+    pub fn relative(&self) -> &str {
+        match &self.0[self.origin().len()..] {
+            "" => "/",
+            relative => relative,
+        }
+    }
+
+    /// A short human-readable form without scheme, query or fragment, e.g. `example.com/docs` as link text.
+    pub fn display(&self) -> String {
+        let Some(parts) = self.parts() else {
+            return self.0.clone();
+        };
+        let host = parts.host.strip_prefix("www.").unwrap_or(parts.host);
+        let path = parts.path.trim_end_matches('/');
+        match parts.port {
+            Some(port) => format!("{host}:{port}{path}"),
+            None => format!("{host}{path}"),
+        }
+    }
+
+    pub fn is_same_origin(&self, other: &WebUrl) -> bool {
+        self.origin() == other.origin()
+    }
+
+    fn parts(&self) -> Option<UrlParts<'_>> {
+        parse_url(&self.0).ok()
+    }
+}
+
+impl AsRef<str> for WebUrl {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+pub trait WebUrlBuilder {
+    type WebUrl;
+    type Error;
+
+    fn validate(&self, input: &str) -> Result<(), Self::Error>;
+
+    fn normalize(&self, input: &str) -> Result<String, Self::Error>;
+
+    fn build(&self, input: impl AsRef<str> + Into<String>) -> Result<Self::WebUrl, Self::Error>;
+
+    fn build_with_normalize(&self, input: impl AsRef<str> + Into<String>) -> Result<Self::WebUrl, Self::Error>;
+}
+
+#[derive(Debug)]
+pub struct DefaultWebUrlBuilder {
+    pub allowed_schemes: Vec<Cow<'static, str>>,
+    pub trim_validation_enabled: bool,
+}
+
+impl DefaultWebUrlBuilder {
+    pub fn new() -> Self {
+        Self {
+            allowed_schemes: vec![Cow::Borrowed("https"), Cow::Borrowed("http")],
+            trim_validation_enabled: true,
+        }
+    }
+
+    pub fn with_scheme(mut self, scheme: impl Into<Cow<'static, str>>) -> Self {
+        self.allowed_schemes.push(scheme.into());
+        self
+    }
+
+    pub fn https_only(mut self) -> Self {
+        self.allowed_schemes = vec![Cow::Borrowed("https")];
+        self
+    }
+
+    pub fn with_trim_validation(mut self, enabled: bool) -> Self {
+        self.trim_validation_enabled = enabled;
+        self
+    }
+}
+
+impl Default for DefaultWebUrlBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WebUrlBuilder for DefaultWebUrlBuilder {
+    type WebUrl = WebUrl;
+    type Error = WebUrlError;
+
+    fn validate(&self, input: &str) -> Result<(), Self::Error> {
+        if self.trim_validation_enabled && !validate_trimmed(input) {
+            return Err(WebUrlError::Untrimmed);
+        }
+
+        let allowed_schemes: Vec<&str> = self.allowed_schemes.iter().map(AsRef::as_ref).collect();
+        validate_web_url(input, &allowed_schemes)?;
+
+        Ok(())
+    }
+
+    /// Lowercases scheme and host, drops the scheme's default port and ensures a path.
+    fn normalize(&self, input: &str) -> Result<String, Self::Error> {
+        let input = if self.trim_validation_enabled {
+            input.trim()
+        } else {
+            input
+        };
+        let parts = parse_url(input)?;
+        let scheme = parts.scheme.to_ascii_lowercase();
+
+        let mut normalized = String::with_capacity(input.len() + 1);
+        normalized.push_str(&scheme);
+        normalized.push_str("://");
+        if let Some(userinfo) = parts.userinfo {
+            normalized.push_str(userinfo);
+            normalized.push('@');
+        }
+        normalized.push_str(&parts.host.to_lowercase());
+        match (scheme.as_str(), parts.port) {
+            ("http", Some(80)) | ("https", Some(443)) | (_, None) => {},
+            (_, Some(port)) => {
+                normalized.push(':');
+                normalized.push_str(&port.to_string());
+            },
+        }
+        normalized.push_str(if parts.path.is_empty() { "/" } else { parts.path });
+        if let Some(query) = parts.query {
+            normalized.push('?');
+            normalized.push_str(query);
+        }
+        if let Some(fragment) = parts.fragment {
+            normalized.push('#');
+            normalized.push_str(fragment);
+        }
+
+        Ok(normalized)
+    }
+
+    fn build(&self, input: impl AsRef<str> + Into<String>) -> Result<Self::WebUrl, Self::Error> {
+        self.validate(input.as_ref())?;
+
+        Ok(WebUrl::from_raw(input))
+    }
+
+    fn build_with_normalize(&self, input: impl AsRef<str> + Into<String>) -> Result<Self::WebUrl, Self::Error> {
+        let normalized_input = self.normalize(input.as_ref())?;
+        self.build(normalized_input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_with_normalize() {
+        let builder = DefaultWebUrlBuilder::new();
+        let url = builder
+            .build_with_normalize(" HTTPS://WWW.Example.COM:443/Docs/?page=2#intro ")
+            .unwrap();
+        assert_eq!(url.as_str(), "https://www.example.com/Docs/?page=2#intro");
+        assert_eq!(url.origin(), "https://www.example.com");
+        assert_eq!(url.relative(), "/Docs/?page=2#intro");
+        assert_eq!(url.display(), "example.com/Docs");
+        assert_eq!(url.query(), Some("page=2"));
+
+        let url = builder.build_with_normalize("http://localhost:8080").unwrap();
+        assert_eq!(url.as_str(), "http://localhost:8080/");
+        assert_eq!(url.port(), Some(8080));
+        assert_eq!(url.relative(), "/");
+        assert!(url.is_same_origin(&builder.build("http://localhost:8080/login").unwrap()));
+    }
+
+    #[test]
+    fn test_validate() {
+        let builder = DefaultWebUrlBuilder::new();
+        assert_eq!(
+            builder.build("ftp://example.com").unwrap_err(),
+            WebUrlError::Invalid(InvalidUrlError::DisallowedScheme)
+        );
+        assert_eq!(
+            builder.build("https://user:pw@example.com").unwrap_err(),
+            WebUrlError::Invalid(InvalidUrlError::Credentials)
+        );
+        assert_eq!(
+            builder.build(" https://example.com").unwrap_err(),
+            WebUrlError::Untrimmed
+        );
+
+        let builder = DefaultWebUrlBuilder::new().https_only();
+        assert_eq!(
+            builder.build("http://example.com").unwrap_err(),
+            WebUrlError::Invalid(InvalidUrlError::DisallowedScheme)
+        );
+
+        let builder = DefaultWebUrlBuilder::default();
+        assert!(builder.build("https://example.com").is_ok());
+        assert_eq!(
+            builder.build("javascript://example.com/%0aalert(1)").unwrap_err(),
+            WebUrlError::Invalid(InvalidUrlError::DisallowedScheme)
+        );
+
+        let mut builder = DefaultWebUrlBuilder::new();
+        builder.allowed_schemes.clear();
+        assert_eq!(
+            builder.build("https://example.com").unwrap_err(),
+            WebUrlError::Invalid(InvalidUrlError::DisallowedScheme)
+        );
+    }
+}
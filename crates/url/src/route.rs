@@ -0,0 +1,158 @@
+use thiserror::Error;
+use zabawa_name::NameBulder;
+use zabawa_validation::percent_encode;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum RoutePathError<E> {
+    #[error("route path must start with '/'")]
+    NotAbsolute,
+
+    #[error("route path has an empty segment at position {index}")]
+    EmptySegment { index: usize },
+
+    #[error("route path segment {index} is a '.' or '..' traversal")]
+    Traversal { index: usize },
+
+    #[error("route path segment {index} is not valid percent-encoded UTF-8")]
+    InvalidEncoding { index: usize },
+
+    #[error("route path segment {index} ({segment:?}) is invalid: {error}")]
+    InvalidSegment { index: usize, segment: String, error: E },
+}
+
+/// Splits an absolute path such as `/acme/website` into segments validated by `builder`, e.g. a
+/// `DefaultNameBuilder`. Segments are percent-decoded first, and the query, fragment and one trailing slash are
+/// ignored; `/` yields no segments.
+pub fn parse_route_path<B: NameBulder>(path: &str, builder: &B) -> Result<Vec<B::Name>, RoutePathError<B::Error>> {
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    let path = path.strip_prefix('/').ok_or(RoutePathError::NotAbsolute)?;
+    let path = path.strip_suffix('/').unwrap_or(path);
+    if path.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    path.split('/')
+        .enumerate()
+        .map(|(index, raw)| {
+            if raw.is_empty() {
+                return Err(RoutePathError::EmptySegment { index });
+            }
+            let segment = percent_decode(raw).ok_or(RoutePathError::InvalidEncoding { index })?;
+            if segment == "." || segment == ".." {
+                return Err(RoutePathError::Traversal { index });
+            }
+            builder
+                .build(segment.as_str())
+                .map_err(|error| RoutePathError::InvalidSegment { index, segment, error })
+        })
+        .collect()
+}
+
+/// Joins segments back into an absolute path, percent-encoding anything outside the unreserved URL characters.
+pub fn format_route_path<S: AsRef<str>>(segments: impl IntoIterator<Item = S>) -> String {
+    let mut path = String::new();
+    for segment in segments {
+        path.push('/');
+        percent_encode(segment.as_ref(), &mut path);
+    }
+    if path.is_empty() {
+        path.push('/');
+    }
+    path
+}
+
+/// Each segment paired with the path up to and including it, e.g. for breadcrumbs:
+/// `[("acme", "/acme"), ("website", "/acme/website")]`.
+pub fn route_path_prefixes<S: AsRef<str>>(segments: &[S]) -> Vec<(&S, String)> {
+    segments
+        .iter()
+        .enumerate()
+        .map(|(index, segment)| (segment, format_route_path(&segments[..=index])))
+        .collect()
+}
+
+fn percent_decode(input: &str) -> Option<String> {
+    if !input.contains('%') {
+        return Some(input.to_owned());
+    }
+    let mut bytes = Vec::with_capacity(input.len());
+    let mut rest = input.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let hex = std::str::from_utf8(tail.get(..2)?).ok()?;
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+    let decoded = String::from_utf8(bytes).ok()?;
+    // An encoded slash would let one segment smuggle in several.
+    (!decoded.contains('/')).then_some(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use zabawa_name::{DefaultNameBuilder, Name, NameError};
+
+    use super::*;
+
+    fn parse(path: &str) -> Result<Vec<Name>, RoutePathError<NameError>> {
+        parse_route_path(path, &DefaultNameBuilder::new())
+    }
+
+    #[test]
+    fn test_parse_route_path() {
+        let segments = parse("/acme/web-site/?tab=settings#top").unwrap();
+        assert_eq!(segments, [Name::from_raw("acme"), Name::from_raw("web-site")]);
+        assert_eq!(parse("/").unwrap(), []);
+        assert_eq!(parse("/ac%6De").unwrap(), [Name::from_raw("acme")]);
+
+        assert!(matches!(parse("acme"), Err(RoutePathError::NotAbsolute)));
+        assert!(matches!(
+            parse("/acme//site"),
+            Err(RoutePathError::EmptySegment { index: 1 })
+        ));
+        assert!(matches!(
+            parse("/acme/../admin"),
+            Err(RoutePathError::Traversal { index: 1 })
+        ));
+        assert!(matches!(
+            parse("/acme/%2e%2E/admin"),
+            Err(RoutePathError::Traversal { index: 1 })
+        ));
+        assert!(matches!(
+            parse("/acme%2Fadmin"),
+            Err(RoutePathError::InvalidEncoding { index: 0 })
+        ));
+        assert!(matches!(
+            parse("/acme/%ff"),
+            Err(RoutePathError::InvalidEncoding { index: 1 })
+        ));
+        assert!(matches!(
+            parse("/acme/%4"),
+            Err(RoutePathError::InvalidEncoding { index: 1 })
+        ));
+        assert!(matches!(
+            parse("/acme/Web%20Site"),
+            Err(RoutePathError::InvalidSegment { index: 1, segment, error: NameError::InvalidCharacters(_) })
+                if segment == "Web Site"
+        ));
+    }
+
+    #[test]
+    fn test_format_route_path() {
+        let segments = parse("/acme/web-site").unwrap();
+        assert_eq!(format_route_path(&segments), "/acme/web-site");
+        assert_eq!(format_route_path(Vec::<&str>::new()), "/");
+        assert_eq!(format_route_path(["a b", "c/d", "ä"]), "/a%20b/c%2Fd/%C3%A4");
+
+        let prefixes = route_path_prefixes(&segments);
+        let prefixes: Vec<(&str, &str)> = prefixes
+            .iter()
+            .map(|(name, href)| (name.as_str(), href.as_str()))
+            .collect();
+        assert_eq!(prefixes, [("acme", "/acme"), ("web-site", "/acme/web-site")]);
+    }
+}
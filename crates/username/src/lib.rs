@@ -0,0 +1,349 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+use derive_more::{Display, Into};
+use thiserror::Error;
+pub use zabawa_validation::InvalidLengthError;
+use zabawa_validation::{validate_length, validate_trimmed};
+
+pub const DEFAULT_RESERVED_USERNAMES: &[&str] = &[
+    "admin",
+    "administrator",
+    "api",
+    "help",
+    "login",
+    "logout",
+    "me",
+    "null",
+    "root",
+    "settings",
+    "signup",
+    "support",
+    "system",
+    "undefined",
+    "www",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Error)]
+pub enum UsernameError {
+    #[error("username has leading or trailing whitespaces")]
+    Untrimmed,
+
+    #[error("username error: {0}")]
+    InvalidLength(#[from] InvalidLengthError),
+
+    #[error("username must start with a letter")]
+    InvalidLeadingCharacter,
+
+    #[error("username may only contain letters, digits, '-', '_' and '.'")]
+    InvalidCharacters,
+
+    #[error("username must not contain consecutive separators")]
+    ConsecutiveSeparators,
+
+    #[error("username must not end with a separator")]
+    TrailingSeparator,
+
+    #[error("username is reserved")]
+    Reserved,
+}
+
+/// A user handle. Comparison, ordering and hashing ignore ASCII case, while the original casing is kept for display.
+#[derive(Clone, Debug, Display, Into)]
+pub struct Username(String);
+
+impl Username {
+    pub fn from_raw(username: impl Into<String>) -> Self {
+        Self(username.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The lowercase form, suitable as a unique lookup key.
+    pub fn canonical(&self) -> String {
+        self.0.to_ascii_lowercase()
+    }
+}
+
+impl AsRef<str> for Username {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq for Username {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_ignore_ascii_case(&other.0)
+    }
+}
+
+impl Eq for Username {}
+
+impl PartialOrd for Username {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Username {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let lhs = self.0.bytes().map(|byte| byte.to_ascii_lowercase());
+        let rhs = other.0.bytes().map(|byte| byte.to_ascii_lowercase());
+        lhs.cmp(rhs)
+    }
+}
+
+impl Hash for Username {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for byte in self.0.bytes() {
+            state.write_u8(byte.to_ascii_lowercase());
+        }
+        state.write_u8(0xff);
+    }
+}
+
+pub trait UsernameBuilder {
+    type Username;
+    type Error;
+
+    fn validate(&self, input: &str) -> Result<(), Self::Error>;
+
+    fn normalize(&self, input: &str) -> Result<String, Self::Error>;
+
+    fn build(&self, input: impl AsRef<str> + Into<String>) -> Result<Self::Username, Self::Error>;
+
+    fn build_with_normalize(&self, input: impl AsRef<str> + Into<String>) -> Result<Self::Username, Self::Error>;
+}
+
+#[derive(Debug, Default)]
+pub struct DefaultUsernameBuilder {
+    pub min_length: usize,
+    pub max_length: usize,
+    pub reserved: Vec<Cow<'static, str>>,
+    pub trim_validation_enabled: bool,
+}
+
+impl DefaultUsernameBuilder {
+    pub fn new() -> Self {
+        Self {
+            min_length: 3,
+            max_length: 32,
+            reserved: DEFAULT_RESERVED_USERNAMES.iter().copied().map(Cow::Borrowed).collect(),
+            trim_validation_enabled: true,
+        }
+    }
+
+    pub fn with_min_length(mut self, min: usize) -> Self {
+        self.min_length = min;
+        self
+    }
+
+    pub fn with_max_length(mut self, max: usize) -> Self {
+        self.max_length = max;
+        self
+    }
+
+    pub fn with_reserved(mut self, username: impl Into<Cow<'static, str>>) -> Self {
+        self.reserved.push(username.into());
+        self
+    }
+
+    pub fn without_reserved(mut self) -> Self {
+        self.reserved.clear();
+        self
+    }
+
+    pub fn with_trim_validation(mut self, enabled: bool) -> Self {
+        self.trim_validation_enabled = enabled;
+        self
+    }
+
+    pub fn is_reserved(&self, input: &str) -> bool {
+        self.reserved
+            .iter()
+            .any(|reserved| reserved.eq_ignore_ascii_case(input))
+    }
+}
+
+impl UsernameBuilder for DefaultUsernameBuilder {
+    type Username = Username;
+    type Error = UsernameError;
+
+    fn validate(&self, input: &str) -> Result<(), Self::Error> {
+        if self.trim_validation_enabled && !validate_trimmed(input) {
+            return Err(UsernameError::Untrimmed);
+        }
+
+        validate_length(input.len(), self.min_length, self.max_length)?;
+        validate_username_chars(input)?;
+
+        if self.is_reserved(input) {
+            return Err(UsernameError::Reserved);
+        }
+
+        Ok(())
+    }
+
+    fn normalize(&self, input: &str) -> Result<String, Self::Error> {
+        let input = if self.trim_validation_enabled {
+            input.trim()
+        } else {
+            input
+        };
+
+        let mut normalized = String::with_capacity(input.len());
+        make_username(input, &mut normalized);
+        normalized.truncate(self.max_length);
+        while normalized.ends_with(is_username_separator) {
+            normalized.pop();
+        }
+
+        Ok(normalized)
+    }
+
+    fn build(&self, input: impl AsRef<str> + Into<String>) -> Result<Self::Username, Self::Error> {
+        self.validate(input.as_ref())?;
+
+        Ok(Username::from_raw(input))
+    }
+
+    fn build_with_normalize(&self, input: impl AsRef<str> + Into<String>) -> Result<Self::Username, Self::Error> {
+        if self.validate(input.as_ref()).is_ok() {
+            return Ok(Username::from_raw(input));
+        }
+
+        let normalized_input = self.normalize(input.as_ref())?;
+        self.build(normalized_input)
+    }
+}
+
+pub fn validate_username_chars(input: &str) -> Result<(), UsernameError> {
+    if !input.starts_with(|ch: char| ch.is_ascii_alphabetic()) {
+        return Err(UsernameError::InvalidLeadingCharacter);
+    }
+
+    let mut previous_separator = false;
+    for ch in input.chars() {
+        let separator = is_username_separator(ch);
+        if !separator && !ch.is_ascii_alphanumeric() {
+            return Err(UsernameError::InvalidCharacters);
+        }
+        if separator && previous_separator {
+            return Err(UsernameError::ConsecutiveSeparators);
+        }
+        previous_separator = separator;
+    }
+
+    if previous_separator {
+        return Err(UsernameError::TrailingSeparator);
+    }
+    Ok(())
+}
+
+fn is_username_separator(ch: char) -> bool {
+    matches!(ch, '-' | '_' | '.')
+}
+
+/// Transliterates `input` and drops everything a username cannot contain, keeping the original casing.
+pub fn make_username(input: &str, output: &mut String) {
+    for ch in input.chars() {
+        if ch.is_ascii() {
+            process_char(ch, output);
+        } else if let Some(transliterated) = deunicode::deunicode_char(ch) {
+            for trans in transliterated.chars() {
+                process_char(trans, output);
+            }
+        }
+    }
+}
+
+fn process_char(ch: char, output: &mut String) {
+    if output.is_empty() {
+        if ch.is_ascii_alphabetic() {
+            output.push(ch);
+        }
+    } else if ch.is_ascii_alphanumeric() {
+        output.push(ch);
+    } else if (is_username_separator(ch) || ch.is_ascii_whitespace()) && !output.ends_with(is_username_separator) {
+        output.push(if ch.is_ascii_whitespace() { '_' } else { ch });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        let builder = DefaultUsernameBuilder::new();
+        assert!(builder.build("jane_doe").is_ok());
+        assert!(builder.build("Jane.Doe-42").is_ok());
+
+        assert_eq!(
+            builder.build("1jane").unwrap_err(),
+            UsernameError::InvalidLeadingCharacter
+        );
+        assert_eq!(
+            builder.build("_jane").unwrap_err(),
+            UsernameError::InvalidLeadingCharacter
+        );
+        assert_eq!(
+            builder.build("jane__doe").unwrap_err(),
+            UsernameError::ConsecutiveSeparators
+        );
+        assert_eq!(builder.build("jane-").unwrap_err(), UsernameError::TrailingSeparator);
+        assert_eq!(builder.build("jane doe").unwrap_err(), UsernameError::InvalidCharacters);
+        assert_eq!(builder.build("Admin").unwrap_err(), UsernameError::Reserved);
+        assert_eq!(builder.build(" jane").unwrap_err(), UsernameError::Untrimmed);
+        assert!(matches!(builder.build("jo"), Err(UsernameError::InvalidLength(_))));
+        assert!(matches!(
+            builder.build("a".repeat(33)),
+            Err(UsernameError::InvalidLength(_))
+        ));
+
+        let builder = DefaultUsernameBuilder::new().without_reserved().with_reserved("staff");
+        assert!(builder.build("admin").is_ok());
+        assert_eq!(builder.build("STAFF").unwrap_err(), UsernameError::Reserved);
+    }
+
+    #[test]
+    fn test_build_with_normalize() {
+        let builder = DefaultUsernameBuilder::new();
+        assert_eq!(
+            builder.build_with_normalize("  Jane  Doe ").unwrap().as_str(),
+            "Jane_Doe"
+        );
+        assert_eq!(builder.build_with_normalize("42 Zoë!!").unwrap().as_str(), "Zoe");
+        assert_eq!(
+            builder.build_with_normalize("jane--doe..").unwrap().as_str(),
+            "jane-doe"
+        );
+        assert_eq!(
+            builder.build_with_normalize("x".repeat(31) + "-abc").unwrap().as_str(),
+            "x".repeat(31)
+        );
+        assert_eq!(
+            builder.build_with_normalize("root").unwrap_err(),
+            UsernameError::Reserved
+        );
+    }
+
+    #[test]
+    fn test_case_insensitive_equality() {
+        let lower = Username::from_raw("jane_doe");
+        let mixed = Username::from_raw("Jane_Doe");
+        assert_eq!(lower, mixed);
+        assert_eq!(lower.cmp(&mixed), Ordering::Equal);
+        assert_eq!(mixed.to_string(), "Jane_Doe");
+        assert_eq!(mixed.canonical(), "jane_doe");
+        assert!(Username::from_raw("alice") < Username::from_raw("Bob"));
+
+        let set: HashSet<_> = [lower, mixed].into_iter().collect();
+        assert_eq!(set.len(), 1);
+    }
+}
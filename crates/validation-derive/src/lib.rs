@@ -0,0 +1,165 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, GenericArgument, LitInt, LitStr, PathArguments, Type, parse_macro_input};
+
+/// Derives `Validate`, checking each named field with the matching `zabawa_validation` function and collecting every
+/// failure under the field's name.
+///
+/// Field attributes, all optional: `#[validate(length(min = 2, max = 64), trimmed, email, custom = "path::to_fn",
+/// nested)]`. `length` counts characters, `custom` names a `fn(&T) -> Result<(), E>` with `E: Display`, and `nested`
+/// prefixes the errors of a field that implements `Validate`, e.g. `address.city`. `Option` fields are only checked
+/// when set.
+#[proc_macro_derive(Validate, attributes(validate))]
+pub fn derive_validate(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+#[derive(Default)]
+struct FieldOptions {
+    length: Option<(Option<LitInt>, Option<LitInt>)>,
+    trimmed: bool,
+    email: bool,
+    custom: Vec<syn::Path>,
+    nested: bool,
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "Validate can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new_spanned(&input.ident, "Validate requires named fields"));
+    };
+
+    let mut checks = Vec::new();
+    for field in &fields.named {
+        let options = parse_options(field)?;
+        let ident = field.ident.as_ref().expect("named field");
+        let name = ident.to_string().trim_start_matches("r#").to_owned();
+        let field_checks = field_checks(&name, options);
+        if field_checks.is_empty() {
+            continue;
+        }
+        checks.push(match option_inner(&field.ty) {
+            Some(_) => quote! {
+                if let ::std::option::Option::Some(value) = &self.#ident {
+                    #(#field_checks)*
+                }
+            },
+            None => quote! {
+                {
+                    let value = &self.#ident;
+                    #(#field_checks)*
+                }
+            },
+        });
+    }
+
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::zabawa_validation::Validate for #name #type_generics #where_clause {
+            fn validate(&self) -> ::std::result::Result<(), ::zabawa_validation::ValidationErrors> {
+                let mut errors = ::zabawa_validation::ValidationErrors::new();
+                #(#checks)*
+                errors.into_result()
+            }
+        }
+    })
+}
+
+fn parse_options(field: &syn::Field) -> syn::Result<FieldOptions> {
+    let mut options = FieldOptions::default();
+    for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("validate")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("length") {
+                let (mut min, mut max) = (None, None);
+                meta.parse_nested_meta(|bound| {
+                    if bound.path.is_ident("min") {
+                        min = Some(bound.value()?.parse()?);
+                    } else if bound.path.is_ident("max") {
+                        max = Some(bound.value()?.parse()?);
+                    } else {
+                        return Err(bound.error("expected `min` or `max`"));
+                    }
+                    Ok(())
+                })?;
+                if min.is_none() && max.is_none() {
+                    return Err(meta.error("`length` requires `min`, `max` or both"));
+                }
+                options.length = Some((min, max));
+            } else if meta.path.is_ident("trimmed") {
+                options.trimmed = true;
+            } else if meta.path.is_ident("email") {
+                options.email = true;
+            } else if meta.path.is_ident("custom") {
+                let path: LitStr = meta.value()?.parse()?;
+                options.custom.push(path.parse()?);
+            } else if meta.path.is_ident("nested") {
+                options.nested = true;
+            } else {
+                return Err(meta.error("unknown validate attribute"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(options)
+}
+
+/// The checks for one field, which refer to its value as `value`.
+fn field_checks(name: &str, options: FieldOptions) -> Vec<TokenStream2> {
+    let as_str = quote! { ::std::convert::AsRef::<str>::as_ref(value) };
+    let mut checks = Vec::new();
+    if options.trimmed {
+        checks.push(quote! {
+            if !::zabawa_validation::validate_trimmed(#as_str) {
+                errors.add(#name, "has leading or trailing whitespaces");
+            }
+        });
+    }
+    if let Some((min, max)) = options.length {
+        let min = min.map_or(quote! { 0 }, |min| quote! { #min });
+        let max = max.map_or(quote! { ::std::primitive::usize::MAX }, |max| quote! { #max });
+        checks.push(quote! {
+            errors.add_result(#name, ::zabawa_validation::validate_length(#as_str.chars().count(), #min, #max));
+        });
+    }
+    if options.email {
+        checks.push(quote! {
+            errors.add_result(#name, ::zabawa_validation::validate_email(#as_str));
+        });
+    }
+    for custom in options.custom {
+        checks.push(quote! {
+            errors.add_result(#name, #custom(value));
+        });
+    }
+    if options.nested {
+        checks.push(quote! {
+            errors.add_nested_result(#name, ::zabawa_validation::Validate::validate(value));
+        });
+    }
+    checks
+}
+
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    match arguments.args.first()? {
+        GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
@@ -0,0 +1,46 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("invalid color: expected #rgb, #rgba, #rrggbb or #rrggbbaa")]
+pub struct InvalidHexColorError;
+
+/// Validates a hex color and returns it lowercased in its long form (`#rrggbb` or `#rrggbbaa`).
+pub fn parse_hex_color(input: &str) -> Result<String, InvalidHexColorError> {
+    let digits = input.trim().strip_prefix('#').ok_or(InvalidHexColorError)?;
+    if !digits.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return Err(InvalidHexColorError);
+    }
+
+    let digits = digits.to_ascii_lowercase();
+    match digits.len() {
+        3 | 4 => Ok(digits
+            .chars()
+            .flat_map(|c| [c, c])
+            .fold(String::from("#"), |mut color, c| {
+                color.push(c);
+                color
+            })),
+        6 | 8 => Ok(format!("#{digits}")),
+        _ => Err(InvalidHexColorError),
+    }
+}
+
+pub fn validate_hex_color(input: &str) -> Result<(), InvalidHexColorError> {
+    parse_hex_color(input).map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hex_color() {
+        assert_eq!(parse_hex_color("#FFF"), Ok("#ffffff".to_owned()));
+        assert_eq!(parse_hex_color("#0f08"), Ok("#00ff0088".to_owned()));
+        assert_eq!(parse_hex_color(" #1A2b3C "), Ok("#1a2b3c".to_owned()));
+        assert_eq!(parse_hex_color("#1a2b3c4d"), Ok("#1a2b3c4d".to_owned()));
+        assert_eq!(parse_hex_color("1a2b3c"), Err(InvalidHexColorError));
+        assert_eq!(parse_hex_color("#12345"), Err(InvalidHexColorError));
+        assert_eq!(parse_hex_color("#ggg"), Err(InvalidHexColorError));
+    }
+}
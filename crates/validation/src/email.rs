@@ -0,0 +1,145 @@
+use thiserror::Error;
+
+pub const MAX_EMAIL_LENGTH: usize = 254;
+pub const MAX_LOCAL_PART_LENGTH: usize = 64;
+pub const MAX_DOMAIN_LABEL_LENGTH: usize = 63;
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidEmailError {
+    #[error("email address must contain an @")]
+    MissingAt,
+
+    #[error("email address is longer than {MAX_EMAIL_LENGTH} characters")]
+    TooLong,
+
+    #[error("email address local part is empty")]
+    EmptyLocalPart,
+
+    #[error("email address local part is longer than {MAX_LOCAL_PART_LENGTH} characters")]
+    LocalPartTooLong,
+
+    /// An empty dot-separated atom, as in `.jane`, `jane.` or `jane..doe`.
+    #[error("email address local part has misplaced dots")]
+    InvalidLocalPart,
+
+    #[error("email address local part contains invalid character {0:?}")]
+    InvalidLocalPartCharacter(char),
+
+    #[error("email address domain is empty")]
+    EmptyDomain,
+
+    #[error("email address domain label is longer than {MAX_DOMAIN_LABEL_LENGTH} characters")]
+    DomainLabelTooLong,
+
+    #[error("email address domain is invalid")]
+    InvalidDomain,
+}
+
+/// Splits an address at its last `@` into local part and domain.
+pub fn split_email(input: &str) -> Option<(&str, &str)> {
+    input.rsplit_once('@')
+}
+
+/// Validates a dot-atom address (`local@example.com`); quoted local parts and domain literals are rejected.
+pub fn validate_email(input: &str) -> Result<(), InvalidEmailError> {
+    let (local, domain) = split_email(input).ok_or(InvalidEmailError::MissingAt)?;
+
+    if input.len() > MAX_EMAIL_LENGTH {
+        return Err(InvalidEmailError::TooLong);
+    }
+    validate_email_local_part(local)?;
+    validate_email_domain(domain)
+}
+
+pub fn validate_email_local_part(local: &str) -> Result<(), InvalidEmailError> {
+    if local.is_empty() {
+        return Err(InvalidEmailError::EmptyLocalPart);
+    }
+    if local.len() > MAX_LOCAL_PART_LENGTH {
+        return Err(InvalidEmailError::LocalPartTooLong);
+    }
+    if let Some(ch) = local.chars().find(|&c| c != '.' && !is_atext(c)) {
+        return Err(InvalidEmailError::InvalidLocalPartCharacter(ch));
+    }
+    if local.split('.').any(str::is_empty) {
+        return Err(InvalidEmailError::InvalidLocalPart);
+    }
+    Ok(())
+}
+
+pub fn validate_email_domain(domain: &str) -> Result<(), InvalidEmailError> {
+    if domain.is_empty() {
+        return Err(InvalidEmailError::EmptyDomain);
+    }
+
+    let labels: Vec<&str> = domain.split('.').collect();
+    if labels.iter().any(|label| label.len() > MAX_DOMAIN_LABEL_LENGTH) {
+        return Err(InvalidEmailError::DomainLabelTooLong);
+    }
+    let valid_labels = labels.iter().all(|label| {
+        !label.is_empty()
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+    });
+    let tld_is_numeric = labels.last().is_some_and(|tld| tld.chars().all(|c| c.is_ascii_digit()));
+
+    if labels.len() < 2 || !valid_labels || tld_is_numeric {
+        return Err(InvalidEmailError::InvalidDomain);
+    }
+    Ok(())
+}
+
+fn is_atext(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "!#$%&'*+/=?^_`{|}~-".contains(c) || (!c.is_ascii() && c.is_alphanumeric())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_email() {
+        assert_eq!(validate_email("jane.doe+news@example.com"), Ok(()));
+        assert_eq!(validate_email("jürgen@bücher.de"), Ok(()));
+        assert_eq!(validate_email("o'brien@mail.example.co.uk"), Ok(()));
+
+        assert_eq!(validate_email("example.com"), Err(InvalidEmailError::MissingAt));
+        assert_eq!(validate_email("@example.com"), Err(InvalidEmailError::EmptyLocalPart));
+        assert_eq!(
+            validate_email("jane..doe@example.com"),
+            Err(InvalidEmailError::InvalidLocalPart)
+        );
+        assert_eq!(
+            validate_email(".jane@example.com"),
+            Err(InvalidEmailError::InvalidLocalPart)
+        );
+        assert_eq!(
+            validate_email("jane doe@example.com"),
+            Err(InvalidEmailError::InvalidLocalPartCharacter(' '))
+        );
+        assert_eq!(
+            validate_email(" jane@example.com"),
+            Err(InvalidEmailError::InvalidLocalPartCharacter(' '))
+        );
+        assert_eq!(validate_email("jane@"), Err(InvalidEmailError::EmptyDomain));
+        assert_eq!(validate_email("jane@localhost"), Err(InvalidEmailError::InvalidDomain));
+        assert_eq!(
+            validate_email("jane@-example.com"),
+            Err(InvalidEmailError::InvalidDomain)
+        );
+        assert_eq!(validate_email("jane@127.0.0.1"), Err(InvalidEmailError::InvalidDomain));
+        assert_eq!(
+            validate_email(&format!("{}@example.com", "a".repeat(65))),
+            Err(InvalidEmailError::LocalPartTooLong)
+        );
+        assert_eq!(
+            validate_email(&format!("jane@{}.com", "a.".repeat(125))),
+            Err(InvalidEmailError::TooLong)
+        );
+        assert_eq!(
+            validate_email(&format!("jane@{}.com", "a".repeat(64))),
+            Err(InvalidEmailError::DomainLabelTooLong)
+        );
+    }
+}
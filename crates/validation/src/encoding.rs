@@ -0,0 +1,27 @@
+/// Appends `input` with every byte except RFC 3986 unreserved characters percent-encoded, for a path segment or
+/// query component.
+pub fn percent_encode(input: &str, output: &mut String) {
+    for byte in input.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+            output.push(char::from(byte));
+        } else {
+            output.push_str(&format!("%{byte:02X}"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percent_encode() {
+        let mut output = String::from("/");
+        percent_encode("a-b_c.d~e", &mut output);
+        assert_eq!(output, "/a-b_c.d~e");
+
+        let mut output = String::new();
+        percent_encode("a b&c/ü", &mut output);
+        assert_eq!(output, "a%20b%26c%2F%C3%BC");
+    }
+}
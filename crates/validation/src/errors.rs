@@ -0,0 +1,316 @@
+use std::fmt;
+use std::fmt::Write;
+
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("{field}: {message}")]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+#[derive(Error, Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationErrors {
+    errors: Vec<FieldError>,
+}
+
+impl ValidationErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, field: impl Into<String>, error: impl fmt::Display) {
+        self.errors.push(FieldError::new(field, error.to_string()));
+    }
+
+    pub fn add_result<E: fmt::Display>(&mut self, field: impl Into<String>, result: Result<(), E>) {
+        if let Err(error) = result {
+            self.add(field, error);
+        }
+    }
+
+    /// Moves all errors of `other` into `self`, keeping their fields.
+    pub fn merge(&mut self, other: ValidationErrors) {
+        self.errors.extend(other.errors);
+    }
+
+    /// Moves all errors of a nested value into `self`, prefixing their fields with `prefix` as by [`field_path`],
+    /// e.g. `email` becomes `user.email`.
+    pub fn merge_nested(&mut self, prefix: &str, nested: ValidationErrors) {
+        self.errors.extend(nested.errors.into_iter().map(|error| FieldError {
+            field: field_path(prefix, &error.field),
+            message: error.message,
+        }));
+    }
+
+    /// Like [`ValidationErrors::merge_nested`], for a nested [`Validate::validate`] result.
+    pub fn add_nested_result(&mut self, prefix: &str, result: Result<(), ValidationErrors>) {
+        if let Err(nested) = result {
+            self.merge_nested(prefix, nested);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &FieldError> {
+        self.errors.iter()
+    }
+
+    pub fn field<'a>(&'a self, field: &'a str) -> impl Iterator<Item = &'a str> {
+        self.errors
+            .iter()
+            .filter(move |error| error.field == field)
+            .map(|error| error.message.as_str())
+    }
+
+    pub fn has_field(&self, field: &str) -> bool {
+        self.errors.iter().any(|error| error.field == field)
+    }
+
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.is_empty() { Ok(()) } else { Err(self) }
+    }
+
+    /// Serializes the messages grouped by field, in order of first appearance, e.g.
+    /// `{"user.email":["must not be empty"],"items[2].name":["is reserved"]}`.
+    pub fn to_json(&self) -> String {
+        let mut fields: Vec<&str> = Vec::new();
+        for error in &self.errors {
+            if !fields.contains(&error.field.as_str()) {
+                fields.push(&error.field);
+            }
+        }
+
+        let mut json = String::from("{");
+        for (index, field) in fields.into_iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+            write_json_string(&mut json, field);
+            json.push_str(":[");
+            for (index, message) in self.field(field).enumerate() {
+                if index > 0 {
+                    json.push(',');
+                }
+                write_json_string(&mut json, message);
+            }
+            json.push(']');
+        }
+        json.push('}');
+        json
+    }
+}
+
+/// Joins a field path, e.g. `user` and `email` to `user.email`, or `items[2]` and `name` to `items[2].name`.
+///
+/// Fields starting with `[` are appended without a dot, and an empty part yields the other one unchanged.
+pub fn field_path(prefix: &str, field: &str) -> String {
+    if prefix.is_empty() {
+        field.to_owned()
+    } else if field.is_empty() {
+        prefix.to_owned()
+    } else if field.starts_with('[') {
+        format!("{prefix}{field}")
+    } else {
+        format!("{prefix}.{field}")
+    }
+}
+
+/// The path of a list element, e.g. `items[2]`.
+pub fn index_path(prefix: &str, index: usize) -> String {
+    format!("{prefix}[{index}]")
+}
+
+/// Appends `value` as a quoted JSON string.
+pub fn write_json_string(output: &mut String, value: &str) {
+    output.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(output, "\\u{:04x}", c as u32);
+            },
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+}
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, error) in self.errors.iter().enumerate() {
+            if index > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{error}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Types that check their own invariants, reporting every failed rule at once.
+pub trait Validate {
+    fn validate(&self) -> Result<(), ValidationErrors>;
+}
+
+impl<'a> IntoIterator for &'a ValidationErrors {
+    type Item = &'a FieldError;
+    type IntoIter = std::slice::Iter<'a, FieldError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Validate, validate_length};
+
+    #[test]
+    fn test_validation_errors_by_field() {
+        let mut errors = ValidationErrors::new();
+        assert!(errors.is_empty());
+
+        errors.add_result("name", validate_length(1, 2, 10));
+        errors.add_result("name", validate_length(5, 2, 10));
+        errors.add("email", "must not be empty");
+        errors.add("name", "must be lowercase");
+
+        assert_eq!(errors.len(), 3);
+        assert!(errors.has_field("name"));
+        assert!(!errors.has_field("password"));
+        assert_eq!(errors.field("name").collect::<Vec<_>>(), [
+            "invalid length: expected 2-10 characters, got 1",
+            "must be lowercase"
+        ]);
+        assert_eq!(errors.field("email").collect::<Vec<_>>(), ["must not be empty"]);
+        assert!(errors.into_result().is_err());
+        assert_eq!(ValidationErrors::new().into_result(), Ok(()));
+    }
+
+    #[test]
+    fn test_validation_errors_nested() {
+        let mut address = ValidationErrors::new();
+        address.add("city", "must not be empty");
+
+        let mut item = ValidationErrors::new();
+        item.add("name", "is reserved");
+        item.add("", "is incomplete");
+
+        let mut errors = ValidationErrors::new();
+        errors.add("user.email", "must not be empty");
+        errors.add_nested_result("user.address", Err(address));
+        errors.add_nested_result("user.phone", Ok(()));
+        errors.merge_nested(&index_path("items", 2), item);
+        let mut other = ValidationErrors::new();
+        other.add("user.email", "is taken");
+        errors.merge(other);
+
+        assert_eq!(errors.iter().map(|error| error.field.as_str()).collect::<Vec<_>>(), [
+            "user.email",
+            "user.address.city",
+            "items[2].name",
+            "items[2]",
+            "user.email"
+        ]);
+        assert_eq!(
+            errors.to_json(),
+            r#"{"user.email":["must not be empty","is taken"],"user.address.city":["must not be empty"],"items[2].name":["is reserved"],"items[2]":["is incomplete"]}"#
+        );
+        assert_eq!(ValidationErrors::new().to_json(), "{}");
+    }
+
+    fn validate_not_admin(name: &String) -> Result<(), &'static str> {
+        if name == "admin" { Err("is reserved") } else { Ok(()) }
+    }
+
+    #[derive(Validate)]
+    struct Address {
+        #[validate(length(min = 1))]
+        city: String,
+    }
+
+    #[derive(Validate)]
+    struct SignUp {
+        #[validate(trimmed, length(min = 2, max = 8), custom = "validate_not_admin")]
+        name: String,
+        #[validate(email)]
+        email: String,
+        #[validate(length(max = 4))]
+        nickname: Option<String>,
+        #[validate(nested)]
+        address: Address,
+        #[allow(dead_code)]
+        accepted: bool,
+    }
+
+    #[test]
+    fn test_derive_validate() {
+        let valid = SignUp {
+            name: "jane".to_owned(),
+            email: "jane@example.com".to_owned(),
+            nickname: None,
+            address: Address {
+                city: "Kraków".to_owned(),
+            },
+            accepted: true,
+        };
+        assert_eq!(valid.validate(), Ok(()));
+
+        let invalid = SignUp {
+            name: " jane doe ".to_owned(),
+            email: "jane".to_owned(),
+            nickname: Some("janedoe".to_owned()),
+            address: Address { city: String::new() },
+            accepted: false,
+        };
+        let errors = invalid.validate().unwrap_err();
+        assert_eq!(errors.field("name").collect::<Vec<_>>(), [
+            "has leading or trailing whitespaces",
+            "invalid length: expected 2-8 characters, got 10"
+        ]);
+        assert_eq!(errors.field("email").collect::<Vec<_>>(), [
+            "email address must contain an @"
+        ]);
+        assert_eq!(errors.field("nickname").count(), 1);
+        assert_eq!(errors.field("address.city").count(), 1);
+
+        let admin = SignUp {
+            name: "admin".to_owned(),
+            ..valid
+        };
+        assert_eq!(admin.validate().unwrap_err().field("name").collect::<Vec<_>>(), [
+            "is reserved"
+        ]);
+    }
+
+    #[test]
+    fn test_field_path() {
+        assert_eq!(field_path("user", "email"), "user.email");
+        assert_eq!(field_path("items", "[0]"), "items[0]");
+        assert_eq!(field_path("", "email"), "email");
+        assert_eq!(field_path("user", ""), "user");
+        assert_eq!(index_path("items", 2), "items[2]");
+    }
+}
@@ -0,0 +1,199 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputFormatError {
+    #[error("invalid phone number: expected 7-15 digits with an optional leading +")]
+    Phone,
+    #[error("invalid amount: expected a number with at most {decimals} decimal places")]
+    Currency { decimals: u8 },
+    #[error("invalid date: expected YYYY-MM-DD")]
+    Date,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum InputFormat {
+    Phone,
+    Currency { decimals: u8 },
+    Date,
+}
+
+impl InputFormat {
+    /// Name of the client-side mask, exposed as `data-mask`.
+    pub fn mask(&self) -> &'static str {
+        match self {
+            InputFormat::Phone => "phone",
+            InputFormat::Currency { .. } => "currency",
+            InputFormat::Date => "date",
+        }
+    }
+
+    /// HTML `pattern` accepting the same inputs as [`InputFormat::parse`].
+    pub fn pattern(&self) -> String {
+        match self {
+            InputFormat::Phone => r"\+?[0-9 \(\)\.\-]{7,}".to_owned(),
+            InputFormat::Currency { decimals: 0 } => r"[0-9][0-9,]*".to_owned(),
+            InputFormat::Currency { decimals } => format!(r"[0-9][0-9,]*(\.[0-9]{{1,{decimals}}})?"),
+            InputFormat::Date => r"[0-9]{4}-[0-9]{2}-[0-9]{2}".to_owned(),
+        }
+    }
+
+    pub fn input_mode(&self) -> &'static str {
+        match self {
+            InputFormat::Phone => "tel",
+            InputFormat::Currency { decimals: 0 } => "numeric",
+            InputFormat::Currency { .. } => "decimal",
+            InputFormat::Date => "numeric",
+        }
+    }
+
+    pub fn placeholder(&self) -> &'static str {
+        match self {
+            InputFormat::Phone => "+1 555 123 4567",
+            InputFormat::Currency { decimals: 0 } => "0",
+            InputFormat::Currency { .. } => "0.00",
+            InputFormat::Date => "YYYY-MM-DD",
+        }
+    }
+
+    /// Validates the submitted value and returns its canonical form.
+    pub fn parse(&self, input: &str) -> Result<String, InputFormatError> {
+        match self {
+            InputFormat::Phone => parse_phone(input),
+            InputFormat::Currency { decimals } => {
+                parse_currency(input, *decimals).map(|minor| format_minor_units(minor, *decimals))
+            },
+            InputFormat::Date => parse_date(input).map(|(year, month, day)| format!("{year:04}-{month:02}-{day:02}")),
+        }
+    }
+}
+
+/// Strips formatting characters and returns the number as `+` followed by digits, or just the digits.
+pub fn parse_phone(input: &str) -> Result<String, InputFormatError> {
+    let input = input.trim();
+    let (plus, rest) = match input.strip_prefix('+') {
+        Some(rest) => ("+", rest),
+        None => ("", input),
+    };
+
+    let mut digits = String::from(plus);
+    for c in rest.chars() {
+        match c {
+            '0'..='9' => digits.push(c),
+            ' ' | '-' | '.' | '(' | ')' => {},
+            _ => return Err(InputFormatError::Phone),
+        }
+    }
+
+    let count = digits.len() - plus.len();
+    if (7..=15).contains(&count) {
+        Ok(digits)
+    } else {
+        Err(InputFormatError::Phone)
+    }
+}
+
+/// Parses an amount such as `1,234.5` into minor units (`123450` with two decimals).
+pub fn parse_currency(input: &str, decimals: u8) -> Result<i64, InputFormatError> {
+    let error = InputFormatError::Currency { decimals };
+    let input = input.trim();
+    let (whole, fraction) = input.split_once('.').unwrap_or((input, ""));
+
+    if whole.is_empty() || whole.starts_with(',') || whole.ends_with(',') || whole.contains(",,") {
+        return Err(error);
+    }
+    if fraction.len() > usize::from(decimals) || (input.contains('.') && fraction.is_empty()) {
+        return Err(error);
+    }
+
+    let mut minor: i64 = 0;
+    let padded = fraction
+        .chars()
+        .chain(std::iter::repeat('0'))
+        .take(usize::from(decimals));
+    for c in whole.chars().filter(|&c| c != ',').chain(padded) {
+        let digit = c.to_digit(10).ok_or(error)?;
+        minor = minor
+            .checked_mul(10)
+            .and_then(|minor| minor.checked_add(i64::from(digit)))
+            .ok_or(error)?;
+    }
+    Ok(minor)
+}
+
+pub fn format_minor_units(minor: i64, decimals: u8) -> String {
+    if decimals == 0 {
+        return minor.to_string();
+    }
+    let divisor = 10i64.pow(u32::from(decimals));
+    format!(
+        "{}{}.{:0width$}",
+        if minor < 0 { "-" } else { "" },
+        (minor / divisor).abs(),
+        (minor % divisor).abs(),
+        width = usize::from(decimals)
+    )
+}
+
+/// Parses an ISO 8601 calendar date (`YYYY-MM-DD`), checking the day against the month length.
+pub fn parse_date(input: &str) -> Result<(u16, u8, u8), InputFormatError> {
+    let error = InputFormatError::Date;
+    let mut parts = input.trim().split('-');
+    let (Some(year), Some(month), Some(day), None) = (parts.next(), parts.next(), parts.next(), parts.next()) else {
+        return Err(error);
+    };
+    if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+        return Err(error);
+    }
+    let number = |part: &str| {
+        part.bytes()
+            .all(|byte| byte.is_ascii_digit())
+            .then(|| part.parse::<u16>().ok())
+            .flatten()
+            .ok_or(error)
+    };
+    let (year, month, day) = (number(year)?, number(month)?, number(day)?);
+
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap => 29,
+        2 => 28,
+        _ => return Err(error),
+    };
+    if day == 0 || day > days_in_month {
+        return Err(error);
+    }
+    Ok((year, month as u8, day as u8))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            InputFormat::Phone.parse("+1 (555) 123-4567"),
+            Ok("+15551234567".to_owned())
+        );
+        assert_eq!(InputFormat::Phone.parse("123"), Err(InputFormatError::Phone));
+        assert_eq!(InputFormat::Phone.parse("555-CALL-NOW"), Err(InputFormatError::Phone));
+
+        let currency = InputFormat::Currency { decimals: 2 };
+        assert_eq!(currency.parse("1,234.5"), Ok("1234.50".to_owned()));
+        assert_eq!(currency.parse("0.05"), Ok("0.05".to_owned()));
+        assert_eq!(parse_currency("12", 2), Ok(1200));
+        assert!(currency.parse("1.234").is_err());
+        assert!(currency.parse("1.").is_err());
+        assert!(currency.parse(",1").is_err());
+        assert!(currency.parse("-1").is_err());
+        assert!(currency.parse("99999999999999999999").is_err());
+
+        assert_eq!(InputFormat::Date.parse("2024-02-29"), Ok("2024-02-29".to_owned()));
+        assert!(InputFormat::Date.parse("2023-02-29").is_err());
+        assert!(InputFormat::Date.parse("2023-13-01").is_err());
+        assert!(InputFormat::Date.parse("2023-1-01").is_err());
+        assert!(InputFormat::Date.parse("+023-01-01").is_err());
+    }
+}
@@ -1,4 +1,24 @@
+// Lets the derive's `::zabawa_validation` paths resolve inside this crate's own tests.
+extern crate self as zabawa_validation;
+
+pub mod color;
+pub mod email;
+pub mod encoding;
+pub mod errors;
+pub mod format;
+pub mod password;
+pub mod url;
+
 use thiserror::Error;
+pub use zabawa_validation_derive::Validate;
+
+pub use self::color::*;
+pub use self::email::*;
+pub use self::encoding::*;
+pub use self::errors::*;
+pub use self::format::*;
+pub use self::password::*;
+pub use self::url::*;
 
 #[derive(Error, Debug, Clone, Copy, PartialEq)]
 #[error("invalid length: expected {min}-{max} characters, got {actual}")]
@@ -0,0 +1,295 @@
+use std::fmt;
+
+use thiserror::Error;
+
+pub const MIN_PASSWORD_LENGTH: usize = 8;
+pub const MAX_PASSWORD_LENGTH: usize = 128;
+/// Passwords at least this long are accepted as passphrases regardless of character classes.
+pub const PASSPHRASE_LENGTH: usize = 16;
+pub const REQUIRED_CHARACTER_CLASSES: usize = 3;
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeakPasswordError {
+    #[error("password must be at least {min} characters")]
+    TooShort { min: usize },
+
+    #[error("password must be at most {max} characters")]
+    TooLong { max: usize },
+
+    #[error("password must mix at least {required} of lowercase, uppercase, digits and symbols")]
+    TooFewCharacterClasses { required: usize },
+
+    #[error("password must not repeat a character more than {max} times in a row")]
+    TooManyRepeats { max: usize },
+
+    #[error("password is too common")]
+    Denied,
+}
+
+/// Every rule a password broke under a [`PasswordPolicy`], in the policy's order, e.g. to render a checklist.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub struct PasswordStrengthError {
+    pub failures: Vec<WeakPasswordError>,
+}
+
+impl fmt::Display for PasswordStrengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("password is too weak: ")?;
+        for (index, failure) in self.failures.iter().enumerate() {
+            if index > 0 {
+                f.write_str("; ")?;
+            }
+            failure.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Configurable password rules, checked by [`validate_password`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordPolicy {
+    pub min_length: usize,
+    pub max_length: usize,
+    pub required_classes: usize,
+    /// Passwords at least this long skip the character class rule.
+    pub passphrase_length: Option<usize>,
+    /// The longest run of one repeated character, e.g. 2 rejects `aaa`.
+    pub max_repeats: Option<usize>,
+    /// Lowercased passwords rejected regardless of case, e.g. from a breached-password list.
+    pub deny_list: Vec<String>,
+}
+
+impl PasswordPolicy {
+    /// The rules of [`validate_password_strength`].
+    pub fn new() -> Self {
+        Self {
+            min_length: MIN_PASSWORD_LENGTH,
+            max_length: MAX_PASSWORD_LENGTH,
+            required_classes: REQUIRED_CHARACTER_CLASSES,
+            passphrase_length: Some(PASSPHRASE_LENGTH),
+            max_repeats: None,
+            deny_list: Vec::new(),
+        }
+    }
+
+    pub fn with_min_length(mut self, min: usize) -> Self {
+        self.min_length = min;
+        self
+    }
+
+    pub fn with_max_length(mut self, max: usize) -> Self {
+        self.max_length = max;
+        self
+    }
+
+    pub fn with_required_classes(mut self, required: usize) -> Self {
+        self.required_classes = required;
+        self
+    }
+
+    pub fn with_passphrase_length(mut self, length: usize) -> Self {
+        self.passphrase_length = Some(length);
+        self
+    }
+
+    pub fn without_passphrase_length(mut self) -> Self {
+        self.passphrase_length = None;
+        self
+    }
+
+    pub fn with_max_repeats(mut self, max: usize) -> Self {
+        self.max_repeats = Some(max);
+        self
+    }
+
+    pub fn without_max_repeats(mut self) -> Self {
+        self.max_repeats = None;
+        self
+    }
+
+    pub fn with_deny_list(mut self, passwords: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        self.deny_list = passwords
+            .into_iter()
+            .map(|password| password.as_ref().to_lowercase())
+            .collect();
+        self
+    }
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Counts the distinct character classes (lowercase, uppercase, digit, other) used in `input`.
+pub fn password_character_classes(input: &str) -> usize {
+    used_character_classes(input).into_iter().filter(|&used| used).count()
+}
+
+/// Whether `input` uses lowercase, uppercase, digit and other characters, in that order.
+fn used_character_classes(input: &str) -> [bool; 4] {
+    let (mut lower, mut upper, mut digit, mut other) = (false, false, false, false);
+    for c in input.chars() {
+        match c {
+            c if c.is_lowercase() => lower = true,
+            c if c.is_uppercase() => upper = true,
+            c if c.is_numeric() => digit = true,
+            _ => other = true,
+        }
+    }
+    [lower, upper, digit, other]
+}
+
+/// The longest run of one repeated character in `input`.
+pub fn longest_password_repeat(input: &str) -> usize {
+    let mut longest = 0;
+    let mut run = 0;
+    let mut previous = None;
+    for c in input.chars() {
+        run = if previous == Some(c) { run + 1 } else { 1 };
+        longest = longest.max(run);
+        previous = Some(c);
+    }
+    longest
+}
+
+/// Estimates entropy in bits as length × log2(pool size), where the pool is the union of the character classes used:
+/// 26 lowercase, 26 uppercase, 10 digits and 33 symbols. Overestimates predictable passwords such as `Password1!`, so
+/// pair it with a deny list.
+pub fn estimate_password_entropy(input: &str) -> f64 {
+    let pool = used_character_classes(input)
+        .into_iter()
+        .zip([26, 26, 10, 33])
+        .filter(|&(used, _)| used)
+        .map(|(_, size)| size)
+        .sum::<u32>();
+    if pool == 0 {
+        return 0.0;
+    }
+    input.chars().count() as f64 * f64::from(pool).log2()
+}
+
+/// Checks every rule of `policy`, collecting all failures rather than stopping at the first.
+pub fn validate_password(input: &str, policy: &PasswordPolicy) -> Result<(), PasswordStrengthError> {
+    let mut failures = Vec::new();
+    let len = input.chars().count();
+    if len < policy.min_length {
+        failures.push(WeakPasswordError::TooShort { min: policy.min_length });
+    }
+    if len > policy.max_length {
+        failures.push(WeakPasswordError::TooLong { max: policy.max_length });
+    }
+    let passphrase = policy.passphrase_length.is_some_and(|length| len >= length);
+    if !passphrase && password_character_classes(input) < policy.required_classes {
+        failures.push(WeakPasswordError::TooFewCharacterClasses {
+            required: policy.required_classes,
+        });
+    }
+    if let Some(max) = policy.max_repeats
+        && longest_password_repeat(input) > max
+    {
+        failures.push(WeakPasswordError::TooManyRepeats { max });
+    }
+    if !policy.deny_list.is_empty() && policy.deny_list.contains(&input.to_lowercase()) {
+        failures.push(WeakPasswordError::Denied);
+    }
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(PasswordStrengthError { failures })
+    }
+}
+
+pub fn validate_password_strength(input: &str) -> Result<(), WeakPasswordError> {
+    let len = input.chars().count();
+    if len < MIN_PASSWORD_LENGTH {
+        return Err(WeakPasswordError::TooShort {
+            min: MIN_PASSWORD_LENGTH,
+        });
+    }
+    if len > MAX_PASSWORD_LENGTH {
+        return Err(WeakPasswordError::TooLong {
+            max: MAX_PASSWORD_LENGTH,
+        });
+    }
+    if len < PASSPHRASE_LENGTH && password_character_classes(input) < REQUIRED_CHARACTER_CLASSES {
+        return Err(WeakPasswordError::TooFewCharacterClasses {
+            required: REQUIRED_CHARACTER_CLASSES,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_password_strength() {
+        assert_eq!(validate_password_strength("Tr0ub4dor"), Ok(()));
+        assert_eq!(validate_password_strength("correct horse battery staple"), Ok(()));
+        assert_eq!(validate_password_strength("pässwörd-1"), Ok(()));
+
+        assert_eq!(
+            validate_password_strength("Ab1!"),
+            Err(WeakPasswordError::TooShort { min: 8 })
+        );
+        assert_eq!(
+            validate_password_strength("password1"),
+            Err(WeakPasswordError::TooFewCharacterClasses { required: 3 })
+        );
+        assert_eq!(
+            validate_password_strength(&"Ab1!".repeat(33)),
+            Err(WeakPasswordError::TooLong { max: 128 })
+        );
+    }
+
+    #[test]
+    fn test_validate_password() {
+        let policy = PasswordPolicy::new()
+            .with_max_repeats(2)
+            .with_deny_list(["Passw0rd!", "letmein"]);
+        assert_eq!(validate_password("Tr0ub4dor", &policy), Ok(()));
+        assert_eq!(validate_password("correct horse battery staple", &policy), Ok(()));
+
+        let error = validate_password("aaa", &policy).unwrap_err();
+        assert_eq!(error.failures, [
+            WeakPasswordError::TooShort { min: 8 },
+            WeakPasswordError::TooFewCharacterClasses { required: 3 },
+            WeakPasswordError::TooManyRepeats { max: 2 },
+        ]);
+        assert_eq!(
+            error.to_string(),
+            "password is too weak: password must be at least 8 characters; password must mix at least 3 of lowercase, \
+             uppercase, digits and symbols; password must not repeat a character more than 2 times in a row"
+        );
+        assert_eq!(validate_password("PASSW0RD!", &policy).unwrap_err().failures, [
+            WeakPasswordError::Denied
+        ]);
+
+        let policy = PasswordPolicy::new().without_passphrase_length();
+        assert_eq!(
+            validate_password("correct horse battery staple", &policy)
+                .unwrap_err()
+                .failures,
+            [WeakPasswordError::TooFewCharacterClasses { required: 3 }]
+        );
+    }
+
+    #[test]
+    fn test_estimate_password_entropy() {
+        assert_eq!(estimate_password_entropy(""), 0.0);
+        assert_eq!(estimate_password_entropy("abcd"), 4.0 * 26f64.log2());
+        assert_eq!(estimate_password_entropy("aB3!"), 4.0 * 95f64.log2());
+        assert!(estimate_password_entropy("correct horse battery staple") > estimate_password_entropy("Tr0ub4dor&3"));
+    }
+
+    #[test]
+    fn test_longest_password_repeat() {
+        assert_eq!(longest_password_repeat(""), 0);
+        assert_eq!(longest_password_repeat("abc"), 1);
+        assert_eq!(longest_password_repeat("abbbcc"), 3);
+    }
+}
@@ -0,0 +1,367 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidUrlError {
+    #[error("url must start with a scheme such as https://")]
+    MissingScheme,
+
+    #[error("url scheme is invalid")]
+    InvalidScheme,
+
+    #[error("url scheme is not allowed")]
+    DisallowedScheme,
+
+    #[error("url must contain a host")]
+    MissingHost,
+
+    #[error("url host is invalid")]
+    InvalidHost,
+
+    #[error("url port is invalid")]
+    InvalidPort,
+
+    #[error("url must not contain credentials")]
+    Credentials,
+
+    #[error("url is longer than {max} characters")]
+    TooLong { max: usize },
+
+    #[error("url host is not allowed")]
+    DisallowedHost,
+
+    #[error("url contains whitespace or control characters")]
+    InvalidCharacters,
+}
+
+/// The components of an absolute, hierarchical URL (`scheme://[userinfo@]host[:port][path][?query][#fragment]`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UrlParts<'a> {
+    pub scheme: &'a str,
+    pub userinfo: Option<&'a str>,
+    pub host: &'a str,
+    pub port: Option<u16>,
+    pub path: &'a str,
+    pub query: Option<&'a str>,
+    pub fragment: Option<&'a str>,
+}
+
+pub fn parse_url(input: &str) -> Result<UrlParts<'_>, InvalidUrlError> {
+    if input.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return Err(InvalidUrlError::InvalidCharacters);
+    }
+
+    let (scheme, rest) = input.split_once("://").ok_or(InvalidUrlError::MissingScheme)?;
+    let valid_scheme = scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    if !valid_scheme {
+        return Err(InvalidUrlError::InvalidScheme);
+    }
+
+    let authority_end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+    let (authority, rest) = rest.split_at(authority_end);
+    let (rest, fragment) = match rest.split_once('#') {
+        Some((rest, fragment)) => (rest, Some(fragment)),
+        None => (rest, None),
+    };
+    let (path, query) = match rest.split_once('?') {
+        Some((path, query)) => (path, Some(query)),
+        None => (rest, None),
+    };
+
+    let (userinfo, host_port) = match authority.rsplit_once('@') {
+        Some((userinfo, host_port)) => (Some(userinfo), host_port),
+        None => (None, authority),
+    };
+    let port_separator = if host_port.starts_with('[') {
+        host_port.find(']').map(|end| end + 1)
+    } else {
+        host_port.rfind(':')
+    };
+    let (host, port) = match port_separator.map(|index| host_port.split_at(index)) {
+        Some((host, port)) if !port.is_empty() => {
+            let port = port.strip_prefix(':').ok_or(InvalidUrlError::InvalidPort)?;
+            let port = port.parse::<u16>().map_err(|_| InvalidUrlError::InvalidPort)?;
+            (host, Some(port))
+        },
+        _ => (host_port, None),
+    };
+
+    validate_host(host)?;
+
+    Ok(UrlParts {
+        scheme,
+        userinfo,
+        host,
+        port,
+        path,
+        query,
+        fragment,
+    })
+}
+
+pub fn validate_host(host: &str) -> Result<(), InvalidUrlError> {
+    if host.is_empty() {
+        return Err(InvalidUrlError::MissingHost);
+    }
+    if let Some(address) = host.strip_prefix('[') {
+        let address = address.strip_suffix(']').ok_or(InvalidUrlError::InvalidHost)?;
+        if address.is_empty() || !address.chars().all(|c| c.is_ascii_hexdigit() || c == ':' || c == '.') {
+            return Err(InvalidUrlError::InvalidHost);
+        }
+        return Ok(());
+    }
+
+    let host = host.strip_suffix('.').unwrap_or(host);
+    let valid = host.split('.').all(|label| {
+        !label.is_empty()
+            && label.chars().count() <= 63
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+    });
+    if valid {
+        Ok(())
+    } else {
+        Err(InvalidUrlError::InvalidHost)
+    }
+}
+
+/// The longest URL [`UrlPolicy::new`] accepts, a limit common browsers and servers share.
+pub const DEFAULT_URL_MAX_LENGTH: usize = 2048;
+
+/// Rules for user-provided URLs such as webhooks or homepages, checked by [`validate_url`].
+///
+/// Host patterns match case-insensitively, either exactly or, when written as `*.example.com`, any subdomain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UrlPolicy {
+    /// Lowercase schemes to accept, or any scheme when empty.
+    pub allowed_schemes: Vec<String>,
+    pub max_length: Option<usize>,
+    /// Hosts to accept, or any host when empty.
+    pub allowed_hosts: Vec<String>,
+    /// Hosts to reject, even when allowed.
+    pub denied_hosts: Vec<String>,
+    pub credentials_allowed: bool,
+}
+
+impl UrlPolicy {
+    /// `http` and `https` URLs of up to [`DEFAULT_URL_MAX_LENGTH`] characters, without credentials.
+    pub fn new() -> Self {
+        Self {
+            allowed_schemes: vec!["http".to_owned(), "https".to_owned()],
+            max_length: Some(DEFAULT_URL_MAX_LENGTH),
+            allowed_hosts: Vec::new(),
+            denied_hosts: Vec::new(),
+            credentials_allowed: false,
+        }
+    }
+
+    /// Like [`UrlPolicy::new`], but only `https`, e.g. for webhooks.
+    pub fn https_only() -> Self {
+        Self::new().with_allowed_schemes(["https"])
+    }
+
+    pub fn with_allowed_schemes(mut self, schemes: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        self.allowed_schemes = lowercase_all(schemes);
+        self
+    }
+
+    pub fn with_max_length(mut self, max: usize) -> Self {
+        self.max_length = Some(max);
+        self
+    }
+
+    pub fn without_max_length(mut self) -> Self {
+        self.max_length = None;
+        self
+    }
+
+    pub fn with_allowed_hosts(mut self, hosts: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        self.allowed_hosts = lowercase_all(hosts);
+        self
+    }
+
+    pub fn with_denied_hosts(mut self, hosts: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        self.denied_hosts = lowercase_all(hosts);
+        self
+    }
+
+    pub fn with_credentials(mut self, allowed: bool) -> Self {
+        self.credentials_allowed = allowed;
+        self
+    }
+}
+
+impl Default for UrlPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn lowercase_all(values: impl IntoIterator<Item = impl AsRef<str>>) -> Vec<String> {
+    values.into_iter().map(|value| value.as_ref().to_lowercase()).collect()
+}
+
+fn host_matches(host: &str, pattern: &str) -> bool {
+    match pattern.strip_prefix("*.") {
+        Some(domain) => host
+            .strip_suffix(domain)
+            .is_some_and(|subdomain| subdomain.len() > 1 && subdomain.ends_with('.')),
+        None => host == pattern,
+    }
+}
+
+/// Validates an absolute URL against `policy`, reporting the first rule it breaks.
+pub fn validate_url(input: &str, policy: &UrlPolicy) -> Result<(), InvalidUrlError> {
+    if let Some(max) = policy.max_length
+        && input.chars().count() > max
+    {
+        return Err(InvalidUrlError::TooLong { max });
+    }
+
+    let parts = parse_url(input)?;
+    if !policy.allowed_schemes.is_empty()
+        && !policy
+            .allowed_schemes
+            .iter()
+            .any(|scheme| scheme.eq_ignore_ascii_case(parts.scheme))
+    {
+        return Err(InvalidUrlError::DisallowedScheme);
+    }
+    if parts.userinfo.is_some() && !policy.credentials_allowed {
+        return Err(InvalidUrlError::Credentials);
+    }
+
+    let host = parts.host.strip_suffix('.').unwrap_or(parts.host).to_lowercase();
+    let denied = policy.denied_hosts.iter().any(|pattern| host_matches(&host, pattern));
+    let allowed =
+        policy.allowed_hosts.is_empty() || policy.allowed_hosts.iter().any(|pattern| host_matches(&host, pattern));
+    if denied || !allowed {
+        return Err(InvalidUrlError::DisallowedHost);
+    }
+    Ok(())
+}
+
+/// Validates an absolute URL whose scheme is one of `allowed_schemes` and which carries no credentials, i.e.
+/// [`validate_url`] with a [`UrlPolicy`] restricted to those schemes and without a length limit.
+/// Unlike in [`UrlPolicy`], an empty `allowed_schemes` rejects every URL.
+pub fn validate_web_url(input: &str, allowed_schemes: &[&str]) -> Result<(), InvalidUrlError> {
+    if allowed_schemes.is_empty() {
+        parse_url(input)?;
+        return Err(InvalidUrlError::DisallowedScheme);
+    }
+    let policy = UrlPolicy::new()
+        .with_allowed_schemes(allowed_schemes)
+        .without_max_length();
+    validate_url(input, &policy)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_url() {
+        assert_eq!(
+            parse_url("https://user:pw@Example.com:8443/a/b?x=1#top"),
+            Ok(UrlParts {
+                scheme: "https",
+                userinfo: Some("user:pw"),
+                host: "Example.com",
+                port: Some(8443),
+                path: "/a/b",
+                query: Some("x=1"),
+                fragment: Some("top"),
+            })
+        );
+        let parts = parse_url("http://[::1]:8080").unwrap();
+        assert_eq!((parts.host, parts.port, parts.path), ("[::1]", Some(8080), ""));
+        assert_eq!(parse_url("https://example.com?q").unwrap().query, Some("q"));
+
+        assert_eq!(parse_url("example.com"), Err(InvalidUrlError::MissingScheme));
+        assert_eq!(parse_url("1http://example.com"), Err(InvalidUrlError::InvalidScheme));
+        assert_eq!(parse_url("https:///path"), Err(InvalidUrlError::MissingHost));
+        assert_eq!(parse_url("https://exa_mple.com"), Err(InvalidUrlError::InvalidHost));
+        assert_eq!(
+            parse_url("https://example.com:99999"),
+            Err(InvalidUrlError::InvalidPort)
+        );
+        assert_eq!(
+            parse_url("https://exa mple.com"),
+            Err(InvalidUrlError::InvalidCharacters)
+        );
+    }
+
+    #[test]
+    fn test_validate_web_url() {
+        let schemes = ["http", "https"];
+        assert_eq!(validate_web_url("HTTPS://example.com", &schemes), Ok(()));
+        assert_eq!(
+            validate_web_url("javascript://example.com/%0aalert(1)", &schemes),
+            Err(InvalidUrlError::DisallowedScheme)
+        );
+        assert_eq!(
+            validate_web_url("https://admin@example.com", &schemes),
+            Err(InvalidUrlError::Credentials)
+        );
+        assert_eq!(
+            validate_web_url(&format!("https://example.com/{}", "a".repeat(4096)), &schemes),
+            Ok(())
+        );
+        assert_eq!(
+            validate_web_url("https://example.com", &[]),
+            Err(InvalidUrlError::DisallowedScheme)
+        );
+    }
+
+    #[test]
+    fn test_validate_url() {
+        let policy = UrlPolicy::new();
+        assert_eq!(validate_url("http://example.com/about", &policy), Ok(()));
+        assert_eq!(
+            validate_url("ftp://example.com", &policy),
+            Err(InvalidUrlError::DisallowedScheme)
+        );
+        assert_eq!(
+            validate_url("https://user:pw@example.com", &policy),
+            Err(InvalidUrlError::Credentials)
+        );
+        assert_eq!(
+            validate_url("https://user:pw@example.com", &UrlPolicy::new().with_credentials(true)),
+            Ok(())
+        );
+        assert_eq!(
+            validate_url(&format!("https://example.com/{}", "a".repeat(2048)), &policy),
+            Err(InvalidUrlError::TooLong { max: 2048 })
+        );
+
+        let policy = UrlPolicy::https_only()
+            .with_max_length(64)
+            .with_allowed_hosts(["example.com", "*.hooks.example.com"])
+            .with_denied_hosts(["internal.hooks.example.com"]);
+        assert_eq!(validate_url("https://EXAMPLE.com./hook", &policy), Ok(()));
+        assert_eq!(validate_url("https://eu.hooks.example.com", &policy), Ok(()));
+        assert_eq!(
+            validate_url("http://example.com", &policy),
+            Err(InvalidUrlError::DisallowedScheme)
+        );
+        for denied in [
+            "https://hooks.example.com",
+            "https://evilhooks.example.com",
+            "https://internal.hooks.example.com",
+            "https://example.org",
+        ] {
+            assert_eq!(
+                validate_url(denied, &policy),
+                Err(InvalidUrlError::DisallowedHost),
+                "{denied}"
+            );
+        }
+        assert_eq!(
+            validate_url(&format!("https://example.com/{}", "a".repeat(64)), &policy),
+            Err(InvalidUrlError::TooLong { max: 64 })
+        );
+    }
+}
@@ -0,0 +1,221 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Error, Fields, GenericArgument, Ident, PathArguments, Type, parse_macro_input};
+
+/// Derives `Component` for a props struct, delegating to its inherent `render(&self, ctx, props)`, and generates a
+/// `with_*` builder method for each named field.
+///
+/// Struct attributes, all optional: `#[component(props = Slots<'p>, render = render_slots, no_context)]`. Props
+/// default to `Children<'p>`; `no_context` calls `render(&self, props)` instead.
+///
+/// Field attributes, all optional: `#[component(into, attrs, skip)]`. `Option` fields also get a `without_*` method,
+/// `Cow` fields always take `impl Into<…>`, and `attrs` additionally forwards `with_id`, `with_data`, `with_aria`
+/// and `with_test_id` to the field so markup attributes can be spread onto the root element.
+#[proc_macro_derive(ViewComponent, attributes(component))]
+pub fn derive_view_component(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+#[derive(Default)]
+struct StructOptions {
+    props: Option<Type>,
+    render: Option<Ident>,
+    no_context: bool,
+}
+
+#[derive(Default)]
+struct FieldOptions {
+    into: bool,
+    attrs: bool,
+    skip: bool,
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "ViewComponent can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new_spanned(&input.ident, "ViewComponent requires named fields"));
+    };
+
+    let mut methods = Vec::new();
+    for field in &fields.named {
+        let options = parse_field_options(field)?;
+        if options.skip {
+            continue;
+        }
+        let ident = field.ident.as_ref().expect("named field");
+        methods.push(builder_methods(ident, &field.ty, &options));
+    }
+
+    let options = parse_struct_options(&input)?;
+    let private = quote! { ::zabawa_view_common::__private };
+    let props = options.props.map_or_else(
+        || quote! { ::zabawa_view_common::Children<'p> },
+        |props| quote! { #props },
+    );
+    let render = options.render.unwrap_or_else(|| format_ident!("render"));
+    let call = if options.no_context {
+        quote! { Self::#render(self, props) }
+    } else {
+        quote! { Self::#render(self, ctx, props) }
+    };
+
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #name #type_generics #where_clause {
+            #(#methods)*
+        }
+
+        impl #impl_generics ::zabawa_view_common::Component for #name #type_generics #where_clause {
+            type Props<'p> = #props;
+
+            #[allow(unused_variables)]
+            fn render<'p>(
+                &self,
+                props: Self::Props<'p>,
+                ctx: &::zabawa_view_common::RenderContext,
+            ) -> impl #private::Renderable {
+                #call
+            }
+        }
+    })
+}
+
+fn parse_struct_options(input: &DeriveInput) -> syn::Result<StructOptions> {
+    let mut options = StructOptions::default();
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("component")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("props") {
+                options.props = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("render") {
+                options.render = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("no_context") {
+                options.no_context = true;
+            } else {
+                return Err(meta.error("unknown component attribute"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(options)
+}
+
+fn parse_field_options(field: &syn::Field) -> syn::Result<FieldOptions> {
+    let mut options = FieldOptions::default();
+    for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("component")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("into") {
+                options.into = true;
+            } else if meta.path.is_ident("attrs") {
+                options.attrs = true;
+            } else if meta.path.is_ident("skip") {
+                options.skip = true;
+            } else {
+                return Err(meta.error("unknown component field attribute"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(options)
+}
+
+fn builder_methods(ident: &Ident, ty: &Type, options: &FieldOptions) -> TokenStream2 {
+    let field_name = ident.to_string().trim_start_matches("r#").to_owned();
+    let with = format_ident!("with_{}", field_name);
+
+    let setter = match option_inner(ty) {
+        Some(inner) => {
+            let without = format_ident!("without_{}", field_name);
+            let (param, value) = setter_param(ident, inner, options);
+            quote! {
+                pub fn #with(mut self, #ident: #param) -> Self {
+                    self.#ident = ::std::option::Option::Some(#value);
+                    self
+                }
+
+                pub fn #without(mut self) -> Self {
+                    self.#ident = ::std::option::Option::None;
+                    self
+                }
+            }
+        },
+        None => {
+            let (param, value) = setter_param(ident, ty, options);
+            quote! {
+                pub fn #with(mut self, #ident: #param) -> Self {
+                    self.#ident = #value;
+                    self
+                }
+            }
+        },
+    };
+
+    let forwarding = options.attrs.then(|| {
+        let cow = quote! { impl ::std::convert::Into<::std::borrow::Cow<'static, str>> };
+        quote! {
+            pub fn with_id(mut self, id: #cow) -> Self {
+                self.#ident = self.#ident.with_id(id);
+                self
+            }
+
+            pub fn with_data(mut self, name: #cow, value: #cow) -> Self {
+                self.#ident = self.#ident.with_data(name, value);
+                self
+            }
+
+            pub fn with_aria(mut self, name: #cow, value: #cow) -> Self {
+                self.#ident = self.#ident.with_aria(name, value);
+                self
+            }
+
+            pub fn with_test_id(mut self, id: #cow) -> Self {
+                self.#ident = self.#ident.with_test_id(id);
+                self
+            }
+        }
+    });
+
+    quote! {
+        #setter
+        #forwarding
+    }
+}
+
+fn setter_param(ident: &Ident, ty: &Type, options: &FieldOptions) -> (TokenStream2, TokenStream2) {
+    if options.into || last_segment(ty).as_deref() == Some("Cow") {
+        (quote! { impl ::std::convert::Into<#ty> }, quote! { #ident.into() })
+    } else {
+        (quote! { #ty }, quote! { #ident })
+    }
+}
+
+fn last_segment(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(path) => path.path.segments.last().map(|segment| segment.ident.to_string()),
+        _ => None,
+    }
+}
+
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    match arguments.args.first()? {
+        GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
@@ -2,9 +2,10 @@ use std::borrow::Cow;
 
 use hypertext::{Renderable, rsx};
 
-use crate::hypertext_elements;
+use crate::{ViewComponent, hypertext_elements};
 
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, ViewComponent)]
+#[component(no_context)]
 pub struct Animation {
     pub name: Cow<'static, str>,
     pub duration: usize,
@@ -20,21 +21,6 @@ impl Animation {
         }
     }
 
-    pub fn with_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
-        self.name = name.into();
-        self
-    }
-
-    pub fn with_duration(mut self, duration: usize) -> Self {
-        self.duration = duration;
-        self
-    }
-
-    pub fn with_iterations(mut self, iterations: usize) -> Self {
-        self.iterations = iterations;
-        self
-    }
-
     pub fn render(&self, children: impl Renderable) -> impl Renderable {
         rsx! {
             <wa-animation name=(self.name) duration=(self.duration) iterations=(self.iterations)>
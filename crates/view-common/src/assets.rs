@@ -0,0 +1,101 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Assets {
+    pub base_url: Cow<'static, str>,
+    pub version: Option<Cow<'static, str>>,
+    pub fingerprints: HashMap<Cow<'static, str>, Cow<'static, str>>,
+}
+
+impl Assets {
+    pub fn new(base_url: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            version: None,
+            fingerprints: HashMap::new(),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<Cow<'static, str>>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    pub fn with_version(mut self, version: impl Into<Cow<'static, str>>) -> Self {
+        self.version = Some(version.into());
+        self
+    }
+
+    pub fn without_version(mut self) -> Self {
+        self.version = None;
+        self
+    }
+
+    pub fn with_fingerprint(
+        mut self,
+        path: impl Into<Cow<'static, str>>,
+        fingerprint: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.fingerprints.insert(path.into(), fingerprint.into());
+        self
+    }
+
+    /// Resolves `path` against the base URL and appends its fingerprint (or the global version) as `?v=`.
+    /// External URLs are returned unchanged.
+    pub fn url(&self, path: &str) -> String {
+        if is_external(path) {
+            return path.to_owned();
+        }
+
+        let path = path.trim_start_matches('/');
+        let base = self.base_url.trim_end_matches('/');
+        let mut url = format!("{base}/{path}");
+
+        let version = self.fingerprints.get(path).or(self.version.as_ref());
+        if let Some(version) = version {
+            url.push(if url.contains('?') { '&' } else { '?' });
+            url.push_str("v=");
+            url.push_str(version);
+        }
+
+        url
+    }
+}
+
+impl Default for Assets {
+    fn default() -> Self {
+        Self::new("/static")
+    }
+}
+
+fn is_external(path: &str) -> bool {
+    path.starts_with("//") || path.contains("://")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assets_url() {
+        let assets = Assets::default();
+        assert_eq!(assets.url("app.css"), "/static/app.css");
+        assert_eq!(assets.url("/app.css"), "/static/app.css");
+        assert_eq!(
+            assets.url("https://cdn.example.com/x.js"),
+            "https://cdn.example.com/x.js"
+        );
+        assert_eq!(assets.url("//cdn.example.com/x.js"), "//cdn.example.com/x.js");
+
+        let assets = Assets::new("https://cdn.example.com/assets/")
+            .with_version("42")
+            .with_fingerprint("app.css", "abc123");
+        assert_eq!(assets.url("app.css"), "https://cdn.example.com/assets/app.css?v=abc123");
+        assert_eq!(assets.url("app.js"), "https://cdn.example.com/assets/app.js?v=42");
+        assert_eq!(
+            assets.url("app.js?module"),
+            "https://cdn.example.com/assets/app.js?module&v=42"
+        );
+    }
+}
@@ -0,0 +1,224 @@
+use std::borrow::Cow;
+use std::ops::Range;
+
+use hypertext::{Buffer, Renderable};
+
+use crate::Classes;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Attrs {
+    pub id: Option<Cow<'static, str>>,
+    pub classes: Classes,
+    pub data: Vec<(Cow<'static, str>, Cow<'static, str>)>,
+    pub aria: Vec<(Cow<'static, str>, Cow<'static, str>)>,
+}
+
+impl Attrs {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_id(mut self, id: impl Into<Cow<'static, str>>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn with_class(mut self, class: impl Into<Cow<'static, str>>) -> Self {
+        self.classes.push(class);
+        self
+    }
+
+    pub fn with_classes(mut self, classes: Classes) -> Self {
+        self.classes = self.classes.merge(&classes);
+        self
+    }
+
+    /// Adds a `data-*` attribute; the `data-` prefix is optional.
+    pub fn with_data(mut self, name: impl Into<Cow<'static, str>>, value: impl Into<Cow<'static, str>>) -> Self {
+        set(&mut self.data, name.into(), "data-", value.into());
+        self
+    }
+
+    /// Adds an `aria-*` attribute; the `aria-` prefix is optional.
+    pub fn with_aria(mut self, name: impl Into<Cow<'static, str>>, value: impl Into<Cow<'static, str>>) -> Self {
+        set(&mut self.aria, name.into(), "aria-", value.into());
+        self
+    }
+
+    pub fn with_test_id(self, id: impl Into<Cow<'static, str>>) -> Self {
+        self.with_data("testid", id)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.id.is_none() && self.classes.is_empty() && self.data.is_empty() && self.aria.is_empty()
+    }
+
+    /// Wraps `root` so that these attributes are added to its first element when rendered.
+    pub fn spread<R: Renderable>(&self, root: R) -> Spread<'_, R> {
+        Spread { attrs: self, root }
+    }
+
+    /// Writes `tag` with these attributes applied: classes are merged into an existing `class`, while `id`,
+    /// `data-*` and `aria-*` replace the root's own value for the same attribute instead of duplicating it.
+    fn render_into_tag(&self, tag: &str, buffer: &mut Buffer) {
+        let mut tag = tag.to_string();
+        if !self.classes.is_empty() {
+            match find_attribute_value(&tag, "class") {
+                Some(value) => tag.insert_str(value.end, &format!(" {}", attribute_value(&self.classes))),
+                None => set_attribute(&mut tag, "class", &self.classes),
+            }
+        }
+
+        if let Some(id) = self.id.as_deref() {
+            set_attribute(&mut tag, "id", id);
+        }
+        for (prefix, pairs) in [("data-", &self.data), ("aria-", &self.aria)] {
+            for (name, value) in pairs {
+                set_attribute(&mut tag, &format!("{prefix}{name}"), value.as_ref());
+            }
+        }
+
+        buffer.dangerously_get_string().push_str(&tag);
+    }
+}
+
+fn set(
+    pairs: &mut Vec<(Cow<'static, str>, Cow<'static, str>)>,
+    name: Cow<'static, str>,
+    prefix: &str,
+    value: Cow<'static, str>,
+) {
+    let name = name.strip_prefix(prefix).unwrap_or(&name).to_ascii_lowercase();
+    let name: String = name
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        .collect();
+    if name.is_empty() {
+        return;
+    }
+
+    match pairs.iter_mut().find(|(existing, _)| *existing == name) {
+        Some((_, existing)) => *existing = value,
+        None => pairs.push((Cow::Owned(name), value)),
+    }
+}
+
+fn attribute_value<V: Renderable<hypertext::context::AttributeValue> + ?Sized>(value: &V) -> String {
+    let mut buffer = Buffer::new();
+    value.render_to(buffer.as_attribute_buffer());
+    buffer.dangerously_get_string().clone()
+}
+
+/// Replaces the value of `name` inside a start tag, or appends the attribute if the tag does not have it.
+fn set_attribute<V: Renderable<hypertext::context::AttributeValue> + ?Sized>(tag: &mut String, name: &str, value: &V) {
+    let value = attribute_value(value);
+    match find_attribute_value(tag, name) {
+        Some(range) => tag.replace_range(range, &value),
+        None => {
+            tag.push(' ');
+            tag.push_str(name);
+            tag.push_str("=\"");
+            tag.push_str(&value);
+            tag.push('"');
+        },
+    }
+}
+
+/// Returns the byte range of the value of `name="..."` inside a start tag.
+fn find_attribute_value(tag: &str, name: &str) -> Option<Range<usize>> {
+    let needle = format!(" {name}=\"");
+    let start = tag.find(&needle)? + needle.len();
+    tag[start..].find('"').map(|end| start..start + end)
+}
+
+/// Returns the end of the first start tag (excluding `>` or `/>`), if the markup begins with an element.
+fn start_tag_end(html: &str) -> Option<usize> {
+    let trimmed = html.trim_start();
+    let offset = html.len() - trimmed.len();
+    if !trimmed.starts_with('<') || !trimmed[1..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    let end = offset + trimmed.find('>')?;
+    Some(if html[..end].ends_with('/') { end - 1 } else { end })
+}
+
+#[derive(Debug)]
+pub struct Spread<'a, R> {
+    attrs: &'a Attrs,
+    root: R,
+}
+
+impl<R: Renderable> Renderable for Spread<'_, R> {
+    fn render_to(&self, buffer: &mut Buffer) {
+        if self.attrs.is_empty() {
+            return self.root.render_to(buffer);
+        }
+
+        let mut root = Buffer::new();
+        self.root.render_to(&mut root);
+        let html = root.rendered().into_inner();
+
+        match start_tag_end(&html) {
+            Some(end) => {
+                self.attrs.render_into_tag(&html[..end], buffer);
+                buffer.dangerously_get_string().push_str(&html[end..]);
+            },
+            None => buffer.dangerously_get_string().push_str(&html),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hypertext::prelude::{AriaAttributes, GlobalAttributes};
+    use hypertext::rsx;
+
+    use super::*;
+    use crate::hypertext_elements;
+
+    #[test]
+    fn test_attrs_spread() {
+        let attrs = Attrs::new()
+            .with_id("save")
+            .with_class("primary")
+            .with_test_id("save-button")
+            .with_data("data-Track", "click \"save\"")
+            .with_aria("describedby", "save-hint")
+            .with_data("!!", "ignored");
+
+        let html = attrs.spread(rsx! { <div class="x">"Save"</div> }).render();
+        assert_eq!(
+            html.as_inner(),
+            r#"<div class="x primary" id="save" data-testid="save-button" data-track="click &quot;save&quot;" aria-describedby="save-hint">Save</div>"#
+        );
+
+        let html = attrs.spread(rsx! { <input id="name"> }).render();
+        assert_eq!(
+            html.as_inner(),
+            r#"<input id="save" class="primary" data-testid="save-button" data-track="click &quot;save&quot;" aria-describedby="save-hint">"#
+        );
+
+        let html = Attrs::new().with_id("x").spread("text").render();
+        assert_eq!(html.as_inner(), "text");
+    }
+
+    #[test]
+    fn test_attrs_spread_replaces_existing_attributes() {
+        let attrs = Attrs::new().with_aria("label", "Saving").with_data("state", "busy");
+
+        let html = attrs
+            .spread(rsx! { <span aria-label="Loading" data-state="idle" class="x">"…"</span> })
+            .render();
+        assert_eq!(
+            html.as_inner(),
+            r#"<span aria-label="Saving" data-state="busy" class="x">…</span>"#
+        );
+
+        let html = Attrs::new()
+            .with_id("dialog")
+            .spread(rsx! { <div id="root" aria-hidden="true"></div> })
+            .render();
+        assert_eq!(html.as_inner(), r#"<div id="dialog" aria-hidden="true"></div>"#);
+    }
+}
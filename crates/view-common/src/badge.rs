@@ -0,0 +1,157 @@
+use std::borrow::Cow;
+
+use hypertext::prelude::GlobalAttributes;
+use hypertext::{Renderable, rsx};
+
+use crate::{Appearance, Attrs, Cluster, Gap, Size, Variant, hypertext_elements};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Badge {
+    pub variant: Variant,
+    pub appearance: Appearance,
+    pub pill: bool,
+    pub pulse: bool,
+    pub attrs: Attrs,
+}
+
+impl Badge {
+    pub fn new(variant: Variant) -> Self {
+        Self {
+            variant,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_variant(mut self, variant: Variant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    pub fn with_appearance(mut self, appearance: Appearance) -> Self {
+        self.appearance = appearance;
+        self
+    }
+
+    pub fn with_pill(mut self, pill: bool) -> Self {
+        self.pill = pill;
+        self
+    }
+
+    pub fn with_pulse(mut self, pulse: bool) -> Self {
+        self.pulse = pulse;
+        self
+    }
+
+    pub fn with_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs = attrs;
+        self
+    }
+
+    pub fn render(&self, children: impl Renderable) -> impl Renderable {
+        self.attrs.spread(rsx! {
+            <wa-badge
+                variant=(self.variant)
+                appearance=(self.appearance)
+                pill[self.pill]
+                attention=[self.pulse.then_some("pulse")]
+            >
+                (children)
+            </wa-badge>
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Tag {
+    pub variant: Variant,
+    pub appearance: Appearance,
+    pub size: Size,
+    pub pill: bool,
+    pub removable: bool,
+    pub attrs: Attrs,
+}
+
+impl Tag {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_variant(mut self, variant: Variant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    pub fn with_appearance(mut self, appearance: Appearance) -> Self {
+        self.appearance = appearance;
+        self
+    }
+
+    pub fn with_size(mut self, size: Size) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn with_pill(mut self, pill: bool) -> Self {
+        self.pill = pill;
+        self
+    }
+
+    pub fn with_removable(mut self, removable: bool) -> Self {
+        self.removable = removable;
+        self
+    }
+
+    pub fn with_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs = attrs;
+        self
+    }
+
+    /// Renders a single tag; `value` is exposed as `data-tag` so remove handlers know which tag was dismissed.
+    pub fn render(&self, value: &str, children: impl Renderable) -> impl Renderable {
+        self.attrs.spread(rsx! {
+            <wa-tag
+                variant=(self.variant)
+                appearance=(self.appearance)
+                size=(self.size)
+                pill[self.pill]
+                with-remove[self.removable]
+                data-tag=(value)
+            >
+                (children)
+            </wa-tag>
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TagList {
+    pub name: Cow<'static, str>,
+    pub tag: Tag,
+}
+
+impl TagList {
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: name.into(),
+            tag: Tag::new().with_removable(true),
+        }
+    }
+
+    pub fn with_tag(mut self, tag: Tag) -> Self {
+        self.tag = tag;
+        self
+    }
+
+    /// Renders each tag alongside a hidden input, so the remaining tags are posted with the form.
+    pub fn render<'a, T: AsRef<str> + 'a>(&'a self, tags: impl IntoIterator<Item = T>) -> impl Renderable + 'a {
+        let tags: Vec<T> = tags.into_iter().collect();
+        rsx! {
+            <div class=(Cluster::new().with_gap(Gap::TwoXs).classes()) data-tag-list=(self.name)>
+                @for tag in &tags {
+                    (self.tag.render(tag.as_ref(), tag.as_ref()))
+                    <input type="hidden" name=(self.name) value=(tag.as_ref())>
+                }
+            </div>
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use std::borrow::Cow;
+
+use hypertext::prelude::AriaAttributes;
+use hypertext::{Renderable, rsx};
+
+use crate::{Attrs, hypertext_elements};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BreadcrumbItem<'a> {
+    pub label: Cow<'a, str>,
+    pub href: Cow<'a, str>,
+}
+
+impl<'a> BreadcrumbItem<'a> {
+    pub fn new(label: impl Into<Cow<'a, str>>, href: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            label: label.into(),
+            href: href.into(),
+        }
+    }
+}
+
+impl<'a, L: Into<Cow<'a, str>>, H: Into<Cow<'a, str>>> From<(L, H)> for BreadcrumbItem<'a> {
+    fn from((label, href): (L, H)) -> Self {
+        Self::new(label, href)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Breadcrumbs {
+    pub label: Cow<'static, str>,
+    pub attrs: Attrs,
+}
+
+impl Breadcrumbs {
+    pub fn new() -> Self {
+        Self {
+            label: Cow::Borrowed("Breadcrumb"),
+            attrs: Attrs::new(),
+        }
+    }
+
+    pub fn with_label(mut self, label: impl Into<Cow<'static, str>>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    pub fn with_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs = attrs;
+        self
+    }
+
+    pub fn render<'a>(
+        &'a self,
+        items: impl IntoIterator<Item = impl Into<BreadcrumbItem<'a>>>,
+    ) -> impl Renderable + 'a {
+        let items: Vec<BreadcrumbItem> = items.into_iter().map(Into::into).collect();
+        let last = items.len().saturating_sub(1);
+        self.attrs.spread(rsx! {
+            <wa-breadcrumb label=(self.label)>
+                @for (index, item) in items.iter().enumerate() {
+                    @if index == last {
+                        <wa-breadcrumb-item aria-current="page">(item.label)</wa-breadcrumb-item>
+                    } @else {
+                        <wa-breadcrumb-item href=(item.href)>(item.label)</wa-breadcrumb-item>
+                    }
+                }
+            </wa-breadcrumb>
+        })
+    }
+}
@@ -0,0 +1,178 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use hypertext::prelude::{AriaAttributes, GlobalAttributes};
+use hypertext::{Renderable, rsx};
+
+use crate::{Appearance, Attrs, Classes, Icon, Size, Variant, hypertext_elements};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ButtonType {
+    #[default]
+    Button,
+    Submit,
+    Reset,
+}
+
+impl ButtonType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ButtonType::Button => "button",
+            ButtonType::Submit => "submit",
+            ButtonType::Reset => "reset",
+        }
+    }
+}
+
+impl fmt::Display for ButtonType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Button {
+    pub variant: Variant,
+    pub appearance: Appearance,
+    pub size: Size,
+    pub button_type: ButtonType,
+    pub form: Option<Cow<'static, str>>,
+    pub name: Option<Cow<'static, str>>,
+    pub value: Option<Cow<'static, str>>,
+    pub pressed: Option<bool>,
+    pub classes: Classes,
+    pub start_icon: Option<Icon>,
+    pub end_icon: Option<Icon>,
+    pub disabled: bool,
+    pub attrs: Attrs,
+}
+
+impl Button {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn submit() -> Self {
+        Self::new().with_type(ButtonType::Submit).with_variant(Variant::Brand)
+    }
+
+    pub fn with_variant(mut self, variant: Variant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    pub fn with_appearance(mut self, appearance: Appearance) -> Self {
+        self.appearance = appearance;
+        self
+    }
+
+    pub fn with_size(mut self, size: Size) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn with_type(mut self, button_type: ButtonType) -> Self {
+        self.button_type = button_type;
+        self
+    }
+
+    pub fn with_form(mut self, form: impl Into<Cow<'static, str>>) -> Self {
+        self.form = Some(form.into());
+        self
+    }
+
+    pub fn without_form(mut self) -> Self {
+        self.form = None;
+        self
+    }
+
+    pub fn with_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn without_name(mut self) -> Self {
+        self.name = None;
+        self
+    }
+
+    pub fn with_value(mut self, value: impl Into<Cow<'static, str>>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    pub fn without_value(mut self) -> Self {
+        self.value = None;
+        self
+    }
+
+    pub fn with_pressed(mut self, pressed: Option<bool>) -> Self {
+        self.pressed = pressed;
+        self
+    }
+
+    pub fn with_class(mut self, class: impl Into<Cow<'static, str>>) -> Self {
+        self.classes.push(class);
+        self
+    }
+
+    pub fn with_classes(mut self, classes: Classes) -> Self {
+        self.classes = classes;
+        self
+    }
+
+    pub fn with_start_icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.start_icon = Some(icon.into());
+        self
+    }
+
+    pub fn without_start_icon(mut self) -> Self {
+        self.start_icon = None;
+        self
+    }
+
+    pub fn with_end_icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.end_icon = Some(icon.into());
+        self
+    }
+
+    pub fn without_end_icon(mut self) -> Self {
+        self.end_icon = None;
+        self
+    }
+
+    pub fn with_disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn with_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs = attrs;
+        self
+    }
+
+    pub fn render(&self, children: impl Renderable) -> impl Renderable {
+        self.attrs.spread(rsx! {
+            <wa-button
+                class=[self.classes.non_empty()]
+                type=(self.button_type.as_str())
+                form=[self.form.as_ref()]
+                name=[self.name.as_ref()]
+                value=[self.value.as_ref()]
+                aria-pressed=[self.pressed]
+                variant=(self.variant)
+                appearance=(self.appearance)
+                size=(self.size)
+                disabled[self.disabled]
+            >
+                @if let Some(icon) = &self.start_icon {
+                    (icon.render_in(Some("start")))
+                }
+                (children)
+                @if let Some(icon) = &self.end_icon {
+                    (icon.render_in(Some("end")))
+                }
+            </wa-button>
+        })
+    }
+}
@@ -0,0 +1,108 @@
+use std::borrow::Cow;
+
+use hypertext::prelude::GlobalAttributes;
+use hypertext::{Renderable, rsx, rsx_borrow};
+
+use crate::{
+    AlignItems, Appearance, Attrs, Button, Classes, Flank, Icon, IconVariant, RenderContext, Size, Slots, Variant,
+    ViewComponent, hypertext_elements,
+};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, ViewComponent)]
+pub struct Callout {
+    pub variant: Variant,
+    #[component(into)]
+    pub icon: Option<Icon>,
+    #[component(skip)]
+    pub classes: Classes,
+    pub dismissible: bool,
+    pub dismiss_script: Option<Cow<'static, str>>,
+    pub attrs: Attrs,
+}
+
+impl Callout {
+    pub fn new(variant: Variant) -> Self {
+        Self {
+            variant,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_class(mut self, class: impl Into<Cow<'static, str>>) -> Self {
+        self.classes.push(class);
+        self
+    }
+
+    pub fn with_classes(mut self, classes: Classes) -> Self {
+        self.classes = classes;
+        self
+    }
+
+    pub fn render(&self, ctx: &RenderContext, children: impl Renderable) -> impl Renderable {
+        let icon = self.icon.as_ref().map(|icon| icon.render_in(Some("icon")));
+        self.render_parts(ctx, icon, children)
+    }
+
+    pub fn render_slots(&self, ctx: &RenderContext, slots: &Slots) -> impl Renderable {
+        let icon = rsx! {
+            @if slots.has("icon") {
+                (slots.render("icon"))
+            } @else if let Some(icon) = &self.icon {
+                (icon.render_in(Some("icon")))
+            }
+        };
+        self.render_parts(ctx, icon, slots.render_children_or(()))
+    }
+
+    fn render_parts(&self, ctx: &RenderContext, icon: impl Renderable, children: impl Renderable) -> impl Renderable {
+        let close_icon = Icon::system("xmark")
+            .with_variant(IconVariant::Solid)
+            .with_label("Close");
+        let close_button = Button::new()
+            .with_class("close")
+            .with_appearance(Appearance::Plain)
+            .with_variant(self.variant)
+            .with_size(Size::Small);
+
+        self.attrs.spread(rsx! {
+            <wa-callout class=[self.classes.non_empty()] variant=(self.variant)>
+                (icon)
+                @if self.dismissible {
+                    (Flank::end().with_align(AlignItems::Start).render(rsx_borrow! {
+                        <div>(children)</div>
+                        <div>
+                            (close_button.render(close_icon.render()))
+                        </div>
+                    }))
+                    @if let Some(script) = &self.dismiss_script {
+                        <script nonce=[ctx.nonce()]>(script)</script>
+                    }
+                } @else {
+                    (children)
+                }
+            </wa-callout>
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{assert_has_selector, assert_no_selector};
+
+    #[test]
+    fn test_render_dismissible() {
+        let ctx = RenderContext::new();
+        let callout = Callout::new(Variant::Warning)
+            .with_icon("triangle-exclamation")
+            .with_dismissible(true);
+        let html = callout.render(&ctx, "Careful").render().into_inner();
+
+        assert_has_selector(
+            &html,
+            "wa-callout[variant=warning] > wa-icon[slot=icon][name=triangle-exclamation]",
+        );
+        assert_has_selector(&html, "wa-callout div wa-button.close wa-icon[label=Close]");
+        assert_no_selector(&html, "script");
+    }
+}
@@ -0,0 +1,58 @@
+use std::borrow::Cow;
+
+use hypertext::prelude::GlobalAttributes;
+use hypertext::{Renderable, rsx};
+
+use crate::{Appearance, Attrs, Classes, Slots, hypertext_elements};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Card {
+    pub appearance: Option<Appearance>,
+    pub classes: Classes,
+    pub attrs: Attrs,
+}
+
+impl Card {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_appearance(mut self, appearance: Appearance) -> Self {
+        self.appearance = Some(appearance);
+        self
+    }
+
+    pub fn with_class(mut self, class: impl Into<Cow<'static, str>>) -> Self {
+        self.classes.push(class);
+        self
+    }
+
+    pub fn with_classes(mut self, classes: Classes) -> Self {
+        self.classes = classes;
+        self
+    }
+
+    pub fn with_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs = attrs;
+        self
+    }
+
+    pub fn render(&self, slots: &Slots) -> impl Renderable {
+        self.attrs.spread(rsx! {
+            <wa-card
+                class=[self.classes.non_empty()]
+                appearance=[self.appearance]
+                with-header[slots.has("header")]
+                with-media[slots.has("media")]
+                with-footer[slots.has("footer")]
+            >
+                (slots.render("media"))
+                (slots.render("header"))
+                (slots.render("header-actions"))
+                (slots.render_children_or(()))
+                (slots.render("footer"))
+                (slots.render("footer-actions"))
+            </wa-card>
+        })
+    }
+}
@@ -0,0 +1,145 @@
+use std::borrow::Cow;
+
+use hypertext::prelude::AriaAttributes;
+use hypertext::{Renderable, rsx};
+use zabawa_validation::ValidationErrors;
+
+use crate::{Attrs, FieldHint, hypertext_elements};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Checkbox {
+    pub name: Cow<'static, str>,
+    pub value: Cow<'static, str>,
+    pub hint: Option<Cow<'static, str>>,
+    pub required: bool,
+    pub attrs: Attrs,
+}
+
+impl Checkbox {
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: name.into(),
+            value: Cow::Borrowed("on"),
+            hint: None,
+            required: false,
+            attrs: Attrs::new(),
+        }
+    }
+
+    pub fn with_value(mut self, value: impl Into<Cow<'static, str>>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    pub fn with_hint(mut self, hint: impl Into<Cow<'static, str>>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    pub fn without_hint(mut self) -> Self {
+        self.hint = None;
+        self
+    }
+
+    pub fn with_required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    pub fn with_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs = attrs;
+        self
+    }
+
+    pub fn render<'a>(
+        &'a self,
+        label: impl Renderable + 'a,
+        checked: bool,
+        errors: &'a ValidationErrors,
+    ) -> impl Renderable + 'a {
+        let invalid = errors.has_field(&self.name);
+        self.attrs.spread(rsx! {
+            <wa-checkbox
+                name=(self.name)
+                value=(self.value)
+                checked[checked]
+                hint=[self.hint.as_ref().filter(|_| !invalid)]
+                required[self.required]
+                data-invalid[invalid]
+                aria-invalid=[invalid.then_some("true")]
+            >
+                (label)
+                (FieldHint { name: &self.name, hint: self.hint.as_deref(), errors })
+            </wa-checkbox>
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Switch {
+    pub name: Cow<'static, str>,
+    pub value: Cow<'static, str>,
+    pub hint: Option<Cow<'static, str>>,
+    pub required: bool,
+    pub attrs: Attrs,
+}
+
+impl Switch {
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: name.into(),
+            value: Cow::Borrowed("on"),
+            hint: None,
+            required: false,
+            attrs: Attrs::new(),
+        }
+    }
+
+    pub fn with_value(mut self, value: impl Into<Cow<'static, str>>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    pub fn with_hint(mut self, hint: impl Into<Cow<'static, str>>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    pub fn without_hint(mut self) -> Self {
+        self.hint = None;
+        self
+    }
+
+    pub fn with_required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    pub fn with_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs = attrs;
+        self
+    }
+
+    pub fn render<'a>(
+        &'a self,
+        label: impl Renderable + 'a,
+        checked: bool,
+        errors: &'a ValidationErrors,
+    ) -> impl Renderable + 'a {
+        let invalid = errors.has_field(&self.name);
+        self.attrs.spread(rsx! {
+            <wa-switch
+                name=(self.name)
+                value=(self.value)
+                checked[checked]
+                hint=[self.hint.as_ref().filter(|_| !invalid)]
+                required[self.required]
+                data-invalid[invalid]
+                aria-invalid=[invalid.then_some("true")]
+            >
+                (label)
+                (FieldHint { name: &self.name, hint: self.hint.as_deref(), errors })
+            </wa-switch>
+        })
+    }
+}
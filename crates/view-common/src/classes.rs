@@ -0,0 +1,154 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use hypertext::context::AttributeValue;
+use hypertext::{Buffer, Renderable};
+
+use crate::Variant;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Classes(Vec<Cow<'static, str>>);
+
+impl Classes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(mut self, class: impl Into<Cow<'static, str>>) -> Self {
+        self.push(class);
+        self
+    }
+
+    pub fn add_if(mut self, condition: bool, class: impl Into<Cow<'static, str>>) -> Self {
+        if condition {
+            self.push(class);
+        }
+        self
+    }
+
+    pub fn add_option(mut self, class: Option<impl Into<Cow<'static, str>>>) -> Self {
+        if let Some(class) = class {
+            self.push(class);
+        }
+        self
+    }
+
+    pub fn variant(self, variant: Variant) -> Self {
+        self.add(format!("wa-{variant}"))
+    }
+
+    pub fn merge(mut self, other: &Classes) -> Self {
+        for class in &other.0 {
+            self.insert(class.clone());
+        }
+        self
+    }
+
+    pub fn push(&mut self, class: impl Into<Cow<'static, str>>) {
+        match class.into() {
+            Cow::Borrowed(classes) => {
+                for class in classes.split_whitespace() {
+                    self.insert(Cow::Borrowed(class));
+                }
+            },
+            Cow::Owned(classes) if classes.contains(char::is_whitespace) => {
+                for class in classes.split_whitespace() {
+                    self.insert(Cow::Owned(class.to_owned()));
+                }
+            },
+            Cow::Owned(class) => {
+                if !class.is_empty() {
+                    self.insert(Cow::Owned(class));
+                }
+            },
+        }
+    }
+
+    pub fn contains(&self, class: &str) -> bool {
+        self.0.iter().any(|existing| existing == class)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(AsRef::as_ref)
+    }
+
+    /// Returns `None` for an empty list, so `class=[classes.non_empty()]` omits the attribute entirely.
+    pub fn non_empty(&self) -> Option<&Self> {
+        (!self.is_empty()).then_some(self)
+    }
+
+    fn insert(&mut self, class: Cow<'static, str>) {
+        if !self.contains(&class) {
+            self.0.push(class);
+        }
+    }
+}
+
+impl fmt::Display for Classes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, class) in self.0.iter().enumerate() {
+            if index > 0 {
+                f.write_str(" ")?;
+            }
+            f.write_str(class)?;
+        }
+        Ok(())
+    }
+}
+
+impl Renderable<AttributeValue> for Classes {
+    fn render_to(&self, buffer: &mut Buffer<AttributeValue>) {
+        for (index, class) in self.0.iter().enumerate() {
+            if index > 0 {
+                " ".render_to(buffer);
+            }
+            class.as_ref().render_to(buffer);
+        }
+    }
+}
+
+impl<C: Into<Cow<'static, str>>> FromIterator<C> for Classes {
+    fn from_iter<T: IntoIterator<Item = C>>(iter: T) -> Self {
+        let mut classes = Classes::new();
+        for class in iter {
+            classes.push(class);
+        }
+        classes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classes_builder() {
+        let classes = Classes::new()
+            .add("close")
+            .add_if(true, "is-active")
+            .add_if(false, "is-hidden")
+            .add_option(Some("extra"))
+            .add_option(None::<&str>)
+            .variant(Variant::Danger);
+        assert_eq!(classes.to_string(), "close is-active extra wa-danger");
+    }
+
+    #[test]
+    fn test_classes_skip_duplicates_and_empty() {
+        let classes = Classes::new()
+            .add("a")
+            .add("")
+            .add("  ")
+            .add("a b")
+            .add(String::from("b  c"))
+            .add(String::new());
+        assert_eq!(classes.iter().collect::<Vec<_>>(), ["a", "b", "c"]);
+        assert!(Classes::new().non_empty().is_none());
+        assert!(classes.non_empty().is_some());
+    }
+}
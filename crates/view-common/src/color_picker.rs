@@ -0,0 +1,113 @@
+use std::borrow::Cow;
+
+use hypertext::prelude::AriaAttributes;
+use hypertext::{Renderable, rsx};
+use zabawa_validation::{ValidationErrors, parse_hex_color};
+
+use crate::{Attrs, FieldHint, hypertext_elements};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ColorPicker {
+    pub name: Cow<'static, str>,
+    pub label: Cow<'static, str>,
+    pub hint: Option<Cow<'static, str>>,
+    pub swatches: Vec<Cow<'static, str>>,
+    pub opacity: bool,
+    pub required: bool,
+    pub attrs: Attrs,
+}
+
+impl ColorPicker {
+    pub fn new(name: impl Into<Cow<'static, str>>, label: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: name.into(),
+            label: label.into(),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_hint(mut self, hint: impl Into<Cow<'static, str>>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    pub fn without_hint(mut self) -> Self {
+        self.hint = None;
+        self
+    }
+
+    /// Adds a preset; presets that are not valid hex colors are skipped when rendering.
+    pub fn with_swatch(mut self, color: impl Into<Cow<'static, str>>) -> Self {
+        self.swatches.push(color.into());
+        self
+    }
+
+    pub fn with_swatches(mut self, colors: impl IntoIterator<Item = impl Into<Cow<'static, str>>>) -> Self {
+        self.swatches.extend(colors.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn with_opacity(mut self, opacity: bool) -> Self {
+        self.opacity = opacity;
+        self
+    }
+
+    pub fn with_required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    pub fn with_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs = attrs;
+        self
+    }
+
+    fn swatches_attr(&self) -> Option<String> {
+        let swatches: Vec<String> = self
+            .swatches
+            .iter()
+            .filter_map(|color| parse_hex_color(color).ok())
+            .collect();
+        (!swatches.is_empty()).then(|| swatches.join("; "))
+    }
+
+    /// Renders the picker; a valid `value` is normalized to the same form [`parse_hex_color`] accepts on submit.
+    pub fn render<'a>(&'a self, value: &'a str, errors: &'a ValidationErrors) -> impl Renderable + 'a {
+        let invalid = errors.has_field(&self.name);
+        let value = parse_hex_color(value).map_or(Cow::Borrowed(value), Cow::Owned);
+        self.attrs.spread(rsx! {
+            <wa-color-picker
+                name=(self.name)
+                label=(self.label)
+                value=(value)
+                format="hex"
+                swatches=[self.swatches_attr()]
+                opacity[self.opacity]
+                hint=[self.hint.as_ref().filter(|_| !invalid)]
+                required[self.required]
+                data-invalid[invalid]
+                aria-invalid=[invalid.then_some("true")]
+            >
+                (FieldHint { name: &self.name, hint: self.hint.as_deref(), errors })
+            </wa-color-picker>
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_has_selector;
+
+    #[test]
+    fn test_render() {
+        let picker = ColorPicker::new("brand", "Brand color").with_swatches(["#F00", "nope", "#00ff00"]);
+        let mut errors = ValidationErrors::new();
+        let html = picker.render("#ABC", &errors).render().into_inner();
+        assert_has_selector(&html, "wa-color-picker[value='#aabbcc'][swatches='#ff0000; #00ff00']");
+
+        errors.add("brand", "invalid color");
+        let html = picker.render("blue", &errors).render().into_inner();
+        assert_has_selector(&html, "wa-color-picker[value=blue][aria-invalid=true] .field-error");
+    }
+}
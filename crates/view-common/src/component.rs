@@ -0,0 +1,158 @@
+use std::fmt;
+
+use hypertext::{Buffer, Renderable};
+
+use crate::RenderContext;
+
+pub trait Component {
+    type Props<'p>;
+
+    fn render<'p>(&self, props: Self::Props<'p>, ctx: &RenderContext) -> impl Renderable;
+
+    fn boxed<'c, 'p>(self) -> BoxedComponent<'c, Self::Props<'p>>
+    where
+        Self: Sized + 'c,
+    {
+        BoxedComponent(Box::new(move |props, ctx, buffer| {
+            Component::render(&self, props, ctx).render_to(buffer)
+        }))
+    }
+}
+
+type RenderFn<'c, P> = dyn Fn(P, &RenderContext, &mut Buffer) + 'c;
+
+pub struct BoxedComponent<'c, P>(Box<RenderFn<'c, P>>);
+
+impl<'c, P: Clone> Component for BoxedComponent<'c, P> {
+    type Props<'p> = P;
+
+    fn render<'p>(&self, props: P, ctx: &RenderContext) -> impl Renderable {
+        BoxedRender {
+            render: &self.0,
+            props,
+            ctx,
+        }
+    }
+}
+
+impl<P> fmt::Debug for BoxedComponent<'_, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BoxedComponent").finish_non_exhaustive()
+    }
+}
+
+struct BoxedRender<'a, 'b, 'c, P> {
+    render: &'a RenderFn<'c, P>,
+    props: P,
+    ctx: &'b RenderContext,
+}
+
+impl<P: Clone> Renderable for BoxedRender<'_, '_, '_, P> {
+    fn render_to(&self, buffer: &mut Buffer) {
+        (self.render)(self.props.clone(), self.ctx, buffer)
+    }
+}
+
+pub(crate) trait ErasedRenderable {
+    fn erased_render_to(&self, buffer: &mut Buffer);
+}
+
+impl<T: Renderable> ErasedRenderable for T {
+    fn erased_render_to(&self, buffer: &mut Buffer) {
+        self.render_to(buffer)
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Children<'p>(&'p dyn ErasedRenderable);
+
+impl<'p> Children<'p> {
+    pub fn new(children: &'p impl Renderable) -> Self {
+        Self(children)
+    }
+
+    pub(crate) fn erased(children: &'p dyn ErasedRenderable) -> Self {
+        Self(children)
+    }
+}
+
+impl Renderable for Children<'_> {
+    fn render_to(&self, buffer: &mut Buffer) {
+        self.0.erased_render_to(buffer)
+    }
+}
+
+impl fmt::Debug for Children<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Children").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use hypertext::prelude::GlobalAttributes;
+    use hypertext::rsx;
+
+    use super::*;
+    use crate::{Animation, Attrs, ViewComponent, hypertext_elements};
+
+    #[derive(Clone, Debug, Default, ViewComponent)]
+    struct Note {
+        title: Cow<'static, str>,
+        subtitle: Option<Cow<'static, str>>,
+        open: bool,
+        #[component(attrs)]
+        attrs: Attrs,
+    }
+
+    impl Note {
+        fn render(&self, ctx: &RenderContext, children: impl Renderable) -> impl Renderable {
+            self.attrs.spread(rsx! {
+                <details open[self.open] lang=(ctx.locale)>
+                    <summary>(self.title) @if let Some(subtitle) = &self.subtitle { " – " (subtitle) }</summary>
+                    (children)
+                </details>
+            })
+        }
+    }
+
+    #[test]
+    fn test_boxed_component_matches_direct_render() {
+        let ctx = RenderContext::new();
+        let animation = Animation::new("fadeIn", 200, 1);
+        let children = "hello";
+
+        let direct = Component::render(&animation, Children::new(&children), &ctx).render();
+        let boxed = animation.boxed();
+        let dynamic = Component::render(&boxed, Children::new(&children), &ctx).render();
+
+        assert_eq!(direct.as_inner(), dynamic.as_inner());
+        assert!(direct.as_inner().contains("hello"));
+    }
+
+    #[test]
+    fn test_derived_component() {
+        let ctx = RenderContext::new();
+        let note = Note::default()
+            .with_title("Heads up")
+            .with_subtitle("draft")
+            .without_subtitle()
+            .with_open(true)
+            .with_id("note")
+            .with_data("kind", "info");
+        let children = "body";
+
+        let html = Component::render(&note, Children::new(&children), &ctx).render();
+        let html = html.as_inner();
+        assert!(html.starts_with("<details"), "{html}");
+        assert!(html.contains(r#"id="note""#));
+        assert!(html.contains(r#"data-kind="info""#));
+        assert!(html.contains("<summary>Heads up</summary>body"));
+        assert_eq!(
+            note.boxed().render(Children::new(&children), &ctx).render().as_inner(),
+            html
+        );
+    }
+}
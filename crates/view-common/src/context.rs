@@ -0,0 +1,111 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use hypertext::{Buffer, Renderable, Rendered};
+
+use crate::{Assets, ColorScheme, OutputMode};
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct RenderContext {
+    pub nonce: Option<Cow<'static, str>>,
+    pub locale: Cow<'static, str>,
+    pub theme: ColorScheme,
+    pub assets: Assets,
+    pub output_mode: OutputMode,
+    pub csrf_token: Option<Cow<'static, str>>,
+}
+
+impl RenderContext {
+    pub fn new() -> Self {
+        Self {
+            nonce: None,
+            locale: Cow::Borrowed("en"),
+            theme: ColorScheme::System,
+            assets: Assets::default(),
+            output_mode: OutputMode::Compact,
+            csrf_token: None,
+        }
+    }
+
+    pub fn with_nonce(mut self, nonce: impl Into<Cow<'static, str>>) -> Self {
+        self.nonce = Some(nonce.into());
+        self
+    }
+
+    pub fn without_nonce(mut self) -> Self {
+        self.nonce = None;
+        self
+    }
+
+    pub fn with_locale(mut self, locale: impl Into<Cow<'static, str>>) -> Self {
+        self.locale = locale.into();
+        self
+    }
+
+    pub fn with_theme(mut self, theme: ColorScheme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    pub fn with_assets(mut self, assets: Assets) -> Self {
+        self.assets = assets;
+        self
+    }
+
+    pub fn with_asset_base_url(mut self, url: impl Into<Cow<'static, str>>) -> Self {
+        self.assets.base_url = url.into();
+        self
+    }
+
+    pub fn with_output_mode(mut self, output_mode: OutputMode) -> Self {
+        self.output_mode = output_mode;
+        self
+    }
+
+    pub fn with_csrf_token(mut self, token: impl Into<Cow<'static, str>>) -> Self {
+        self.csrf_token = Some(token.into());
+        self
+    }
+
+    pub fn without_csrf_token(mut self) -> Self {
+        self.csrf_token = None;
+        self
+    }
+
+    pub fn asset_url(&self, path: &str) -> String {
+        self.assets.url(path)
+    }
+
+    pub fn nonce(&self) -> Option<&str> {
+        self.nonce.as_deref()
+    }
+
+    pub fn csrf_token(&self) -> Option<&str> {
+        self.csrf_token.as_deref()
+    }
+
+    /// Renders a complete response body, formatted according to the output mode.
+    pub fn render(&self, renderable: impl Renderable) -> Rendered<String> {
+        let html = renderable.render().into_inner();
+        Buffer::dangerously_from_string(self.output_mode.apply(html)).rendered()
+    }
+}
+
+impl fmt::Debug for RenderContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RenderContext")
+            .field("nonce", &self.nonce)
+            .field("locale", &self.locale)
+            .field("theme", &self.theme)
+            .field("assets", &self.assets)
+            .field("output_mode", &self.output_mode)
+            .field("csrf_token", &self.csrf_token.as_ref().map(|_| "***"))
+            .finish()
+    }
+}
+
+impl Default for RenderContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,110 @@
+use std::borrow::Cow;
+
+use hypertext::{Renderable, rsx};
+
+use crate::{Attrs, Icon, hypertext_elements};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CopySource {
+    Value(Cow<'static, str>),
+    /// Copies from another element: `id`, `id[attribute]` or `id.property`.
+    From(Cow<'static, str>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CopyButton {
+    pub source: CopySource,
+    pub copy_label: Option<Cow<'static, str>>,
+    pub success_label: Option<Cow<'static, str>>,
+    pub error_label: Option<Cow<'static, str>>,
+    pub feedback_duration: Option<u32>,
+    pub icon: Option<Icon>,
+    pub disabled: bool,
+    pub attrs: Attrs,
+}
+
+impl CopyButton {
+    pub fn new(value: impl Into<Cow<'static, str>>) -> Self {
+        Self::from_source(CopySource::Value(value.into()))
+    }
+
+    pub fn from(target: impl Into<Cow<'static, str>>) -> Self {
+        Self::from_source(CopySource::From(target.into()))
+    }
+
+    fn from_source(source: CopySource) -> Self {
+        Self {
+            source,
+            copy_label: None,
+            success_label: None,
+            error_label: None,
+            feedback_duration: None,
+            icon: None,
+            disabled: false,
+            attrs: Attrs::new(),
+        }
+    }
+
+    pub fn with_copy_label(mut self, label: impl Into<Cow<'static, str>>) -> Self {
+        self.copy_label = Some(label.into());
+        self
+    }
+
+    pub fn with_success_label(mut self, label: impl Into<Cow<'static, str>>) -> Self {
+        self.success_label = Some(label.into());
+        self
+    }
+
+    pub fn with_error_label(mut self, label: impl Into<Cow<'static, str>>) -> Self {
+        self.error_label = Some(label.into());
+        self
+    }
+
+    pub fn with_feedback_duration(mut self, milliseconds: u32) -> Self {
+        self.feedback_duration = Some(milliseconds);
+        self
+    }
+
+    pub fn with_icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    pub fn without_icon(mut self) -> Self {
+        self.icon = None;
+        self
+    }
+
+    pub fn with_disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn with_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs = attrs;
+        self
+    }
+
+    pub fn render(&self) -> impl Renderable {
+        let (value, from) = match &self.source {
+            CopySource::Value(value) => (Some(value), None),
+            CopySource::From(from) => (None, Some(from)),
+        };
+
+        self.attrs.spread(rsx! {
+            <wa-copy-button
+                value=[value]
+                from=[from]
+                copy-label=[self.copy_label.as_ref()]
+                success-label=[self.success_label.as_ref()]
+                error-label=[self.error_label.as_ref()]
+                feedback-duration=[self.feedback_duration]
+                disabled[self.disabled]
+            >
+                @if let Some(icon) = &self.icon {
+                    (icon.render_in(Some("copy-icon")))
+                }
+            </wa-copy-button>
+        })
+    }
+}
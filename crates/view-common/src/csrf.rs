@@ -0,0 +1,87 @@
+use std::borrow::Cow;
+
+use hypertext::{Renderable, rsx};
+use zabawa_csrf::{DEFAULT_CSRF_FIELD_NAME, DEFAULT_CSRF_HEADER_NAME};
+use zabawa_validation::write_json_string;
+
+use crate::{RenderContext, hypertext_elements};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CsrfField {
+    pub field_name: Cow<'static, str>,
+    pub header_name: Cow<'static, str>,
+}
+
+impl CsrfField {
+    pub fn new() -> Self {
+        Self {
+            field_name: Cow::Borrowed(DEFAULT_CSRF_FIELD_NAME),
+            header_name: Cow::Borrowed(DEFAULT_CSRF_HEADER_NAME),
+        }
+    }
+
+    pub fn with_field_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.field_name = name.into();
+        self
+    }
+
+    pub fn with_header_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.header_name = name.into();
+        self
+    }
+
+    /// Renders the hidden form field, or nothing when the context carries no token.
+    pub fn render(&self, ctx: &RenderContext) -> impl Renderable {
+        rsx! {
+            @if let Some(token) = ctx.csrf_token() {
+                <input type="hidden" name=(self.field_name) value=(token)>
+            }
+        }
+    }
+
+    pub fn render_meta(&self, ctx: &RenderContext) -> impl Renderable {
+        rsx! {
+            @if let Some(token) = ctx.csrf_token() {
+                <meta name="csrf-token" content=(token)>
+                <meta name="csrf-header" content=(self.header_name)>
+            }
+        }
+    }
+
+    /// Returns the JSON value for an `hx-headers` attribute that sends the token with every htmx request.
+    pub fn hx_headers(&self, ctx: &RenderContext) -> Option<String> {
+        let token = ctx.csrf_token()?;
+        let mut json = String::from("{");
+        write_json_string(&mut json, &self.header_name);
+        json.push_str(": ");
+        write_json_string(&mut json, token);
+        json.push('}');
+        Some(json)
+    }
+}
+
+impl Default for CsrfField {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let field = CsrfField::new();
+        let ctx = RenderContext::new();
+        assert_eq!(field.render(&ctx).render().as_inner(), "");
+        assert_eq!(field.hx_headers(&ctx), None);
+
+        let ctx = ctx.with_csrf_token("a\"b");
+        assert_eq!(
+            field.render(&ctx).render().as_inner(),
+            r#"<input type="hidden" name="csrf_token" value="a&quot;b">"#
+        );
+        assert_eq!(field.hx_headers(&ctx).as_deref(), Some(r#"{"X-CSRF-Token": "a\"b"}"#));
+    }
+}
@@ -0,0 +1,155 @@
+use std::borrow::Cow;
+
+use hypertext::prelude::GlobalAttributes;
+use hypertext::{Renderable, rsx};
+
+use crate::{Attrs, Slots, hypertext_elements};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Dialog {
+    /// The dialog's element id, which [`Dialog::render_trigger`] targets. An id set in `attrs` takes precedence.
+    pub id: Cow<'static, str>,
+    pub label: Cow<'static, str>,
+    pub open: bool,
+    pub light_dismiss: bool,
+    pub header: bool,
+    pub attrs: Attrs,
+}
+
+impl Dialog {
+    pub fn new(id: impl Into<Cow<'static, str>>, label: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            open: false,
+            light_dismiss: true,
+            header: true,
+            attrs: Attrs::new(),
+        }
+    }
+
+    pub fn with_open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
+    pub fn with_light_dismiss(mut self, enabled: bool) -> Self {
+        self.light_dismiss = enabled;
+        self
+    }
+
+    pub fn with_header(mut self, enabled: bool) -> Self {
+        self.header = enabled;
+        self
+    }
+
+    pub fn with_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs = attrs;
+        self
+    }
+
+    fn element_id(&self) -> &str {
+        self.attrs.id.as_deref().unwrap_or(&self.id)
+    }
+
+    pub fn render(&self, children: impl Renderable) -> impl Renderable {
+        self.attrs.spread(rsx! {
+            <wa-dialog id=(self.element_id()) label=(self.label) open[self.open] light-dismiss[self.light_dismiss] without-header[!self.header]>
+                (children)
+            </wa-dialog>
+        })
+    }
+
+    pub fn render_with_footer(&self, children: impl Renderable, footer: impl Renderable) -> impl Renderable {
+        self.render(rsx! {
+            (children)
+            <div slot="footer">(footer)</div>
+        })
+    }
+
+    pub fn render_with_slots(
+        &self,
+        header_actions: impl Renderable,
+        children: impl Renderable,
+        footer: impl Renderable,
+    ) -> impl Renderable {
+        self.render(rsx! {
+            <div slot="header-actions">(header_actions)</div>
+            (children)
+            <div slot="footer">(footer)</div>
+        })
+    }
+
+    pub fn render_slots(&self, slots: &Slots) -> impl Renderable {
+        self.render(rsx! {
+            (slots.render("label"))
+            (slots.render("header-actions"))
+            (slots.render_children_or(()))
+            (slots.render("footer"))
+        })
+    }
+
+    pub fn render_trigger(&self, label: impl Renderable) -> impl Renderable {
+        rsx! {
+            <wa-button data-dialog={ "open " (self.element_id()) }>(label)</wa-button>
+        }
+    }
+
+    pub fn render_close(&self, label: impl Renderable) -> impl Renderable {
+        rsx! {
+            <wa-button data-dialog="close">(label)</wa-button>
+        }
+    }
+}
+
+impl Default for Dialog {
+    fn default() -> Self {
+        Self::new("", "")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{assert_has_selector, assert_no_selector};
+
+    #[test]
+    fn test_render() {
+        let dialog = Dialog::new("confirm", "Delete project?");
+        let html = dialog.render("Sure?").render().into_inner();
+        assert_has_selector(&html, "wa-dialog#confirm[label=\"Delete project?\"][light-dismiss]");
+        assert_no_selector(&html, "wa-dialog[open]");
+        assert_no_selector(&html, "wa-dialog[without-header]");
+
+        let html = dialog
+            .clone()
+            .with_open(true)
+            .with_light_dismiss(false)
+            .with_header(false)
+            .render("Sure?")
+            .render()
+            .into_inner();
+        assert_has_selector(&html, "wa-dialog#confirm[open][without-header]");
+        assert_no_selector(&html, "wa-dialog[light-dismiss]");
+    }
+
+    #[test]
+    fn test_render_trigger() {
+        let dialog = Dialog::new("confirm", "Delete project?");
+        let html = dialog.render_trigger("Delete").render().into_inner();
+        assert_has_selector(&html, "wa-button[data-dialog=\"open confirm\"]");
+
+        let dialog = dialog.with_attrs(Attrs::new().with_id("delete-dialog"));
+        let html = dialog.render(()).render().into_inner();
+        assert_has_selector(&html, "wa-dialog#delete-dialog");
+        assert_no_selector(&html, "#confirm");
+        let html = dialog.render_trigger("Delete").render().into_inner();
+        assert_has_selector(&html, "wa-button[data-dialog=\"open delete-dialog\"]");
+    }
+
+    #[test]
+    fn test_default() {
+        assert!(Dialog::default().light_dismiss);
+        assert!(Dialog::default().header);
+    }
+}
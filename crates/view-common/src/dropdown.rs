@@ -0,0 +1,131 @@
+use std::borrow::Cow;
+
+use hypertext::prelude::GlobalAttributes;
+use hypertext::{Renderable, rsx};
+
+use crate::{Attrs, Icon, hypertext_elements};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MenuItem<'a> {
+    pub label: Cow<'a, str>,
+    pub value: Cow<'a, str>,
+    pub icon: Option<Icon>,
+    pub action: Option<Cow<'a, str>>,
+    pub danger: bool,
+    pub disabled: bool,
+}
+
+impl<'a> MenuItem<'a> {
+    pub fn new(label: impl Into<Cow<'a, str>>, value: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            label: label.into(),
+            value: value.into(),
+            icon: None,
+            action: None,
+            danger: false,
+            disabled: false,
+        }
+    }
+
+    pub fn with_icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    pub fn without_icon(mut self) -> Self {
+        self.icon = None;
+        self
+    }
+
+    pub fn with_action(mut self, action: impl Into<Cow<'a, str>>) -> Self {
+        self.action = Some(action.into());
+        self
+    }
+
+    pub fn without_action(mut self) -> Self {
+        self.action = None;
+        self
+    }
+
+    pub fn with_danger(mut self, danger: bool) -> Self {
+        self.danger = danger;
+        self
+    }
+
+    pub fn with_disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn render(&self) -> impl Renderable {
+        rsx! {
+            <wa-dropdown-item
+                value=(self.value)
+                variant=[self.danger.then_some("danger")]
+                disabled[self.disabled]
+                data-action=[self.action.as_ref()]
+            >
+                @if let Some(icon) = &self.icon {
+                    (icon.render_in(Some("icon")))
+                }
+                (self.label)
+            </wa-dropdown-item>
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MenuEntry<'a> {
+    Item(MenuItem<'a>),
+    Divider,
+}
+
+impl<'a> From<MenuItem<'a>> for MenuEntry<'a> {
+    fn from(item: MenuItem<'a>) -> Self {
+        MenuEntry::Item(item)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Dropdown {
+    pub placement: Cow<'static, str>,
+    pub attrs: Attrs,
+}
+
+impl Dropdown {
+    pub fn new() -> Self {
+        Self {
+            placement: Cow::Borrowed("bottom-start"),
+            attrs: Attrs::new(),
+        }
+    }
+
+    pub fn with_placement(mut self, placement: impl Into<Cow<'static, str>>) -> Self {
+        self.placement = placement.into();
+        self
+    }
+
+    pub fn with_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs = attrs;
+        self
+    }
+
+    pub fn render<'a>(
+        &'a self,
+        trigger: impl Renderable + 'a,
+        entries: impl IntoIterator<Item = impl Into<MenuEntry<'a>>>,
+    ) -> impl Renderable + 'a {
+        let entries: Vec<MenuEntry> = entries.into_iter().map(Into::into).collect();
+        self.attrs.spread(rsx! {
+            <wa-dropdown placement=(self.placement)>
+                <div slot="trigger">(trigger)</div>
+                @for entry in &entries {
+                    @match entry {
+                        MenuEntry::Item(item) => (item.render()),
+                        MenuEntry::Divider => <wa-divider></wa-divider>,
+                    }
+                }
+            </wa-dropdown>
+        })
+    }
+}
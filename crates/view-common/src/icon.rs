@@ -0,0 +1,288 @@
+use std::borrow::Cow;
+
+use hypertext::prelude::{AriaAttributes, GlobalAttributes};
+use hypertext::{Renderable, rsx};
+
+use crate::hypertext_elements;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum IconLibrary {
+    #[default]
+    Default,
+    System,
+    Custom(Cow<'static, str>),
+    /// Renders inline SVG referencing a symbol in a sprite sheet instead of `wa-icon`, so no icon CDN is needed.
+    Sprite(IconSprite),
+}
+
+impl IconLibrary {
+    pub fn as_attr(&self) -> Option<&str> {
+        match self {
+            IconLibrary::Default | IconLibrary::Sprite(_) => None,
+            IconLibrary::System => Some("system"),
+            IconLibrary::Custom(name) => Some(name),
+        }
+    }
+}
+
+/// The icon set a sprite sheet was built from, which decides the symbol names [`Icon`] references.
+///
+/// No sprite sheet is embedded in this crate: the `heroicons` and `lucide` features only add the mapping from the
+/// component icon names to each set's symbol ids. The application serves the sheet itself, e.g. one `<symbol>` per
+/// icon generated from the 24px outline set, and passes its URL to [`IconSprite`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SpriteSet {
+    /// Symbols are named exactly like the icon.
+    #[default]
+    Custom,
+    /// Symbols are named like Heroicons, e.g. `x-mark`.
+    #[cfg(feature = "heroicons")]
+    Heroicons,
+    /// Symbols are named like Lucide icons, e.g. `x`.
+    #[cfg(feature = "lucide")]
+    Lucide,
+}
+
+impl SpriteSet {
+    /// Maps the Font Awesome names used across the components to this set's symbol names.
+    pub fn symbol<'a>(&self, name: &'a str) -> &'a str {
+        match self {
+            SpriteSet::Custom => name,
+            #[cfg(feature = "heroicons")]
+            SpriteSet::Heroicons => match name {
+                "xmark" => "x-mark",
+                "circle-exclamation" => "exclamation-circle",
+                "triangle-exclamation" => "exclamation-triangle",
+                "circle-check" => "check-circle",
+                "circle-info" => "information-circle",
+                "pen-to-square" => "pencil-square",
+                "desktop" => "computer-desktop",
+                "magnifying-glass" => "magnifying-glass",
+                "copy" => "document-duplicate",
+                "check" => "check",
+                "chevron-down" => "chevron-down",
+                "chevron-right" => "chevron-right",
+                "ellipsis" => "ellipsis-horizontal",
+                _ => name,
+            },
+            #[cfg(feature = "lucide")]
+            SpriteSet::Lucide => match name {
+                "xmark" => "x",
+                "circle-exclamation" => "circle-alert",
+                "triangle-exclamation" => "triangle-alert",
+                "circle-check" => "circle-check",
+                "circle-info" => "info",
+                "pen-to-square" => "square-pen",
+                "desktop" => "monitor",
+                "magnifying-glass" => "search",
+                "copy" => "copy",
+                "check" => "check",
+                "chevron-down" => "chevron-down",
+                "chevron-right" => "chevron-right",
+                "ellipsis" => "ellipsis",
+                _ => name,
+            },
+        }
+    }
+}
+
+/// A sprite sheet served by the application, referenced as `<use href="{href}#{symbol}">`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IconSprite {
+    pub href: Cow<'static, str>,
+    pub set: SpriteSet,
+}
+
+impl IconSprite {
+    pub fn new(href: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            href: href.into(),
+            set: SpriteSet::Custom,
+        }
+    }
+
+    /// A Heroicons sprite sheet the application serves at `href`, e.g. `/static/heroicons.svg`.
+    #[cfg(feature = "heroicons")]
+    pub fn heroicons(href: impl Into<Cow<'static, str>>) -> Self {
+        Self::new(href).with_set(SpriteSet::Heroicons)
+    }
+
+    /// A Lucide sprite sheet the application serves at `href`, e.g. `/static/lucide.svg`.
+    #[cfg(feature = "lucide")]
+    pub fn lucide(href: impl Into<Cow<'static, str>>) -> Self {
+        Self::new(href).with_set(SpriteSet::Lucide)
+    }
+
+    pub fn with_set(mut self, set: SpriteSet) -> Self {
+        self.set = set;
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IconVariant {
+    Solid,
+    Regular,
+}
+
+impl IconVariant {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IconVariant::Solid => "solid",
+            IconVariant::Regular => "regular",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Icon {
+    pub name: Cow<'static, str>,
+    pub library: IconLibrary,
+    pub variant: Option<IconVariant>,
+    pub fixed_width: bool,
+    pub label: Option<Cow<'static, str>>,
+    pub slot: Option<Cow<'static, str>>,
+}
+
+impl Icon {
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: name.into(),
+            ..Self::default()
+        }
+    }
+
+    pub fn system(name: impl Into<Cow<'static, str>>) -> Self {
+        Self::new(name).with_library(IconLibrary::System)
+    }
+
+    pub fn with_library(mut self, library: IconLibrary) -> Self {
+        self.library = library;
+        self
+    }
+
+    pub fn with_variant(mut self, variant: IconVariant) -> Self {
+        self.variant = Some(variant);
+        self
+    }
+
+    pub fn without_variant(mut self) -> Self {
+        self.variant = None;
+        self
+    }
+
+    pub fn with_fixed_width(mut self, fixed_width: bool) -> Self {
+        self.fixed_width = fixed_width;
+        self
+    }
+
+    /// Makes the icon informative: it is announced to assistive technology with the given label.
+    pub fn with_label(mut self, label: impl Into<Cow<'static, str>>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Makes the icon decorative: it is hidden from assistive technology.
+    pub fn without_label(mut self) -> Self {
+        self.label = None;
+        self
+    }
+
+    pub fn with_slot(mut self, slot: impl Into<Cow<'static, str>>) -> Self {
+        self.slot = Some(slot.into());
+        self
+    }
+
+    pub fn without_slot(mut self) -> Self {
+        self.slot = None;
+        self
+    }
+
+    pub fn render(&self) -> impl Renderable {
+        self.render_in(self.slot.as_deref())
+    }
+
+    pub fn render_in<'a>(&'a self, slot: Option<&'a str>) -> impl Renderable + 'a {
+        rsx! {
+            @if let IconLibrary::Sprite(sprite) = &self.library {
+                <svg
+                    slot=[slot]
+                    class={ "icon icon-" (self.name) }
+                    width="1em"
+                    height="1em"
+                    role=[self.label.as_ref().map(|_| "img")]
+                    aria-label=[self.label.as_ref()]
+                    aria-hidden=[self.label.is_none().then_some("true")]
+                >
+                    <use href={ (sprite.href) "#" (sprite.set.symbol(&self.name)) }></use>
+                </svg>
+            } @else {
+                (self.render_wa_icon(slot))
+            }
+        }
+    }
+
+    fn render_wa_icon<'a>(&'a self, slot: Option<&'a str>) -> impl Renderable + 'a {
+        rsx! {
+            <wa-icon
+                slot=[slot]
+                name=(self.name)
+                library=[self.library.as_attr()]
+                variant=[self.variant.map(|variant| variant.as_str())]
+                fixed-width[self.fixed_width]
+                label=[self.label.as_ref()]
+                role=[self.label.as_ref().map(|_| "img")]
+                aria-label=[self.label.as_ref()]
+                aria-hidden=[self.label.is_none().then_some("true")]
+            ></wa-icon>
+        }
+    }
+}
+
+impl From<&'static str> for Icon {
+    fn from(name: &'static str) -> Self {
+        Icon::new(name)
+    }
+}
+
+impl From<String> for Icon {
+    fn from(name: String) -> Self {
+        Icon::new(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{assert_has_selector, assert_selector_count};
+
+    #[test]
+    fn test_render_sprite() {
+        let sprite = IconSprite::new("/static/icons.svg");
+        let html = Icon::new("xmark")
+            .with_library(IconLibrary::Sprite(sprite))
+            .with_label("Close")
+            .render()
+            .render()
+            .into_inner();
+        assert_has_selector(
+            &html,
+            "svg.icon-xmark[role=img][aria-label=Close] > use[href=\"/static/icons.svg#xmark\"]",
+        );
+        assert_selector_count(&html, "wa-icon", 0);
+    }
+
+    #[cfg(feature = "heroicons")]
+    #[test]
+    fn test_heroicons_symbols() {
+        assert_eq!(SpriteSet::Heroicons.symbol("xmark"), "x-mark");
+        assert_eq!(SpriteSet::Heroicons.symbol("user"), "user");
+    }
+
+    #[cfg(feature = "lucide")]
+    #[test]
+    fn test_lucide_symbols() {
+        assert_eq!(SpriteSet::Lucide.symbol("magnifying-glass"), "search");
+        assert_eq!(SpriteSet::Lucide.symbol("user"), "user");
+    }
+}
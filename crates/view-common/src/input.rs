@@ -0,0 +1,288 @@
+use std::borrow::Cow;
+
+use hypertext::prelude::{AriaAttributes, GlobalAttributes};
+use hypertext::{Renderable, rsx};
+use zabawa_validation::{InputFormat, ValidationErrors};
+
+use crate::{Attrs, hypertext_elements};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum InputType {
+    #[default]
+    Text,
+    Email,
+    Password,
+    Number,
+    Search,
+    Tel,
+    Url,
+    Date,
+}
+
+impl InputType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InputType::Text => "text",
+            InputType::Email => "email",
+            InputType::Password => "password",
+            InputType::Number => "number",
+            InputType::Search => "search",
+            InputType::Tel => "tel",
+            InputType::Url => "url",
+            InputType::Date => "date",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TextInput {
+    pub name: Cow<'static, str>,
+    pub label: Cow<'static, str>,
+    pub input_type: InputType,
+    pub hint: Option<Cow<'static, str>>,
+    pub placeholder: Option<Cow<'static, str>>,
+    pub required: bool,
+    pub format: Option<InputFormat>,
+    pub attrs: Attrs,
+}
+
+impl TextInput {
+    pub fn new(name: impl Into<Cow<'static, str>>, label: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: name.into(),
+            label: label.into(),
+            input_type: InputType::Text,
+            hint: None,
+            placeholder: None,
+            required: false,
+            format: None,
+            attrs: Attrs::new(),
+        }
+    }
+
+    pub fn with_type(mut self, input_type: InputType) -> Self {
+        self.input_type = input_type;
+        self
+    }
+
+    pub fn with_hint(mut self, hint: impl Into<Cow<'static, str>>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    pub fn without_hint(mut self) -> Self {
+        self.hint = None;
+        self
+    }
+
+    pub fn with_placeholder(mut self, placeholder: impl Into<Cow<'static, str>>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    pub fn without_placeholder(mut self) -> Self {
+        self.placeholder = None;
+        self
+    }
+
+    pub fn with_required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// Masks the field client-side; validate the submission with the same [`InputFormat::parse`].
+    pub fn with_format(mut self, format: InputFormat) -> Self {
+        if format == InputFormat::Phone {
+            self.input_type = InputType::Tel;
+        }
+        self.format = Some(format);
+        self
+    }
+
+    pub fn without_format(mut self) -> Self {
+        self.format = None;
+        self
+    }
+
+    pub fn with_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs = attrs;
+        self
+    }
+
+    pub fn render<'a>(&'a self, value: &'a str, errors: &'a ValidationErrors) -> impl Renderable + 'a {
+        let invalid = errors.has_field(&self.name);
+        let placeholder = self
+            .placeholder
+            .as_deref()
+            .or(self.format.map(|format| format.placeholder()));
+        self.attrs.spread(rsx! {
+            <wa-input
+                name=(self.name)
+                label=(self.label)
+                type=(self.input_type.as_str())
+                value=(value)
+                hint=[self.hint.as_ref().filter(|_| !invalid)]
+                placeholder=[placeholder]
+                pattern=[self.format.map(|format| format.pattern())]
+                inputmode=[self.format.map(|format| format.input_mode())]
+                data-mask=[self.format.map(|format| format.mask())]
+                required[self.required]
+                data-invalid[invalid]
+                aria-invalid=[invalid.then_some("true")]
+            >
+                (FieldHint { name: &self.name, hint: self.hint.as_deref(), errors })
+            </wa-input>
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TextArea {
+    pub name: Cow<'static, str>,
+    pub label: Cow<'static, str>,
+    pub rows: usize,
+    pub hint: Option<Cow<'static, str>>,
+    pub placeholder: Option<Cow<'static, str>>,
+    pub required: bool,
+    pub attrs: Attrs,
+}
+
+impl TextArea {
+    pub fn new(name: impl Into<Cow<'static, str>>, label: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: name.into(),
+            label: label.into(),
+            rows: 4,
+            hint: None,
+            placeholder: None,
+            required: false,
+            attrs: Attrs::new(),
+        }
+    }
+
+    pub fn with_rows(mut self, rows: usize) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    pub fn with_hint(mut self, hint: impl Into<Cow<'static, str>>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    pub fn without_hint(mut self) -> Self {
+        self.hint = None;
+        self
+    }
+
+    pub fn with_placeholder(mut self, placeholder: impl Into<Cow<'static, str>>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    pub fn without_placeholder(mut self) -> Self {
+        self.placeholder = None;
+        self
+    }
+
+    pub fn with_required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    pub fn with_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs = attrs;
+        self
+    }
+
+    pub fn render<'a>(&'a self, value: &'a str, errors: &'a ValidationErrors) -> impl Renderable + 'a {
+        let invalid = errors.has_field(&self.name);
+        self.attrs.spread(rsx! {
+            <wa-textarea
+                name=(self.name)
+                label=(self.label)
+                rows=(self.rows)
+                value=(value)
+                hint=[self.hint.as_ref().filter(|_| !invalid)]
+                placeholder=[self.placeholder.as_ref()]
+                required[self.required]
+                data-invalid[invalid]
+                aria-invalid=[invalid.then_some("true")]
+            >
+                (FieldHint { name: &self.name, hint: self.hint.as_deref(), errors })
+            </wa-textarea>
+        })
+    }
+}
+
+/// Replaces the `hint` slot with the hint text followed by the field's error messages, if there are any.
+#[derive(Clone, Copy, Debug)]
+pub struct FieldHint<'a> {
+    pub name: &'a str,
+    pub hint: Option<&'a str>,
+    pub errors: &'a ValidationErrors,
+}
+
+impl Renderable for FieldHint<'_> {
+    fn render_to(&self, buffer: &mut hypertext::Buffer) {
+        if !self.errors.has_field(self.name) {
+            return;
+        }
+
+        rsx! {
+            <div slot="hint" class="field-errors">
+                @if let Some(hint) = self.hint {
+                    <div class="field-hint">(hint)</div>
+                }
+                @for message in self.errors.field(self.name) {
+                    <div class="field-error">(message)</div>
+                }
+            </div>
+        }
+        .render_to(buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{Document, assert_has_selector, assert_no_selector, assert_selector_count};
+
+    #[test]
+    fn test_render_invalid() {
+        let mut errors = ValidationErrors::new();
+        errors.add("email", "is required");
+        errors.add("email", "must contain @");
+        errors.add("name", "is too short");
+        let input = TextInput::new("email", "Email").with_hint("We never share it");
+
+        let html = input.render("", &errors).render().into_inner();
+        assert_has_selector(&html, "wa-input[name=email][data-invalid][aria-invalid=true]");
+        assert_no_selector(&html, "wa-input[hint]");
+        assert_has_selector(&html, "wa-input > div.field-errors[slot=hint] > .field-hint");
+        let document = Document::parse(&html);
+        let messages: Vec<&str> = document
+            .select(".field-error")
+            .into_iter()
+            .map(|element| element.text.as_str())
+            .collect();
+        assert_eq!(messages, ["is required", "must contain @"]);
+
+        let html = TextArea::new("name", "Name").render("x", &errors).render().into_inner();
+        assert_has_selector(&html, "wa-textarea[data-invalid] .field-error");
+        assert_selector_count(&html, ".field-error", 1);
+    }
+
+    #[test]
+    fn test_render_valid() {
+        let errors = ValidationErrors::new();
+        let html = TextInput::new("email", "Email")
+            .with_hint("We never share it")
+            .render("a@example.com", &errors)
+            .render()
+            .into_inner();
+        assert_has_selector(&html, "wa-input[hint=\"We never share it\"]");
+        assert_no_selector(&html, "wa-input[data-invalid]");
+        assert_no_selector(&html, ".field-errors");
+    }
+}
@@ -0,0 +1,222 @@
+use std::borrow::Cow;
+
+use hypertext::prelude::GlobalAttributes;
+use hypertext::{Renderable, rsx};
+
+use crate::{Classes, hypertext_elements};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Gap {
+    None,
+    ThreeXs,
+    TwoXs,
+    Xs,
+    S,
+    M,
+    L,
+    Xl,
+    TwoXl,
+    ThreeXl,
+}
+
+impl Gap {
+    pub fn class(&self) -> &'static str {
+        match self {
+            Gap::None => "wa-gap-0",
+            Gap::ThreeXs => "wa-gap-3xs",
+            Gap::TwoXs => "wa-gap-2xs",
+            Gap::Xs => "wa-gap-xs",
+            Gap::S => "wa-gap-s",
+            Gap::M => "wa-gap-m",
+            Gap::L => "wa-gap-l",
+            Gap::Xl => "wa-gap-xl",
+            Gap::TwoXl => "wa-gap-2xl",
+            Gap::ThreeXl => "wa-gap-3xl",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AlignItems {
+    Start,
+    End,
+    Center,
+    Stretch,
+    Baseline,
+}
+
+impl AlignItems {
+    pub fn class(&self) -> &'static str {
+        match self {
+            AlignItems::Start => "wa-align-items-start",
+            AlignItems::End => "wa-align-items-end",
+            AlignItems::Center => "wa-align-items-center",
+            AlignItems::Stretch => "wa-align-items-stretch",
+            AlignItems::Baseline => "wa-align-items-baseline",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FlankSide {
+    #[default]
+    Start,
+    End,
+}
+
+fn layout_classes(base: &'static str, gap: Option<Gap>, align: Option<AlignItems>) -> Classes {
+    Classes::new()
+        .add(base)
+        .add_option(gap.map(|gap| gap.class()))
+        .add_option(align.map(|align| align.class()))
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Stack {
+    pub gap: Option<Gap>,
+    pub align: Option<AlignItems>,
+}
+
+impl Stack {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_gap(mut self, gap: Gap) -> Self {
+        self.gap = Some(gap);
+        self
+    }
+
+    pub fn with_align(mut self, align: AlignItems) -> Self {
+        self.align = Some(align);
+        self
+    }
+
+    pub fn classes(&self) -> Classes {
+        layout_classes("wa-stack", self.gap, self.align)
+    }
+
+    pub fn render(&self, children: impl Renderable) -> impl Renderable {
+        rsx! {
+            <div class=(self.classes())>(children)</div>
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Cluster {
+    pub gap: Option<Gap>,
+    pub align: Option<AlignItems>,
+}
+
+impl Cluster {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_gap(mut self, gap: Gap) -> Self {
+        self.gap = Some(gap);
+        self
+    }
+
+    pub fn with_align(mut self, align: AlignItems) -> Self {
+        self.align = Some(align);
+        self
+    }
+
+    pub fn classes(&self) -> Classes {
+        layout_classes("wa-cluster", self.gap, self.align)
+    }
+
+    pub fn render(&self, children: impl Renderable) -> impl Renderable {
+        rsx! {
+            <div class=(self.classes())>(children)</div>
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Flank {
+    pub side: FlankSide,
+    pub gap: Option<Gap>,
+    pub align: Option<AlignItems>,
+}
+
+impl Flank {
+    pub fn start() -> Self {
+        Self::default()
+    }
+
+    pub fn end() -> Self {
+        Self {
+            side: FlankSide::End,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_gap(mut self, gap: Gap) -> Self {
+        self.gap = Some(gap);
+        self
+    }
+
+    pub fn with_align(mut self, align: AlignItems) -> Self {
+        self.align = Some(align);
+        self
+    }
+
+    pub fn classes(&self) -> Classes {
+        let base = match self.side {
+            FlankSide::Start => "wa-flank",
+            FlankSide::End => "wa-flank:end",
+        };
+        layout_classes(base, self.gap, self.align)
+    }
+
+    pub fn render(&self, children: impl Renderable) -> impl Renderable {
+        rsx! {
+            <div class=(self.classes())>(children)</div>
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Grid {
+    pub gap: Option<Gap>,
+    pub align: Option<AlignItems>,
+    pub min_column_size: Option<Cow<'static, str>>,
+}
+
+impl Grid {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_gap(mut self, gap: Gap) -> Self {
+        self.gap = Some(gap);
+        self
+    }
+
+    pub fn with_align(mut self, align: AlignItems) -> Self {
+        self.align = Some(align);
+        self
+    }
+
+    pub fn with_min_column_size(mut self, size: impl Into<Cow<'static, str>>) -> Self {
+        self.min_column_size = Some(size.into());
+        self
+    }
+
+    pub fn classes(&self) -> Classes {
+        layout_classes("wa-grid", self.gap, self.align)
+    }
+
+    pub fn render(&self, children: impl Renderable) -> impl Renderable {
+        let style = self
+            .min_column_size
+            .as_ref()
+            .map(|size| format!("--min-column-size: {size};"));
+        rsx! {
+            <div class=(self.classes()) style=[style.as_ref()]>(children)</div>
+        }
+    }
+}
@@ -1,13 +1,97 @@
+// Lets the derive's `::zabawa_view_common` paths resolve inside this crate.
+extern crate self as zabawa_view_common;
+
 pub mod animation;
+pub mod assets;
+pub mod attrs;
+pub mod badge;
+pub mod breadcrumbs;
+pub mod button;
+pub mod callout;
+pub mod card;
+pub mod checkbox;
+pub mod classes;
+pub mod color_picker;
+pub mod component;
+pub mod context;
+pub mod copy_button;
+pub mod csrf;
+pub mod dialog;
+pub mod dropdown;
+pub mod icon;
+pub mod input;
+pub mod layout;
+pub mod memo;
+pub mod nav;
+pub mod output;
+pub mod page;
+pub mod pagination;
+pub mod popover;
+pub mod progress;
+pub mod select;
+pub mod slots;
+pub mod spinner;
+pub mod table;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+pub mod theme;
+pub mod tree;
+pub mod variant;
+pub mod visibility;
+pub mod wa;
+
+pub use zabawa_view_common_derive::ViewComponent;
 
 pub use self::animation::*;
+pub use self::assets::*;
+pub use self::attrs::*;
+pub use self::badge::*;
+pub use self::breadcrumbs::*;
+pub use self::button::*;
+pub use self::callout::*;
+pub use self::card::*;
+pub use self::checkbox::*;
+pub use self::classes::*;
+pub use self::color_picker::*;
+pub use self::component::*;
+pub use self::context::*;
+pub use self::copy_button::*;
+pub use self::csrf::*;
+pub use self::dialog::*;
+pub use self::dropdown::*;
+pub use self::icon::*;
+pub use self::input::*;
+pub use self::layout::*;
+pub use self::memo::*;
+pub use self::nav::*;
+pub use self::output::*;
+pub use self::page::*;
+pub use self::pagination::*;
+pub use self::popover::*;
+pub use self::progress::*;
+pub use self::select::*;
+pub use self::slots::*;
+pub use self::spinner::*;
+pub use self::table::*;
+pub use self::theme::*;
+pub use self::tree::*;
+pub use self::variant::*;
+pub use self::visibility::*;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use hypertext::Renderable;
+}
 
 pub mod hypertext_elements {
     use hypertext::define_elements;
-    // Re-export all standard HTML elements
+    // Re-export all standard HTML elements along with the Web Awesome ones
     pub use hypertext::validation::hypertext_elements::*;
 
+    pub use crate::wa::*;
+
     define_elements! {
-        wa_animation { name duration iterations }
+        svg { xmlns width height fill stroke }
+        r#use { href }
     }
 }
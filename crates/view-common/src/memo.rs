@@ -0,0 +1,109 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::{Arc, PoisonError, RwLock};
+
+use hypertext::{Buffer, Renderable};
+
+/// Pre-rendered HTML that can be cheaply cloned and re-emitted.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StaticFragment(Arc<str>);
+
+impl StaticFragment {
+    pub fn new(renderable: impl Renderable) -> Self {
+        Self(renderable.render().into_inner().into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Renderable for StaticFragment {
+    fn render_to(&self, buffer: &mut Buffer) {
+        // The fragment was produced by a `Renderable`, so it is already escaped.
+        buffer.dangerously_get_string().push_str(&self.0);
+    }
+}
+
+/// A keyed cache of [`StaticFragment`]s, meant to be shared across requests.
+#[derive(Debug, Default)]
+pub struct Memo {
+    fragments: RwLock<HashMap<Cow<'static, str>, StaticFragment>>,
+}
+
+impl Memo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, key: &str) -> Option<StaticFragment> {
+        let fragments = self.fragments.read().unwrap_or_else(PoisonError::into_inner);
+        fragments.get(key).cloned()
+    }
+
+    /// Returns the cached fragment for `key`, rendering it with `render` on first use.
+    pub fn get_or_render<R: Renderable>(
+        &self,
+        key: impl Into<Cow<'static, str>>,
+        render: impl FnOnce() -> R,
+    ) -> StaticFragment {
+        let key = key.into();
+        if let Some(fragment) = self.get(&key) {
+            return fragment;
+        }
+
+        let fragment = StaticFragment::new(render());
+        let mut fragments = self.fragments.write().unwrap_or_else(PoisonError::into_inner);
+        fragments.entry(key).or_insert(fragment).clone()
+    }
+
+    pub fn invalidate(&self, key: &str) -> bool {
+        let mut fragments = self.fragments.write().unwrap_or_else(PoisonError::into_inner);
+        fragments.remove(key).is_some()
+    }
+
+    pub fn clear(&self) {
+        self.fragments.write().unwrap_or_else(PoisonError::into_inner).clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.fragments.read().unwrap_or_else(PoisonError::into_inner).len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    #[test]
+    fn test_memo_renders_once_until_invalidated() {
+        let memo = Memo::new();
+        let renders = Cell::new(0);
+        let render = || {
+            renders.set(renders.get() + 1);
+            "<menu>"
+        };
+
+        let first = memo.get_or_render("menu", render);
+        let second = memo.get_or_render("menu", render);
+        assert_eq!(renders.get(), 1);
+        assert_eq!(first, second);
+        assert_eq!(first.as_str(), "&lt;menu&gt;");
+        assert_eq!(first.render().as_inner(), "&lt;menu&gt;");
+
+        assert!(memo.invalidate("menu"));
+        assert!(!memo.invalidate("menu"));
+        memo.get_or_render("menu", render);
+        assert_eq!(renders.get(), 2);
+        assert_eq!(memo.len(), 1);
+
+        memo.clear();
+        assert!(memo.is_empty());
+    }
+}
@@ -0,0 +1,387 @@
+use std::borrow::Cow;
+
+use hypertext::prelude::{AriaAttributes, GlobalAttributes};
+use hypertext::{Buffer, Renderable, rsx};
+
+use crate::{Attrs, Cluster, Gap, Icon, Stack, hypertext_elements};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum NavMatch {
+    /// Active on its own path and every path below it, e.g. `/projects` for `/projects/42`.
+    #[default]
+    Prefix,
+    Exact,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NavItem {
+    pub label: Cow<'static, str>,
+    pub href: Cow<'static, str>,
+    pub icon: Option<Icon>,
+    pub matching: NavMatch,
+    pub permission: Option<Cow<'static, str>>,
+    pub children: Vec<NavItem>,
+}
+
+impl NavItem {
+    pub fn new(label: impl Into<Cow<'static, str>>, href: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            label: label.into(),
+            href: href.into(),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    pub fn without_icon(mut self) -> Self {
+        self.icon = None;
+        self
+    }
+
+    pub fn with_matching(mut self, matching: NavMatch) -> Self {
+        self.matching = matching;
+        self
+    }
+
+    /// Hides the item, and everything below it, from users without this permission.
+    pub fn with_permission(mut self, permission: impl Into<Cow<'static, str>>) -> Self {
+        self.permission = Some(permission.into());
+        self
+    }
+
+    pub fn without_permission(mut self) -> Self {
+        self.permission = None;
+        self
+    }
+
+    pub fn with_child(mut self, child: NavItem) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    pub fn with_children(mut self, children: impl IntoIterator<Item = NavItem>) -> Self {
+        self.children.extend(children);
+        self
+    }
+
+    /// Whether this item itself matches `path`; query strings and fragments are ignored.
+    pub fn matches(&self, path: &str) -> bool {
+        let path = trim_path(path.split(['?', '#']).next().unwrap_or_default());
+        let href = trim_path(&self.href);
+        match self.matching {
+            NavMatch::Exact => path == href,
+            NavMatch::Prefix if href == "/" => path == href,
+            NavMatch::Prefix => path
+                .strip_prefix(href)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('/')),
+        }
+    }
+}
+
+fn trim_path(path: &str) -> &str {
+    match path.trim_end_matches('/') {
+        "" => "/",
+        trimmed => trimmed,
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NavModel {
+    pub items: Vec<NavItem>,
+}
+
+impl NavModel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_item(mut self, item: NavItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// A copy without the items whose permission `has_permission` rejects.
+    pub fn visible_to(&self, has_permission: impl Fn(&str) -> bool) -> NavModel {
+        NavModel {
+            items: visible_items(&self.items, &has_permission),
+        }
+    }
+
+    /// Indexes from the top level down to the item for `path`. The item with the longest matching `href` wins, so
+    /// `/projects/new` selects "New project" rather than its "Projects" parent or sibling.
+    pub fn active_trail(&self, path: &str) -> Vec<usize> {
+        best_match(&self.items, path)
+            .map(|(_, trail)| trail)
+            .unwrap_or_default()
+    }
+
+    pub fn active_item(&self, path: &str) -> Option<&NavItem> {
+        let mut items = &self.items;
+        let mut active = None;
+        for index in self.active_trail(path) {
+            let item = &items[index];
+            items = &item.children;
+            active = Some(item);
+        }
+        active
+    }
+}
+
+fn visible_items(items: &[NavItem], has_permission: &impl Fn(&str) -> bool) -> Vec<NavItem> {
+    items
+        .iter()
+        .filter(|item| item.permission.as_deref().is_none_or(has_permission))
+        .map(|item| NavItem {
+            children: visible_items(&item.children, has_permission),
+            ..item.clone()
+        })
+        .collect()
+}
+
+fn best_match(items: &[NavItem], path: &str) -> Option<(usize, Vec<usize>)> {
+    let mut best: Option<(usize, Vec<usize>)> = None;
+    for (index, item) in items.iter().enumerate() {
+        let own = item.matches(path).then(|| (trim_path(&item.href).len(), vec![index]));
+        let child = best_match(&item.children, path).map(|(specificity, mut trail)| {
+            trail.insert(0, index);
+            (specificity, trail)
+        });
+        for candidate in [own, child].into_iter().flatten() {
+            if best
+                .as_ref()
+                .is_none_or(|(specificity, trail)| (candidate.0, candidate.1.len()) > (*specificity, trail.len()))
+            {
+                best = Some(candidate);
+            }
+        }
+    }
+    best
+}
+
+/// A horizontal bar of the top-level items. Items on the active trail get the `active` class and the current
+/// page also gets `aria-current="page"`; nested items are left to a [`SideNav`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NavBar {
+    pub label: Cow<'static, str>,
+    pub attrs: Attrs,
+}
+
+impl NavBar {
+    pub fn new() -> Self {
+        Self {
+            label: Cow::Borrowed("Main"),
+            attrs: Attrs::new(),
+        }
+    }
+
+    pub fn with_label(mut self, label: impl Into<Cow<'static, str>>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    pub fn with_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs = attrs;
+        self
+    }
+
+    pub fn render<'a>(&'a self, model: &'a NavModel, current_path: &str) -> impl Renderable + 'a {
+        let trail = model.active_trail(current_path);
+        self.attrs.spread(rsx! {
+            <nav class="nav-bar" aria-label=(self.label)>
+                <ul class=(Cluster::new().with_gap(Gap::S).classes())>
+                    @for (index, item) in model.items.iter().enumerate() {
+                        <li>
+                            (NavLink {
+                                item,
+                                active: trail.first() == Some(&index),
+                                current: trail == [index],
+                            })
+                        </li>
+                    }
+                </ul>
+            </nav>
+        })
+    }
+}
+
+impl Default for NavBar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A vertical, nested list of all items, marked up like [`NavBar`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SideNav {
+    pub label: Cow<'static, str>,
+    pub attrs: Attrs,
+}
+
+impl SideNav {
+    pub fn new() -> Self {
+        Self {
+            label: Cow::Borrowed("Sidebar"),
+            attrs: Attrs::new(),
+        }
+    }
+
+    pub fn with_label(mut self, label: impl Into<Cow<'static, str>>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    pub fn with_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs = attrs;
+        self
+    }
+
+    pub fn render<'a>(&'a self, model: &'a NavModel, current_path: &str) -> impl Renderable + 'a {
+        let trail = model.active_trail(current_path);
+        self.attrs.spread(rsx! {
+            <nav class="side-nav" aria-label=(self.label)>
+                (SideNavList { items: &model.items, trail: Some(&trail) })
+            </nav>
+        })
+    }
+}
+
+impl Default for SideNav {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct SideNavList<'a> {
+    items: &'a [NavItem],
+    /// The remaining active trail, or `None` when this list is off the trail.
+    trail: Option<&'a [usize]>,
+}
+
+impl Renderable for SideNavList<'_> {
+    fn render_to(&self, buffer: &mut Buffer) {
+        let trail = self.trail.unwrap_or_default();
+        rsx! {
+            <ul class=(Stack::new().with_gap(Gap::TwoXs).classes())>
+                @for (index, item) in self.items.iter().enumerate() {
+                    <li>
+                        (NavLink {
+                            item,
+                            active: trail.first() == Some(&index),
+                            current: trail == [index],
+                        })
+                        @if !item.children.is_empty() {
+                            (SideNavList {
+                                items: &item.children,
+                                trail: (trail.first() == Some(&index)).then(|| &trail[1..]),
+                            })
+                        }
+                    </li>
+                }
+            </ul>
+        }
+        .render_to(buffer);
+    }
+}
+
+struct NavLink<'a> {
+    item: &'a NavItem,
+    active: bool,
+    current: bool,
+}
+
+impl Renderable for NavLink<'_> {
+    fn render_to(&self, buffer: &mut Buffer) {
+        let item = self.item;
+        rsx! {
+            <a
+                href=(item.href)
+                class=[self.active.then_some("active")]
+                aria-current=[self.current.then_some("page")]
+            >
+                @if let Some(icon) = &item.icon {
+                    (icon.render())
+                }
+                (item.label)
+            </a>
+        }
+        .render_to(buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{Document, assert_has_selector, assert_selector_count};
+
+    fn model() -> NavModel {
+        NavModel::new()
+            .with_item(NavItem::new("Home", "/").with_icon(Icon::new("house")))
+            .with_item(
+                NavItem::new("Projects", "/projects")
+                    .with_child(NavItem::new("All projects", "/projects").with_matching(NavMatch::Exact))
+                    .with_child(NavItem::new("New project", "/projects/new").with_permission("projects.create")),
+            )
+            .with_item(NavItem::new("Admin", "/admin").with_permission("admin"))
+    }
+
+    #[test]
+    fn test_matches() {
+        let item = NavItem::new("Projects", "/projects/");
+        assert!(item.matches("/projects"));
+        assert!(item.matches("/projects/42?tab=settings"));
+        assert!(!item.matches("/projects-archive"));
+        assert!(!item.with_matching(NavMatch::Exact).matches("/projects/42"));
+        assert!(NavItem::new("Home", "/").matches("/"));
+        assert!(!NavItem::new("Home", "/").matches("/projects"));
+    }
+
+    #[test]
+    fn test_active_trail() {
+        let model = model();
+        assert_eq!(model.active_trail("/"), [0]);
+        assert_eq!(model.active_trail("/projects"), [1, 0]);
+        assert_eq!(model.active_trail("/projects/new"), [1, 1]);
+        assert_eq!(model.active_trail("/projects/42"), [1]);
+        assert_eq!(model.active_trail("/unknown"), [] as [usize; 0]);
+        assert_eq!(
+            model.active_item("/projects/new").map(|item| &*item.label),
+            Some("New project")
+        );
+    }
+
+    #[test]
+    fn test_visible_to() {
+        let model = model().visible_to(|permission| permission == "admin");
+        let labels: Vec<&str> = model.items.iter().map(|item| &*item.label).collect();
+        assert_eq!(labels, ["Home", "Projects", "Admin"]);
+        assert_eq!(model.items[1].children.len(), 1);
+        assert_eq!(self::model().visible_to(|_| false).items.len(), 2);
+    }
+
+    #[test]
+    fn test_nav_bar() {
+        let model = model();
+        let html = NavBar::new().render(&model, "/projects/new").render().into_inner();
+        assert_has_selector(&html, "nav.nav-bar[aria-label=Main] > ul.wa-cluster");
+        assert_selector_count(&html, "nav > ul > li", 3);
+        assert_selector_count(&html, "a.active", 1);
+        assert_selector_count(&html, "a[aria-current]", 0);
+        assert_has_selector(&html, "a[href=\"/\"] wa-icon[name=house]");
+    }
+
+    #[test]
+    fn test_side_nav() {
+        let model = model();
+        let html = SideNav::new().render(&model, "/projects/new").render().into_inner();
+        assert_selector_count(&html, "nav.side-nav ul ul > li", 2);
+        assert_selector_count(&html, "a.active", 2);
+        let document = Document::parse(&html);
+        let current = document.select("a[aria-current=page]");
+        assert_eq!(current.len(), 1);
+        assert_eq!(current[0].attr("href"), Some("/projects/new"));
+    }
+}
@@ -0,0 +1,191 @@
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track", "wbr",
+];
+const PREFORMATTED_ELEMENTS: &[&str] = &["pre", "textarea", "script", "style"];
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum OutputMode {
+    /// Output exactly as rendered by the components.
+    #[default]
+    Compact,
+    /// Collapses whitespace in text, for production.
+    Minified,
+    /// One tag per line with indentation, for debugging and snapshots.
+    Pretty,
+}
+
+impl OutputMode {
+    pub fn apply(&self, html: String) -> String {
+        match self {
+            OutputMode::Compact => html,
+            OutputMode::Minified => minify_html(&html),
+            OutputMode::Pretty => pretty_html(&html),
+        }
+    }
+}
+
+enum Token<'a> {
+    Open(&'a str, &'a str),
+    Close(&'a str),
+    Other(&'a str),
+    Text(&'a str),
+    Preformatted(&'a str),
+}
+
+fn tag_name(tag: &str) -> &str {
+    let tag = tag.trim_start_matches(['<', '/']);
+    let end = tag
+        .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
+        .unwrap_or(tag.len());
+    &tag[..end]
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+        rest = &rest[start..];
+
+        let end = if rest.starts_with("<!--") {
+            rest.find("-->").map_or(rest.len(), |end| end + 3)
+        } else {
+            rest.find('>').map_or(rest.len(), |end| end + 1)
+        };
+        let tag = &rest[..end];
+        rest = &rest[end..];
+
+        if tag.starts_with("</") {
+            tokens.push(Token::Close(tag));
+        } else if tag.starts_with("<!") || tag.ends_with("/>") {
+            tokens.push(Token::Other(tag));
+        } else {
+            let name = tag_name(tag);
+            if VOID_ELEMENTS.iter().any(|void| void.eq_ignore_ascii_case(name)) {
+                tokens.push(Token::Other(tag));
+            } else if PREFORMATTED_ELEMENTS.iter().any(|pre| pre.eq_ignore_ascii_case(name)) {
+                let closing = format!("</{name}");
+                let end = rest.find(&closing).unwrap_or(rest.len());
+                tokens.push(Token::Open(tag, name));
+                if end > 0 {
+                    tokens.push(Token::Preformatted(&rest[..end]));
+                }
+                rest = &rest[end..];
+            } else {
+                tokens.push(Token::Open(tag, name));
+            }
+        }
+    }
+
+    tokens
+}
+
+fn collapse_whitespace(text: &str, output: &mut String) {
+    let mut in_whitespace = false;
+    for c in text.chars() {
+        if c.is_ascii_whitespace() {
+            if !in_whitespace {
+                output.push(' ');
+            }
+            in_whitespace = true;
+        } else {
+            output.push(c);
+            in_whitespace = false;
+        }
+    }
+}
+
+pub fn minify_html(html: &str) -> String {
+    let mut output = String::with_capacity(html.len());
+    for token in tokenize(html) {
+        match token {
+            Token::Text(text) => collapse_whitespace(text, &mut output),
+            Token::Open(tag, _) | Token::Close(tag) | Token::Other(tag) | Token::Preformatted(tag) => {
+                output.push_str(tag)
+            },
+        }
+    }
+    output
+}
+
+pub fn pretty_html(html: &str) -> String {
+    const INDENT: &str = "  ";
+
+    let mut lines: Vec<String> = Vec::new();
+    let mut depth = 0usize;
+    let mut inline = false;
+
+    for token in tokenize(html) {
+        match token {
+            Token::Open(tag, name) => {
+                lines.push(format!("{}{tag}", INDENT.repeat(depth)));
+                depth += 1;
+                inline = PREFORMATTED_ELEMENTS.iter().any(|pre| pre.eq_ignore_ascii_case(name));
+            },
+            Token::Close(tag) => {
+                depth = depth.saturating_sub(1);
+                match lines.last_mut() {
+                    Some(line) if inline => line.push_str(tag),
+                    _ => lines.push(format!("{}{tag}", INDENT.repeat(depth))),
+                }
+                inline = false;
+            },
+            Token::Other(tag) => lines.push(format!("{}{tag}", INDENT.repeat(depth))),
+            Token::Preformatted(text) => match lines.last_mut() {
+                Some(line) => line.push_str(text),
+                None => lines.push(text.to_owned()),
+            },
+            Token::Text(text) => {
+                let mut collapsed = String::new();
+                collapse_whitespace(text.trim(), &mut collapsed);
+                if !collapsed.is_empty() {
+                    lines.push(format!("{}{collapsed}", INDENT.repeat(depth)));
+                }
+            },
+        }
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HTML: &str = "<!DOCTYPE html><div class=\"a  b\">\n  Hello   <b>world</b><br><pre>  keep\n  this </pre><script>if (a<b) {}</script></div>";
+
+    #[test]
+    fn test_minify_html() {
+        assert_eq!(
+            minify_html(HTML),
+            "<!DOCTYPE html><div class=\"a  b\"> Hello <b>world</b><br><pre>  keep\n  this </pre><script>if (a<b) {}</script></div>"
+        );
+    }
+
+    #[test]
+    fn test_pretty_html() {
+        assert_eq!(
+            pretty_html(HTML),
+            [
+                "<!DOCTYPE html>",
+                "<div class=\"a  b\">",
+                "  Hello",
+                "  <b>",
+                "    world",
+                "  </b>",
+                "  <br>",
+                "  <pre>  keep\n  this </pre>",
+                "  <script>if (a<b) {}</script>",
+                "</div>",
+            ]
+            .join("\n")
+        );
+    }
+}
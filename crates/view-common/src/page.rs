@@ -0,0 +1,145 @@
+use std::borrow::Cow;
+
+use hypertext::prelude::{AriaAttributes, GlobalAttributes, HtmxAttributes};
+use hypertext::{Renderable, rsx};
+use zabawa_theme::Theme;
+
+use crate::{CsrfField, RenderContext, ThemeAttr, hypertext_elements};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Page {
+    pub title: Cow<'static, str>,
+    pub description: Option<Cow<'static, str>>,
+    pub web_awesome_url: Cow<'static, str>,
+    pub stylesheets: Vec<Cow<'static, str>>,
+    pub scripts: Vec<Cow<'static, str>>,
+    pub toast_container_id: Cow<'static, str>,
+    pub csrf: CsrfField,
+    /// Generated by [`Theme::to_css`] and inlined after the stylesheets, so its tokens win.
+    pub theme_css: Option<String>,
+}
+
+impl Page {
+    pub fn new(title: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            title: title.into(),
+            description: None,
+            web_awesome_url: Cow::Borrowed("https://early.webawesome.com/webawesome@3.0.0/dist"),
+            stylesheets: Vec::new(),
+            scripts: Vec::new(),
+            toast_container_id: Cow::Borrowed("notifications"),
+            csrf: CsrfField::new(),
+            theme_css: None,
+        }
+    }
+
+    pub fn with_title(mut self, title: impl Into<Cow<'static, str>>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn with_description(mut self, description: impl Into<Cow<'static, str>>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn without_description(mut self) -> Self {
+        self.description = None;
+        self
+    }
+
+    pub fn with_web_awesome_url(mut self, url: impl Into<Cow<'static, str>>) -> Self {
+        self.web_awesome_url = url.into();
+        self
+    }
+
+    pub fn with_stylesheet(mut self, href: impl Into<Cow<'static, str>>) -> Self {
+        self.stylesheets.push(href.into());
+        self
+    }
+
+    pub fn with_script(mut self, src: impl Into<Cow<'static, str>>) -> Self {
+        self.scripts.push(src.into());
+        self
+    }
+
+    pub fn with_toast_container_id(mut self, id: impl Into<Cow<'static, str>>) -> Self {
+        self.toast_container_id = id.into();
+        self
+    }
+
+    pub fn with_csrf(mut self, csrf: CsrfField) -> Self {
+        self.csrf = csrf;
+        self
+    }
+
+    pub fn with_theme(mut self, theme: &Theme) -> Self {
+        self.theme_css = (!theme.is_empty()).then(|| theme.to_css());
+        self
+    }
+
+    pub fn without_theme(mut self) -> Self {
+        self.theme_css = None;
+        self
+    }
+
+    pub fn render(&self, ctx: &RenderContext, body: impl Renderable) -> impl Renderable {
+        let theme = ThemeAttr::new(ctx.theme);
+        rsx! {
+            <!DOCTYPE html>
+            <html lang=(ctx.locale) class=[theme.class()] data-theme=(theme.data_theme())>
+                <head>
+                    <meta charset="utf-8">
+                    <meta name="viewport" content="width=device-width, initial-scale=1">
+                    <title>(self.title)</title>
+                    @if let Some(description) = &self.description {
+                        <meta name="description" content=(description)>
+                    }
+                    (self.csrf.render_meta(ctx))
+                    <link rel="stylesheet" href={ (self.web_awesome_url) "/styles/webawesome.css" }>
+                    <script type="module" nonce=[ctx.nonce()] src={ (self.web_awesome_url) "/webawesome.loader.js" }></script>
+                    @for href in &self.stylesheets {
+                        <link rel="stylesheet" href=(ctx.asset_url(href))>
+                    }
+                    @for src in &self.scripts {
+                        <script type="module" nonce=[ctx.nonce()] src=(ctx.asset_url(src))></script>
+                    }
+                    @if let Some(css) = &self.theme_css {
+                        <style nonce=[ctx.nonce()]>(css)</style>
+                    }
+                </head>
+                <body hx-headers=[self.csrf.hx_headers(ctx)]>
+                    (body)
+                    <div id=(self.toast_container_id) class="toast-container" aria-live="polite"></div>
+                </body>
+            </html>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{Document, assert_no_selector};
+
+    #[test]
+    fn test_render_theme() {
+        let ctx = RenderContext::new().with_nonce("abc");
+        let theme = Theme::parse("color.brand = #4f46e5\nspace.m = 1.25rem").unwrap();
+
+        let page = Page::new("Home").with_theme(&theme);
+        let html = page.render(&ctx, "body").render().into_inner();
+        let document = Document::parse(&html);
+        let style = document.select("head > style[nonce=abc]");
+        assert_eq!(style.len(), 1);
+        assert!(style[0].text.contains("--wa-color-brand-fill-loud: #4f46e5;"));
+        assert!(style[0].text.contains("--wa-space-m: 1.25rem;"));
+
+        let html = page
+            .with_theme(&Theme::new())
+            .render(&ctx, "body")
+            .render()
+            .into_inner();
+        assert_no_selector(&html, "style");
+    }
+}
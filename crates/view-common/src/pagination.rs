@@ -0,0 +1,151 @@
+use std::borrow::Cow;
+
+use hypertext::prelude::{AriaAttributes, GlobalAttributes};
+use hypertext::{Renderable, rsx};
+use zabawa_pagination::{Page, PageRequest};
+
+use crate::{Attrs, hypertext_elements};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Pagination {
+    pub label: Cow<'static, str>,
+    pub previous_label: Cow<'static, str>,
+    pub next_label: Cow<'static, str>,
+    pub page_param: Cow<'static, str>,
+    pub cursor_param: Cow<'static, str>,
+    /// Number of page links shown on each side of the current page.
+    pub radius: u32,
+    pub attrs: Attrs,
+}
+
+impl Pagination {
+    pub fn new() -> Self {
+        Self {
+            label: Cow::Borrowed("Pagination"),
+            previous_label: Cow::Borrowed("Previous"),
+            next_label: Cow::Borrowed("Next"),
+            page_param: Cow::Borrowed("page"),
+            cursor_param: Cow::Borrowed("after"),
+            radius: 2,
+            attrs: Attrs::new(),
+        }
+    }
+
+    pub fn with_label(mut self, label: impl Into<Cow<'static, str>>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    pub fn with_previous_label(mut self, label: impl Into<Cow<'static, str>>) -> Self {
+        self.previous_label = label.into();
+        self
+    }
+
+    pub fn with_next_label(mut self, label: impl Into<Cow<'static, str>>) -> Self {
+        self.next_label = label.into();
+        self
+    }
+
+    pub fn with_page_param(mut self, param: impl Into<Cow<'static, str>>) -> Self {
+        self.page_param = param.into();
+        self
+    }
+
+    pub fn with_cursor_param(mut self, param: impl Into<Cow<'static, str>>) -> Self {
+        self.cursor_param = param.into();
+        self
+    }
+
+    pub fn with_radius(mut self, radius: u32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    pub fn with_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs = attrs;
+        self
+    }
+
+    /// Renders previous/next links and, for numbered pages, a window of page links appended to `base_url`.
+    pub fn render<'a, T>(&'a self, page: &'a Page<T>, base_url: &'a str) -> impl Renderable + 'a {
+        let separator = if base_url.contains('?') { '&' } else { '?' };
+        let page_href = move |number: u32| format!("{base_url}{separator}{}={number}", self.page_param);
+        let current = page.request.page();
+
+        let previous = match (&page.request, page.has_previous()) {
+            (PageRequest::Numbered { page, .. }, true) => Some(page_href(page - 1)),
+            _ => None,
+        };
+        let next = match (&page.request, page.has_next) {
+            (PageRequest::Numbered { page, .. }, true) => Some(page_href(page + 1)),
+            (PageRequest::Cursor { .. }, true) => page
+                .next_cursor
+                .as_ref()
+                .map(|cursor| format!("{base_url}{separator}{}={cursor}", self.cursor_param)),
+            _ => None,
+        };
+        let window: Vec<(Option<u32>, Option<String>)> = page
+            .page_window(self.radius)
+            .into_iter()
+            .map(|number| (number, number.filter(|number| Some(*number) != current).map(page_href)))
+            .collect();
+
+        self.attrs.spread(rsx! {
+            <nav class="pagination" aria-label=(self.label)>
+                @if let Some(href) = &previous {
+                    <a class="pagination-previous" href=(href) rel="prev">(self.previous_label)</a>
+                } @else {
+                    <span class="pagination-previous" aria-disabled="true">(self.previous_label)</span>
+                }
+                @for (number, href) in &window {
+                    @match (number, href) {
+                        (Some(number), Some(href)) => <a class="pagination-page" href=(href)>(number)</a>,
+                        (Some(number), None) => <span class="pagination-page" aria-current="page">(number)</span>,
+                        (None, _) => <span class="pagination-gap">"…"</span>,
+                    }
+                }
+                @if let Some(href) = &next {
+                    <a class="pagination-next" href=(href) rel="next">(self.next_label)</a>
+                } @else {
+                    <span class="pagination-next" aria-disabled="true">(self.next_label)</span>
+                }
+            </nav>
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{assert_has_selector, assert_selector_count};
+
+    #[test]
+    fn test_render_numbered() {
+        let all: Vec<u32> = (1..=100).collect();
+        let page = Page::paginate(&all, PageRequest::Numbered { page: 5, size: 10 });
+        let html = Pagination::new()
+            .render(&page, "/projects?sort=name")
+            .render()
+            .into_inner();
+
+        assert_has_selector(
+            &html,
+            "nav.pagination > a[rel=prev][href=\"/projects?sort=name&page=4\"]",
+        );
+        assert_has_selector(&html, "a[rel=next][href=\"/projects?sort=name&page=6\"]");
+        assert_has_selector(&html, "span[aria-current=page]");
+        assert_selector_count(&html, "a.pagination-page", 6);
+        assert_selector_count(&html, ".pagination-gap", 2);
+    }
+
+    #[test]
+    fn test_render_cursor() {
+        let request = PageRequest::Cursor { after: None, size: 2 };
+        let page = Page::from_overfetched(vec!["a", "b", "c"], request).with_next_cursor(|item| item.to_string());
+        let html = Pagination::new().render(&page, "/feed").render().into_inner();
+
+        assert_has_selector(&html, "span.pagination-previous[aria-disabled=true]");
+        assert_has_selector(&html, "a[rel=next][href=\"/feed?after=b\"]");
+        assert_selector_count(&html, ".pagination-page", 0);
+    }
+}
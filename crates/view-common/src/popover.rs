@@ -0,0 +1,145 @@
+use std::borrow::Cow;
+
+use hypertext::prelude::{AriaAttributes, GlobalAttributes};
+use hypertext::{Renderable, rsx, rsx_borrow};
+
+use crate::{Attrs, hypertext_elements};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Placement {
+    Top,
+    TopStart,
+    TopEnd,
+    Right,
+    RightStart,
+    RightEnd,
+    #[default]
+    Bottom,
+    BottomStart,
+    BottomEnd,
+    Left,
+    LeftStart,
+    LeftEnd,
+}
+
+impl Placement {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Placement::Top => "top",
+            Placement::TopStart => "top-start",
+            Placement::TopEnd => "top-end",
+            Placement::Right => "right",
+            Placement::RightStart => "right-start",
+            Placement::RightEnd => "right-end",
+            Placement::Bottom => "bottom",
+            Placement::BottomStart => "bottom-start",
+            Placement::BottomEnd => "bottom-end",
+            Placement::Left => "left",
+            Placement::LeftStart => "left-start",
+            Placement::LeftEnd => "left-end",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PopoverTrigger {
+    /// The popover toggles when its anchor is clicked.
+    #[default]
+    Click,
+    /// The popover is only opened and closed by setting `open`, e.g. from a script or an htmx swap.
+    Manual,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Popover {
+    pub id: Cow<'static, str>,
+    pub label: Option<Cow<'static, str>>,
+    pub placement: Placement,
+    pub trigger: PopoverTrigger,
+    pub arrow: bool,
+    pub distance: Option<u16>,
+    pub open: bool,
+    pub attrs: Attrs,
+}
+
+impl Popover {
+    pub fn new(id: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            id: id.into(),
+            arrow: true,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the accessible name of the popover panel.
+    pub fn with_label(mut self, label: impl Into<Cow<'static, str>>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn with_placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    pub fn with_trigger(mut self, trigger: PopoverTrigger) -> Self {
+        self.trigger = trigger;
+        self
+    }
+
+    pub fn with_arrow(mut self, arrow: bool) -> Self {
+        self.arrow = arrow;
+        self
+    }
+
+    pub fn with_distance(mut self, distance: u16) -> Self {
+        self.distance = Some(distance);
+        self
+    }
+
+    pub fn with_open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
+    pub fn with_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs = attrs;
+        self
+    }
+
+    pub fn anchor_id(&self) -> String {
+        format!("{}-anchor", self.id)
+    }
+
+    /// Renders the anchor followed by the popover panel, which is linked to the anchor for assistive technology.
+    pub fn render(&self, anchor: impl Renderable, children: impl Renderable) -> impl Renderable {
+        let anchor_id = self.anchor_id();
+        let click = self.trigger == PopoverTrigger::Click;
+
+        rsx! {
+            <span
+                id=(anchor_id)
+                class="popover-anchor"
+                aria-haspopup="dialog"
+                aria-controls=(self.id)
+                aria-expanded=(if self.open { "true" } else { "false" })
+            >
+                (anchor)
+            </span>
+            (self.attrs.spread(rsx_borrow! {
+                <wa-popover
+                    id=(self.id)
+                    for=[click.then_some(&anchor_id)]
+                    placement=(self.placement.as_str())
+                    distance=[self.distance]
+                    without-arrow[!self.arrow]
+                    open[self.open]
+                    role="dialog"
+                    aria-label=[self.label.as_ref()]
+                >
+                    (children)
+                </wa-popover>
+            }))
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use std::borrow::Cow;
+
+use hypertext::prelude::GlobalAttributes;
+use hypertext::{Renderable, rsx};
+
+use crate::{Attrs, Variant, hypertext_elements};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProgressBar {
+    pub value: Option<u8>,
+    pub label: Cow<'static, str>,
+    pub variant: Option<Variant>,
+    pub show_value: bool,
+    pub attrs: Attrs,
+}
+
+impl ProgressBar {
+    pub fn new(value: u8) -> Self {
+        Self {
+            value: Some(value.min(100)),
+            label: Cow::Borrowed("Progress"),
+            variant: None,
+            show_value: false,
+            attrs: Attrs::new(),
+        }
+    }
+
+    pub fn indeterminate() -> Self {
+        Self {
+            value: None,
+            ..Self::new(0)
+        }
+    }
+
+    pub fn with_value(mut self, value: u8) -> Self {
+        self.value = Some(value.min(100));
+        self
+    }
+
+    pub fn with_label(mut self, label: impl Into<Cow<'static, str>>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    pub fn with_variant(mut self, variant: Variant) -> Self {
+        self.variant = Some(variant);
+        self
+    }
+
+    pub fn without_variant(mut self) -> Self {
+        self.variant = None;
+        self
+    }
+
+    pub fn with_show_value(mut self, show: bool) -> Self {
+        self.show_value = show;
+        self
+    }
+
+    pub fn with_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs = attrs;
+        self
+    }
+
+    pub fn render(&self) -> impl Renderable {
+        self.attrs.spread(rsx! {
+            <wa-progress-bar
+                value=[self.value]
+                indeterminate[self.value.is_none()]
+                label=(self.label)
+                style=[self.variant.map(indicator_style)]
+            >
+                @if let Some(value) = self.value.filter(|_| self.show_value) {
+                    (value) "%"
+                }
+            </wa-progress-bar>
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProgressRing {
+    pub value: u8,
+    pub label: Cow<'static, str>,
+    pub variant: Option<Variant>,
+    pub show_value: bool,
+    pub attrs: Attrs,
+}
+
+impl ProgressRing {
+    pub fn new(value: u8) -> Self {
+        Self {
+            value: value.min(100),
+            label: Cow::Borrowed("Progress"),
+            variant: None,
+            show_value: false,
+            attrs: Attrs::new(),
+        }
+    }
+
+    pub fn with_value(mut self, value: u8) -> Self {
+        self.value = value.min(100);
+        self
+    }
+
+    pub fn with_label(mut self, label: impl Into<Cow<'static, str>>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    pub fn with_variant(mut self, variant: Variant) -> Self {
+        self.variant = Some(variant);
+        self
+    }
+
+    pub fn without_variant(mut self) -> Self {
+        self.variant = None;
+        self
+    }
+
+    pub fn with_show_value(mut self, show: bool) -> Self {
+        self.show_value = show;
+        self
+    }
+
+    pub fn with_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs = attrs;
+        self
+    }
+
+    pub fn render(&self) -> impl Renderable {
+        self.attrs.spread(rsx! {
+            <wa-progress-ring value=(self.value) label=(self.label) style=[self.variant.map(indicator_style)]>
+                @if self.show_value {
+                    (self.value) "%"
+                }
+            </wa-progress-ring>
+        })
+    }
+}
+
+fn indicator_style(variant: Variant) -> String {
+    format!("--indicator-color: var(--wa-color-{variant}-fill-loud);")
+}
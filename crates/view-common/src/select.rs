@@ -0,0 +1,218 @@
+use std::borrow::Cow;
+
+use hypertext::prelude::AriaAttributes;
+use hypertext::{Renderable, rsx};
+use zabawa_validation::ValidationErrors;
+
+use crate::{Attrs, FieldHint, hypertext_elements};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SelectOption<'a> {
+    pub value: Cow<'a, str>,
+    pub label: Cow<'a, str>,
+    pub selected: bool,
+    pub disabled: bool,
+}
+
+impl<'a> SelectOption<'a> {
+    pub fn new(value: impl Into<Cow<'a, str>>, label: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            value: value.into(),
+            label: label.into(),
+            selected: false,
+            disabled: false,
+        }
+    }
+
+    pub fn with_selected(mut self, selected: bool) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    pub fn with_disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Select {
+    pub name: Cow<'static, str>,
+    pub label: Cow<'static, str>,
+    pub hint: Option<Cow<'static, str>>,
+    pub placeholder: Option<Cow<'static, str>>,
+    pub multiple: bool,
+    pub required: bool,
+    pub attrs: Attrs,
+}
+
+impl Select {
+    pub fn new(name: impl Into<Cow<'static, str>>, label: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: name.into(),
+            label: label.into(),
+            hint: None,
+            placeholder: None,
+            multiple: false,
+            required: false,
+            attrs: Attrs::new(),
+        }
+    }
+
+    pub fn with_hint(mut self, hint: impl Into<Cow<'static, str>>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    pub fn without_hint(mut self) -> Self {
+        self.hint = None;
+        self
+    }
+
+    pub fn with_placeholder(mut self, placeholder: impl Into<Cow<'static, str>>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    pub fn without_placeholder(mut self) -> Self {
+        self.placeholder = None;
+        self
+    }
+
+    pub fn with_multiple(mut self, multiple: bool) -> Self {
+        self.multiple = multiple;
+        self
+    }
+
+    pub fn with_required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    pub fn with_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs = attrs;
+        self
+    }
+
+    pub fn render<'a>(
+        &'a self,
+        options: impl IntoIterator<Item = SelectOption<'a>>,
+        errors: &'a ValidationErrors,
+    ) -> impl Renderable + 'a {
+        let options: Vec<_> = options.into_iter().collect();
+        let invalid = errors.has_field(&self.name);
+        self.attrs.spread(rsx! {
+            <wa-select
+                name=(self.name)
+                label=(self.label)
+                hint=[self.hint.as_ref().filter(|_| !invalid)]
+                placeholder=[self.placeholder.as_ref()]
+                multiple[self.multiple]
+                required[self.required]
+                data-invalid[invalid]
+                aria-invalid=[invalid.then_some("true")]
+            >
+                @for option in &options {
+                    <wa-option value=(option.value) selected[option.selected] disabled[option.disabled]>
+                        (option.label)
+                    </wa-option>
+                }
+                (FieldHint { name: &self.name, hint: self.hint.as_deref(), errors })
+            </wa-select>
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    #[default]
+    Vertical,
+    Horizontal,
+}
+
+impl Orientation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Orientation::Vertical => "vertical",
+            Orientation::Horizontal => "horizontal",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RadioGroup {
+    pub name: Cow<'static, str>,
+    pub label: Cow<'static, str>,
+    pub hint: Option<Cow<'static, str>>,
+    pub orientation: Orientation,
+    pub required: bool,
+    pub attrs: Attrs,
+}
+
+impl RadioGroup {
+    pub fn new(name: impl Into<Cow<'static, str>>, label: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: name.into(),
+            label: label.into(),
+            hint: None,
+            orientation: Orientation::Vertical,
+            required: false,
+            attrs: Attrs::new(),
+        }
+    }
+
+    pub fn with_hint(mut self, hint: impl Into<Cow<'static, str>>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    pub fn without_hint(mut self) -> Self {
+        self.hint = None;
+        self
+    }
+
+    pub fn with_orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    pub fn with_required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    pub fn with_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs = attrs;
+        self
+    }
+
+    pub fn render<'a>(
+        &'a self,
+        options: impl IntoIterator<Item = SelectOption<'a>>,
+        errors: &'a ValidationErrors,
+    ) -> impl Renderable + 'a {
+        let options: Vec<_> = options.into_iter().collect();
+        let value = options
+            .iter()
+            .find(|option| option.selected)
+            .map(|option| option.value.clone());
+        let invalid = errors.has_field(&self.name);
+        self.attrs.spread(rsx! {
+            <wa-radio-group
+                name=(self.name)
+                label=(self.label)
+                value=[value.as_ref()]
+                hint=[self.hint.as_ref().filter(|_| !invalid)]
+                orientation=(self.orientation.as_str())
+                required[self.required]
+                data-invalid[invalid]
+                aria-invalid=[invalid.then_some("true")]
+            >
+                @for option in &options {
+                    <wa-radio value=(option.value) disabled[option.disabled]>(option.label)</wa-radio>
+                }
+                (FieldHint { name: &self.name, hint: self.hint.as_deref(), errors })
+            </wa-radio-group>
+        })
+    }
+}
@@ -0,0 +1,127 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use hypertext::prelude::GlobalAttributes;
+use hypertext::{Renderable, rsx};
+
+use crate::component::ErasedRenderable;
+use crate::{Children, hypertext_elements};
+
+type SlotContent<'s> = Box<dyn ErasedRenderable + 's>;
+
+#[derive(Default)]
+pub struct Slots<'s> {
+    children: Option<SlotContent<'s>>,
+    named: Vec<(Cow<'static, str>, SlotContent<'s>)>,
+}
+
+impl<'s> Slots<'s> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_children(mut self, children: impl Renderable + 's) -> Self {
+        self.children = Some(Box::new(children));
+        self
+    }
+
+    pub fn with_slot(mut self, name: impl Into<Cow<'static, str>>, content: impl Renderable + 's) -> Self {
+        let name = name.into();
+        self.named.retain(|(existing, _)| *existing != name);
+        self.named.push((name, Box::new(content)));
+        self
+    }
+
+    pub fn without_slot(mut self, name: &str) -> Self {
+        self.named.retain(|(existing, _)| existing != name);
+        self
+    }
+
+    pub fn children(&self) -> Option<Children<'_>> {
+        self.children.as_deref().map(Children::erased)
+    }
+
+    pub fn get(&self, name: &str) -> Option<Children<'_>> {
+        self.named
+            .iter()
+            .find(|(existing, _)| existing == name)
+            .map(|(_, content)| Children::erased(content.as_ref()))
+    }
+
+    pub fn has(&self, name: &str) -> bool {
+        self.named.iter().any(|(existing, _)| existing == name)
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.named.iter().map(|(name, _)| name.as_ref())
+    }
+
+    /// Renders the default (unnamed) content, falling back to `default` when none was given.
+    pub fn render_children_or(&self, default: impl Renderable) -> impl Renderable {
+        rsx! {
+            @if let Some(children) = self.children() {
+                (children)
+            } @else {
+                (default)
+            }
+        }
+    }
+
+    /// Renders the named slot wrapped in a `<div slot="...">`, or nothing when it is not set.
+    pub fn render(&self, name: &str) -> impl Renderable {
+        rsx! {
+            @if let Some(content) = self.get(name) {
+                <div slot=(name)>(content)</div>
+            }
+        }
+    }
+
+    pub fn render_or(&self, name: &str, default: impl Renderable) -> impl Renderable {
+        rsx! {
+            <div slot=(name)>
+                @if let Some(content) = self.get(name) {
+                    (content)
+                } @else {
+                    (default)
+                }
+            </div>
+        }
+    }
+}
+
+impl fmt::Debug for Slots<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Slots")
+            .field("children", &self.children.is_some())
+            .field("named", &self.names().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slots() {
+        let slots = Slots::new()
+            .with_children("body")
+            .with_slot("footer", "first")
+            .with_slot("footer", "second");
+
+        assert!(slots.has("footer"));
+        assert!(!slots.has("header"));
+        assert_eq!(slots.names().collect::<Vec<_>>(), ["footer"]);
+        assert_eq!(
+            slots.render("footer").render().as_inner(),
+            r#"<div slot="footer">second</div>"#
+        );
+        assert_eq!(slots.render("header").render().as_inner(), "");
+        assert_eq!(
+            slots.render_or("header", "Title").render().as_inner(),
+            r#"<div slot="header">Title</div>"#
+        );
+        assert_eq!(slots.render_children_or("empty").render().as_inner(), "body");
+        assert_eq!(Slots::new().render_children_or("empty").render().as_inner(), "empty");
+    }
+}
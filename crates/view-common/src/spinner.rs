@@ -0,0 +1,194 @@
+use std::borrow::Cow;
+
+use hypertext::prelude::{AriaAttributes, GlobalAttributes};
+use hypertext::{Renderable, rsx};
+
+use crate::{Attrs, ButtonType, Size, Variant, hypertext_elements};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Spinner {
+    pub size: Size,
+    pub variant: Option<Variant>,
+    pub label: Cow<'static, str>,
+    pub attrs: Attrs,
+}
+
+impl Spinner {
+    pub fn new() -> Self {
+        Self {
+            size: Size::Medium,
+            variant: None,
+            label: Cow::Borrowed("Loading"),
+            attrs: Attrs::new(),
+        }
+    }
+
+    pub fn with_size(mut self, size: Size) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn with_variant(mut self, variant: Variant) -> Self {
+        self.variant = Some(variant);
+        self
+    }
+
+    pub fn without_variant(mut self) -> Self {
+        self.variant = None;
+        self
+    }
+
+    pub fn with_label(mut self, label: impl Into<Cow<'static, str>>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    pub fn with_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs = attrs;
+        self
+    }
+
+    pub fn render(&self) -> impl Renderable {
+        self.attrs.spread(rsx! {
+            <wa-spinner style=(self.style()) aria-label=(self.label)></wa-spinner>
+        })
+    }
+
+    /// Renders the spinner as an htmx request indicator, to be referenced with `hx-indicator="#{id}"`.
+    pub fn render_indicator(&self, id: &str) -> impl Renderable {
+        rsx! {
+            <span id=(id) class="htmx-indicator">(self.render())</span>
+        }
+    }
+
+    fn style(&self) -> String {
+        let font_size = match self.size {
+            Size::Small => "var(--wa-font-size-s)",
+            Size::Medium => "var(--wa-font-size-m)",
+            Size::Large => "var(--wa-font-size-xl)",
+        };
+
+        match self.variant {
+            Some(variant) => format!("font-size: {font_size}; --indicator-color: var(--wa-color-{variant}-fill-loud);"),
+            None => format!("font-size: {font_size};"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Busy {
+    pub busy: bool,
+    pub spinner: Spinner,
+}
+
+impl Busy {
+    pub fn new(busy: bool) -> Self {
+        Self {
+            busy,
+            spinner: Spinner::new(),
+        }
+    }
+
+    pub fn with_spinner(mut self, spinner: Spinner) -> Self {
+        self.spinner = spinner;
+        self
+    }
+
+    pub fn render(&self, children: impl Renderable) -> impl Renderable {
+        rsx! {
+            <div aria-busy=(self.busy) aria-live="polite">
+                @if self.busy {
+                    (self.spinner.render())
+                }
+                (children)
+            </div>
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LoadingButton {
+    pub loading: bool,
+    pub variant: Variant,
+    pub button_type: ButtonType,
+}
+
+impl LoadingButton {
+    pub fn new() -> Self {
+        Self {
+            loading: false,
+            variant: Variant::Brand,
+            button_type: ButtonType::Submit,
+        }
+    }
+
+    pub fn with_loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    pub fn with_variant(mut self, variant: Variant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    pub fn with_type(mut self, button_type: ButtonType) -> Self {
+        self.button_type = button_type;
+        self
+    }
+
+    pub fn render(&self, label: impl Renderable) -> impl Renderable {
+        rsx! {
+            <wa-button type=(self.button_type.as_str()) variant=(self.variant) loading[self.loading] aria-busy=(self.loading)>
+                (label)
+            </wa-button>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{assert_has_selector, assert_no_selector, assert_selector_count};
+
+    #[test]
+    fn test_render_spinner() {
+        let html = Spinner::new().render().render().into_inner();
+        assert_has_selector(&html, "wa-spinner[aria-label=Loading]");
+
+        let html = Spinner::new()
+            .with_attrs(Attrs::new().with_aria("label", "Saving"))
+            .render_indicator("save-indicator")
+            .render()
+            .into_inner();
+        assert_has_selector(
+            &html,
+            "span#save-indicator.htmx-indicator > wa-spinner[aria-label=Saving]",
+        );
+        assert_no_selector(&html, "wa-spinner[aria-label=Loading]");
+    }
+
+    #[test]
+    fn test_render_busy() {
+        let html = Busy::new(true).render("Results").render().into_inner();
+        assert_has_selector(&html, "div[aria-busy=true][aria-live=polite] > wa-spinner");
+
+        let html = Busy::new(false).render("Results").render().into_inner();
+        assert_has_selector(&html, "div[aria-busy=false]");
+        assert_selector_count(&html, "wa-spinner", 0);
+    }
+
+    #[test]
+    fn test_render_loading_button() {
+        let html = LoadingButton::new()
+            .with_loading(true)
+            .render("Save")
+            .render()
+            .into_inner();
+        assert_has_selector(&html, "wa-button[type=submit][loading][aria-busy=true]");
+
+        let html = LoadingButton::new().render("Save").render().into_inner();
+        assert_has_selector(&html, "wa-button[aria-busy=false]");
+        assert_no_selector(&html, "wa-button[loading]");
+    }
+}
@@ -0,0 +1,264 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use hypertext::prelude::{AriaAttributes, GlobalAttributes};
+use hypertext::{Buffer, Renderable, rsx};
+use zabawa_validation::percent_encode;
+
+use crate::{Attrs, Classes, hypertext_elements};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SortDirection {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortDirection::Ascending => "asc",
+            SortDirection::Descending => "desc",
+        }
+    }
+
+    pub fn aria_sort(&self) -> &'static str {
+        match self {
+            SortDirection::Ascending => "ascending",
+            SortDirection::Descending => "descending",
+        }
+    }
+
+    pub fn reversed(&self) -> Self {
+        match self {
+            SortDirection::Ascending => SortDirection::Descending,
+            SortDirection::Descending => SortDirection::Ascending,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Sort<'a> {
+    pub key: Cow<'a, str>,
+    pub direction: SortDirection,
+}
+
+impl<'a> Sort<'a> {
+    pub fn new(key: impl Into<Cow<'a, str>>, direction: SortDirection) -> Self {
+        Self {
+            key: key.into(),
+            direction,
+        }
+    }
+}
+
+type CellRenderer<'a, T> = Box<dyn Fn(&T, &mut Buffer) + 'a>;
+
+pub struct Column<'a, T> {
+    pub header: Cow<'a, str>,
+    pub sort_key: Option<Cow<'a, str>>,
+    cell: CellRenderer<'a, T>,
+}
+
+impl<'a, T> Column<'a, T> {
+    pub fn new<R: Renderable>(header: impl Into<Cow<'a, str>>, cell: impl Fn(&T) -> R + 'a) -> Self {
+        Self {
+            header: header.into(),
+            sort_key: None,
+            cell: Box::new(move |row, buffer| cell(row).render_to(buffer)),
+        }
+    }
+
+    pub fn sortable(mut self, key: impl Into<Cow<'a, str>>) -> Self {
+        self.sort_key = Some(key.into());
+        self
+    }
+}
+
+impl<T> fmt::Debug for Column<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Column")
+            .field("header", &self.header)
+            .field("sort_key", &self.sort_key)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug)]
+pub struct Table<'a, T> {
+    pub columns: Vec<Column<'a, T>>,
+    pub zebra: bool,
+    pub empty_message: Cow<'a, str>,
+    pub sort: Option<Sort<'a>>,
+    pub sort_href: Cow<'a, str>,
+    pub attrs: Attrs,
+}
+
+impl<'a, T> Table<'a, T> {
+    pub fn new() -> Self {
+        Self {
+            columns: Vec::new(),
+            zebra: false,
+            empty_message: Cow::Borrowed("No entries"),
+            sort: None,
+            sort_href: Cow::Borrowed(""),
+            attrs: Attrs::new(),
+        }
+    }
+
+    pub fn with_column(mut self, column: Column<'a, T>) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    pub fn with_zebra(mut self, enabled: bool) -> Self {
+        self.zebra = enabled;
+        self
+    }
+
+    pub fn with_empty_message(mut self, message: impl Into<Cow<'a, str>>) -> Self {
+        self.empty_message = message.into();
+        self
+    }
+
+    pub fn with_sort(mut self, sort: Sort<'a>) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    pub fn without_sort(mut self) -> Self {
+        self.sort = None;
+        self
+    }
+
+    /// Sets the URL sort links point to, typically the current page. Any `sort` and `order` query parameters it
+    /// already has are replaced, and its fragment is kept at the end.
+    pub fn with_sort_href(mut self, href: impl Into<Cow<'a, str>>) -> Self {
+        self.sort_href = href.into();
+        self
+    }
+
+    pub fn with_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs = attrs;
+        self
+    }
+
+    /// Renders rows lazily from the iterator, so rows are streamed straight into the output buffer.
+    pub fn render<'b, I>(&'b self, rows: I) -> impl Renderable + 'b
+    where
+        I: IntoIterator<Item = &'b T> + Clone + 'b,
+        T: 'b,
+    {
+        self.attrs.spread(rsx! {
+            <table class=[Classes::new().add_if(self.zebra, "wa-zebra-rows").non_empty()]>
+                <thead>
+                    <tr>
+                        @for column in &self.columns {
+                            @if let Some(key) = &column.sort_key {
+                                <th aria-sort=[self.aria_sort(key)]>
+                                    <a href=(self.sort_link(key))>(column.header)</a>
+                                </th>
+                            } @else {
+                                <th>(column.header)</th>
+                            }
+                        }
+                    </tr>
+                </thead>
+                <tbody>
+                    @if rows.clone().into_iter().next().is_none() {
+                        <tr class="empty">
+                            <td colspan=(self.columns.len())>(self.empty_message)</td>
+                        </tr>
+                    }
+                    @for row in rows.clone() {
+                        <tr>
+                            @for column in &self.columns {
+                                <td>(Cell { column, row })</td>
+                            }
+                        </tr>
+                    }
+                </tbody>
+            </table>
+        })
+    }
+
+    pub fn sort_link(&self, key: &str) -> String {
+        let direction = match &self.sort {
+            Some(sort) if sort.key == key => sort.direction.reversed(),
+            _ => SortDirection::Ascending,
+        };
+        let (base, fragment) = match self.sort_href.find('#') {
+            Some(index) => self.sort_href.split_at(index),
+            None => (self.sort_href.as_ref(), ""),
+        };
+        let (path, query) = base.split_once('?').unwrap_or((base, ""));
+
+        let mut href = path.to_string();
+        let mut separator = '?';
+        for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+            let name = pair.split_once('=').map_or(pair, |(name, _)| name);
+            if name != "sort" && name != "order" {
+                href.push(separator);
+                href.push_str(pair);
+                separator = '&';
+            }
+        }
+        href.push(separator);
+        href.push_str("sort=");
+        percent_encode(key, &mut href);
+        href.push_str("&order=");
+        href.push_str(direction.as_str());
+        href.push_str(fragment);
+        href
+    }
+
+    fn aria_sort(&self, key: &str) -> Option<&'static str> {
+        self.sort
+            .as_ref()
+            .filter(|sort| sort.key == key)
+            .map(|sort| sort.direction.aria_sort())
+    }
+}
+
+impl<T> Default for Table<'_, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct Cell<'c, 'a, T> {
+    column: &'c Column<'a, T>,
+    row: &'c T,
+}
+
+impl<T> Renderable for Cell<'_, '_, T> {
+    fn render_to(&self, buffer: &mut Buffer) {
+        (self.column.cell)(self.row, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sort_link() {
+        let table = Table::<()>::new().with_sort_href("/projects");
+        assert_eq!(table.sort_link("name"), "/projects?sort=name&order=asc");
+
+        let table = Table::<()>::new()
+            .with_sort_href("/projects?page=2")
+            .with_sort(Sort::new("created at", SortDirection::Ascending));
+        assert_eq!(
+            table.sort_link("created at"),
+            "/projects?page=2&sort=created%20at&order=desc"
+        );
+        assert_eq!(table.sort_link("a&b"), "/projects?page=2&sort=a%26b&order=asc");
+
+        let table = Table::<()>::new()
+            .with_sort_href("/items?sort=name&order=asc&page=3#list")
+            .with_sort(Sort::new("name", SortDirection::Ascending));
+        assert_eq!(table.sort_link("name"), "/items?page=3&sort=name&order=desc#list");
+        assert_eq!(table.sort_link("size"), "/items?page=3&sort=size&order=asc#list");
+    }
+}
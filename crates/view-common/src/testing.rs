@@ -0,0 +1,412 @@
+use thiserror::Error;
+
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track", "wbr",
+];
+const RAW_TEXT_ELEMENTS: &[&str] = &["script", "style"];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Element {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub text: String,
+    parent: Option<usize>,
+}
+
+impl Element {
+    pub fn attr(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(existing, _)| existing == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn has_attr(&self, name: &str) -> bool {
+        self.attr(name).is_some()
+    }
+
+    pub fn has_class(&self, class: &str) -> bool {
+        self.attr("class")
+            .is_some_and(|classes| classes.split_whitespace().any(|existing| existing == class))
+    }
+}
+
+/// A flat view of the elements in a rendered fragment, in document order.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Document {
+    elements: Vec<Element>,
+}
+
+impl Document {
+    pub fn parse(html: &str) -> Self {
+        let mut elements: Vec<Element> = Vec::new();
+        let mut open: Vec<usize> = Vec::new();
+        let mut rest = html;
+
+        while !rest.is_empty() {
+            let Some(start) = rest.find('<') else {
+                push_text(&mut elements, &open, rest);
+                break;
+            };
+            push_text(&mut elements, &open, &rest[..start]);
+            rest = &rest[start..];
+
+            if let Some(comment) = rest.strip_prefix("<!--") {
+                rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            } else if rest.starts_with("<!") || rest.starts_with("<?") {
+                rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+            } else if let Some(closing) = rest.strip_prefix("</") {
+                let end = closing.find('>').unwrap_or(closing.len());
+                let name = closing[..end].trim().to_ascii_lowercase();
+                if let Some(position) = open.iter().rposition(|&index| elements[index].name == name) {
+                    open.truncate(position);
+                }
+                rest = closing.get(end + 1..).unwrap_or("");
+            } else {
+                let (element, self_closing, remainder) = parse_start_tag(&rest[1..]);
+                let Some(mut element) = element else {
+                    push_text(&mut elements, &open, "<");
+                    rest = &rest[1..];
+                    continue;
+                };
+                element.parent = open.last().copied();
+                let name = element.name.clone();
+                elements.push(element);
+                rest = remainder;
+
+                if RAW_TEXT_ELEMENTS.contains(&name.as_str()) {
+                    let end_tag = format!("</{name}");
+                    let end = rest.find(&end_tag).unwrap_or(rest.len());
+                    let index = elements.len() - 1;
+                    elements[index].text.push_str(&rest[..end]);
+                    rest = &rest[end..];
+                    open.push(index);
+                } else if !self_closing && !VOID_ELEMENTS.contains(&name.as_str()) {
+                    open.push(elements.len() - 1);
+                }
+            }
+        }
+
+        Self { elements }
+    }
+
+    pub fn elements(&self) -> impl Iterator<Item = &Element> {
+        self.elements.iter()
+    }
+
+    /// Returns the elements matching `selector`.
+    ///
+    /// Supports type (`wa-icon`, `*`), `#id`, `.class`, `[attr]` and `[attr=value]` selectors combined with
+    /// descendant (` `) and child (`>`) combinators.
+    pub fn select(&self, selector: &str) -> Vec<&Element> {
+        let selector = Selector::parse(selector).unwrap_or_else(|error| panic!("{error}"));
+        (0..self.elements.len())
+            .filter(|&index| self.matches(index, &selector.parts))
+            .map(|index| &self.elements[index])
+            .collect()
+    }
+
+    fn matches(&self, index: usize, parts: &[(Combinator, Compound)]) -> bool {
+        let Some(((combinator, compound), ancestors)) = parts.split_last() else {
+            return true;
+        };
+        if !compound.matches(&self.elements[index]) {
+            return false;
+        }
+        if ancestors.is_empty() {
+            return true;
+        }
+
+        match combinator {
+            Combinator::Child => self.elements[index]
+                .parent
+                .is_some_and(|parent| self.matches(parent, ancestors)),
+            Combinator::Descendant => {
+                let mut parent = self.elements[index].parent;
+                while let Some(candidate) = parent {
+                    if self.matches(candidate, ancestors) {
+                        return true;
+                    }
+                    parent = self.elements[candidate].parent;
+                }
+                false
+            },
+        }
+    }
+}
+
+fn push_text(elements: &mut [Element], open: &[usize], text: &str) {
+    if text.is_empty() {
+        return;
+    }
+    let text = decode_entities(text);
+    for &index in open {
+        elements[index].text.push_str(&text);
+    }
+}
+
+fn parse_start_tag(input: &str) -> (Option<Element>, bool, &str) {
+    let name_end = input
+        .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
+        .unwrap_or(input.len());
+    let name = &input[..name_end];
+    if !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return (None, false, input);
+    }
+
+    let mut element = Element {
+        name: name.to_ascii_lowercase(),
+        attributes: Vec::new(),
+        text: String::new(),
+        parent: None,
+    };
+    let mut rest = &input[name_end..];
+
+    loop {
+        rest = rest.trim_start();
+        if let Some(remainder) = rest.strip_prefix("/>") {
+            return (Some(element), true, remainder);
+        }
+        if let Some(remainder) = rest.strip_prefix('>') {
+            return (Some(element), false, remainder);
+        }
+        if let Some(remainder) = rest.strip_prefix('/') {
+            rest = remainder;
+            continue;
+        }
+        if rest.is_empty() {
+            return (Some(element), false, rest);
+        }
+
+        let attr_end = rest
+            .find(|c: char| c.is_whitespace() || c == '=' || c == '>' || c == '/')
+            .unwrap_or(rest.len());
+        let attr_name = rest[..attr_end].to_ascii_lowercase();
+        rest = rest[attr_end..].trim_start();
+
+        let value = if let Some(remainder) = rest.strip_prefix('=') {
+            let remainder = remainder.trim_start();
+            let (value, remainder) = match remainder.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let inner = &remainder[1..];
+                    let end = inner.find(quote).unwrap_or(inner.len());
+                    (&inner[..end], inner.get(end + 1..).unwrap_or(""))
+                },
+                _ => {
+                    let end = remainder
+                        .find(|c: char| c.is_whitespace() || c == '>')
+                        .unwrap_or(remainder.len());
+                    (&remainder[..end], &remainder[end..])
+                },
+            };
+            rest = remainder;
+            decode_entities(value)
+        } else {
+            String::new()
+        };
+        element.attributes.push((attr_name, value));
+    }
+}
+
+fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_owned();
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&nbsp;", "\u{a0}")
+        .replace("&amp;", "&")
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Combinator {
+    Descendant,
+    Child,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct Compound {
+    name: Option<String>,
+    id: Option<String>,
+    classes: Vec<String>,
+    attributes: Vec<(String, Option<String>)>,
+}
+
+impl Compound {
+    fn matches(&self, element: &Element) -> bool {
+        self.name.as_ref().is_none_or(|name| *name == element.name)
+            && self.id.as_ref().is_none_or(|id| element.attr("id") == Some(id))
+            && self.classes.iter().all(|class| element.has_class(class))
+            && self.attributes.iter().all(|(name, value)| match value {
+                Some(value) => element.attr(name) == Some(value),
+                None => element.has_attr(name),
+            })
+    }
+}
+
+#[derive(Error, Clone, Debug, PartialEq, Eq)]
+#[error("invalid selector `{selector}`: {reason}")]
+pub struct SelectorError {
+    selector: String,
+    reason: &'static str,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Selector {
+    parts: Vec<(Combinator, Compound)>,
+}
+
+impl Selector {
+    fn parse(selector: &str) -> Result<Self, SelectorError> {
+        let error = |reason| SelectorError {
+            selector: selector.to_owned(),
+            reason,
+        };
+        let is_ident = |c: char| c.is_alphanumeric() || matches!(c, '-' | '_');
+
+        let mut parts = Vec::new();
+        let mut combinator = Combinator::Descendant;
+        let mut chars = selector.trim().chars().peekable();
+        let mut compound: Option<Compound> = None;
+
+        while let Some(c) = chars.next() {
+            match c {
+                c if c.is_whitespace() || c == '>' => {
+                    if let Some(done) = compound.take() {
+                        parts.push((combinator, done));
+                        combinator = Combinator::Descendant;
+                    }
+                    if c == '>' {
+                        if parts.is_empty() {
+                            return Err(error("child combinator without a parent"));
+                        }
+                        combinator = Combinator::Child;
+                    }
+                },
+                '#' | '.' => {
+                    let mut ident = String::new();
+                    while let Some(&next) = chars.peek().filter(|&&next| is_ident(next)) {
+                        ident.push(next);
+                        chars.next();
+                    }
+                    if ident.is_empty() {
+                        return Err(error("expected an identifier"));
+                    }
+                    let current = compound.get_or_insert_with(Compound::default);
+                    if c == '#' {
+                        current.id = Some(ident);
+                    } else {
+                        current.classes.push(ident);
+                    }
+                },
+                '[' => {
+                    let mut inner = String::new();
+                    for next in chars.by_ref() {
+                        if next == ']' {
+                            break;
+                        }
+                        inner.push(next);
+                    }
+                    let (name, value) = match inner.split_once('=') {
+                        Some((name, value)) => {
+                            let value = value.trim();
+                            let value = value
+                                .strip_prefix('"')
+                                .and_then(|value| value.strip_suffix('"'))
+                                .or_else(|| value.strip_prefix('\'').and_then(|value| value.strip_suffix('\'')))
+                                .unwrap_or(value);
+                            (name.trim(), Some(value.to_owned()))
+                        },
+                        None => (inner.trim(), None),
+                    };
+                    if name.is_empty() {
+                        return Err(error("expected an attribute name"));
+                    }
+                    let current = compound.get_or_insert_with(Compound::default);
+                    current.attributes.push((name.to_ascii_lowercase(), value));
+                },
+                '*' => {
+                    compound.get_or_insert_with(Compound::default);
+                },
+                c if is_ident(c) => {
+                    let mut name = String::from(c);
+                    while let Some(&next) = chars.peek().filter(|&&next| is_ident(next)) {
+                        name.push(next);
+                        chars.next();
+                    }
+                    let current = compound.get_or_insert_with(Compound::default);
+                    current.name = Some(name.to_ascii_lowercase());
+                },
+                _ => return Err(error("unsupported character")),
+            }
+        }
+
+        match compound {
+            Some(done) => parts.push((combinator, done)),
+            None if parts.is_empty() => return Err(error("empty selector")),
+            None => return Err(error("dangling combinator")),
+        }
+        Ok(Self { parts })
+    }
+}
+
+#[track_caller]
+pub fn assert_has_selector(html: impl AsRef<str>, selector: &str) {
+    let html = html.as_ref();
+    if Document::parse(html).select(selector).is_empty() {
+        panic!("expected an element matching `{selector}` in:\n{html}");
+    }
+}
+
+#[track_caller]
+pub fn assert_no_selector(html: impl AsRef<str>, selector: &str) {
+    let html = html.as_ref();
+    let count = Document::parse(html).select(selector).len();
+    if count > 0 {
+        panic!("expected no element matching `{selector}`, found {count} in:\n{html}");
+    }
+}
+
+#[track_caller]
+pub fn assert_selector_count(html: impl AsRef<str>, selector: &str, expected: usize) {
+    let html = html.as_ref();
+    let count = Document::parse(html).select(selector).len();
+    if count != expected {
+        panic!("expected {expected} elements matching `{selector}`, found {count} in:\n{html}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HTML: &str = r#"<!DOCTYPE html><ul id="menu" class="menu wa-stack"><li data-value="a &amp; b"><wa-icon name="x"></wa-icon>One</li><li><br><input type=text disabled/><span class='two'>Two</span></li></ul><script>if (a < b) {}</script>"#;
+
+    #[test]
+    fn test_select() {
+        let document = Document::parse(HTML);
+        assert_eq!(document.select("li").len(), 2);
+        assert_eq!(document.select("#menu.menu > li > wa-icon[name=x]").len(), 1);
+        assert_eq!(document.select("ul wa-icon").len(), 1);
+        assert_eq!(document.select("ul > wa-icon").len(), 0);
+        assert_eq!(document.select("li[data-value='a & b']")[0].text, "One");
+        assert_eq!(document.select("input[disabled]").len(), 1);
+        assert_eq!(document.select("li span.two")[0].text, "Two");
+        assert_eq!(document.select("ul")[0].text, "OneTwo");
+        assert_eq!(document.select("script")[0].text, "if (a < b) {}");
+        assert_eq!(document.select("*").len(), 8);
+    }
+
+    #[test]
+    fn test_assertions() {
+        assert_has_selector(HTML, "ul.wa-stack li");
+        assert_no_selector(HTML, "wa-callout");
+        assert_selector_count(HTML, "li", 2);
+        assert!(Selector::parse("> li").is_err());
+        assert!(Selector::parse("li >").is_err());
+        assert!(Selector::parse("").is_err());
+    }
+}
@@ -0,0 +1,140 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::str::FromStr;
+
+use hypertext::prelude::{AriaAttributes, GlobalAttributes};
+use hypertext::{Renderable, rsx};
+use thiserror::Error;
+
+use crate::{Appearance, Button, ButtonType, Icon, RenderContext, hypertext_elements};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ColorScheme {
+    Light,
+    Dark,
+    #[default]
+    System,
+}
+
+impl ColorScheme {
+    pub const ALL: [ColorScheme; 3] = [ColorScheme::Light, ColorScheme::Dark, ColorScheme::System];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ColorScheme::Light => "light",
+            ColorScheme::Dark => "dark",
+            ColorScheme::System => "system",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ColorScheme::Light => "Light",
+            ColorScheme::Dark => "Dark",
+            ColorScheme::System => "System",
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            ColorScheme::Light => "sun",
+            ColorScheme::Dark => "moon",
+            ColorScheme::System => "desktop",
+        }
+    }
+}
+
+impl fmt::Display for ColorScheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("unknown color scheme")]
+pub struct UnknownColorSchemeError;
+
+impl FromStr for ColorScheme {
+    type Err = UnknownColorSchemeError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.trim() {
+            "light" => Ok(ColorScheme::Light),
+            "dark" => Ok(ColorScheme::Dark),
+            "system" | "" => Ok(ColorScheme::System),
+            _ => Err(UnknownColorSchemeError),
+        }
+    }
+}
+
+/// Attributes for the root `<html>` element derived from the server-known color scheme preference.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ThemeAttr {
+    pub scheme: ColorScheme,
+}
+
+impl ThemeAttr {
+    pub fn new(scheme: ColorScheme) -> Self {
+        Self { scheme }
+    }
+
+    pub fn class(&self) -> Option<&'static str> {
+        match self.scheme {
+            ColorScheme::Light => Some("wa-light"),
+            ColorScheme::Dark => Some("wa-dark"),
+            ColorScheme::System => None,
+        }
+    }
+
+    pub fn data_theme(&self) -> &'static str {
+        self.scheme.as_str()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ThemeToggle {
+    pub action: Cow<'static, str>,
+    pub field_name: Cow<'static, str>,
+}
+
+impl ThemeToggle {
+    pub fn new(action: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            action: action.into(),
+            field_name: Cow::Borrowed("theme"),
+        }
+    }
+
+    pub fn with_field_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.field_name = name.into();
+        self
+    }
+
+    /// Renders one submit button per scheme; without scripts the `<noscript>` select still posts the preference.
+    pub fn render(&self, ctx: &RenderContext) -> impl Renderable {
+        let current = ctx.theme;
+        rsx! {
+            <form method="post" action=(self.action) class="theme-toggle">
+                <wa-button-group label="Color scheme">
+                    @for scheme in ColorScheme::ALL {
+                        (Button::new()
+                            .with_type(ButtonType::Submit)
+                            .with_name(self.field_name.clone())
+                            .with_value(scheme.as_str())
+                            .with_appearance(if scheme == current { Appearance::Filled } else { Appearance::Plain })
+                            .with_pressed(Some(scheme == current))
+                            .render(Icon::new(scheme.icon()).with_label(scheme.label()).render()))
+                    }
+                </wa-button-group>
+                <noscript>
+                    <select name=(self.field_name) aria-label="Color scheme">
+                        @for scheme in ColorScheme::ALL {
+                            <option value=(scheme.as_str()) selected[scheme == current]>(scheme.label())</option>
+                        }
+                    </select>
+                    <button type="submit">"Apply"</button>
+                </noscript>
+            </form>
+        }
+    }
+}
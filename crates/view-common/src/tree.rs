@@ -0,0 +1,164 @@
+use std::fmt;
+
+use hypertext::{Buffer, Renderable, rsx};
+
+use crate::{Attrs, hypertext_elements};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TreeSelection {
+    #[default]
+    Single,
+    Multiple,
+    Leaf,
+}
+
+impl TreeSelection {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TreeSelection::Single => "single",
+            TreeSelection::Multiple => "multiple",
+            TreeSelection::Leaf => "leaf",
+        }
+    }
+}
+
+type LabelRenderer<'a, T> = Box<dyn Fn(&T, &mut Buffer) + 'a>;
+type ChildrenAccessor<'a, T> = Box<dyn for<'n> Fn(&'n T) -> &'n [T] + 'a>;
+type NodePredicate<'a, T> = Box<dyn Fn(&T) -> bool + 'a>;
+
+pub struct Tree<'a, T> {
+    pub selection: TreeSelection,
+    pub attrs: Attrs,
+    label: LabelRenderer<'a, T>,
+    children: ChildrenAccessor<'a, T>,
+    expanded: Option<NodePredicate<'a, T>>,
+    selected: Option<NodePredicate<'a, T>>,
+}
+
+impl<'a, T> Tree<'a, T> {
+    pub fn new<R: Renderable>(label: impl Fn(&T) -> R + 'a, children: impl for<'n> Fn(&'n T) -> &'n [T] + 'a) -> Self {
+        Self {
+            selection: TreeSelection::Single,
+            attrs: Attrs::new(),
+            label: Box::new(move |node, buffer| label(node).render_to(buffer)),
+            children: Box::new(children),
+            expanded: None,
+            selected: None,
+        }
+    }
+
+    pub fn with_selection(mut self, selection: TreeSelection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Nodes for which `expanded` returns true are rendered open; all others start collapsed.
+    pub fn with_expanded(mut self, expanded: impl Fn(&T) -> bool + 'a) -> Self {
+        self.expanded = Some(Box::new(expanded));
+        self
+    }
+
+    pub fn with_selected(mut self, selected: impl Fn(&T) -> bool + 'a) -> Self {
+        self.selected = Some(Box::new(selected));
+        self
+    }
+
+    pub fn with_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs = attrs;
+        self
+    }
+
+    pub fn render<'b>(&'b self, roots: &'b [T]) -> impl Renderable + 'b {
+        self.attrs.spread(rsx! {
+            <wa-tree selection=(self.selection.as_str())>
+                @for node in roots {
+                    (TreeItem { tree: self, node })
+                }
+            </wa-tree>
+        })
+    }
+
+    fn is_expanded(&self, node: &T) -> bool {
+        self.expanded.as_ref().is_some_and(|expanded| expanded(node))
+    }
+
+    fn is_selected(&self, node: &T) -> bool {
+        self.selected.as_ref().is_some_and(|selected| selected(node))
+    }
+}
+
+impl<T> fmt::Debug for Tree<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tree")
+            .field("selection", &self.selection)
+            .field("attrs", &self.attrs)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A single node of a [`Tree`], rendered recursively together with its children.
+pub struct TreeItem<'t, 'a, T> {
+    tree: &'t Tree<'a, T>,
+    node: &'t T,
+}
+
+impl<T> Renderable for TreeItem<'_, '_, T> {
+    fn render_to(&self, buffer: &mut Buffer) {
+        let Self { tree, node } = *self;
+        let label = Label { tree, node };
+        rsx! {
+            <wa-tree-item expanded[tree.is_expanded(node)] selected[tree.is_selected(node)]>
+                (label)
+                @for child in (tree.children)(node) {
+                    (TreeItem { tree, node: child })
+                }
+            </wa-tree-item>
+        }
+        .render_to(buffer);
+    }
+}
+
+struct Label<'t, 'a, T> {
+    tree: &'t Tree<'a, T>,
+    node: &'t T,
+}
+
+impl<T> Renderable for Label<'_, '_, T> {
+    fn render_to(&self, buffer: &mut Buffer) {
+        (self.tree.label)(self.node, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{Document, assert_selector_count};
+
+    struct Folder {
+        name: &'static str,
+        children: Vec<Folder>,
+    }
+
+    fn folder(name: &'static str, children: Vec<Folder>) -> Folder {
+        Folder { name, children }
+    }
+
+    #[test]
+    fn test_render_nested() {
+        let roots = [
+            folder("docs", vec![folder("guides", vec![folder("setup", vec![])])]),
+            folder("src", vec![]),
+        ];
+        let tree = Tree::new(|folder: &Folder| folder.name, |folder: &Folder| &folder.children)
+            .with_expanded(|folder| folder.name == "docs")
+            .with_selected(|folder| folder.name == "setup");
+        let html = tree.render(&roots).render().into_inner();
+
+        assert_selector_count(&html, "wa-tree[selection=single] > wa-tree-item", 2);
+        assert_selector_count(&html, "wa-tree-item wa-tree-item wa-tree-item[selected]", 1);
+        let document = Document::parse(&html);
+        let expanded = document.select("wa-tree-item[expanded]");
+        assert_eq!(expanded.len(), 1);
+        assert_eq!(expanded[0].text, "docsguidessetup");
+    }
+}
@@ -0,0 +1,91 @@
+use std::fmt;
+
+use hypertext::context::Context;
+use hypertext::{Buffer, Renderable};
+
+macro_rules! impl_keyword {
+    ($ty:ty) => {
+        impl fmt::Display for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl<C: Context> Renderable<C> for $ty
+        where
+            str: Renderable<C>,
+        {
+            fn render_to(&self, buffer: &mut Buffer<C>) {
+                self.as_str().render_to(buffer);
+            }
+        }
+    };
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Variant {
+    Brand,
+    #[default]
+    Neutral,
+    Success,
+    Warning,
+    Danger,
+}
+
+impl Variant {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Variant::Brand => "brand",
+            Variant::Neutral => "neutral",
+            Variant::Success => "success",
+            Variant::Warning => "warning",
+            Variant::Danger => "danger",
+        }
+    }
+}
+
+impl_keyword!(Variant);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Size {
+    Small,
+    #[default]
+    Medium,
+    Large,
+}
+
+impl Size {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Size::Small => "small",
+            Size::Medium => "medium",
+            Size::Large => "large",
+        }
+    }
+}
+
+impl_keyword!(Size);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Appearance {
+    #[default]
+    Accent,
+    Filled,
+    Outlined,
+    FilledOutlined,
+    Plain,
+}
+
+impl Appearance {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Appearance::Accent => "accent",
+            Appearance::Filled => "filled",
+            Appearance::Outlined => "outlined",
+            Appearance::FilledOutlined => "filled outlined",
+            Appearance::Plain => "plain",
+        }
+    }
+}
+
+impl_keyword!(Appearance);
@@ -0,0 +1,123 @@
+use std::borrow::Cow;
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Breakpoint {
+    Sm,
+    Md,
+    Lg,
+    Xl,
+}
+
+impl Breakpoint {
+    pub const ALL: [Breakpoint; 4] = [Breakpoint::Sm, Breakpoint::Md, Breakpoint::Lg, Breakpoint::Xl];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Breakpoint::Sm => "sm",
+            Breakpoint::Md => "md",
+            Breakpoint::Lg => "lg",
+            Breakpoint::Xl => "xl",
+        }
+    }
+
+    pub fn min_width(&self) -> u16 {
+        match self {
+            Breakpoint::Sm => 640,
+            Breakpoint::Md => 768,
+            Breakpoint::Lg => 1024,
+            Breakpoint::Xl => 1280,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Visibility {
+    /// Hidden on viewports narrower than the breakpoint.
+    HideBelow(Breakpoint),
+    /// Hidden on viewports at least as wide as the breakpoint.
+    HideFrom(Breakpoint),
+}
+
+impl Visibility {
+    pub fn hide_below(breakpoint: Breakpoint) -> Self {
+        Visibility::HideBelow(breakpoint)
+    }
+
+    pub fn hide_from(breakpoint: Breakpoint) -> Self {
+        Visibility::HideFrom(breakpoint)
+    }
+
+    pub fn show_below(breakpoint: Breakpoint) -> Self {
+        Visibility::HideFrom(breakpoint)
+    }
+
+    pub fn show_from(breakpoint: Breakpoint) -> Self {
+        Visibility::HideBelow(breakpoint)
+    }
+
+    pub fn class(&self) -> &'static str {
+        match self {
+            Visibility::HideBelow(Breakpoint::Sm) => "hide-below-sm",
+            Visibility::HideBelow(Breakpoint::Md) => "hide-below-md",
+            Visibility::HideBelow(Breakpoint::Lg) => "hide-below-lg",
+            Visibility::HideBelow(Breakpoint::Xl) => "hide-below-xl",
+            Visibility::HideFrom(Breakpoint::Sm) => "hide-from-sm",
+            Visibility::HideFrom(Breakpoint::Md) => "hide-from-md",
+            Visibility::HideFrom(Breakpoint::Lg) => "hide-from-lg",
+            Visibility::HideFrom(Breakpoint::Xl) => "hide-from-xl",
+        }
+    }
+
+    pub fn media_query(&self) -> String {
+        match self {
+            Visibility::HideBelow(breakpoint) => format!("(max-width: {}px)", breakpoint.min_width() - 1),
+            Visibility::HideFrom(breakpoint) => format!("(min-width: {}px)", breakpoint.min_width()),
+        }
+    }
+
+    /// Returns the stylesheet backing the visibility classes, to be served or inlined once per page.
+    pub fn stylesheet() -> String {
+        Breakpoint::ALL
+            .into_iter()
+            .flat_map(|breakpoint| [Visibility::HideBelow(breakpoint), Visibility::HideFrom(breakpoint)])
+            .map(|visibility| {
+                format!(
+                    "@media {} {{ .{} {{ display: none !important; }} }}\n",
+                    visibility.media_query(),
+                    visibility.class()
+                )
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for Visibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.class())
+    }
+}
+
+impl From<Visibility> for Cow<'static, str> {
+    fn from(visibility: Visibility) -> Self {
+        Cow::Borrowed(visibility.class())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Classes;
+
+    #[test]
+    fn test_visibility_classes() {
+        let classes = Classes::new()
+            .add(Visibility::hide_below(Breakpoint::Md))
+            .add(Visibility::show_below(Breakpoint::Xl));
+        assert_eq!(classes.to_string(), "hide-below-md hide-from-xl");
+
+        let stylesheet = Visibility::stylesheet();
+        assert!(stylesheet.contains("@media (max-width: 767px) { .hide-below-md { display: none !important; } }"));
+        assert!(stylesheet.contains("@media (min-width: 1280px) { .hide-from-xl { display: none !important; } }"));
+    }
+}
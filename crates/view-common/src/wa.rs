@@ -0,0 +1,100 @@
+use hypertext::define_elements;
+
+// Web Awesome 3 custom elements and their attributes.
+define_elements! {
+    wa_animated_image { src alt play }
+    wa_animation {
+        name play delay direction duration easing end_delay fill iterations iteration_start playback_rate
+    }
+    wa_avatar { image label initials loading shape }
+    wa_badge { variant appearance pill attention }
+    wa_breadcrumb { label }
+    wa_breadcrumb_item { href target rel }
+    wa_button {
+        appearance variant size with_caret disabled loading pill href target rel download name value r#type form
+        formaction formenctype formmethod formnovalidate formtarget
+    }
+    wa_button_group { label orientation size variant }
+    wa_callout { variant appearance size }
+    wa_card { appearance orientation with_header with_media with_footer }
+    wa_carousel {
+        r#loop navigation pagination autoplay autoplay_interval slides_per_page slides_per_move orientation
+        mouse_dragging
+    }
+    wa_carousel_item
+    wa_checkbox { name value checked indeterminate hint size required disabled form }
+    wa_color_picker {
+        name value label hint format opacity uppercase size swatches without_format_toggle required disabled form
+    }
+    wa_comparison { position }
+    wa_copy_button {
+        value from disabled copy_label success_label error_label feedback_duration tooltip_placement
+    }
+    wa_details { summary open disabled appearance icon_placement name }
+    wa_dialog { label open light_dismiss without_header }
+    wa_divider { orientation }
+    wa_drawer { label open placement light_dismiss without_header }
+    wa_dropdown { open placement distance skidding size }
+    wa_dropdown_item { value variant r#type checked disabled submenu_open }
+    wa_format_bytes { value unit display lang }
+    wa_format_date {
+        date weekday era year month day hour minute second time_zone_name time_zone hour_format lang
+    }
+    wa_format_number {
+        value r#type without_grouping currency currency_display minimum_integer_digits minimum_fraction_digits
+        maximum_fraction_digits minimum_significant_digits maximum_significant_digits lang
+    }
+    wa_icon { name family library variant label fixed_width auto_width swap_opacity src }
+    wa_include { src mode allow_scripts }
+    wa_input {
+        name label r#type value hint placeholder size appearance pill with_clear password_toggle password_visible
+        without_spin_buttons required disabled readonly form pattern minlength maxlength min max step
+        autocapitalize autocorrect autocomplete autofocus enterkeyhint spellcheck inputmode with_label with_hint
+    }
+    wa_intersection_observer { root root_margin threshold intersect_class once disabled }
+    wa_mutation_observer { attr attr_old_value char_data char_data_old_value child_list disabled }
+    wa_option { value label selected disabled }
+    wa_page { mobile_breakpoint nav_open disable_sticky }
+    wa_popover { r#for open placement distance skidding without_arrow }
+    wa_popup {
+        anchor active placement boundary distance skidding arrow arrow_placement arrow_padding flip
+        flip_fallback_placements flip_fallback_strategy flip_boundary flip_padding shift shift_boundary
+        shift_padding auto_size sync auto_size_boundary auto_size_padding hover_bridge
+    }
+    wa_progress_bar { value indeterminate label }
+    wa_progress_ring { value label }
+    wa_qr_code { value label size fill background radius error_correction }
+    wa_radio { value size appearance disabled }
+    wa_radio_group { name label value hint orientation size required disabled form }
+    wa_rating { name label value max precision size readonly disabled }
+    wa_relative_time { date format numeric lang sync }
+    wa_resize_observer { disabled }
+    wa_scroller { orientation without_scrollbar without_shadow }
+    wa_select {
+        name label value hint placeholder size appearance pill with_clear multiple max_options_visible placement
+        required disabled form
+    }
+    wa_skeleton { effect }
+    wa_slider {
+        name label value hint min max step orientation size indicator_offset with_markers with_tooltip range
+        min_value max_value readonly disabled form
+    }
+    wa_spinner
+    wa_split_panel { position position_in_pixels orientation primary snap snap_threshold disabled }
+    wa_switch { name value checked hint size required disabled form }
+    wa_tab { panel disabled }
+    wa_tab_group { active placement activation without_scroll_controls }
+    wa_tab_panel { name active }
+    wa_tag { variant appearance size pill with_remove }
+    wa_textarea {
+        name label rows value hint placeholder size appearance resize required disabled readonly form minlength
+        maxlength autocapitalize autocorrect autocomplete autofocus enterkeyhint spellcheck inputmode
+    }
+    wa_tooltip { r#for open placement distance skidding show_delay hide_delay trigger without_arrow disabled }
+    wa_tree { selection }
+    wa_tree_item { expanded selected disabled lazy }
+    wa_zoomable_frame {
+        src srcdoc allowfullscreen loading referrerpolicy sandbox zoom zoom_levels without_controls
+        without_interaction
+    }
+}